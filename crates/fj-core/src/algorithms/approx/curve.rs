@@ -1,5 +1,3 @@
-use std::collections::BTreeMap;
-
 use fj_interop::Tolerance;
 use fj_math::{Circle, Line, Point};
 
@@ -7,12 +5,15 @@ use crate::{
     geometry::{
         CurveBoundary, Geometry, Path,
         repr::tri_mesh::convert_point_surface_to_global, surfaces::SweptCurve,
+        traits::GenTriMesh,
     },
-    storage::Handle,
+    storage::{Handle, ObjectId},
     topology::{Curve, Surface},
 };
 
-use super::{ApproxPoint, circle::approx_circle, line::approx_line};
+use super::{
+    ApproxPoint, circle::approx_circle, line::approx_line, shared::SharedMap,
+};
 
 /// Approximate the provided curve
 ///
@@ -26,23 +27,36 @@ pub fn approx_curve_with_cache(
     cache: &mut CurveApproxCache,
     geometry: &Geometry,
 ) -> CurveApprox {
-    match cache.get(curve, boundary) {
+    let tolerance = tolerance.into();
+
+    match cache.get(curve, boundary, tolerance) {
         Some(approx) => approx,
         None => {
-            let approx = approx_curve(
-                &geometry
-                    .of_curve(curve)
-                    .unwrap()
-                    .local_on(surface)
-                    .unwrap()
-                    .path,
-                geometry.of_surface(surface),
-                boundary,
-                tolerance,
-                geometry,
-            );
+            let path = &geometry
+                .of_curve(curve)
+                .unwrap()
+                .local_on(surface)
+                .unwrap()
+                .path;
+
+            let approx = match geometry.of_surface_if_defined(surface) {
+                Some(surface_geom) => approx_curve(
+                    path,
+                    surface_geom,
+                    boundary,
+                    tolerance,
+                    geometry,
+                ),
+                None => approx_curve_on_any_surface(
+                    path,
+                    geometry.of_surface_2(surface).unwrap().generator.as_ref(),
+                    boundary,
+                    tolerance,
+                    geometry,
+                ),
+            };
 
-            cache.insert(curve.clone(), boundary, approx)
+            cache.insert(curve.clone(), boundary, tolerance, approx)
         }
     }
 }
@@ -148,6 +162,69 @@ fn approx_line_on_any_surface(
     points
 }
 
+/// # Approximate a curve on a surface that only has new-style geometry
+///
+/// Such a surface can't be inspected, to choose the points of the
+/// approximation analytically. Instead, the curve is bisected, as long as the
+/// midpoint of a segment's chord deviates from the point on the curve at the
+/// middle of the segment by more than the tolerance.
+fn approx_curve_on_any_surface(
+    path: &Path<2>,
+    surface: &dyn GenTriMesh,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: impl Into<Tolerance>,
+    geometry: &Geometry,
+) -> CurveApprox {
+    // Limits the recursion, in case the curve is degenerate in some way we
+    // didn't anticipate. Each level halves the segment, so this is plenty.
+    const MAX_DEPTH: u32 = 32;
+
+    let tolerance = tolerance.into();
+
+    let approx_point = |point_curve: Point<1>| {
+        let point_global = convert_point_surface_to_global(
+            surface,
+            path.point_from_path_coords(point_curve),
+            tolerance,
+            geometry,
+        );
+        ApproxPoint::new(point_curve, point_global)
+    };
+
+    fn bisect(
+        approx_point: &impl Fn(Point<1>) -> ApproxPoint<1>,
+        [a, b]: [ApproxPoint<1>; 2],
+        tolerance: Tolerance,
+        depth: u32,
+        points: &mut Vec<ApproxPoint<1>>,
+    ) {
+        let middle =
+            approx_point(Point::from([(a.local_form.t + b.local_form.t) / 2.]));
+        let midpoint_of_chord =
+            a.global_form + (b.global_form - a.global_form) / 2.;
+
+        if depth < MAX_DEPTH
+            && midpoint_of_chord.distance_to(&middle.global_form)
+                > tolerance.inner()
+        {
+            bisect(approx_point, [a, middle], tolerance, depth + 1, points);
+            points.push(middle);
+            bisect(approx_point, [middle, b], tolerance, depth + 1, points);
+        }
+    }
+
+    let mut points = Vec::new();
+    bisect(
+        &approx_point,
+        boundary.inner.map(approx_point),
+        tolerance,
+        0,
+        &mut points,
+    );
+
+    CurveApprox { points }
+}
+
 /// Approximation of a [`Curve`], within a specific boundary
 ///
 /// The approximation of the curve only includes points _within_ the boundary,
@@ -167,9 +244,16 @@ impl CurveApprox {
 }
 
 /// Cache for curve approximations
-#[derive(Default)]
+///
+/// Approximations are keyed by curve, boundary, and tolerance. This makes it
+/// possible to use the same cache for approximations at different tolerances.
+///
+/// Curves are identified by their [`ObjectId`], so the cache doesn't keep them
+/// alive. Clones of a cache share the cached approximations.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct CurveApproxCache {
-    inner: BTreeMap<(Handle<Curve>, CurveBoundary<Point<1>>), CurveApprox>,
+    inner:
+        SharedMap<(ObjectId, CurveBoundary<Point<1>>, Tolerance), CurveApprox>,
 }
 
 impl CurveApproxCache {
@@ -177,14 +261,18 @@ impl CurveApproxCache {
         &self,
         handle: &Handle<Curve>,
         boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
     ) -> Option<CurveApprox> {
-        if let Some(approx) = self.inner.get(&(handle.clone(), boundary)) {
-            return Some(approx.clone());
+        if let Some(approx) =
+            self.inner.get(&(handle.id(), boundary, tolerance))
+        {
+            return Some(approx);
         }
         if let Some(approx) =
-            self.inner.get(&(handle.clone(), boundary.reverse()))
+            self.inner
+                .get(&(handle.id(), boundary.reverse(), tolerance))
         {
-            return Some(approx.clone().reverse());
+            return Some(approx.reverse());
         }
 
         None
@@ -194,11 +282,16 @@ impl CurveApproxCache {
         &mut self,
         handle: Handle<Curve>,
         boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
         approx: CurveApprox,
     ) -> CurveApprox {
         self.inner
-            .insert((handle, boundary), approx.clone())
-            .unwrap_or(approx)
+            .insert((handle.id(), boundary, tolerance), approx.clone());
+        approx
+    }
+
+    pub(super) fn clear(&self) {
+        self.inner.clear();
     }
 }
 
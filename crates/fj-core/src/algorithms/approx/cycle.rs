@@ -6,7 +6,7 @@ use fj_interop::Tolerance;
 use fj_math::LineSegment;
 
 use crate::{
-    geometry::{CurveBoundary, Geometry},
+    geometry::Geometry,
     storage::Handle,
     topology::{Cycle, Surface},
 };
@@ -31,22 +31,8 @@ pub fn approx_cycle(
         .half_edges()
         .pairs()
         .map(|(half_edge, next_half_edge)| {
-            let boundary = CurveBoundary {
-                inner: [
-                    geometry
-                        .of_vertex(half_edge.start_vertex())
-                        .unwrap()
-                        .local_on(half_edge.curve())
-                        .unwrap()
-                        .position,
-                    geometry
-                        .of_vertex(next_half_edge.start_vertex())
-                        .unwrap()
-                        .local_on(half_edge.curve())
-                        .unwrap()
-                        .position,
-                ],
-            };
+            let boundary =
+                half_edge.boundary(next_half_edge.start_vertex(), geometry);
             let [start_position_curve, _] = boundary.inner;
 
             let start = approx_vertex(
@@ -10,6 +10,8 @@ pub mod solid;
 mod circle;
 mod curve;
 mod line;
+mod polyline;
+mod shared;
 mod vertex;
 
 use std::{
@@ -23,6 +25,8 @@ use fj_interop::Tolerance;
 use fj_math::Point;
 use vertex::VertexApproxCache;
 
+pub use self::polyline::PolylineApproxCache;
+
 use crate::geometry::Geometry;
 
 /// Approximate an object
@@ -58,14 +62,42 @@ pub trait Approx: Sized {
     ) -> Self::Approximation;
 }
 
-/// Cache for half-edge approximations
-#[derive(Default)]
+/// Cache for approximations
+///
+/// Used by triangulation (see [`triangulate_with_cache`]) and, if configured
+/// via [`ValidationConfig::approx_cache`], by validation. Clones of a cache
+/// share the cached approximations, so the same cache can be used for both,
+/// including from multiple threads.
+///
+/// Objects are identified by their [`ObjectId`], so the cache doesn't keep
+/// them alive. Since IDs are derived from the location of objects in memory, a
+/// cache must not outlive the objects it was used with. Use a new cache (or
+/// call [`ApproxCache::clear`]), when switching to objects from a different
+/// instance of [`Core`].
+///
+/// [`triangulate_with_cache`]: crate::algorithms::triangulate::triangulate_with_cache
+/// [`ValidationConfig::approx_cache`]: crate::validation::ValidationConfig::approx_cache
+/// [`ObjectId`]: crate::storage::ObjectId
+/// [`Core`]: crate::Core
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ApproxCache {
     /// Cache for vertex approximations
     pub vertex: VertexApproxCache,
 
     /// Cache for curve approximations
     pub curve: CurveApproxCache,
+
+    /// Cache for polylines that are generated from curve geometry
+    pub polyline: PolylineApproxCache,
+}
+
+impl ApproxCache {
+    /// Remove all cached approximations
+    pub fn clear(&self) {
+        self.vertex.clear();
+        self.curve.clear();
+        self.polyline.clear();
+    }
 }
 
 /// A point from an approximation, with local and global forms
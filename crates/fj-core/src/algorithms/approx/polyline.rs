@@ -0,0 +1,193 @@
+use fj_interop::Tolerance;
+use fj_math::Point;
+
+use crate::{
+    geometry::{CurveBoundary, traits::GenPolyline},
+    storage::{Handle, ObjectId},
+    topology::{Curve, Surface},
+};
+
+use super::shared::SharedMap;
+
+/// Cache for polylines that are generated from curve geometry
+///
+/// Polylines are keyed by the curve and the surface whose local curve geometry
+/// they were generated from, as well as the boundary and the tolerance.
+///
+/// Curves and surfaces are identified by their [`ObjectId`], so the cache
+/// doesn't keep them alive. Objects are immutable, so a curve that changes is
+/// replaced by a new object with a new ID, and the polylines that were cached
+/// for the old one are no longer used. Clones of a cache share the cached
+/// polylines.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PolylineApproxCache {
+    inner: SharedMap<PolylineKey, Vec<Point<1>>>,
+}
+
+type PolylineKey = (ObjectId, ObjectId, CurveBoundary<Point<1>>, Tolerance);
+
+impl PolylineApproxCache {
+    /// Generate a polyline, or return it from the cache
+    ///
+    /// `generator` must be the geometry of `curve`, local to `surface`. If a
+    /// polyline has been generated for the same curve, surface, boundary, and
+    /// tolerance before, that is returned, and `generator` is not called.
+    pub fn generate_polyline(
+        &self,
+        curve: &Handle<Curve>,
+        surface: &Handle<Surface>,
+        generator: &impl GenPolyline<2>,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Vec<Point<1>> {
+        let tolerance = tolerance.into();
+        let key = (curve.id(), surface.id(), boundary, tolerance);
+
+        if let Some(polyline) = self.inner.get(&key) {
+            return polyline;
+        }
+
+        // The map is not locked while generating, to not block other threads.
+        // If another thread generates the same polyline in the meantime, the
+        // result is the same anyway.
+        let polyline = generator.generate_polyline(boundary, tolerance);
+        self.inner.insert(key, polyline.clone());
+
+        polyline
+    }
+
+    /// Return the number of cached polylines
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Indicate whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all cached polylines
+    pub fn clear(&self) {
+        self.inner.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use fj_interop::Tolerance;
+    use fj_math::{LineSegment, Point};
+
+    use crate::{
+        Core,
+        algorithms::approx::ApproxCache,
+        geometry::{CurveBoundary, Path, traits::GenPolyline},
+        operations::{build::BuildSketch, insert::Insert},
+        topology::{Curve, Sketch},
+        validate::Validate,
+        validation::ValidationConfig,
+    };
+
+    use super::PolylineApproxCache;
+
+    #[test]
+    fn second_approximation_at_same_tolerance_hits_cache() -> anyhow::Result<()>
+    {
+        let mut core = Core::new();
+
+        let curve = Curve::new().insert(&mut core);
+        let surface = core.layers.topology.surfaces.space_2d();
+        let generator = CountingCurve {
+            path: Path::circle_from_radius(1.),
+            calls: AtomicUsize::new(0),
+        };
+        let boundary = CurveBoundary::from([[0.], [1.]]);
+        let coarse = Tolerance::from_scalar(0.01)?;
+        let fine = Tolerance::from_scalar(0.001)?;
+
+        let cache = PolylineApproxCache::default();
+        let generate = |tolerance| {
+            cache.generate_polyline(
+                &curve, &surface, &generator, boundary, tolerance,
+            )
+        };
+
+        let first = generate(coarse);
+        let second = generate(coarse);
+        assert_eq!(first, second);
+        assert_eq!(generator.calls.load(Ordering::SeqCst), 1);
+
+        // A different tolerance must not use the cached polyline.
+        let third = generate(fine);
+        assert!(third.len() > first.len());
+        assert_eq!(generator.calls.load(Ordering::SeqCst), 2);
+
+        // Clones share the cached polylines.
+        let clone = cache.clone();
+        clone.generate_polyline(&curve, &surface, &generator, boundary, fine);
+        assert_eq!(generator.calls.load(Ordering::SeqCst), 2);
+        assert_eq!(cache.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn validation_fills_shared_cache() {
+        let mut core = Core::new();
+
+        let sketch = Sketch::circle([0., 0.], 1., &mut core);
+
+        let cache = ApproxCache::default();
+        let config = ValidationConfig {
+            approx_cache: Some(cache.clone()),
+            ..ValidationConfig::default()
+        };
+        sketch.validate(&config, &mut Vec::new(), &core.layers.geometry);
+
+        // The configuration holds a clone of the cache, which shares the
+        // polylines with the original.
+        assert!(!cache.polyline.is_empty());
+        assert_eq!(config.approx_cache, Some(cache));
+    }
+
+    struct CountingCurve {
+        path: Path<2>,
+        calls: AtomicUsize,
+    }
+
+    impl GenPolyline<2> for CountingCurve {
+        fn origin(&self) -> Point<2> {
+            self.path.origin()
+        }
+
+        fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<2> {
+            self.path.point_from_curve_coords(point_curve)
+        }
+
+        fn line_segment_at(
+            &self,
+            point_curve: Point<1>,
+            tolerance: Tolerance,
+        ) -> LineSegment<2> {
+            self.path.line_segment_at(point_curve, tolerance)
+        }
+
+        fn generate_polyline(
+            &self,
+            boundary: CurveBoundary<Point<1>>,
+            tolerance: Tolerance,
+        ) -> Vec<Point<1>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.path.generate_polyline(boundary, tolerance)
+        }
+
+        fn project_point(
+            &self,
+            point: Point<2>,
+            tolerance: Tolerance,
+        ) -> Point<1> {
+            self.path.project_point(point, tolerance)
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// A map of cached approximations, that is shared between clones
+///
+/// This is the building block of the caches that make up [`ApproxCache`].
+/// Sharing the map between clones makes it possible to use the same cache from
+/// multiple places, including multiple threads, as is the case during
+/// validation.
+///
+/// [`ApproxCache`]: super::ApproxCache
+pub struct SharedMap<K, V> {
+    inner: Arc<Mutex<BTreeMap<K, V>>>,
+}
+
+impl<K, V> SharedMap<K, V>
+where
+    K: Ord,
+    V: Clone,
+{
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.lock().get(key).cloned()
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.lock().insert(key, value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<K, V>> {
+        // The map can't be left in an inconsistent state by a panic, so it's
+        // fine to ignore poisoning.
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<K, V> Clone for SharedMap<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<K, V> Default for SharedMap<K, V> {
+    fn default() -> Self {
+        Self {
+            inner: Arc::default(),
+        }
+    }
+}
+
+impl<K, V> fmt::Debug for SharedMap<K, V>
+where
+    K: Ord,
+    V: Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SharedMap")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<K, V> PartialEq for SharedMap<K, V> {
+    /// Maps are equal, if they are shared
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
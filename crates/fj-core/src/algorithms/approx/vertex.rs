@@ -1,14 +1,12 @@
-use std::collections::BTreeMap;
-
 use fj_math::Point;
 
 use crate::{
     geometry::{Geometry, repr::tri_mesh::convert_point_surface_to_global},
-    storage::Handle,
+    storage::{Handle, ObjectId},
     topology::{Curve, Surface, Vertex},
 };
 
-use super::{ApproxPoint, Tolerance};
+use super::{ApproxPoint, Tolerance, shared::SharedMap};
 
 /// # Approximate a vertex position
 pub fn approx_vertex(
@@ -20,6 +18,8 @@ pub fn approx_vertex(
     cache: &mut VertexApproxCache,
     geometry: &Geometry,
 ) -> ApproxPoint<1> {
+    let tolerance = tolerance.into();
+
     let position_surface = geometry
         .of_curve(curve)
         .unwrap()
@@ -28,7 +28,7 @@ pub fn approx_vertex(
         .path
         .point_from_path_coords(position_curve);
 
-    let position_global = match cache.get(&vertex) {
+    let position_global = match cache.get(&vertex, tolerance) {
         Some(position) => position,
         None => {
             let position_global = convert_point_surface_to_global(
@@ -37,7 +37,7 @@ pub fn approx_vertex(
                 tolerance,
                 geometry,
             );
-            cache.insert(vertex, position_global)
+            cache.insert(vertex, tolerance, position_global)
         }
     };
 
@@ -45,23 +45,39 @@ pub fn approx_vertex(
 }
 
 /// Cache for vertex approximations
-#[derive(Default)]
+///
+/// Approximations are keyed by vertex and tolerance. This makes it possible to
+/// use the same cache for approximations at different tolerances.
+///
+/// Vertices are identified by their [`ObjectId`], so the cache doesn't keep
+/// them alive. Clones of a cache share the cached approximations.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct VertexApproxCache {
-    inner: BTreeMap<Handle<Vertex>, Point<3>>,
+    inner: SharedMap<(ObjectId, Tolerance), Point<3>>,
 }
 
 impl VertexApproxCache {
     /// Get an approximated vertex from the cache
-    pub fn get(&self, handle: &Handle<Vertex>) -> Option<Point<3>> {
-        self.inner.get(handle).cloned()
+    pub fn get(
+        &self,
+        handle: &Handle<Vertex>,
+        tolerance: Tolerance,
+    ) -> Option<Point<3>> {
+        self.inner.get(&(handle.id(), tolerance))
     }
 
     /// Insert an approximated vertex into the cache
     pub fn insert(
         &mut self,
         handle: Handle<Vertex>,
+        tolerance: Tolerance,
         position: Point<3>,
     ) -> Point<3> {
-        self.inner.insert(handle, position).unwrap_or(position)
+        self.inner.insert((handle.id(), tolerance), position);
+        position
+    }
+
+    pub(super) fn clear(&self) {
+        self.inner.clear();
     }
 }
@@ -1,47 +1,103 @@
-use std::ops::Deref;
-
 use fj_interop::Tolerance;
-use fj_math::{Aabb, Vector};
+use fj_math::Aabb;
+
+use crate::{geometry::Geometry, topology::Face};
+
+impl super::BoundingVolume<2> for &Face {
+    fn aabb(self, geometry: &Geometry) -> Option<Aabb<2>> {
+        self.aabb_with_tolerance(super::default_tolerance(), geometry)
+    }
 
-use crate::{
-    geometry::{
-        Geometry, repr::tri_mesh::convert_point_surface_to_global,
-        traits::GenTriMesh,
-    },
-    topology::Face,
-};
+    fn aabb_with_tolerance(
+        self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Aabb<2>> {
+        super::surface_aabb(
+            [self.region()],
+            self.surface(),
+            tolerance.into(),
+            geometry,
+        )
+    }
+}
 
 impl super::BoundingVolume<3> for &Face {
     fn aabb(self, geometry: &Geometry) -> Option<Aabb<3>> {
-        // A bounding volume must include the body it bounds, but does not need
-        // to match it precisely. So it's okay, if it's a bit larger.
-        //
-        // Let's just choose a reasonable tolerance value here, that we can use
-        // to enlarge any AABBs we use in this method as necessary.
-        let tolerance = Tolerance::from_scalar(0.001)
-            .expect("Tolerance provided is larger than zero");
-
-        (self.region().exterior().deref(), self.surface())
-            .aabb(geometry)
-            .map(|aabb2| {
-                let surface =
-                    &geometry.of_surface_2(self.surface()).unwrap().generator;
-                let tri_mesh =
-                    surface.generate_tri_mesh(aabb2, tolerance, geometry);
-                let tri_mesh = tri_mesh.into_iter().map(|point| {
-                    convert_point_surface_to_global(
-                        surface, point, tolerance, geometry,
-                    )
-                });
-
-                let mut aabb3 = Aabb::<3>::from_points(tri_mesh);
-
-                let offset = Vector::from([tolerance.inner(); 3]);
-
-                aabb3.min -= offset;
-                aabb3.max += offset;
-
-                aabb3
-            })
+        self.aabb_with_tolerance(super::default_tolerance(), geometry)
+    }
+
+    fn aabb_with_tolerance(
+        self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Aabb<3>> {
+        let tolerance = tolerance.into();
+
+        let aabb = super::surface_aabb(
+            [self.region()],
+            self.surface(),
+            tolerance,
+            geometry,
+        )?;
+        super::global_aabb(aabb, self.surface(), tolerance, geometry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Scalar};
+
+    use crate::{
+        Core, algorithms::bounding_volume::BoundingVolume,
+        operations::build::BuildFace, topology::Face,
+    };
+
+    #[test]
+    fn aabb_of_triangle() {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xz_plane(),
+            [[0., 0.], [1., 0.], [0., 1.]],
+            &mut core,
+        );
+
+        assert_eq!(
+            face.aabb_with_tolerance(0.001, &core.layers.geometry),
+            Some(Aabb {
+                min: Point::from([0., 0.]),
+                max: Point::from([1., 1.]),
+            }),
+        );
+        assert_eq!(
+            face.aabb_with_tolerance(0.001, &core.layers.geometry),
+            Some(Aabb {
+                min: Point::from([-0.001, -0.001, -0.001]),
+                max: Point::from([1.001, 0.001, 1.001]),
+            }),
+        );
+    }
+
+    #[test]
+    fn aabb_of_circle() {
+        let mut core = Core::new();
+
+        let face = Face::circle(
+            core.layers.topology.surfaces.xy_plane(),
+            [1., 1.],
+            2.,
+            &mut core,
+        );
+
+        let aabb: Aabb<2> = face
+            .aabb_with_tolerance(0.001, &core.layers.geometry)
+            .expect("Face has a boundary");
+
+        // The points where the circle touches its bounding box are computed
+        // using trigonometry, so we can't expect them to be exact.
+        let epsilon = Scalar::from(1e-12);
+        assert!(aabb.min.distance_to(&Point::from([-1., -1.])) < epsilon);
+        assert!(aabb.max.distance_to(&Point::from([3., 3.])) < epsilon);
     }
 }
@@ -34,16 +34,9 @@ impl super::BoundingVolume<2>
             }
             Path::Line(_) => {
                 let points =
-                    [half_edge.start_vertex(), end_vertex].map(|vertex| {
-                        let point_curve = geometry
-                            .of_vertex(vertex)
-                            .unwrap()
-                            .local_on(half_edge.curve())
-                            .unwrap()
-                            .position;
-
-                        path.point_from_path_coords(point_curve)
-                    });
+                    half_edge.boundary(end_vertex, geometry).inner.map(
+                        |point_curve| path.point_from_path_coords(point_curve),
+                    );
 
                 Some(Aabb::<2>::from_points(points))
             }
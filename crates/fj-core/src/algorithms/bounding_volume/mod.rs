@@ -4,11 +4,20 @@ mod cycle;
 mod face;
 mod half_edge;
 mod shell;
+mod sketch;
 mod solid;
 
-use fj_math::Aabb;
+use fj_interop::Tolerance;
+use fj_math::{Aabb, Point, Vector};
 
-use crate::geometry::Geometry;
+use crate::{
+    geometry::{
+        Geometry, repr::tri_mesh::convert_point_surface_to_global,
+        traits::GenTriMesh,
+    },
+    storage::Handle,
+    topology::{Region, Surface},
+};
 
 /// Compute a bounding volume for an object
 pub trait BoundingVolume<const D: usize> {
@@ -16,4 +25,91 @@ pub trait BoundingVolume<const D: usize> {
     ///
     /// Return `None`, if no AABB can be computed (if the object is empty).
     fn aabb(self, geometry: &Geometry) -> Option<Aabb<D>>;
+
+    /// Compute an AABB, approximating curved geometry at the given tolerance
+    ///
+    /// Objects whose AABB doesn't depend on a tolerance return the same result
+    /// as [`BoundingVolume::aabb`], which is what the default implementation
+    /// does.
+    fn aabb_with_tolerance(
+        self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Aabb<D>>
+    where
+        Self: Sized,
+    {
+        let _ = tolerance;
+        self.aabb(geometry)
+    }
+}
+
+/// The tolerance used by [`BoundingVolume::aabb`], where one is required
+///
+/// A bounding volume must include the body it bounds, but does not need to
+/// match it precisely. So it's okay, if it's a bit larger, and any reasonable
+/// tolerance value will do.
+fn default_tolerance() -> Tolerance {
+    Tolerance::from_scalar(0.001)
+        .expect("Tolerance provided is larger than zero")
+}
+
+/// Compute the surface AABB of the polylines that approximate the regions
+///
+/// Returns `None`, if the regions have no half-edges, or if the geometry of any
+/// of their half-edges is not defined on the surface.
+fn surface_aabb<'r>(
+    regions: impl IntoIterator<Item = &'r Handle<Region>>,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> Option<Aabb<2>> {
+    let cycles = regions.into_iter().flat_map(|region| {
+        [region.exterior()].into_iter().chain(region.interiors())
+    });
+
+    let mut points: Vec<Point<2>> = Vec::new();
+
+    for cycle in cycles {
+        for (_, polyline) in
+            cycle.half_edge_polylines(surface, tolerance, geometry)?
+        {
+            points.extend(polyline);
+        }
+    }
+
+    if points.is_empty() {
+        return None;
+    }
+
+    Some(Aabb::<2>::from_points(points))
+}
+
+/// Convert a surface AABB into a global AABB that contains that part of the
+/// surface
+///
+/// Returns `None`, if the surface has no geometry that would define its
+/// position in global space.
+fn global_aabb(
+    aabb: Aabb<2>,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> Option<Aabb<3>> {
+    let surface = &geometry.of_surface_2(surface)?.generator;
+
+    let tri_mesh = surface.generate_tri_mesh(aabb, tolerance, geometry);
+    let tri_mesh = tri_mesh.into_iter().map(|point| {
+        convert_point_surface_to_global(surface, point, tolerance, geometry)
+    });
+
+    let mut aabb = Aabb::<3>::from_points(tri_mesh);
+
+    // The triangle mesh only approximates the surface, so enlarge the AABB to
+    // make sure it still contains all of it.
+    let offset = Vector::from([tolerance.inner(); 3]);
+    aabb.min -= offset;
+    aabb.max += offset;
+
+    Some(aabb)
 }
@@ -0,0 +1,75 @@
+use fj_interop::Tolerance;
+use fj_math::Aabb;
+
+use crate::{geometry::Geometry, topology::Sketch};
+
+impl super::BoundingVolume<2> for &Sketch {
+    fn aabb(self, geometry: &Geometry) -> Option<Aabb<2>> {
+        self.aabb_with_tolerance(super::default_tolerance(), geometry)
+    }
+
+    fn aabb_with_tolerance(
+        self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Aabb<2>> {
+        super::surface_aabb(
+            self.regions(),
+            self.surface(),
+            tolerance.into(),
+            geometry,
+        )
+    }
+}
+
+impl super::BoundingVolume<3> for &Sketch {
+    fn aabb(self, geometry: &Geometry) -> Option<Aabb<3>> {
+        self.aabb_with_tolerance(super::default_tolerance(), geometry)
+    }
+
+    fn aabb_with_tolerance(
+        self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Aabb<3>> {
+        let tolerance = tolerance.into();
+
+        let aabb = super::surface_aabb(
+            self.regions(),
+            self.surface(),
+            tolerance,
+            geometry,
+        )?;
+        super::global_aabb(aabb, self.surface(), tolerance, geometry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point};
+
+    use crate::{
+        Core, algorithms::bounding_volume::BoundingVolume,
+        operations::build::BuildSketch, topology::Sketch,
+    };
+
+    #[test]
+    fn aabb_of_sketch() {
+        let mut core = Core::new();
+
+        let sketch = Sketch::polygon([[0., 0.], [2., 0.], [0., 3.]], &mut core);
+
+        assert_eq!(
+            sketch.aabb_with_tolerance(0.001, &core.layers.geometry),
+            Some(Aabb {
+                min: Point::from([0., 0.]),
+                max: Point::from([2., 3.]),
+            }),
+        );
+
+        // The sketch is defined in 2D space, which has no position in 3D.
+        let aabb: Option<Aabb<3>> =
+            sketch.aabb_with_tolerance(0.001, &core.layers.geometry);
+        assert_eq!(aabb, None);
+    }
+}
@@ -3,13 +3,78 @@
 mod delaunay;
 mod polygon;
 
-use fj_interop::{MeshTriangle, Tolerance, TriMesh};
-
-use crate::{Core, operations::presentation::GetColor};
+use std::collections::BTreeMap;
+
+use fj_interop::{Mesh, MeshTriangle, Tolerance, TriMesh};
+use fj_math::{Point, Winding};
+
+use crate::{
+    Core,
+    geometry::Geometry,
+    operations::presentation::GetColor,
+    storage::Handle,
+    topology::{Face, Handedness},
+};
+
+use self::{delaunay::TriangulationPoint, polygon::Polygon};
+
+use super::approx::{
+    Approx, ApproxCache, ApproxPoint,
+    cycle::CycleApprox,
+    face::{FaceApprox, approx_face},
+    half_edge::HalfEdgeApprox,
+};
+
+/// # Triangulate a face into a [`Mesh`]
+///
+/// Approximates the exterior and interior cycles of the face within the
+/// provided tolerance, triangulates the resulting polygon in surface
+/// coordinates, and converts the vertices into global coordinates.
+///
+/// The triangles are wound such that their normals point towards the front of
+/// the face, as defined by the surface and the handedness of its coordinate
+/// system.
+pub fn triangulate(
+    face: &Handle<Face>,
+    geometry: &Geometry,
+    tolerance: impl Into<Tolerance>,
+) -> Mesh {
+    triangulate_with_cache(
+        face,
+        geometry,
+        tolerance,
+        &mut ApproxCache::default(),
+    )
+}
 
-use self::polygon::Polygon;
+/// # Triangulate a face into a [`Mesh`], using the provided cache
+///
+/// Works like [`triangulate`], but takes the approximations of the face's
+/// curves and vertices from the cache, where possible, and adds any new ones.
+/// Using the same cache for multiple faces of a shape avoids approximating
+/// their shared edges repeatedly. The cache can be used at different
+/// tolerances, and shared with validation, via
+/// [`ValidationConfig::approx_cache`].
+///
+/// [`ValidationConfig::approx_cache`]: crate::validation::ValidationConfig::approx_cache
+pub fn triangulate_with_cache(
+    face: &Handle<Face>,
+    geometry: &Geometry,
+    tolerance: impl Into<Tolerance>,
+    cache: &mut ApproxCache,
+) -> Mesh {
+    let approx = approx_face(face.clone(), tolerance, cache, geometry);
+
+    // Points that are shared between triangles are already identical in the
+    // approximation. The merge distance only needs to absorb numerical noise.
+    let mut mesh = Mesh::new(1e-9);
+
+    for triangle in triangulate_approx(approx) {
+        mesh.push_triangle(triangle.map(|point| point.point_global));
+    }
 
-use super::approx::{Approx, face::FaceApprox};
+    mesh
+}
 
 /// Triangulate a shape
 pub trait Triangulate: Sized {
@@ -45,28 +110,9 @@ where
 
 impl Triangulate for FaceApprox {
     fn triangulate_into_mesh(self, mesh: &mut TriMesh, core: &mut Core) {
-        let face_as_polygon = Polygon::new()
-            .with_exterior(
-                self.exterior
-                    .points()
-                    .into_iter()
-                    .map(|point| point.local_form),
-            )
-            .with_interiors(self.interiors.iter().map(|interior| {
-                interior.points().into_iter().map(|point| point.local_form)
-            }));
-
-        let cycles = [self.exterior].into_iter().chain(self.interiors);
-        let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
-        triangles.retain(|triangle| {
-            face_as_polygon
-                .contains_triangle(triangle.map(|point| point.point_surface))
-        });
-
         let color = self.face.region().get_color(core).unwrap_or_default();
 
-        for triangle in triangles {
+        for triangle in triangulate_approx(self) {
             let points = triangle.map(|point| point.point_global);
             mesh.triangles.push(MeshTriangle {
                 inner: points.into(),
@@ -77,17 +123,84 @@ impl Triangulate for FaceApprox {
     }
 }
 
+/// # Triangulate a simple polygon in surface coordinates
+///
+/// Returns the triangles as indices into `points`, wound in the same direction
+/// as the polygon. Returns `None`, if the polygon is degenerate, meaning it has
+/// no winding.
+pub(crate) fn triangulate_polygon(
+    points: &[Point<2>],
+) -> Option<Vec<[usize; 3]>> {
+    let coord_handedness = match fj_math::Polygon::from_points(
+        points.iter().copied(),
+    )
+    .winding()?
+    {
+        Winding::Ccw => Handedness::RightHanded,
+        Winding::Cw => Handedness::LeftHanded,
+    };
+
+    let exterior = CycleApprox {
+        half_edges: vec![HalfEdgeApprox {
+            points: points
+                .iter()
+                .map(|&point| ApproxPoint::new(point, point.to_xyz()))
+                .collect(),
+        }],
+    };
+    let polygon = Polygon::new().with_exterior(
+        exterior.points().into_iter().map(|point| point.local_form),
+    );
+
+    let mut indices = BTreeMap::new();
+    for (i, point) in points.iter().enumerate() {
+        indices.entry(*point).or_insert(i);
+    }
+
+    let triangles = delaunay::triangulate([exterior], coord_handedness)
+        .into_iter()
+        .map(|triangle| triangle.map(|point| point.point_surface))
+        .filter(|&triangle| polygon.contains_triangle(triangle))
+        .map(|triangle| triangle.map(|point| indices[&point]))
+        .collect();
+
+    Some(triangles)
+}
+
+fn triangulate_approx(approx: FaceApprox) -> Vec<[TriangulationPoint; 3]> {
+    let face_as_polygon = Polygon::new()
+        .with_exterior(
+            approx
+                .exterior
+                .points()
+                .into_iter()
+                .map(|point| point.local_form),
+        )
+        .with_interiors(approx.interiors.iter().map(|interior| {
+            interior.points().into_iter().map(|point| point.local_form)
+        }));
+
+    let cycles = [approx.exterior].into_iter().chain(approx.interiors);
+    let mut triangles = delaunay::triangulate(cycles, approx.coord_handedness);
+    triangles.retain(|triangle| {
+        face_as_polygon
+            .contains_triangle(triangle.map(|point| point.point_surface))
+    });
+
+    triangles
+}
+
 #[cfg(test)]
 mod tests {
     use fj_interop::{Tolerance, TriMesh};
-    use fj_math::{Point, Scalar};
+    use fj_math::{Point, Scalar, Triangle, Vector};
 
     use crate::{
         Core,
         algorithms::approx::{ApproxCache, face::approx_face},
         geometry::repr::tri_mesh::convert_point_surface_to_global,
         operations::{
-            build::{BuildCycle, BuildFace},
+            build::{BuildCycle, BuildFace, FaceBuilder},
             insert::Insert,
             update::{UpdateFace, UpdateRegion},
         },
@@ -279,6 +392,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn triangulate_square_into_mesh() {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let mesh = super::triangulate(&face, &core.layers.geometry, 0.001);
+
+        assert_eq!(mesh.vertices().len(), 4);
+        assert_eq!(mesh.indices().len(), 2);
+        assert_mesh_faces_towards([0., 0., 1.], &mesh);
+    }
+
+    #[test]
+    fn triangulate_square_with_hole_into_mesh() {
+        let mut core = Core::new();
+
+        // The xz-plane faces towards negative y.
+        let face = FaceBuilder::new()
+            .exterior([[0., 0.], [4., 0.], [4., 4.], [0., 4.]])
+            .hole([[1., 1.], [3., 1.], [3., 3.], [1., 3.]])
+            .build(core.layers.topology.surfaces.xz_plane(), &mut core)
+            .insert(&mut core);
+
+        let mesh = super::triangulate(&face, &core.layers.geometry, 0.001);
+
+        assert_eq!(mesh.vertices().len(), 8);
+        assert_eq!(mesh.indices().len(), 8);
+        assert_mesh_faces_towards([0., -1., 0.], &mesh);
+
+        // The hole is not covered by any triangles.
+        let area = mesh
+            .triangles()
+            .map(|[a, b, c]| (b - a).cross(&(c - a)).magnitude() / 2.)
+            .fold(Scalar::ZERO, |sum, area| sum + area);
+        assert_eq!(area, Scalar::from(12.));
+    }
+
+    fn assert_mesh_faces_towards(
+        normal: impl Into<Vector<3>>,
+        mesh: &fj_interop::Mesh,
+    ) {
+        let normal = normal.into();
+
+        for triangle in mesh.triangles() {
+            let triangle_normal = Triangle::from(triangle).normal();
+            assert!(
+                (triangle_normal - normal).magnitude() < Scalar::from(1e-12)
+            );
+        }
+    }
+
     fn triangulate(
         face: Handle<Face>,
         core: &mut Core,
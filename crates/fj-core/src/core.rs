@@ -4,7 +4,12 @@
 
 use fj_interop::Tolerance;
 
-use crate::{layers::Layers, validation::ValidationConfig};
+use crate::{
+    layers::Layers,
+    storage::Handle,
+    topology::{AnyObject, Stored},
+    validation::ValidationConfig,
+};
 
 /// An instance of the Fornjot core
 ///
@@ -31,6 +36,31 @@ impl Core {
     pub fn tolerance(&self) -> Tolerance {
         self.layers.validation.config.tolerance
     }
+
+    /// Assign a human-readable label to an object
+    ///
+    /// Any label previously assigned to the object is replaced. Objects derived
+    /// from this one (for example by transforming it) inherit the label.
+    pub fn set_label<T>(&mut self, handle: Handle<T>, label: impl Into<String>)
+    where
+        Handle<T>: Into<AnyObject<Stored>>,
+    {
+        self.layers
+            .presentation
+            .set_label(handle.into(), label.into());
+    }
+
+    /// Access the label assigned to an object, if any
+    pub fn label<T>(&self, handle: &Handle<T>) -> Option<&str>
+    where
+        Handle<T>: Into<AnyObject<Stored>>,
+    {
+        self.layers
+            .presentation
+            .label
+            .get(&handle.clone().into())
+            .map(String::as_str)
+    }
 }
 
 impl Default for Core {
@@ -0,0 +1,407 @@
+//! # Geometry code specific to Bézier curves
+
+use fj_interop::Tolerance;
+use fj_math::{LineSegment, Point, Scalar, Vector};
+
+use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+/// # A quadratic or cubic Bézier curve
+///
+/// The curve coordinate of a Bézier curve is its parameter `t`. The curve
+/// starts at the first control point (`t = 0`), and ends at the last control
+/// point (`t = 1`). Outside of that domain, the curve's polynomial is
+/// extrapolated, but no approximation is generated there.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Bezier<const D: usize> {
+    control_points: Vec<Point<D>>,
+}
+
+impl<const D: usize> Bezier<D> {
+    /// # Construct a Bézier curve
+    ///
+    /// Returns an error, if the degree is not `2` (quadratic) or `3` (cubic),
+    /// or if the number of control points is not the degree plus one.
+    pub fn new(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Result<Self, InvalidBezier> {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        if !matches!(degree, 2 | 3) {
+            return Err(InvalidBezier::UnsupportedDegree { degree });
+        }
+        if control_points.len() != degree + 1 {
+            return Err(InvalidBezier::WrongNumberOfControlPoints {
+                expected: degree + 1,
+                actual: control_points.len(),
+            });
+        }
+
+        Ok(Self { control_points })
+    }
+
+    /// # Access the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.control_points.len() - 1
+    }
+
+    /// # Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// # Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_path_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+
+        // De Casteljau's algorithm
+        let mut points = self.control_points.clone();
+        while points.len() > 1 {
+            points = points
+                .windows(2)
+                .map(|p| p[0] + (p[1] - p[0]) * t)
+                .collect();
+        }
+
+        points[0]
+    }
+
+    /// # Compute the derivative of the curve at the provided point
+    ///
+    /// The derivative of a Bézier curve of degree `n` is itself a Bézier curve
+    /// of degree `n - 1`, the hodograph. Its control points are the
+    /// differences between consecutive control points, times `n`.
+    pub fn derivative_at(&self, point: impl Into<Point<1>>) -> Vector<D> {
+        let t = point.into().t;
+
+        let mut vectors = self
+            .control_points
+            .windows(2)
+            .map(|p| (p[1] - p[0]) * self.degree() as f64)
+            .collect::<Vec<_>>();
+        while vectors.len() > 1 {
+            vectors = vectors
+                .windows(2)
+                .map(|v| v[0] + (v[1] - v[0]) * t)
+                .collect();
+        }
+
+        vectors[0]
+    }
+
+    /// # Compute the curve coordinates used to approximate the curve
+    ///
+    /// The returned values start at zero and end at one. They are computed by
+    /// recursively splitting the curve in half, until the control points of
+    /// each part are within the tolerance of the chord between its end points.
+    /// Since the curve is contained in the convex hull of its control points,
+    /// this means the polyline stays within the tolerance of the curve.
+    ///
+    /// The subdivision always covers the whole curve, which makes the result
+    /// only depend on the curve and the tolerance.
+    fn approx_params(&self, tolerance: Tolerance) -> Vec<Scalar> {
+        // Limits the recursion, in case the tolerance is too small to be
+        // reached with floating point numbers.
+        const MAX_DEPTH: u32 = 32;
+
+        fn subdivide<const D: usize>(
+            control_points: Vec<Point<D>>,
+            [a, b]: [Scalar; 2],
+            tolerance: Tolerance,
+            depth: u32,
+            params: &mut Vec<Scalar>,
+        ) {
+            let chord = LineSegment::from([
+                control_points[0],
+                control_points[control_points.len() - 1],
+            ]);
+            let is_flat = control_points.iter().all(|point| {
+                chord.distance_to_point(*point) <= tolerance.inner()
+            });
+
+            if is_flat || depth >= MAX_DEPTH {
+                params.push(b);
+                return;
+            }
+
+            let [left, right] = split_in_half(control_points);
+            let middle = (a + b) / 2.;
+
+            subdivide(left, [a, middle], tolerance, depth + 1, params);
+            subdivide(right, [middle, b], tolerance, depth + 1, params);
+        }
+
+        let mut params = vec![Scalar::ZERO];
+        subdivide(
+            self.control_points.clone(),
+            [Scalar::ZERO, Scalar::ONE],
+            tolerance,
+            0,
+            &mut params,
+        );
+
+        params
+    }
+}
+
+impl<const D: usize> GenPolyline<D> for Bezier<D> {
+    fn origin(&self) -> Point<D> {
+        self.control_points[0]
+    }
+
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_path_coords(point_curve)
+    }
+
+    fn line_segment_at(
+        &self,
+        point_curve: Point<1>,
+        tolerance: Tolerance,
+    ) -> LineSegment<D> {
+        let params = self.approx_params(tolerance);
+        let t = point_curve.t;
+
+        let i = params
+            .partition_point(|param| *param <= t)
+            .clamp(1, params.len() - 1);
+        let [a, b] = [params[i - 1], params[i]];
+
+        // If the point is located on one of the points of the polyline, we
+        // should return a degenerate line segment at that point. Same as for
+        // circles, floating point inaccuracy must not prevent that.
+        let [a, b] = if (t - a).abs() < Scalar::from(1e-9) {
+            [a, a]
+        } else if (t - b).abs() < Scalar::from(1e-9) {
+            [b, b]
+        } else {
+            [a, b]
+        };
+
+        let points_curve = [a, b].map(|t| Point::from([t]));
+        let points = points_curve
+            .map(|point_curve| self.point_from_path_coords(point_curve));
+
+        LineSegment {
+            points,
+            points_line: points_curve,
+        }
+    }
+
+    fn generate_polyline(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Vec<Point<1>> {
+        let [start, end] = boundary.inner;
+        let [min, max] = if start < end {
+            [start.t, end.t]
+        } else {
+            [end.t, start.t]
+        };
+
+        let mut points = self
+            .approx_params(tolerance)
+            .into_iter()
+            .filter(|t| min < *t && *t < max)
+            .map(|t| Point::from([t]))
+            .collect::<Vec<_>>();
+
+        if start > end {
+            points.reverse();
+        }
+
+        points
+    }
+
+    fn project_point(&self, point: Point<D>, tolerance: Tolerance) -> Point<1> {
+        // Start with the closest point of the approximation. The closest point
+        // on the curve must then be located between its neighbors, where we
+        // can find it using a golden-section search.
+        let params = self.approx_params(tolerance);
+        let distance_at =
+            |t: Scalar| self.point_from_path_coords([t]).distance_to(&point);
+
+        let (i, _) = params
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, t)| distance_at(**t))
+            .expect("Approximation of Bézier curve always has points");
+
+        let mut a = params[i.saturating_sub(1)];
+        let mut b = params[(i + 1).min(params.len() - 1)];
+
+        let ratio = (Scalar::from(5.).sqrt() - 1.) / 2.;
+        while b - a > Scalar::from(1e-12) {
+            let c = b - (b - a) * ratio;
+            let d = a + (b - a) * ratio;
+
+            if distance_at(c) < distance_at(d) {
+                b = d;
+            } else {
+                a = c;
+            }
+        }
+
+        Point::from([(a + b) / 2.])
+    }
+
+    fn tangent_at(&self, point_curve: Point<1>, _: Tolerance) -> Vector<D> {
+        let derivative = self.derivative_at(point_curve);
+
+        // The derivative is zero, where control points coincide with an end
+        // point. There is no meaningful tangent in that case.
+        if derivative.magnitude() == Scalar::ZERO {
+            return derivative;
+        }
+
+        derivative.normalize()
+    }
+}
+
+/// # Error constructing a [`Bezier`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum InvalidBezier {
+    /// # The degree is neither quadratic nor cubic
+    #[error(
+        "Only quadratic and cubic Bézier curves are supported; got {degree}"
+    )]
+    UnsupportedDegree {
+        /// # The unsupported degree
+        degree: usize,
+    },
+
+    /// # Number of control points doesn't match the degree
+    #[error("Expected {expected} control points; got {actual}")]
+    WrongNumberOfControlPoints {
+        /// # The expected number of control points
+        expected: usize,
+
+        /// # The actual number of control points
+        actual: usize,
+    },
+}
+
+/// # Split a Bézier curve at `t = 0.5`, using De Casteljau's algorithm
+fn split_in_half<const D: usize>(
+    control_points: Vec<Point<D>>,
+) -> [Vec<Point<D>>; 2] {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    let mut points = control_points;
+    while let (Some(first), Some(last)) = (points.first(), points.last()) {
+        left.push(*first);
+        right.push(*last);
+
+        points = points
+            .windows(2)
+            .map(|p| p[0] + (p[1] - p[0]) / 2.)
+            .collect();
+    }
+    right.reverse();
+
+    [left, right]
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+    use super::{Bezier, InvalidBezier};
+
+    #[test]
+    fn collinear_control_points_flatten_to_line() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let curve = Bezier::new(3, [[0., 0.], [1., 1.], [2., 2.], [3., 3.]])?;
+
+        let boundary = CurveBoundary::from([[0.], [1.]]);
+        assert!(curve.generate_polyline(boundary, tolerance).is_empty());
+
+        let segment = curve.line_segment_at(Point::from([0.5]), tolerance);
+        assert_eq!(segment.points, [[0., 0.], [3., 3.]].map(Point::from));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tighter_tolerance_adds_segments() -> anyhow::Result<()> {
+        let curve = Bezier::new(2, [[0., 0.], [1., 2.], [2., 0.]])?;
+        let boundary = CurveBoundary::from([[0.], [1.]]);
+
+        let [coarse, fine] = [0.1, 0.001].map(|tolerance| {
+            let tolerance =
+                Tolerance::from_scalar(tolerance).expect("Tolerance is valid");
+            curve.generate_polyline(boundary, tolerance).len()
+        });
+        assert!(coarse < fine);
+
+        // The approximation stays within the tolerance.
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let points = [Point::from([0.])]
+            .into_iter()
+            .chain(curve.generate_polyline(boundary, tolerance))
+            .chain([Point::from([1.])])
+            .collect::<Vec<_>>();
+        for segment in points.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+            let middle = Point::from([(a.t + b.t) / 2.]);
+
+            let [a, b, middle] =
+                [a, b, middle].map(|t| curve.point_from_path_coords(t));
+            let chord_midpoint = a + (b - a) / 2.;
+
+            assert!(chord_midpoint.distance_to(&middle) <= tolerance.inner());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn tangent_from_hodograph() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let curve = Bezier::new(3, [[0., 0.], [0., 1.], [1., 1.], [1., 0.]])?;
+
+        assert_eq!(curve.origin(), Point::from([0., 0.]));
+        assert_eq!(curve.derivative_at([0.]), Vector::from([0., 3.]));
+        assert_eq!(
+            curve.tangent_at(Point::from([0.5]), tolerance),
+            Vector::from([1., 0.]),
+        );
+        assert_eq!(
+            curve.tangent_at(Point::from([1.]), tolerance),
+            Vector::from([0., -1.]),
+        );
+
+        let point = curve.point_from_path_coords([0.3]);
+        let projected = curve.project_point(point, tolerance);
+        assert!((projected.t - Scalar::from(0.3)).abs() < Scalar::from(1e-9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_control_points() {
+        assert_eq!(
+            Bezier::<2>::new(4, [[0., 0.]; 5]),
+            Err(InvalidBezier::UnsupportedDegree { degree: 4 }),
+        );
+        assert_eq!(
+            Bezier::<2>::new(3, [[0., 0.]; 3]),
+            Err(InvalidBezier::WrongNumberOfControlPoints {
+                expected: 4,
+                actual: 3,
+            }),
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! # Geometry code specific to circles
 
 use fj_interop::{CircleApproxParams, Tolerance};
-use fj_math::{Circle, LineSegment, Point};
+use fj_math::{Circle, LineSegment, Point, Scalar};
 
 use crate::geometry::{CurveBoundary, traits::GenPolyline};
 
@@ -10,6 +10,10 @@ impl<const D: usize> GenPolyline<D> for Circle<D> {
         self.center() + self.a()
     }
 
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_circle_coords(point_curve)
+    }
+
     fn line_segment_at(
         &self,
         point_curve: Point<1>,
@@ -22,6 +26,16 @@ impl<const D: usize> GenPolyline<D> for Circle<D> {
         // figure out where `point` is on the curve, in units of this increment.
         let t = point_curve.t / params.increment();
 
+        // If the point is located on one of the points of the polyline, we
+        // should return a degenerate line segment at that point. But due to
+        // floating point inaccuracy, `t` might end up just next to the integer
+        // number it should be, resulting in the wrong line segment.
+        let t = if (t - t.round()).abs() < Scalar::from(1e-9) {
+            t.round()
+        } else {
+            t
+        };
+
         // Now pick two points on the curve, again in units of approximation
         // increment, where the locations of the two closest approximation
         // points to the provided point are.
@@ -56,6 +70,13 @@ impl<const D: usize> GenPolyline<D> for Circle<D> {
         let params = CircleApproxParams::new(self.radius(), tolerance);
         params.approx_circle(boundary.inner).collect()
     }
+
+    fn project_point(&self, point: Point<D>, _: Tolerance) -> Point<1> {
+        // Projecting onto the circle itself, not its approximation, is exact.
+        // For the center point, which is equally close to all points on the
+        // circle, this returns the origin of the circle.
+        self.point_to_circle_coords(point)
+    }
 }
 
 #[cfg(test)]
@@ -63,7 +84,7 @@ mod tests {
     use std::f64::consts::TAU;
 
     use fj_interop::Tolerance;
-    use fj_math::{Point, Scalar};
+    use fj_math::{Point, Scalar, Vector};
 
     use crate::geometry::{
         CurveBoundary, curves::circle::Circle, traits::GenPolyline,
@@ -141,6 +162,224 @@ mod tests {
         }
     }
 
+    #[test]
+    fn chord_error_of_full_circle_within_tolerance() -> anyhow::Result<()> {
+        test_chord_error([[0.], [TAU]], 1., 0.01)?;
+        test_chord_error([[0.], [TAU]], 10., 0.1)?;
+        test_chord_error([[TAU], [0.]], 3., 0.05)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn chord_error_of_partial_arc_within_tolerance() -> anyhow::Result<()> {
+        test_chord_error([[0.5], [2.5]], 1., 0.01)?;
+        test_chord_error([[1.], [TAU - 1.]], 10., 0.1)?;
+        test_chord_error([[2.5], [0.5]], 3., 0.05)?;
+
+        Ok(())
+    }
+
+    fn test_chord_error(
+        boundary: impl Into<CurveBoundary<Point<1>>>,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Scalar>,
+    ) -> anyhow::Result<()> {
+        let boundary = boundary.into();
+        let tolerance = Tolerance::from_scalar(tolerance)?;
+
+        let circle = Circle::from_center_and_radius([0., 0.], radius);
+
+        // `generate_polyline` only returns points between the boundary, so we
+        // need to add the boundary points ourselves to get the full polyline.
+        let [start, end] = boundary.inner;
+        let points = [start]
+            .into_iter()
+            .chain(circle.generate_polyline(boundary, tolerance))
+            .chain([end])
+            .map(|point_curve| circle.point_from_circle_coords(point_curve))
+            .collect::<Vec<_>>();
+
+        assert!(points.len() > 2);
+
+        for segment in points.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+
+            // The chord error (or sagitta) is the maximum distance between
+            // the chord and the arc. It can be found at the chord's midpoint.
+            let midpoint = a + (b - a) / 2.;
+            let chord_error =
+                circle.radius() - midpoint.distance_to(&circle.center());
+
+            assert!(
+                chord_error <= tolerance.inner(),
+                "Chord error `{chord_error}` exceeds tolerance `{}`",
+                tolerance.inner(),
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn project_point_onto_circle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let circle = Circle::from_center_and_radius([1., 1.], 2.);
+
+        // A point outside of the circle is projected onto the nearest point on
+        // the circle.
+        let point = Point::from([1., 4.]);
+        let projected = circle.project_point(point, tolerance);
+        assert_eq!(projected, Point::from([Scalar::PI / 2.]));
+
+        // As is a point inside of it.
+        let point = Point::from([0.5, 1.]);
+        let projected = circle.project_point(point, tolerance);
+        assert_eq!(projected, Point::from([Scalar::PI]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn length_of_arc_converges() -> anyhow::Result<()> {
+        let circle = Circle::from_center_and_radius([0., 0.], 2.);
+        let boundary =
+            CurveBoundary::<Point<1>>::from([[0.5], [0.5 + TAU / 2.]]);
+        let exact = Scalar::TAU;
+
+        let mut previous_error = None;
+        for tolerance in [0.1, 0.01, 0.001] {
+            let tolerance = Tolerance::from_scalar(tolerance)?;
+            let length = circle.length(boundary, tolerance);
+
+            // The polyline is inscribed into the circle, so it can't be longer.
+            let error = exact - length;
+            assert!(error >= Scalar::ZERO);
+
+            if let Some(previous_error) = previous_error {
+                assert!(error < previous_error);
+            }
+            previous_error = Some(error);
+        }
+
+        assert!(previous_error < Some(Scalar::from(0.01)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn bounding_box_of_arc_converges() -> anyhow::Result<()> {
+        let circle = Circle::from_center_and_radius([0., 0.], 2.);
+
+        // The arc crosses the positive x-axis, where it reaches its maximum
+        // x-coordinate. That's not a boundary point, so the polyline might not
+        // reach it.
+        let boundary = CurveBoundary::<Point<1>>::from([[-0.5], [TAU / 4.]]);
+        let exact_max = Point::from([2., 2.]);
+
+        let mut previous_max: Option<Point<2>> = None;
+        for tolerance in [0.1, 0.01, 0.001] {
+            let tolerance = Tolerance::from_scalar(tolerance)?;
+            let aabb = circle.bounding_box(boundary, tolerance);
+
+            // The box is conservative, but exceeds the exact extent by no
+            // more than the tolerance along each axis.
+            for (max, exact) in aabb
+                .max
+                .coords
+                .components
+                .into_iter()
+                .zip(exact_max.coords.components)
+            {
+                assert!(max >= exact);
+                assert!(max - exact <= tolerance.inner() + 1e-12);
+            }
+
+            if let Some(previous_max) = previous_max {
+                assert!(aabb.max.u < previous_max.u);
+                assert!(aabb.max.v < previous_max.v);
+            }
+            previous_max = Some(aabb.max);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn bounding_box_of_arc_starting_at_zero() -> anyhow::Result<()> {
+        let circle = Circle::from_center_and_radius([0., 0.], 1.);
+        let tolerance = Tolerance::from_scalar(0.1)?;
+
+        // The arc reaches its maximum y-coordinate at its middle, which is not
+        // necessarily a point of the polyline.
+        let boundary = CurveBoundary::<Point<1>>::from([[0.], [TAU / 2.]]);
+        let aabb = circle.bounding_box(boundary, tolerance);
+
+        assert!(aabb.max.v >= Scalar::ONE);
+        assert!(aabb.max.u >= Scalar::ONE);
+        assert!(aabb.min.u <= -Scalar::ONE);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tangent_of_circle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let circle = Circle::from_center_and_radius([1., 1.], 2.);
+
+        // Circles use the finite difference approximation on the polyline.
+        // Between approximation points, that results in the direction of the
+        // polyline's segment, which deviates from the exact tangent by no more
+        // than half an increment.
+        let params = CircleApproxParams::new(circle.radius(), tolerance);
+        for t in [Scalar::ZERO, params.increment() * 3., Scalar::from(1.)] {
+            let tangent = circle.tangent_at(Point::from([t]), tolerance);
+            let (sin, cos) = t.sin_cos();
+            let exact = Vector::from([-sin, cos]);
+
+            assert!((tangent - exact).magnitude() < params.increment() / 2.);
+            assert!(
+                (tangent.magnitude() - Scalar::ONE).abs() < Scalar::from(1e-12)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn adaptive_polyline_refines_with_tolerance() -> anyhow::Result<()> {
+        let circle = Circle::from_center_and_radius([0., 0.], 2.);
+        let boundary = CurveBoundary::from([[0.], [TAU * 0.75]]);
+
+        let mut previous_num_points = None;
+        for tolerance in [0.1, 0.01, 0.001] {
+            let tolerance = Tolerance::from_scalar(tolerance)?;
+
+            let points = circle.generate_polyline_adaptive(boundary, tolerance);
+            assert_eq!(points.first(), Some(&boundary.inner[0]));
+            assert_eq!(points.last(), Some(&boundary.inner[1]));
+
+            for segment in points.windows(2) {
+                let [a, b] = [segment[0], segment[1]]
+                    .map(|point| circle.point_from_circle_coords(point));
+
+                let midpoint = a + (b - a) / 2.;
+                let chord_error =
+                    circle.radius() - midpoint.distance_to(&circle.center());
+                assert!(chord_error <= tolerance.inner());
+            }
+
+            if let Some(previous_num_points) = previous_num_points {
+                assert!(points.len() > previous_num_points);
+            }
+            previous_num_points = Some(points.len());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn curve_representation_must_be_deterministic() -> anyhow::Result<()> {
         let circle = Circle::from_center_and_radius([0., 0.], 1.);
@@ -0,0 +1,378 @@
+//! # Geometry code specific to ellipses
+
+use fj_interop::Tolerance;
+use fj_math::{LineSegment, Point, Scalar, Vector};
+
+use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+/// # An n-dimensional ellipse
+///
+/// The curve coordinate of an ellipse is the angular parameter `t`, which maps
+/// to the point `center + a * cos(t) + b * sin(t)`. Unless the ellipse is a
+/// circle, this is not the same as the angle between `a` and the point.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ellipse<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Ellipse<D> {
+    /// # Construct an ellipse
+    ///
+    /// `a` is the semi-major axis, `b` the semi-minor axis.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - `b` must not have zero length.
+    /// - `a` must be at least as long as `b`.
+    /// - `a` and `b` must be perpendicular to each other.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        assert_ne!(
+            b.magnitude(),
+            Scalar::ZERO,
+            "semi-minor axis must not have zero length"
+        );
+        assert!(
+            a.magnitude() >= b.magnitude(),
+            "semi-major axis must not be shorter than semi-minor axis"
+        );
+        // Same as for circles, requiring the axes to be *precisely*
+        // perpendicular is not practical.
+        assert!(
+            a.dot(&b).abs() < Scalar::from(1e-12),
+            "`a` and `b` must be perpendicular to each other"
+        );
+
+        Self { center, a, b }
+    }
+
+    /// # Access the center of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// # Access the semi-major axis
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// # Access the semi-minor axis
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// # Compute the foci of the ellipse
+    ///
+    /// For any point on the ellipse, the sum of the distances to both foci is
+    /// the length of the major axis.
+    pub fn foci(&self) -> [Point<D>; 2] {
+        let [a, b] = [self.a, self.b].map(|axis| axis.magnitude());
+        let focal_distance = (a * a - b * b).sqrt();
+        let offset = self.a.normalize() * focal_distance;
+
+        [self.center - offset, self.center + offset]
+    }
+
+    /// # Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_path_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let (sin, cos) = point.into().t.sin_cos();
+        self.center + self.a * cos + self.b * sin
+    }
+
+    /// # Compute the curve coordinates used to approximate a full turn
+    ///
+    /// The returned values start at zero and end at `2π`. They are computed by
+    /// adaptive subdivision of the four quarters of the ellipse, which results
+    /// in more points near the ends of the major axis, where the curvature is
+    /// highest.
+    ///
+    /// Since the quarters are fixed, the result only depends on the ellipse and
+    /// the tolerance. This keeps the approximation deterministic, regardless of
+    /// the boundary it is generated for.
+    fn approx_params(&self, tolerance: Tolerance) -> Vec<Scalar> {
+        let quarter = Scalar::PI / 2.;
+
+        let mut params = vec![Scalar::ZERO];
+        for i in 0..4 {
+            let boundary = CurveBoundary::from(
+                [quarter * i as f64, quarter * (i + 1) as f64].map(|t| [t]),
+            );
+
+            params.extend(
+                self.generate_polyline_adaptive(boundary, tolerance)
+                    .into_iter()
+                    .skip(1)
+                    .map(|point| point.t),
+            );
+        }
+
+        params
+    }
+}
+
+impl<const D: usize> GenPolyline<D> for Ellipse<D> {
+    fn origin(&self) -> Point<D> {
+        self.center + self.a
+    }
+
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_path_coords(point_curve)
+    }
+
+    fn line_segment_at(
+        &self,
+        point_curve: Point<1>,
+        tolerance: Tolerance,
+    ) -> LineSegment<D> {
+        let params = self.approx_params(tolerance);
+
+        // The approximation parameters only cover a single turn. Figure out
+        // which turn the point is in, and where it is within that turn.
+        let turn = (point_curve.t / Scalar::TAU).floor();
+        let t = point_curve.t - turn * Scalar::TAU;
+
+        let i = params
+            .partition_point(|param| *param <= t)
+            .clamp(1, params.len() - 1);
+        let [a, b] = [params[i - 1], params[i]];
+
+        // If the point is located on one of the points of the polyline, we
+        // should return a degenerate line segment at that point. Same as for
+        // circles, floating point inaccuracy must not prevent that.
+        let [a, b] = if (t - a).abs() < Scalar::from(1e-9) {
+            [a, a]
+        } else if (t - b).abs() < Scalar::from(1e-9) {
+            [b, b]
+        } else {
+            [a, b]
+        };
+
+        let points_curve =
+            [a, b].map(|t| Point::from([t + turn * Scalar::TAU]));
+        let points = points_curve
+            .map(|point_curve| self.point_from_path_coords(point_curve));
+
+        LineSegment {
+            points,
+            points_line: points_curve,
+        }
+    }
+
+    fn generate_polyline(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Vec<Point<1>> {
+        let mut params = self.approx_params(tolerance);
+
+        // The last value is `2π`, which is the same point as the first value of
+        // the next turn.
+        params.pop();
+
+        let [start, end] = boundary.inner;
+        let [min, max] = if start < end {
+            [start.t, end.t]
+        } else {
+            [end.t, start.t]
+        };
+
+        let [first_turn, last_turn] =
+            [min, max].map(|t| (t / Scalar::TAU).floor().into_f64() as i64);
+
+        let mut points = (first_turn..=last_turn)
+            .flat_map(|turn| {
+                params.iter().map(move |t| *t + Scalar::TAU * turn as f64)
+            })
+            .filter(|t| min < *t && *t < max)
+            .map(|t| Point::from([t]))
+            .collect::<Vec<_>>();
+
+        if start > end {
+            points.reverse();
+        }
+
+        points
+    }
+
+    fn project_point(&self, point: Point<D>, tolerance: Tolerance) -> Point<1> {
+        // There is no closed-form solution for projecting a point onto an
+        // ellipse. Start with the closest point of the approximation, then
+        // refine that using Newton's method, to find the curve coordinate where
+        // the vector to the point is perpendicular to the tangent.
+        let mut t = self
+            .approx_params(tolerance)
+            .into_iter()
+            .min_by_key(|t| {
+                self.point_from_path_coords([*t]).distance_to(&point)
+            })
+            .expect("Approximation of ellipse always has points");
+
+        for _ in 0..32 {
+            let (sin, cos) = t.sin_cos();
+            let to_point = self.point_from_path_coords([t]) - point;
+            let derivative = self.b * cos - self.a * sin;
+            let second_derivative = -(self.a * cos + self.b * sin);
+
+            let f = to_point.dot(&derivative);
+            let df =
+                derivative.dot(&derivative) + to_point.dot(&second_derivative);
+
+            if df == Scalar::ZERO {
+                break;
+            }
+
+            let step = f / df;
+            t -= step;
+
+            if step.abs() < Scalar::from(1e-12) {
+                break;
+            }
+        }
+
+        let t = t - (t / Scalar::TAU).floor() * Scalar::TAU;
+        Point::from([t])
+    }
+
+    fn tangent_at(&self, point_curve: Point<1>, _: Tolerance) -> Vector<D> {
+        // The derivative can't be zero, as `a` and `b` are perpendicular, and
+        // neither is zero.
+        let (sin, cos) = point_curve.t.sin_cos();
+        (self.b * cos - self.a * sin).normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar};
+
+    use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+    use super::Ellipse;
+
+    #[test]
+    fn points_are_on_analytic_ellipse() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let ellipse = Ellipse::new([1., 2.], [3., 0.], [0., 2.]);
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+
+        for point_curve in ellipse.generate_polyline(boundary, tolerance) {
+            let point = ellipse.point_from_path_coords(point_curve);
+            let [x, y] = [point.u - 1., point.v - 2.];
+
+            let error = (x / 3.) * (x / 3.) + (y / 2.) * (y / 2.) - 1.;
+            assert!(error.abs() < Scalar::from(1e-12));
+
+            let expected = Point::from([
+                point_curve.t.cos() * 3. + 1.,
+                point_curve.t.sin() * 2. + 2.,
+            ]);
+            assert!(point.distance_to(&expected) < Scalar::from(1e-12));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sum_of_distances_to_foci_is_constant() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let ellipse = Ellipse::new([0., 0., 1.], [0., 5., 0.], [3., 0., 0.]);
+        let [f1, f2] = ellipse.foci();
+        let major_axis = Scalar::from(10.);
+
+        let boundary = CurveBoundary::from([[-1.], [TAU]]);
+        for point_curve in ellipse.generate_polyline(boundary, tolerance) {
+            let point = ellipse.point_from_path_coords(point_curve);
+            let sum = point.distance_to(&f1) + point.distance_to(&f2);
+
+            assert!((sum - major_axis).abs() < Scalar::from(1e-12));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn approximation_is_denser_near_ends_of_major_axis() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let ellipse = Ellipse::new([0., 0.], [4., 0.], [0., 1.]);
+
+        // Curvature is highest at the ends of the major axis (`t = 0`), and
+        // lowest at the ends of the minor axis (`t = π/2`).
+        let [near_major, near_minor] = [0.01, TAU / 4. - 0.01].map(|t| {
+            let segment = ellipse.line_segment_at(Point::from([t]), tolerance);
+            let [a, b] = segment.points_line;
+            (b - a).t
+        });
+        assert!(near_major < near_minor);
+
+        // The approximation stays within the tolerance.
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+        let points = [Point::from([0.])]
+            .into_iter()
+            .chain(ellipse.generate_polyline(boundary, tolerance))
+            .chain([Point::from([TAU])])
+            .collect::<Vec<_>>();
+        for segment in points.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+            let middle = Point::from([(a.t + b.t) / 2.]);
+
+            let [a, b, middle] =
+                [a, b, middle].map(|t| ellipse.point_from_path_coords(t));
+            let chord_midpoint = a + (b - a) / 2.;
+
+            assert!(chord_midpoint.distance_to(&middle) <= tolerance.inner());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn polyline_is_deterministic_across_turns() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let ellipse = Ellipse::new([0., 0.], [2., 0.], [0., 1.]);
+
+        let a = ellipse.line_segment_at(Point::from([0.3]), tolerance);
+        let b = ellipse.line_segment_at(Point::from([0.3 + TAU]), tolerance);
+
+        assert_eq!(a.points_line[0].t + TAU, b.points_line[0].t);
+        assert!(a.points[0].distance_to(&b.points[0]) < Scalar::from(1e-12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn project_point_onto_ellipse() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let ellipse = Ellipse::new([1., 1.], [3., 0.], [0., 1.]);
+
+        for t in [0., 0.4, 2., 3.5, 6.] {
+            let point = ellipse.point_from_path_coords([t]);
+            let projected = ellipse.project_point(point, tolerance);
+
+            assert!((projected.t - Scalar::from(t)).abs() < Scalar::from(1e-9));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+//! # Geometry code specific to helices
+
+use fj_interop::{CircleApproxParams, Tolerance};
+use fj_math::{Circle, LineSegment, Point, Scalar, Vector};
+
+use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+/// # A helix, as used for modeling threads
+///
+/// The curve coordinate of a helix is the angle around its axis. The angle is
+/// measured from the reference direction, counter-clockwise when looking
+/// against the axis. Angles beyond `2π` represent further turns, each of which
+/// advances along the axis by the pitch.
+///
+/// With a positive pitch, the helix is right-handed. A negative pitch results
+/// in a left-handed helix.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Helix {
+    /// # A point on the axis, where the height is zero
+    pub origin: Point<3>,
+
+    /// # The direction of the axis, normalized
+    pub axis: Vector<3>,
+
+    /// # The radius of the helix
+    pub radius: Scalar,
+
+    /// # The distance along the axis, that the helix advances per turn
+    pub pitch: Scalar,
+
+    /// # The direction that corresponds to an angle of zero, normalized
+    ///
+    /// This is perpendicular to the axis.
+    pub reference: Vector<3>,
+}
+
+impl Helix {
+    /// # Construct a helix
+    ///
+    /// The axis doesn't need to be normalized. Neither does the reference
+    /// direction, nor does it need to be perpendicular to the axis. Only its
+    /// component perpendicular to the axis is used.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the axis has zero length, or if the reference direction is
+    /// parallel to the axis.
+    pub fn new(
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        radius: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+        reference: impl Into<Vector<3>>,
+    ) -> Self {
+        let axis = axis.into();
+        let reference = reference.into();
+
+        assert!(
+            axis.magnitude() > Scalar::ZERO,
+            "Axis of helix must not have zero length",
+        );
+        let axis = axis.normalize();
+
+        let reference = reference - axis * reference.dot(&axis);
+        assert!(
+            reference.magnitude() > Scalar::ZERO,
+            "Reference direction of helix must not be parallel to axis",
+        );
+        let reference = reference.normalize();
+
+        Self {
+            origin: origin.into(),
+            axis,
+            radius: radius.into(),
+            pitch: pitch.into(),
+            reference,
+        }
+    }
+
+    /// # Convert a point in curve coordinates into a 3D point
+    pub fn point_from_helix_coords(
+        &self,
+        point_curve: impl Into<Point<1>>,
+    ) -> Point<3> {
+        let point_curve = point_curve.into();
+
+        self.circle().point_from_circle_coords(point_curve)
+            + self.axis * self.height_at(point_curve.t)
+    }
+
+    /// # Convert a 3D point into curve coordinates
+    ///
+    /// The angle around the axis determines the position within a turn. The
+    /// height along the axis is then used to select the turn that is closest to
+    /// the point. For points on the helix, this is exact.
+    ///
+    /// For points on the axis, the angle is not defined, and an angle of zero
+    /// is assumed. If the pitch is zero, all turns coincide, and the first one
+    /// is selected.
+    pub fn point_to_helix_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<1> {
+        let point = point.into();
+
+        let height = (point - self.origin).dot(&self.axis);
+        let angle = self
+            .circle()
+            .point_to_circle_coords(point - self.axis * height)
+            .t;
+
+        if self.pitch == Scalar::ZERO {
+            return Point::from([angle]);
+        }
+
+        // Where the point would be, if it were located on the helix, judging by
+        // its height alone. The angle we computed is only correct up to a full
+        // turn, so we add the number of full turns that gets us closest.
+        let angle_from_height = height / self.pitch * Scalar::TAU;
+        let turns = ((angle_from_height - angle) / Scalar::TAU).round();
+
+        Point::from([angle + turns * Scalar::TAU])
+    }
+
+    fn height_at(&self, angle: Scalar) -> Scalar {
+        self.pitch * angle / Scalar::TAU
+    }
+
+    fn circle(&self) -> Circle<3> {
+        let a = self.reference * self.radius;
+        let b = self.axis.cross(&self.reference) * self.radius;
+
+        Circle::new(self.origin, a, b)
+    }
+}
+
+impl GenPolyline<3> for Helix {
+    fn origin(&self) -> Point<3> {
+        self.point_from_helix_coords([Scalar::ZERO])
+    }
+
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<3> {
+        self.point_from_helix_coords(point_curve)
+    }
+
+    fn line_segment_at(
+        &self,
+        point_curve: Point<1>,
+        tolerance: Tolerance,
+    ) -> LineSegment<3> {
+        // The distance between a segment of the helix and its chord is the same
+        // as for the circle that results from projecting it along the axis. So
+        // we can use the same approximation parameters as a circle, and the
+        // same approach to finding the line segment.
+        let params = CircleApproxParams::new(self.radius, tolerance);
+
+        let t = point_curve.t / params.increment();
+        let t = if (t - t.round()).abs() < Scalar::from(1e-9) {
+            t.round()
+        } else {
+            t
+        };
+
+        let points_curve = [t.floor(), t.ceil()]
+            .map(|point_curve_in_increment_units| {
+                [point_curve_in_increment_units * params.increment()]
+            })
+            .map(Point::from);
+        let points = points_curve
+            .map(|point_curve| self.point_from_helix_coords(point_curve));
+
+        LineSegment {
+            points,
+            points_line: points_curve,
+        }
+    }
+
+    fn generate_polyline(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Vec<Point<1>> {
+        let params = CircleApproxParams::new(self.radius, tolerance);
+        params.approx_circle(boundary.inner).collect()
+    }
+
+    fn project_point(&self, point: Point<3>, _: Tolerance) -> Point<1> {
+        self.point_to_helix_coords(point)
+    }
+
+    fn tangent_at(&self, point_curve: Point<1>, _: Tolerance) -> Vector<3> {
+        // The helix is a smooth curve, so we can compute the exact derivative.
+        // It can't be zero, as the circular component never vanishes.
+        let circle = self.circle();
+        let (sin, cos) = point_curve.t.sin_cos();
+
+        let derivative = circle.a() * -sin
+            + circle.b() * cos
+            + self.axis * (self.pitch / Scalar::TAU);
+
+        derivative.normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+    use super::Helix;
+
+    #[test]
+    fn one_pitch() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let helix =
+            Helix::new([0., 0., 0.], [0., 0., 1.], 2., 0.5, [1., 0., 0.]);
+        let boundary = CurveBoundary::<Point<1>>::from([[0.], [TAU]]);
+
+        let [start, end] = boundary.inner;
+        let points = [start]
+            .into_iter()
+            .chain(helix.generate_polyline(boundary, tolerance))
+            .chain([end])
+            .map(|point_curve| helix.point_from_helix_coords(point_curve))
+            .collect::<Vec<_>>();
+
+        assert_eq!(points.first(), Some(&helix.origin()));
+        assert_close(points[0], [2., 0., 0.]);
+        assert_close(*points.last().unwrap(), [2., 0., 0.5]);
+
+        // The height increases monotonically along the polyline.
+        for segment in points.windows(2) {
+            assert!(segment[1].z > segment[0].z);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn chord_error_within_tolerance() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let helix =
+            Helix::new([0., 0., 0.], [0., 0., 1.], 2., 0.5, [1., 0., 0.]);
+        let boundary = CurveBoundary::<Point<1>>::from([[0.5], [TAU * 3.]]);
+
+        for point_curve in helix.generate_polyline(boundary, tolerance) {
+            let segment = helix.line_segment_at(point_curve, tolerance);
+            let [a, b] = segment.points_line;
+            let midpoint_curve = Point::from([(a.t + b.t) / 2.]);
+
+            let midpoint_chord = segment.points[0]
+                + (segment.points[1] - segment.points[0]) / 2.;
+            let midpoint_helix = helix.point_from_helix_coords(midpoint_curve);
+
+            assert!(
+                midpoint_chord.distance_to(&midpoint_helix)
+                    <= tolerance.inner()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn project_point_across_multiple_turns() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let helix =
+            Helix::new([1., 1., 1.], [0., 0., 2.], 2., -0.5, [0., 1., 0.]);
+
+        for t in [0., 1., 7., 13.5, -4.] {
+            let point = helix.point_from_helix_coords([t]);
+            let projected = helix.project_point(point, tolerance);
+
+            assert!((projected.t - Scalar::from(t)).abs() < Scalar::from(1e-9));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn tangent_of_helix() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let helix = Helix::new(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            1.,
+            Scalar::TAU,
+            [1., 0., 0.],
+        );
+
+        // With this pitch, the helix rises by one unit per radian, which is the
+        // same as the speed of its circular component. The tangent is at 45°.
+        let tangent = helix.tangent_at(Point::from([0.]), tolerance);
+        let expected = Vector::from([0., 1., 1.]).normalize();
+        assert!((tangent - expected).magnitude() < Scalar::from(1e-12));
+
+        Ok(())
+    }
+
+    fn assert_close(actual: Point<3>, expected: impl Into<Point<3>>) {
+        let expected = expected.into();
+        assert!(
+            actual.distance_to(&expected) < Scalar::from(1e-12),
+            "Expected {expected:?}, got {actual:?}",
+        );
+    }
+}
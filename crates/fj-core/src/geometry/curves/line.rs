@@ -1,7 +1,7 @@
 //! # Geometry code specific to lines
 
 use fj_interop::Tolerance;
-use fj_math::{Line, LineSegment, Point};
+use fj_math::{Line, LineSegment, Point, Vector};
 
 use crate::geometry::{CurveBoundary, traits::GenPolyline};
 
@@ -10,6 +10,10 @@ impl<const D: usize> GenPolyline<D> for Line<D> {
         self.origin()
     }
 
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_line_coords(point_curve)
+    }
+
     fn line_segment_at(
         &self,
         point_curve: Point<1>,
@@ -24,6 +28,10 @@ impl<const D: usize> GenPolyline<D> for Line<D> {
         }
     }
 
+    fn is_linear(&self) -> bool {
+        true
+    }
+
     fn generate_polyline(
         &self,
         boundary: CurveBoundary<Point<1>>,
@@ -31,4 +39,146 @@ impl<const D: usize> GenPolyline<D> for Line<D> {
     ) -> Vec<Point<1>> {
         boundary.inner.into()
     }
+
+    fn project_point(&self, point: Point<D>, _: Tolerance) -> Point<1> {
+        // Lines don't require approximation, so the projection is exact.
+        self.point_to_line_coords(point)
+    }
+
+    fn tangent_at(&self, _: Point<1>, _: Tolerance) -> Vector<D> {
+        // A line's direction is constant, and can't be zero.
+        self.direction().normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Aabb, Line, Point, Scalar, Vector};
+
+    use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+    #[test]
+    fn project_point_onto_line() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 1., 1.]),
+            Vector::from([2., 0., 0.]),
+        );
+
+        // Points on the line are projected to their own line coordinates.
+        assert_eq!(
+            line.project_point(Point::from([4., 1., 1.]), tolerance),
+            Point::from([1.5]),
+        );
+
+        // Points off the line are projected to the foot of the perpendicular.
+        let point = Point::from([0., 3., -2.]);
+        let projected = line.project_point(point, tolerance);
+        assert_eq!(projected, Point::from([-0.5]));
+
+        let foot = line.point_from_line_coords(projected);
+        assert_eq!(foot, Point::from([0., 1., 1.]));
+        assert_eq!((point - foot).dot(&line.direction()), Scalar::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn length_of_line() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 1., 1.]),
+            Vector::from([3., 4., 0.]),
+        );
+
+        let boundary = CurveBoundary::<Point<1>>::from([[-1.], [1.]]);
+        assert_eq!(line.length(boundary, tolerance), Scalar::from(10.));
+        assert_eq!(
+            line.length(boundary.reverse(), tolerance),
+            Scalar::from(10.)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn bounding_box_of_line_segment() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 1., 1.]),
+            Vector::from([3., -4., 0.]),
+        );
+
+        let boundary = CurveBoundary::<Point<1>>::from([[-1.], [1.]]);
+        assert_eq!(
+            line.bounding_box(boundary, tolerance),
+            Aabb {
+                min: Point::from([-2., -3., 1.]),
+                max: Point::from([4., 5., 1.]),
+            },
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tangent_of_line() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 1., 1.]),
+            Vector::from([0., 3., 4.]),
+        );
+
+        for t in [-10., 0., 0.5, 100.] {
+            assert_eq!(
+                line.tangent_at(Point::from([t]), tolerance),
+                Vector::from([0., 0.6, 0.8]),
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn adaptive_polyline_of_line() -> anyhow::Result<()> {
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 1.]),
+            Vector::from([2., 1.]),
+        );
+        let boundary = CurveBoundary::from([[-3.], [5.]]);
+
+        for tolerance in [0.1, 0.01, 0.0001] {
+            let tolerance = Tolerance::from_scalar(tolerance)?;
+
+            let points = line.generate_polyline_adaptive(boundary, tolerance);
+            assert_eq!(points, boundary.inner.to_vec());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sample_line_evenly() {
+        let line = Line::from_origin_and_direction(
+            Point::from([1., 0.]),
+            Vector::from([2., 0.]),
+        );
+        let boundary = CurveBoundary::from([[0.], [2.]]);
+
+        let samples = line.sample(boundary, 4).collect::<Vec<_>>();
+        assert_eq!(
+            samples,
+            [[1., 0.], [2., 0.], [3., 0.], [4., 0.], [5., 0.]].map(Point::from),
+        );
+
+        let spacing = Scalar::from(1.);
+        for segment in samples.windows(2) {
+            assert_eq!(segment[0].distance_to(&segment[1]), spacing);
+        }
+    }
 }
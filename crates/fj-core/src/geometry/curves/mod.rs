@@ -1,4 +1,8 @@
 //! # Geometry code specific to various types of curves
 
+pub mod bezier;
 pub mod circle;
+pub mod ellipse;
+pub mod helix;
 pub mod line;
+pub mod nurbs;
@@ -0,0 +1,562 @@
+//! # Geometry code specific to NURBS curves
+
+use fj_interop::Tolerance;
+use fj_math::{LineSegment, Point, Scalar, Vector};
+
+use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+/// # A non-uniform rational B-spline (NURBS) curve
+///
+/// The curve coordinate of a NURBS curve is its parameter, as defined by the
+/// knot vector. The curve is defined between `knots[degree]` and
+/// `knots[knots.len() - degree - 1]`. Evaluating it outside of that domain
+/// clamps the parameter to the domain.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NurbsCurve<const D: usize> {
+    degree: usize,
+    control_points: Vec<Point<D>>,
+    weights: Vec<Scalar>,
+    knots: Vec<Scalar>,
+}
+
+impl<const D: usize> NurbsCurve<D> {
+    /// # Construct a NURBS curve
+    ///
+    /// Returns an error, if the following requirements are not met:
+    ///
+    /// - There must be more control points than the degree.
+    /// - There must be one weight per control point, and all weights must be
+    ///   positive.
+    /// - The number of knots must equal the number of control points, plus the
+    ///   degree, plus one.
+    /// - The knots must not be decreasing, and the domain of the curve must not
+    ///   be empty.
+    pub fn new(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+        weights: impl IntoIterator<Item = impl Into<Scalar>>,
+        knots: impl IntoIterator<Item = impl Into<Scalar>>,
+    ) -> Result<Self, InvalidNurbsCurve> {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+        let weights = weights.into_iter().map(Into::into).collect::<Vec<_>>();
+        let knots = knots.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        let num_control_points = control_points.len();
+        if num_control_points <= degree {
+            return Err(InvalidNurbsCurve::NotEnoughControlPoints {
+                degree,
+                num_control_points,
+            });
+        }
+        if weights.len() != num_control_points {
+            return Err(InvalidNurbsCurve::WrongNumberOfWeights {
+                expected: num_control_points,
+                actual: weights.len(),
+            });
+        }
+        if weights.iter().any(|weight| *weight <= Scalar::ZERO) {
+            return Err(InvalidNurbsCurve::NonPositiveWeight);
+        }
+        if knots.len() != num_control_points + degree + 1 {
+            return Err(InvalidNurbsCurve::WrongNumberOfKnots {
+                expected: num_control_points + degree + 1,
+                actual: knots.len(),
+            });
+        }
+        if knots.windows(2).any(|knots| knots[0] > knots[1])
+            || knots[degree] >= knots[num_control_points]
+        {
+            return Err(InvalidNurbsCurve::InvalidKnots);
+        }
+
+        Ok(Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        })
+    }
+
+    /// # Construct a Bézier curve
+    ///
+    /// A Bézier curve is a NURBS curve without interior knots, with all weights
+    /// being one. Its degree is one less than the number of control points,
+    /// and its domain is `[0, 1]`.
+    ///
+    /// Returns an error, if there are fewer than two control points.
+    pub fn bezier(
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Result<Self, InvalidNurbsCurve> {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+        let num_control_points = control_points.len();
+
+        if num_control_points < 2 {
+            return Err(InvalidNurbsCurve::NotEnoughControlPoints {
+                degree: 1,
+                num_control_points,
+            });
+        }
+
+        let knots = [0., 1.]
+            .into_iter()
+            .flat_map(|knot| vec![knot; num_control_points]);
+
+        Self::new(
+            num_control_points - 1,
+            control_points,
+            vec![1.; num_control_points],
+            knots,
+        )
+    }
+
+    /// # Access the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// # Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// # Access the weights of the control points
+    pub fn weights(&self) -> &[Scalar] {
+        &self.weights
+    }
+
+    /// # Access the knot vector of the curve
+    pub fn knots(&self) -> &[Scalar] {
+        &self.knots
+    }
+
+    /// # Access the domain of the curve, in curve coordinates
+    pub fn domain(&self) -> [Scalar; 2] {
+        [
+            self.knots[self.degree],
+            self.knots[self.control_points.len()],
+        ]
+    }
+
+    /// # Convert a point in curve coordinates into a `D`-dimensional point
+    ///
+    /// Uses de Boor's algorithm in homogeneous coordinates, to account for the
+    /// weights.
+    pub fn point_from_path_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let [min, max] = self.domain();
+        let t = point.into().t.clamp(min, max);
+
+        // Find the knot span that contains `t`. At the end of the domain, we
+        // need to use the last non-empty span, instead of the empty one after.
+        let p = self.degree;
+        let k = self
+            .knots
+            .partition_point(|knot| *knot <= t)
+            .saturating_sub(1)
+            .clamp(p, self.control_points.len() - 1);
+
+        let mut d = (0..=p)
+            .map(|j| {
+                let i = j + k - p;
+                let weight = self.weights[i];
+                (self.control_points[i].coords * weight, weight)
+            })
+            .collect::<Vec<_>>();
+
+        for r in 1..=p {
+            for j in (r..=p).rev() {
+                let left = self.knots[j + k - p];
+                let right = self.knots[j + 1 + k - r];
+                let alpha = (t - left) / (right - left);
+
+                let (a, a_weight) = d[j - 1];
+                let (b, b_weight) = d[j];
+                d[j] = (
+                    a * (Scalar::ONE - alpha) + b * alpha,
+                    a_weight * (Scalar::ONE - alpha) + b_weight * alpha,
+                );
+            }
+        }
+
+        let (coords, weight) = d[p];
+        Point {
+            coords: coords / weight,
+        }
+    }
+
+    /// # Compute the curve coordinates used to approximate the curve
+    ///
+    /// Each non-empty knot span is subdivided adaptively. Since the knot spans
+    /// are fixed, this keeps the approximation deterministic, regardless of the
+    /// boundary it is generated for.
+    fn approx_params(&self, tolerance: Tolerance) -> Vec<Scalar> {
+        let [min, max] = self.domain();
+
+        let mut params = vec![min];
+        for span in self.knots.windows(2) {
+            let [a, b] = [span[0], span[1]];
+            if a == b || b <= min || a >= max {
+                continue;
+            }
+
+            let boundary = CurveBoundary::from([[a], [b]]);
+            params.extend(
+                self.generate_polyline_adaptive(boundary, tolerance)
+                    .into_iter()
+                    .skip(1)
+                    .map(|point| point.t),
+            );
+        }
+
+        params
+    }
+}
+
+impl<const D: usize> GenPolyline<D> for NurbsCurve<D> {
+    fn origin(&self) -> Point<D> {
+        let [start, _] = self.domain();
+        self.point_from_path_coords([start])
+    }
+
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_path_coords(point_curve)
+    }
+
+    fn line_segment_at(
+        &self,
+        point_curve: Point<1>,
+        tolerance: Tolerance,
+    ) -> LineSegment<D> {
+        let params = self.approx_params(tolerance);
+        let t = point_curve.t;
+
+        let i = params
+            .partition_point(|param| *param <= t)
+            .clamp(1, params.len() - 1);
+        let [a, b] = [params[i - 1], params[i]];
+
+        // If the point is located on one of the points of the polyline, we
+        // should return a degenerate line segment at that point. Same as for
+        // circles, floating point inaccuracy must not prevent that.
+        let [a, b] = if (t - a).abs() < Scalar::from(1e-9) {
+            [a, a]
+        } else if (t - b).abs() < Scalar::from(1e-9) {
+            [b, b]
+        } else {
+            [a, b]
+        };
+
+        let points_curve = [a, b].map(|t| Point::from([t]));
+        let points = points_curve
+            .map(|point_curve| self.point_from_path_coords(point_curve));
+
+        LineSegment {
+            points,
+            points_line: points_curve,
+        }
+    }
+
+    fn generate_polyline(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Vec<Point<1>> {
+        let [start, end] = boundary.inner;
+        let [min, max] = if start < end {
+            [start.t, end.t]
+        } else {
+            [end.t, start.t]
+        };
+
+        let mut points = self
+            .approx_params(tolerance)
+            .into_iter()
+            .filter(|t| min < *t && *t < max)
+            .map(|t| Point::from([t]))
+            .collect::<Vec<_>>();
+
+        if start > end {
+            points.reverse();
+        }
+
+        points
+    }
+
+    fn project_point(&self, point: Point<D>, tolerance: Tolerance) -> Point<1> {
+        // Start with the closest point of the approximation. The closest point
+        // on the curve must then be located between its neighbors, where we
+        // can find it using a golden-section search.
+        let params = self.approx_params(tolerance);
+        let distance_at =
+            |t: Scalar| self.point_from_path_coords([t]).distance_to(&point);
+
+        let (i, _) = params
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, t)| distance_at(**t))
+            .expect("Approximation of NURBS curve always has points");
+
+        let mut a = params[i.saturating_sub(1)];
+        let mut b = params[(i + 1).min(params.len() - 1)];
+
+        let ratio = (Scalar::from(5.).sqrt() - 1.) / 2.;
+        while b - a > Scalar::from(1e-12) {
+            let c = b - (b - a) * ratio;
+            let d = a + (b - a) * ratio;
+
+            if distance_at(c) < distance_at(d) {
+                b = d;
+            } else {
+                a = c;
+            }
+        }
+
+        Point::from([(a + b) / 2.])
+    }
+
+    fn tangent_at(&self, point_curve: Point<1>, _: Tolerance) -> Vector<D> {
+        // Evaluating the derivative of a NURBS curve analytically is possible,
+        // but involved. A central difference on the exact curve is accurate
+        // enough for our purposes.
+        let [min, max] = self.domain();
+        let step = (max - min) * 1e-6;
+
+        let [before, after] = [point_curve.t - step, point_curve.t + step]
+            .map(|t| self.point_from_path_coords([t.clamp(min, max)]));
+        let derivative = after - before;
+
+        if derivative.magnitude() == Scalar::ZERO {
+            return Vector::from([Scalar::ZERO; D]);
+        }
+
+        derivative.normalize()
+    }
+}
+
+/// # Error constructing a [`NurbsCurve`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum InvalidNurbsCurve {
+    /// # Not enough control points for the degree
+    #[error(
+        "A NURBS curve of degree {degree} requires more than {degree} control \
+        points; got {num_control_points}"
+    )]
+    NotEnoughControlPoints {
+        /// # The degree of the curve
+        degree: usize,
+
+        /// # The number of control points
+        num_control_points: usize,
+    },
+
+    /// # Number of weights doesn't match number of control points
+    #[error("Expected {expected} weights; got {actual}")]
+    WrongNumberOfWeights {
+        /// # The expected number of weights
+        expected: usize,
+
+        /// # The actual number of weights
+        actual: usize,
+    },
+
+    /// # A weight is zero or negative
+    #[error("Weights of NURBS curve must be positive")]
+    NonPositiveWeight,
+
+    /// # Number of knots doesn't match degree and number of control points
+    #[error("Expected {expected} knots; got {actual}")]
+    WrongNumberOfKnots {
+        /// # The expected number of knots
+        expected: usize,
+
+        /// # The actual number of knots
+        actual: usize,
+    },
+
+    /// # The knots are decreasing, or the domain of the curve is empty
+    #[error(
+        "Knots of NURBS curve must be non-decreasing, with non-empty domain"
+    )]
+    InvalidKnots,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar};
+
+    use crate::geometry::{CurveBoundary, traits::GenPolyline};
+
+    use super::{InvalidNurbsCurve, NurbsCurve};
+
+    #[test]
+    fn bezier() -> anyhow::Result<()> {
+        let curve = NurbsCurve::bezier([[0., 0.], [1., 2.], [2., 0.]])?;
+
+        assert_eq!(curve.degree(), 2);
+        assert_eq!(curve.domain(), [Scalar::ZERO, Scalar::ONE]);
+
+        // For a quadratic Bézier curve, the point in the middle is halfway
+        // between the middle control point and the middle of the other two.
+        assert_eq!(curve.point_from_path_coords([0.5]), Point::from([1., 1.]));
+        assert_eq!(curve.point_from_path_coords([1.]), Point::from([2., 0.]));
+
+        assert!(matches!(
+            NurbsCurve::bezier([[0., 0.]]),
+            Err(InvalidNurbsCurve::NotEnoughControlPoints { .. }),
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn straight_line() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let line = NurbsCurve::new(
+            1,
+            [[1., 1.], [3., 2.]],
+            [1., 1.],
+            [0., 0., 1., 1.],
+        )?;
+
+        assert_eq!(line.origin(), Point::from([1., 1.]));
+        assert_eq!(line.point_from_path_coords([0.5]), Point::from([2., 1.5]));
+        assert_eq!(line.point_from_path_coords([1.]), Point::from([3., 2.]));
+
+        // A line requires no approximation.
+        let boundary = CurveBoundary::from([[0.], [1.]]);
+        assert!(line.generate_polyline(boundary, tolerance).is_empty());
+        assert_eq!(
+            line.generate_polyline_adaptive(boundary, tolerance),
+            boundary.inner.to_vec(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn circle_as_rational_quadratic() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // The standard representation of a full unit circle, made up of four
+        // quarter arcs.
+        let w = FRAC_1_SQRT_2;
+        let circle = NurbsCurve::new(
+            2,
+            [
+                [1., 0.],
+                [1., 1.],
+                [0., 1.],
+                [-1., 1.],
+                [-1., 0.],
+                [-1., -1.],
+                [0., -1.],
+                [1., -1.],
+                [1., 0.],
+            ],
+            [1., w, 1., w, 1., w, 1., w, 1.],
+            [0., 0., 0., 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1., 1., 1.],
+        )?;
+
+        let boundary = CurveBoundary::from([[0.], [1.]]);
+        let points = [Point::from([0.])]
+            .into_iter()
+            .chain(circle.generate_polyline(boundary, tolerance))
+            .chain([Point::from([1.])])
+            .collect::<Vec<_>>();
+        assert!(points.len() > 4);
+
+        for point_curve in &points {
+            let point = circle.point_from_path_coords(*point_curve);
+            assert!(
+                (point.distance_to(&Point::origin()) - Scalar::ONE).abs()
+                    < Scalar::from(1e-12)
+            );
+        }
+
+        for segment in points.windows(2) {
+            let [a, b] = [segment[0], segment[1]]
+                .map(|point| circle.point_from_path_coords(point));
+
+            let midpoint = a + (b - a) / 2.;
+            let chord_error =
+                Scalar::ONE - midpoint.distance_to(&Point::origin());
+            assert!(chord_error <= tolerance.inner());
+        }
+
+        let point = circle.point_from_path_coords([0.3]);
+        let projected = circle.project_point(point, tolerance);
+        assert!((projected.t - Scalar::from(0.3)).abs() < Scalar::from(1e-9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_construction() {
+        assert_eq!(
+            NurbsCurve::<2>::new(
+                2,
+                [[0., 0.], [1., 0.]],
+                [1., 1.],
+                [0., 0., 0., 1., 1.],
+            ),
+            Err(InvalidNurbsCurve::NotEnoughControlPoints {
+                degree: 2,
+                num_control_points: 2,
+            }),
+        );
+        assert_eq!(
+            NurbsCurve::<2>::new(
+                1,
+                [[0., 0.], [1., 0.]],
+                [1.],
+                [0., 0., 1., 1.]
+            ),
+            Err(InvalidNurbsCurve::WrongNumberOfWeights {
+                expected: 2,
+                actual: 1,
+            }),
+        );
+        assert_eq!(
+            NurbsCurve::<2>::new(
+                1,
+                [[0., 0.], [1., 0.]],
+                [1., 0.],
+                [0., 0., 1., 1.],
+            ),
+            Err(InvalidNurbsCurve::NonPositiveWeight),
+        );
+        assert_eq!(
+            NurbsCurve::<2>::new(
+                1,
+                [[0., 0.], [1., 0.]],
+                [1., 1.],
+                [0., 1., 1.]
+            ),
+            Err(InvalidNurbsCurve::WrongNumberOfKnots {
+                expected: 4,
+                actual: 3,
+            }),
+        );
+        assert_eq!(
+            NurbsCurve::<2>::new(
+                1,
+                [[0., 0.], [1., 0.]],
+                [1., 1.],
+                [0., 1., 0., 1.],
+            ),
+            Err(InvalidNurbsCurve::InvalidKnots),
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use std::collections::BTreeMap;
 
-use fj_math::Vector;
+use fj_interop::Tolerance;
+use fj_math::{Point, Scalar, Vector};
 
 use crate::{
     storage::Handle,
-    topology::{Curve, Surface, Topology, Vertex},
+    topology::{AnyObject, Curve, Stored, Surface, Topology, Vertex},
 };
 
 use super::{
@@ -24,6 +25,8 @@ pub struct Geometry {
     curves: BTreeMap<Handle<Curve>, CurveGeom2>,
     surfaces: BTreeMap<Handle<Surface>, SurfaceGeom>,
 
+    rigid_copies: BTreeMap<AnyObject<Stored>, AnyObject<Stored>>,
+
     space_2d: Handle<Surface>,
 
     xy_plane: Handle<Surface>,
@@ -42,6 +45,8 @@ impl Geometry {
             curves: BTreeMap::new(),
             surfaces: BTreeMap::new(),
 
+            rigid_copies: BTreeMap::new(),
+
             space_2d: topology.surfaces.space_2d(),
 
             xy_plane: topology.surfaces.xy_plane(),
@@ -79,6 +84,7 @@ impl Geometry {
                     v: Vector::unit_y(),
                 }),
                 geometry: TriMesh::empty(),
+                is_reversed: false,
             },
         );
         self_.define_surface_inner_2(
@@ -89,6 +95,7 @@ impl Geometry {
                     v: Vector::unit_z(),
                 }),
                 geometry: TriMesh::empty(),
+                is_reversed: false,
             },
         );
         self_.define_surface_inner_2(
@@ -99,6 +106,7 @@ impl Geometry {
                     v: Vector::unit_z(),
                 }),
                 geometry: TriMesh::empty(),
+                is_reversed: false,
             },
         );
 
@@ -179,6 +187,14 @@ impl Geometry {
             .insert(curve, geometry);
     }
 
+    pub(crate) fn define_rigid_copy_inner(
+        &mut self,
+        original: AnyObject<Stored>,
+        copy: AnyObject<Stored>,
+    ) {
+        self.rigid_copies.insert(copy, original);
+    }
+
     /// # Access the geometry of the provided curve
     pub fn of_curve(&self, curve: &Handle<Curve>) -> Option<&CurveGeom> {
         self.curve.get(curve)
@@ -195,6 +211,17 @@ impl Geometry {
             .expect("Expected geometry of surface to be defined")
     }
 
+    /// # Access the geometry of the provided surface, if it is defined
+    ///
+    /// Unlike [`Geometry::of_surface`], this doesn't panic for surfaces that
+    /// have no geometry in 3D space, like the one for 2D sketches.
+    pub(crate) fn of_surface_if_defined(
+        &self,
+        surface: &Handle<Surface>,
+    ) -> Option<&SweptCurve> {
+        self.surface.get(surface)
+    }
+
     /// # Access the geometry of the provided vertex
     pub fn of_vertex(&self, vertex: &Handle<Vertex>) -> Option<&VertexGeom> {
         self.vertex.get(vertex)
@@ -227,6 +254,22 @@ impl Geometry {
         self.surfaces.get(surface)
     }
 
+    /// # Access the original that an object is a rigid copy of
+    ///
+    /// Returns the object that the provided object was derived from,
+    /// if that derivation was a rigid motion (see
+    /// [`DeriveFrom::derive_from_rigid_motion`]). Such a copy is geometrically
+    /// identical to its original, save for its position and orientation in
+    /// space.
+    ///
+    /// [`DeriveFrom::derive_from_rigid_motion`]: crate::operations::derive::DeriveFrom::derive_from_rigid_motion
+    pub fn original_of_rigid_copy(
+        &self,
+        copy: &AnyObject<Stored>,
+    ) -> Option<&AnyObject<Stored>> {
+        self.rigid_copies.get(copy)
+    }
+
     /// Access the geometry of the xy-plane
     pub fn xy_plane(&self) -> &SweptCurve {
         self.of_surface(&self.xy_plane)
@@ -298,7 +341,7 @@ pub enum CurveGeom2 {
     /// # The curve is defined locally on a surface
     Surface {
         /// # A generator for local curve geometry
-        generator: Box<dyn GenPolyline<2>>,
+        generator: Box<dyn GenPolyline<2> + Send + Sync>,
 
         /// # The local representation of curve geometry
         geometry: Polyline<2>,
@@ -310,7 +353,7 @@ pub enum CurveGeom2 {
     /// # The curve is defined globally in 3D space
     Global {
         /// # A generator for global curve geometry
-        generator: Box<dyn GenPolyline<3>>,
+        generator: Box<dyn GenPolyline<3> + Send + Sync>,
 
         /// # The global representation of curve geometry
         geometry: Polyline<3>,
@@ -323,8 +366,50 @@ pub enum CurveGeom2 {
 /// representation.
 pub struct SurfaceGeom {
     /// # A generator for surface geometry
-    pub generator: Box<dyn GenTriMesh>,
+    pub generator: Box<dyn GenTriMesh + Send + Sync>,
 
     /// # The generated surface geometry
     pub geometry: TriMesh,
+
+    /// # Indicate whether the surface is reversed
+    ///
+    /// A reversed surface has the same shape as the one its generator defines,
+    /// but faces the other way. Its normal is flipped.
+    pub is_reversed: bool,
+}
+
+impl SurfaceGeom {
+    /// # Project a 3D point onto the surface
+    ///
+    /// Returns the surface coordinates of the nearest point on the surface, and
+    /// the signed distance of the provided point from the surface. See
+    /// [`GenTriMesh::project_point`] for details.
+    pub fn project_point(
+        &self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        self.generator
+            .project_point(point.into(), tolerance.into(), geometry)
+    }
+
+    /// # Compute the normal at the provided point on the surface
+    ///
+    /// Returns the unit normal, as computed by [`GenTriMesh::normal_at`],
+    /// flipped if the surface is reversed.
+    pub fn normal_at(
+        &self,
+        point_surface: impl Into<Point<2>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Vector<3> {
+        let normal = self.generator.normal_at(
+            point_surface.into(),
+            tolerance.into(),
+            geometry,
+        );
+
+        if self.is_reversed { -normal } else { normal }
+    }
 }
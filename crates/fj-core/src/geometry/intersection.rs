@@ -0,0 +1,380 @@
+//! # Intersections between curve and surface geometry
+//!
+//! See [`intersect`] and [`intersect_surfaces`].
+
+use fj_interop::Tolerance;
+use fj_math::{Line, Point, Scalar, Vector};
+
+use super::{Path, surfaces::SweptCurve};
+
+/// # The intersection between two curves in surface coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurveIntersection {
+    /// The curves cross at a single point
+    Point {
+        /// The intersection point, in the coordinates of each curve
+        points_curve: [Point<1>; 2],
+
+        /// The intersection point, in surface coordinates
+        point_surface: Point<2>,
+    },
+
+    /// The curves are coincident
+    ///
+    /// Lines that are coincident overlap completely, so there's not a single
+    /// point of intersection. Instead, this provides the mapping between the
+    /// coordinate systems of both curves: The points with the coordinates `0`
+    /// and `1` on the first curve have the coordinates in `points_on_b` on the
+    /// second curve.
+    Coincident {
+        /// The coordinates on the second curve, of `0` and `1` on the first
+        points_on_b: [Point<1>; 2],
+    },
+}
+
+/// # Compute the intersection between two curves in surface coordinates
+///
+/// Returns `Ok(None)`, if the curves don't intersect.
+///
+/// Two curves are considered to be coincident, if they are parallel and within
+/// the provided tolerance of each other.
+///
+/// ## Implementation Note
+///
+/// Only intersections between lines are supported so far. Intersecting a
+/// circle with any curve results in [`IntersectionError::UnsupportedCurves`].
+pub fn intersect(
+    a: &Path<2>,
+    b: &Path<2>,
+    tolerance: impl Into<Tolerance>,
+) -> Result<Option<CurveIntersection>, IntersectionError> {
+    let tolerance = tolerance.into();
+
+    match (a, b) {
+        (Path::Line(a), Path::Line(b)) => Ok(intersect_lines(a, b, tolerance)),
+        _ => Err(IntersectionError::UnsupportedCurves),
+    }
+}
+
+fn intersect_lines(
+    a: &Line<2>,
+    b: &Line<2>,
+    tolerance: Tolerance,
+) -> Option<CurveIntersection> {
+    let Some([t, s]) = line_coords_of_intersection(a, b) else {
+        let distance = (b.origin() - a.origin()).cross2d(&a.direction()).abs()
+            / a.direction().magnitude();
+
+        if distance < tolerance.inner() {
+            let points_on_b =
+                [Point::from([0.]), Point::from([1.])].map(|point| {
+                    b.point_to_line_coords(a.point_from_line_coords(point))
+                });
+
+            return Some(CurveIntersection::Coincident { points_on_b });
+        }
+
+        return None;
+    };
+
+    Some(CurveIntersection::Point {
+        points_curve: [Point::from([t]), Point::from([s])],
+        point_surface: a.point_from_line_coords([t]),
+    })
+}
+
+/// # Compute where two lines cross, in the line coordinates of each
+///
+/// Returns `None`, if the lines are parallel.
+pub(crate) fn line_coords_of_intersection(
+    a: &Line<2>,
+    b: &Line<2>,
+) -> Option<[Scalar; 2]> {
+    let [da, db] = [a.direction(), b.direction()];
+    let origin_a_to_b = b.origin() - a.origin();
+
+    // The cross product of the directions, which is the determinant of the
+    // 2x2 system we need to solve. It is zero, if the lines are parallel.
+    let denominator = da.cross2d(&db);
+
+    let is_parallel = denominator.abs()
+        <= Scalar::from(f64::EPSILON) * da.magnitude() * db.magnitude();
+    if is_parallel {
+        return None;
+    }
+
+    let t = origin_a_to_b.cross2d(&db) / denominator;
+    let s = origin_a_to_b.cross2d(&da) / denominator;
+
+    Some([t, s])
+}
+
+/// # Intersecting the provided geometry is not supported
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum IntersectionError {
+    /// # Intersecting circles is not supported yet
+    #[error("Intersecting circles is not supported yet")]
+    UnsupportedCurves,
+
+    /// # Intersecting curved surfaces is not supported yet
+    #[error("Intersecting curved surfaces is not supported yet")]
+    UnsupportedSurfaces,
+}
+
+/// # The intersection between two surfaces
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SurfaceIntersection {
+    /// The surfaces intersect along a line
+    ///
+    /// The origin of the line is the point on it that is closest to the global
+    /// origin. Its direction is normalized, and points along the cross product
+    /// of the normals of the first and the second surface.
+    Line(Line<3>),
+
+    /// The surfaces are coincident
+    Coincident,
+}
+
+/// # Compute the intersection between two surfaces
+///
+/// Returns `Ok(None)`, if the surfaces don't intersect.
+///
+/// Two planes are considered to be coincident, if they are parallel and within
+/// the provided tolerance of each other.
+///
+/// ## Implementation Note
+///
+/// Only intersections between planes are supported so far. Intersecting a
+/// curved surface with any surface results in
+/// [`IntersectionError::UnsupportedSurfaces`].
+pub fn intersect_surfaces(
+    a: &SweptCurve,
+    b: &SweptCurve,
+    tolerance: impl Into<Tolerance>,
+) -> Result<Option<SurfaceIntersection>, IntersectionError> {
+    let tolerance = tolerance.into();
+
+    match (a.u, b.u) {
+        (Path::Line(a_u), Path::Line(b_u)) => {
+            Ok(intersect_planes([(a_u, a.v), (b_u, b.v)], tolerance))
+        }
+        _ => Err(IntersectionError::UnsupportedSurfaces),
+    }
+}
+
+fn intersect_planes(
+    planes: [(Line<3>, Vector<3>); 2],
+    tolerance: Tolerance,
+) -> Option<SurfaceIntersection> {
+    let [(a_u, a_v), (b_u, b_v)] = planes;
+
+    let [na, nb] = [(a_u, a_v), (b_u, b_v)]
+        .map(|(u, v)| u.direction().cross(&v).normalize());
+    let direction = na.cross(&nb);
+
+    // The normals are normalized, so the magnitude of their cross product is
+    // the sine of the angle between them.
+    if direction.magnitude() <= Scalar::from(f64::EPSILON) {
+        let distance = (b_u.origin() - a_u.origin()).dot(&na).abs();
+
+        if distance < tolerance.inner() {
+            return Some(SurfaceIntersection::Coincident);
+        }
+
+        return None;
+    }
+
+    // The distance of each plane from the global origin, along its normal.
+    let [ha, hb] = [(na, a_u), (nb, b_u)]
+        .map(|(normal, u)| normal.dot(&u.origin().coords));
+
+    // The point that is on both planes, and on the plane through the global
+    // origin that is perpendicular to the line. This is the point on the line
+    // that is closest to the global origin.
+    let origin = (nb.cross(&direction) * ha + direction.cross(&na) * hb)
+        / direction.dot(&direction);
+
+    Some(SurfaceIntersection::Line(Line::from_origin_and_direction(
+        Point { coords: origin },
+        direction.normalize(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::geometry::{Path, surfaces::SweptCurve};
+
+    use super::{
+        CurveIntersection, IntersectionError, SurfaceIntersection, intersect,
+        intersect_surfaces,
+    };
+
+    #[test]
+    fn crossing_lines() -> Result<(), IntersectionError> {
+        let (a, _) = Path::line_from_points([[0., 0.], [2., 2.]]);
+        let (b, _) = Path::line_from_points([[0., 2.], [2., 0.]]);
+
+        let Some(CurveIntersection::Point {
+            points_curve,
+            point_surface,
+        }) = intersect(&a, &b, 0.001)?
+        else {
+            panic!("Expected lines to intersect at a point");
+        };
+
+        assert_eq!(point_surface, Point::from([1., 1.]));
+        for (path, point_curve) in [a, b].into_iter().zip(points_curve) {
+            assert_eq!(path.point_from_path_coords(point_curve), point_surface);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parallel_lines() -> Result<(), IntersectionError> {
+        let (a, _) = Path::line_from_points([[0., 0.], [1., 0.]]);
+        let (b, _) = Path::line_from_points([[0., 1.], [1., 1.]]);
+
+        assert_eq!(intersect(&a, &b, 0.001)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn collinear_lines() -> Result<(), IntersectionError> {
+        let (a, _) = Path::line_from_points([[0., 0.], [1., 0.]]);
+        let (b, _) = Path::line_from_points([[3., 0.], [1., 0.]]);
+
+        let Some(CurveIntersection::Coincident { points_on_b }) =
+            intersect(&a, &b, 0.001)?
+        else {
+            panic!("Expected lines to be coincident");
+        };
+
+        // `b` has its origin at `[3, 0]` and runs in the opposite direction,
+        // at twice the speed of `a`.
+        assert_eq!(points_on_b, [Point::from([1.5]), Point::from([1.])]);
+        for (point_a, point_b) in [[0.], [1.]].into_iter().zip(points_on_b) {
+            assert_eq!(
+                a.point_from_path_coords(point_a),
+                b.point_from_path_coords(point_b),
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn circle_and_line() {
+        let (line, _) = Path::line_from_points([[0., 0.], [1., 0.]]);
+        let circle = Path::circle_from_radius(1.);
+
+        for [a, b] in [[&line, &circle], [&circle, &line]] {
+            assert_eq!(
+                intersect(a, b, 0.001),
+                Err(IntersectionError::UnsupportedCurves),
+            );
+        }
+    }
+
+    #[test]
+    fn perpendicular_planes() -> Result<(), IntersectionError> {
+        let a = plane([0., 0., 1.], [1., 0., 0.], [0., 1., 0.]);
+        let b = plane([2., 0., 0.], [0., 1., 0.], [0., 0., 1.]);
+
+        let Some(SurfaceIntersection::Line(line)) =
+            intersect_surfaces(&a, &b, 0.001)?
+        else {
+            panic!("Expected planes to intersect along a line");
+        };
+
+        // The direction is perpendicular to both normals.
+        for normal in [[0., 0., 1.], [1., 0., 0.]].map(Vector::from) {
+            assert_eq!(line.direction().dot(&normal), Scalar::ZERO);
+        }
+        assert_eq!(line.direction(), Vector::from([0., 1., 0.]));
+
+        // The origin is on both planes, and closest to the global origin.
+        assert_eq!(line.origin(), Point::from([2., 0., 1.]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn oblique_planes() -> Result<(), IntersectionError> {
+        let a = plane([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let b = plane([0., 0., 3.], [0., 1., 0.], [1., 0., 1.]);
+
+        let Some(SurfaceIntersection::Line(line)) =
+            intersect_surfaces(&a, &b, 0.001)?
+        else {
+            panic!("Expected planes to intersect along a line");
+        };
+
+        let [na, nb] = [
+            Vector::from([0., 0., 1.]),
+            Vector::from([1., 0., -1.]).normalize(),
+        ];
+        for normal in [na, nb] {
+            let dot = line.direction().dot(&normal);
+            assert!(dot.abs() < Scalar::from(1e-12));
+        }
+
+        // The origin is on both planes, and the vector from the global origin
+        // to it is perpendicular to the line.
+        let origin = line.origin();
+        assert!(origin.z.abs() < Scalar::from(1e-12));
+        assert!(
+            (origin - Point::from([0., 0., 3.])).dot(&nb).abs()
+                < Scalar::from(1e-12)
+        );
+        assert!(
+            origin.coords.dot(&line.direction()).abs() < Scalar::from(1e-12)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parallel_and_coincident_planes() -> Result<(), IntersectionError> {
+        let a = plane([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let b = plane([0., 0., 1.], [0., 1., 0.], [1., 0., 0.]);
+        let c = plane([5., 5., 0.], [1., 1., 0.], [1., -1., 0.]);
+
+        assert_eq!(intersect_surfaces(&a, &b, 0.001)?, None);
+        assert_eq!(
+            intersect_surfaces(&a, &c, 0.001)?,
+            Some(SurfaceIntersection::Coincident),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn plane_and_curved_surface() {
+        let plane = plane([0., 0., 0.], [1., 0., 0.], [0., 1., 0.]);
+        let cylinder = SweptCurve {
+            u: Path::circle_from_radius(1.),
+            v: Vector::from([0., 0., 1.]),
+        };
+
+        for [a, b] in [[&plane, &cylinder], [&cylinder, &plane]] {
+            assert_eq!(
+                intersect_surfaces(a, b, 0.001),
+                Err(IntersectionError::UnsupportedSurfaces),
+            );
+        }
+    }
+
+    fn plane(origin: [f64; 3], u: [f64; 3], v: [f64; 3]) -> SweptCurve {
+        SweptCurve {
+            u: Path::Line(Line::from_origin_and_direction(
+                Point::from(origin),
+                Vector::from(u),
+            )),
+            v: Vector::from(v),
+        }
+    }
+}
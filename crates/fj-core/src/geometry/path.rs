@@ -132,4 +132,20 @@ impl<const D: usize> Path<D> {
             Self::Line(line) => Self::Line(line.reverse()),
         }
     }
+
+    /// Convert path coordinates into those of the reversed path
+    ///
+    /// Returns the coordinates that refer to the same point on the path
+    /// returned by [`Path::reverse`], as the provided coordinates refer to on
+    /// this path.
+    pub fn reverse_path_coords(&self, point: impl Into<Point<1>>) -> Point<1> {
+        let point = point.into();
+
+        match self {
+            // Reversing a circle mirrors it along `a`, so the angle is negated.
+            Self::Circle(_) => Point::from([-point.t]),
+            // Reversing a line moves its origin to the point at `1`.
+            Self::Line(_) => Point::from([Scalar::ONE - point.t]),
+        }
+    }
 }
@@ -0,0 +1,444 @@
+use fj_interop::{CircleApproxParams, Tolerance};
+use fj_math::{Aabb, Circle, Point, Scalar, Triangle, Vector};
+
+use crate::geometry::{Geometry, traits::GenTriMesh};
+
+/// # A conical surface
+///
+/// The surface coordinates of a cone are the angle around the axis (`u`), and
+/// the distance from the apex along the axis (`v`). The angle is measured from
+/// the reference direction, counter-clockwise when looking against the axis.
+///
+/// The cone extends infinitely in both directions of the axis. Points with a
+/// negative `v` are located on the other nappe, the half of the cone that opens
+/// into the opposite direction. A point with negative `v` is located on the
+/// opposite side of the axis as its `u` indicates, which makes the coordinates
+/// continuous across the apex.
+///
+/// Since `u` is an angle, it is periodic: Any `u` maps to the same point as
+/// `u + 2π`. Points converted into surface coordinates always have a `u` in the
+/// range `[0, 2π)`.
+///
+/// ## Apex
+///
+/// At a `v` of zero, all angles map to the same point, the apex. Converting a
+/// point on the axis into surface coordinates always results in an angle of
+/// zero.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Cone {
+    /// # The apex of the cone
+    pub apex: Point<3>,
+
+    /// # The direction of the axis, normalized
+    pub axis: Vector<3>,
+
+    /// # The angle between the axis and the surface of the cone
+    pub half_angle: Scalar,
+
+    /// # The direction that corresponds to an angle of zero, normalized
+    ///
+    /// This is perpendicular to the axis.
+    pub reference: Vector<3>,
+}
+
+impl Cone {
+    /// # Construct a cone
+    ///
+    /// The axis doesn't need to be normalized. Neither does the reference
+    /// direction, nor does it need to be perpendicular to the axis. Only its
+    /// component perpendicular to the axis is used.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the axis has zero length, if the reference direction is
+    /// parallel to the axis, or if the half-angle is not within `(0, π/2)`.
+    pub fn new(
+        apex: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        half_angle: impl Into<Scalar>,
+        reference: impl Into<Vector<3>>,
+    ) -> Self {
+        let axis = axis.into();
+        let half_angle = half_angle.into();
+        let reference = reference.into();
+
+        assert!(
+            axis.magnitude() > Scalar::ZERO,
+            "Axis of cone must not have zero length",
+        );
+        let axis = axis.normalize();
+
+        assert!(
+            half_angle > Scalar::ZERO && half_angle < Scalar::PI / 2.,
+            "Half-angle of cone must be between zero and a quarter turn",
+        );
+
+        let reference = reference - axis * reference.dot(&axis);
+        assert!(
+            reference.magnitude() > Scalar::ZERO,
+            "Reference direction of cone must not be parallel to axis",
+        );
+        let reference = reference.normalize();
+
+        Self {
+            apex: apex.into(),
+            axis,
+            half_angle,
+            reference,
+        }
+    }
+
+    /// # Compute the radius of the cone at the provided distance from the apex
+    ///
+    /// The distance is measured along the axis. The radius is negative for
+    /// negative distances, as explained in the documentation of [`Cone`].
+    pub fn radius_at(&self, v: impl Into<Scalar>) -> Scalar {
+        let (sin, cos) = self.half_angle.sin_cos();
+        v.into() * sin / cos
+    }
+
+    /// # Convert a point in surface coordinates into a 3D point
+    pub fn point_from_surface_coords(
+        &self,
+        point_surface: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point_surface = point_surface.into();
+
+        let (sin_u, cos_u) = point_surface.u.sin_cos();
+        let radial = self.reference * cos_u + self.binormal() * sin_u;
+
+        self.apex
+            + self.axis * point_surface.v
+            + radial * self.radius_at(point_surface.v)
+    }
+
+    /// # Convert a 3D point into surface coordinates
+    ///
+    /// Projects the point onto the cone, if it isn't located on it. This
+    /// results in the surface coordinates of the nearest point on the cone,
+    /// unless the point is located on the axis. In that case, all points on the
+    /// circle that is nearest to it are equally near, and an angle of zero is
+    /// returned. This includes the apex itself, which has surface coordinates
+    /// of zero.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let point = point.into();
+
+        let to_point = point - self.apex;
+        let height = to_point.dot(&self.axis);
+        let radial = to_point - self.axis * height;
+
+        let [x, y] =
+            [self.reference, self.binormal()].map(|dir| radial.dot(&dir));
+        let distance_from_axis = (x * x + y * y).sqrt();
+
+        let u = y.atan2(x);
+        let u = if u < Scalar::ZERO { u + Scalar::TAU } else { u };
+
+        // Adding `2π` to a tiny negative number can round up to `2π` exactly,
+        // which is equivalent to zero.
+        let u = if u >= Scalar::TAU { u - Scalar::TAU } else { u };
+
+        // Within the half-plane of the angle we just computed, the cone
+        // consists of two half-lines that meet at the apex, one on each nappe.
+        // The point is nearest to the one on its side of the apex.
+        let (sin, cos) = self.half_angle.sin_cos();
+        let along_surface = if height >= Scalar::ZERO {
+            height * cos + distance_from_axis * sin
+        } else {
+            height * cos - distance_from_axis * sin
+        };
+        let v = along_surface * cos;
+
+        // Points on the other nappe have a negative radius, which puts them on
+        // the opposite side of the axis, as indicated by their angle. We need
+        // to compensate for that.
+        let u = if v < Scalar::ZERO {
+            if u < Scalar::PI {
+                u + Scalar::PI
+            } else {
+                u - Scalar::PI
+            }
+        } else {
+            u
+        };
+
+        Point::from([u, v])
+    }
+
+    /// # Compute the signed distance of a point from the cone
+    ///
+    /// The distance is positive outside of the cone (away from the axis), and
+    /// negative inside of it.
+    pub fn distance_to(&self, point: impl Into<Point<3>>) -> Scalar {
+        let to_point = point.into() - self.apex;
+        let height = to_point.dot(&self.axis);
+        let distance_from_axis = (to_point - self.axis * height).magnitude();
+
+        let (sin, cos) = self.half_angle.sin_cos();
+        distance_from_axis * cos - height.abs() * sin
+    }
+
+    fn binormal(&self) -> Vector<3> {
+        self.axis.cross(&self.reference)
+    }
+
+    fn circle_at(&self, v: Scalar) -> Circle<3> {
+        let radius = self.radius_at(v);
+        let a = self.reference * radius;
+        let b = self.binormal() * radius;
+
+        Circle::new(self.apex + self.axis * v, a, b)
+    }
+}
+
+impl GenTriMesh for Cone {
+    fn origin(&self, _: &Geometry) -> Point<3> {
+        self.apex
+    }
+
+    fn triangle_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        _: &Geometry,
+    ) -> (Triangle<3>, [Scalar; 3]) {
+        // The cone is only curved around its axis, so the triangle has a
+        // height of zero, like the one of a cylinder. It does get wider though,
+        // the farther away from the apex it is.
+        let radius = self.radius_at(point_surface.v).abs();
+
+        if radius <= tolerance.inner() {
+            // Close to the apex, the cone is indistinguishable from a point.
+            let point = self.point_from_surface_coords(point_surface);
+            let triangle = Triangle::from([point, point, point]);
+            return (triangle, [Scalar::ONE, Scalar::ZERO, Scalar::ZERO]);
+        }
+
+        let increment = CircleApproxParams::new(radius, tolerance).increment();
+
+        let t = point_surface.u / increment;
+        let t = if (t - t.round()).abs() < Scalar::from(1e-9) {
+            t.round()
+        } else {
+            t
+        };
+        let [u_min, u_max] = [t.floor(), t.floor() + 1.].map(|t| t * increment);
+        let u_frac = (point_surface.u - u_min) / (u_max - u_min);
+
+        let circle = self.circle_at(point_surface.v);
+        let [a, b] =
+            [u_min, u_max].map(|u| circle.point_from_circle_coords([u]));
+        let c = a + (b - a) / 2.;
+
+        let triangle = Triangle::from([a, b, c]);
+        let barycentric_coords = [Scalar::ONE - u_frac, u_frac, Scalar::ZERO];
+
+        (triangle, barycentric_coords)
+    }
+
+    fn generate_tri_mesh(
+        &self,
+        boundary: Aabb<2>,
+        tolerance: Tolerance,
+        _: &Geometry,
+    ) -> Vec<Point<2>> {
+        // The deviation from the surface is largest where the cone is widest,
+        // so that's the radius that determines the approximation.
+        let radius = self
+            .radius_at(boundary.min.v)
+            .abs()
+            .max(self.radius_at(boundary.max.v).abs());
+
+        let mut points_u = vec![boundary.min.u];
+        if radius > tolerance.inner() {
+            points_u.extend(
+                CircleApproxParams::new(radius, tolerance)
+                    .approx_circle(
+                        [[boundary.min.u], [boundary.max.u]].map(Point::from),
+                    )
+                    .map(|point| point.t),
+            );
+        }
+        points_u.push(boundary.max.u);
+
+        // The cone is not curved along its axis, but the apex needs to be part
+        // of the mesh, if it is within the boundary.
+        let mut points_v = vec![boundary.min.v];
+        if boundary.min.v < Scalar::ZERO && boundary.max.v > Scalar::ZERO {
+            points_v.push(Scalar::ZERO);
+        }
+        points_v.push(boundary.max.v);
+
+        points_v
+            .iter()
+            .flat_map(|&v| points_u.iter().map(move |&u| Point::from([u, v])))
+            .collect()
+    }
+
+    fn project_point(
+        &self,
+        point: Point<3>,
+        _: Tolerance,
+        _: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        (self.point_to_surface_coords(point), self.distance_to(point))
+    }
+
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        _: Tolerance,
+        _: &Geometry,
+    ) -> Vector<3> {
+        // The normal points away from the axis, tilted towards the apex. The
+        // normal is not defined at the apex itself. There, we return the normal
+        // of the nappe that `v` approaches from above.
+        let (sin_u, cos_u) = point_surface.u.sin_cos();
+        let (sin, cos) = self.half_angle.sin_cos();
+
+        let radial = self.reference * cos_u + self.binormal() * sin_u;
+        let normal = radial * cos - self.axis * sin;
+
+        // On the other nappe, the radial direction points the other way, as do
+        // both derivatives.
+        if point_surface.v < Scalar::ZERO {
+            -normal
+        } else {
+            normal
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar};
+
+    use crate::{Core, geometry::traits::GenTriMesh};
+
+    use super::Cone;
+
+    #[test]
+    fn point_from_surface_coords() {
+        let cone = Cone::new(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            Scalar::PI / 4.,
+            [1., 0., 0.],
+        );
+
+        assert_close(cone.point_from_surface_coords([0., 0.]), [0., 0., 0.]);
+        assert_close(cone.point_from_surface_coords([3., 0.]), [0., 0., 0.]);
+        assert_close(cone.point_from_surface_coords([0., 1.]), [1., 0., 1.]);
+        assert_close(
+            cone.point_from_surface_coords([Scalar::PI / 2., Scalar::from(2.)]),
+            [0., 2., 2.],
+        );
+
+        // Negative `v` is on the other nappe, on the opposite side of the axis.
+        assert_close(cone.point_from_surface_coords([0., -1.]), [-1., 0., -1.]);
+    }
+
+    #[test]
+    fn points_lie_on_cone() {
+        let cone = Cone::new(
+            [1., 2., 3.],
+            [1., 1., 0.],
+            Scalar::from(0.3),
+            [0., 0., 1.],
+        );
+        let (sin, cos) = cone.half_angle.sin_cos();
+        let tan_squared = (sin / cos) * (sin / cos);
+
+        for point_surface in [[0., 1.], [1., 2.], [4., -3.], [6., 0.5]] {
+            let point = cone.point_from_surface_coords(point_surface);
+
+            // A point is on the cone, if its squared distance from the axis is
+            // proportional to its squared height above the apex.
+            let to_point = point - cone.apex;
+            let height = to_point.dot(&cone.axis);
+            let distance_from_axis = to_point - cone.axis * height;
+
+            let residual = distance_from_axis.dot(&distance_from_axis)
+                - height * height * tan_squared;
+            assert!(residual.abs() < Scalar::from(1e-12));
+            assert!(cone.distance_to(point).abs() < Scalar::from(1e-12));
+        }
+    }
+
+    #[test]
+    fn point_to_surface_coords_and_back() {
+        let cone = Cone::new(
+            [1., 2., 3.],
+            [1., 1., 0.],
+            Scalar::from(0.3),
+            [0., 0., 1.],
+        );
+
+        for point_surface in [[0., 1.], [1., 2.], [4., -3.], [6., 0.5]] {
+            let point_surface = Point::from(point_surface);
+            let point = cone.point_from_surface_coords(point_surface);
+
+            assert_close(cone.point_to_surface_coords(point), point_surface);
+        }
+    }
+
+    #[test]
+    fn points_on_axis() {
+        let cone = Cone::new(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            Scalar::PI / 4.,
+            [1., 0., 0.],
+        );
+
+        // The apex.
+        assert_close(cone.point_to_surface_coords([0., 0., 0.]), [0., 0.]);
+
+        // All points on the circle at half the height are nearest.
+        assert_close(cone.point_to_surface_coords([0., 0., 1.]), [0., 0.5]);
+    }
+
+    #[test]
+    fn project_point() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cone = Cone::new(
+            [0., 0., 0.],
+            [0., 0., 1.],
+            Scalar::PI / 4.,
+            [1., 0., 0.],
+        );
+
+        let sqrt_2 = Scalar::from(2.).sqrt();
+        for (point, expected_distance) in
+            [([0., 3., 1.], sqrt_2), ([0., 1., 3.], -sqrt_2)]
+        {
+            let (point_surface, distance) = cone.project_point(
+                Point::from(point),
+                tolerance,
+                &core.layers.geometry,
+            );
+
+            assert_close(point_surface, [Scalar::PI / 2., Scalar::from(2.)]);
+            assert!((distance - expected_distance).abs() < Scalar::from(1e-12));
+        }
+
+        Ok(())
+    }
+
+    fn assert_close<const D: usize>(
+        actual: Point<D>,
+        expected: impl Into<Point<D>>,
+    ) {
+        let expected = expected.into();
+        assert!(
+            actual.distance_to(&expected) < Scalar::from(1e-12),
+            "Expected {expected:?}, got {actual:?}",
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use fj_interop::Tolerance;
+use fj_math::{Aabb, Circle, Point, Scalar, Triangle, Vector};
+
+use crate::geometry::{Geometry, Path, traits::GenTriMesh};
+
+use super::SweptCurve;
+
+/// # A cylindrical surface
+///
+/// The surface coordinates of a cylinder are the angle around the axis (`u`),
+/// and the height along the axis (`v`). The angle is measured from the
+/// reference direction, counter-clockwise when looking against the axis.
+///
+/// Since `u` is an angle, it is periodic: Any `u` maps to the same point as
+/// `u + 2π`. Points converted into surface coordinates always have a `u` in the
+/// range `[0, 2π)`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Cylinder {
+    /// # A point on the axis, where the height is zero
+    pub origin: Point<3>,
+
+    /// # The direction of the axis, normalized
+    pub axis: Vector<3>,
+
+    /// # The radius of the cylinder
+    pub radius: Scalar,
+
+    /// # The direction that corresponds to an angle of zero, normalized
+    ///
+    /// This is perpendicular to the axis.
+    pub reference: Vector<3>,
+}
+
+impl Cylinder {
+    /// # Construct a cylinder
+    ///
+    /// The axis doesn't need to be normalized. Neither does the reference
+    /// direction, nor does it need to be perpendicular to the axis. Only its
+    /// component perpendicular to the axis is used.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the axis has zero length, or if the reference direction is
+    /// parallel to the axis.
+    pub fn new(
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        radius: impl Into<Scalar>,
+        reference: impl Into<Vector<3>>,
+    ) -> Self {
+        let axis = axis.into();
+        let reference = reference.into();
+
+        assert!(
+            axis.magnitude() > Scalar::ZERO,
+            "Axis of cylinder must not have zero length",
+        );
+        let axis = axis.normalize();
+
+        let reference = reference - axis * reference.dot(&axis);
+        assert!(
+            reference.magnitude() > Scalar::ZERO,
+            "Reference direction of cylinder must not be parallel to axis",
+        );
+        let reference = reference.normalize();
+
+        Self {
+            origin: origin.into(),
+            axis,
+            radius: radius.into(),
+            reference,
+        }
+    }
+
+    /// # Convert a point in surface coordinates into a 3D point
+    pub fn point_from_surface_coords(
+        &self,
+        point_surface: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point_surface = point_surface.into();
+
+        self.circle().point_from_circle_coords([point_surface.u])
+            + self.axis * point_surface.v
+    }
+
+    /// # Convert a 3D point into surface coordinates
+    ///
+    /// Projects the point onto the cylinder, if it isn't located on it. This
+    /// results in the surface coordinates of the nearest point on the cylinder,
+    /// unless the point is located on the axis. In that case, all points on the
+    /// circle at its height are equally near, and a `u` of zero is returned.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let point = point.into();
+
+        let v = (point - self.origin).dot(&self.axis);
+        let u = self
+            .circle()
+            .point_to_circle_coords(point - self.axis * v)
+            .t;
+
+        // `point_to_circle_coords` returns a value in the range `[0, 2π]`, but
+        // `2π` is equivalent to zero.
+        let u = if u >= Scalar::TAU { u - Scalar::TAU } else { u };
+
+        Point::from([u, v])
+    }
+
+    /// # Convert the cylinder into the equivalent swept curve
+    pub fn to_swept_curve(&self) -> SweptCurve {
+        SweptCurve {
+            u: Path::Circle(self.circle()),
+            v: self.axis,
+        }
+    }
+
+    fn circle(&self) -> Circle<3> {
+        let a = self.reference * self.radius;
+        let b = self.axis.cross(&self.reference) * self.radius;
+
+        Circle::new(self.origin, a, b)
+    }
+}
+
+impl GenTriMesh for Cylinder {
+    fn origin(&self, geometry: &Geometry) -> Point<3> {
+        self.to_swept_curve().origin(geometry)
+    }
+
+    fn triangle_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> (Triangle<3>, [Scalar; 3]) {
+        self.to_swept_curve()
+            .triangle_at(point_surface, tolerance, geometry)
+    }
+
+    fn generate_tri_mesh(
+        &self,
+        boundary: Aabb<2>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> Vec<Point<2>> {
+        self.to_swept_curve()
+            .generate_tri_mesh(boundary, tolerance, geometry)
+    }
+
+    fn project_point(
+        &self,
+        point: Point<3>,
+        _: Tolerance,
+        _: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        let point_surface = self.point_to_surface_coords(point);
+
+        // The normal points away from the axis, so points outside of the
+        // cylinder have a positive distance.
+        let from_axis = point - self.origin - self.axis * point_surface.v;
+        let distance = from_axis.magnitude() - self.radius;
+
+        (point_surface, distance)
+    }
+
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> Vector<3> {
+        self.to_swept_curve()
+            .normal_at(point_surface, tolerance, geometry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar};
+
+    use crate::{Core, geometry::traits::GenTriMesh};
+
+    use super::Cylinder;
+
+    #[test]
+    fn point_from_surface_coords() {
+        let cylinder =
+            Cylinder::new([0., 0., 0.], [0., 0., 1.], 1., [1., 0., 0.]);
+
+        assert_close(
+            cylinder.point_from_surface_coords([0., 0.]),
+            [1., 0., 0.],
+        );
+        assert_close(
+            cylinder
+                .point_from_surface_coords([Scalar::PI / 2., Scalar::from(2.)]),
+            [0., 1., 2.],
+        );
+        assert_close(
+            cylinder.point_from_surface_coords([Scalar::PI, -Scalar::ONE]),
+            [-1., 0., -1.],
+        );
+    }
+
+    #[test]
+    fn point_to_surface_coords_and_back() {
+        let cylinder =
+            Cylinder::new([0., 0., 0.], [0., 0., 1.], 1., [1., 0., 0.]);
+
+        for point_surface in [[0., 0.], [1., 2.], [3., -1.], [6., 0.5]] {
+            let point_surface = Point::from(point_surface);
+            let point = cylinder.point_from_surface_coords(point_surface);
+
+            assert_close(
+                cylinder.point_to_surface_coords(point),
+                point_surface,
+            );
+        }
+    }
+
+    #[test]
+    fn u_wraps_at_tau() {
+        let cylinder =
+            Cylinder::new([0., 0., 0.], [0., 0., 1.], 1., [1., 0., 0.]);
+
+        for (u, expected) in [
+            (Scalar::TAU + 0.5, Scalar::from(0.5)),
+            (-Scalar::from(0.5), Scalar::TAU - 0.5),
+            (Scalar::TAU, Scalar::ZERO),
+        ] {
+            let point = cylinder.point_from_surface_coords([u, Scalar::ONE]);
+            let point_surface = cylinder.point_to_surface_coords(point);
+
+            assert_close(point_surface, [expected, Scalar::ONE]);
+        }
+    }
+
+    #[test]
+    fn project_point() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cylinder =
+            Cylinder::new([0., 0., 0.], [0., 0., 1.], 2., [1., 0., 0.]);
+
+        for (point, expected_distance) in
+            [([0., 3., 1.], 1.), ([0., 1., 1.], -1.)]
+        {
+            let (point_surface, distance) = cylinder.project_point(
+                Point::from(point),
+                tolerance,
+                &core.layers.geometry,
+            );
+
+            assert_close(point_surface, [Scalar::PI / 2., Scalar::ONE]);
+            assert_eq!(distance, Scalar::from(expected_distance));
+        }
+
+        Ok(())
+    }
+
+    fn assert_close<const D: usize>(
+        actual: Point<D>,
+        expected: impl Into<Point<D>>,
+    ) {
+        let expected = expected.into();
+        assert!(
+            actual.distance_to(&expected) < Scalar::from(1e-12),
+            "Expected {expected:?}, got {actual:?}",
+        );
+    }
+}
@@ -1,8 +1,13 @@
 //! # Geometry code specific to various types of surfaces
 
+mod cone;
+mod cylinder;
+mod sphere;
 mod swept_curve;
+mod torus;
 mod transformed_surface;
 
 pub use self::{
-    swept_curve::SweptCurve, transformed_surface::TransformedSurface,
+    cone::Cone, cylinder::Cylinder, sphere::Sphere, swept_curve::SweptCurve,
+    torus::Torus, transformed_surface::TransformedSurface,
 };
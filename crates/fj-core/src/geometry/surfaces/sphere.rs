@@ -0,0 +1,384 @@
+use fj_interop::{CircleApproxParams, Tolerance};
+use fj_math::{Aabb, Point, Scalar, Triangle, Vector};
+
+use crate::geometry::{Geometry, traits::GenTriMesh};
+
+/// # A spherical surface
+///
+/// The surface coordinates of a sphere are longitude (`u`) and latitude (`v`),
+/// both angles. Longitude is measured around the z-axis, counter-clockwise when
+/// looking against it, starting at the x-axis. Latitude is measured from the
+/// equator, which is in the xy-plane, and is positive towards positive z.
+///
+/// Since `u` is an angle, it is periodic: Any `u` maps to the same point as
+/// `u + 2π`. Points converted into surface coordinates always have a `u` in the
+/// range `[0, 2π)`, and a `v` in the range `[-π/2, π/2]`.
+///
+/// ## Poles
+///
+/// At a latitude of `±π/2`, all longitudes map to the same point, the north or
+/// south pole respectively. Converting a pole into surface coordinates always
+/// results in a longitude of zero.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Sphere {
+    /// # The center of the sphere
+    pub center: Point<3>,
+
+    /// # The radius of the sphere
+    pub radius: Scalar,
+}
+
+impl Sphere {
+    /// # Construct a sphere
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the radius is not positive.
+    pub fn new(center: impl Into<Point<3>>, radius: impl Into<Scalar>) -> Self {
+        let radius = radius.into();
+        assert!(radius > Scalar::ZERO, "Radius of sphere must be positive");
+
+        Self {
+            center: center.into(),
+            radius,
+        }
+    }
+
+    /// # Convert a point in surface coordinates into a 3D point
+    pub fn point_from_surface_coords(
+        &self,
+        point_surface: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point_surface = point_surface.into();
+
+        let (sin_u, cos_u) = point_surface.u.sin_cos();
+        let (sin_v, cos_v) = point_surface.v.sin_cos();
+
+        self.center
+            + Vector::from([cos_v * cos_u, cos_v * sin_u, sin_v]) * self.radius
+    }
+
+    /// # Convert a 3D point into surface coordinates
+    ///
+    /// The point is assumed to be located on the sphere. If it isn't, it is
+    /// projected onto the sphere along the line through the center. This
+    /// results in the surface coordinates of the nearest point on the sphere,
+    /// unless the point is the center itself. In that case, all points on the
+    /// sphere are equally near, and the origin of the surface coordinates is
+    /// returned.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let [x, y, z] = (point.into() - self.center).components;
+
+        // Computing the latitude from both components, instead of only using
+        // `z`, is robust against points that are slightly off the sphere.
+        let v = z.atan2((x * x + y * y).sqrt());
+
+        // At the poles, `x` and `y` are zero, and `atan2` returns zero. This is
+        // exactly the convention we documented.
+        let u = y.atan2(x);
+        let u = if u < Scalar::ZERO { u + Scalar::TAU } else { u };
+
+        // Adding `2π` to a tiny negative number can round up to `2π` exactly,
+        // which is equivalent to zero.
+        let u = if u >= Scalar::TAU { u - Scalar::TAU } else { u };
+
+        Point::from([u, v])
+    }
+}
+
+impl GenTriMesh for Sphere {
+    fn origin(&self, _: &Geometry) -> Point<3> {
+        self.point_from_surface_coords([0., 0.])
+    }
+
+    fn triangle_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        _: &Geometry,
+    ) -> (Triangle<3>, [Scalar; 3]) {
+        let params = CircleApproxParams::new(self.radius, tolerance);
+
+        // A sphere is curved along both axes, so we need to approximate it with
+        // a grid, in both directions. We use the same increment as for a circle
+        // of the same radius, which keeps the deviation within the tolerance.
+        //
+        // First, figure out which cell of that grid the point is in.
+        let [[u_min, u_max], [v_min, v_max]] =
+            [point_surface.u, point_surface.v].map(|coord| {
+                let t = coord / params.increment();
+
+                // Same as with circles, make sure floating point inaccuracy
+                // doesn't put us into the wrong cell.
+                let t = if (t - t.round()).abs() < Scalar::from(1e-9) {
+                    t.round()
+                } else {
+                    t
+                };
+
+                [t.floor(), t.floor() + 1.].map(|t| t * params.increment())
+            });
+
+        // The grid doesn't necessarily line up with the poles. Clamping the
+        // latitude makes sure the cells next to them end there, instead of
+        // extending beyond.
+        let [v_min, v_max] = [v_min, v_max].map(clamp_latitude);
+
+        // Now figure out where within the cell the point is located.
+        let [u_frac, v_frac] = [
+            (point_surface.u, u_min, u_max),
+            (point_surface.v, v_min, v_max),
+        ]
+        .map(|(coord, min, max)| {
+            if max == min {
+                Scalar::ZERO
+            } else {
+                (coord - min) / (max - min)
+            }
+        });
+
+        let corner =
+            |u: Scalar, v: Scalar| self.point_from_surface_coords([u, v]);
+
+        // Each cell is split into two triangles along its diagonal.
+        if u_frac + v_frac <= Scalar::ONE {
+            let triangle = Triangle::from([
+                corner(u_min, v_min),
+                corner(u_max, v_min),
+                corner(u_min, v_max),
+            ]);
+            let barycentric_coords =
+                [Scalar::ONE - u_frac - v_frac, u_frac, v_frac];
+
+            (triangle, barycentric_coords)
+        } else {
+            let triangle = Triangle::from([
+                corner(u_max, v_max),
+                corner(u_min, v_max),
+                corner(u_max, v_min),
+            ]);
+            let barycentric_coords = [
+                u_frac + v_frac - Scalar::ONE,
+                Scalar::ONE - u_frac,
+                Scalar::ONE - v_frac,
+            ];
+
+            (triangle, barycentric_coords)
+        }
+    }
+
+    fn generate_tri_mesh(
+        &self,
+        boundary: Aabb<2>,
+        tolerance: Tolerance,
+        _: &Geometry,
+    ) -> Vec<Point<2>> {
+        let params = CircleApproxParams::new(self.radius, tolerance);
+
+        let points_u = params
+            .approx_circle(
+                [[boundary.min.u], [boundary.max.u]].map(Point::from),
+            )
+            .collect::<Vec<_>>();
+        let points_v = params
+            .approx_circle(
+                [boundary.min.v, boundary.max.v]
+                    .map(|v| Point::from([clamp_latitude(v)])),
+            )
+            .collect::<Vec<_>>();
+
+        points_v
+            .iter()
+            .flat_map(|v| points_u.iter().map(|u| Point::from([u.t, v.t])))
+            .collect()
+    }
+
+    fn project_point(
+        &self,
+        point: Point<3>,
+        _: Tolerance,
+        _: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        // The normal points away from the center, so points outside of the
+        // sphere have a positive distance.
+        let distance = point.distance_to(&self.center) - self.radius;
+        (self.point_to_surface_coords(point), distance)
+    }
+
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        _: Tolerance,
+        _: &Geometry,
+    ) -> Vector<3> {
+        // The normal points away from the center. This is also well-defined at
+        // the poles, where the derivative along `u` vanishes.
+        let (sin_u, cos_u) = point_surface.u.sin_cos();
+        let (sin_v, cos_v) = point_surface.v.sin_cos();
+
+        Vector::from([cos_v * cos_u, cos_v * sin_u, sin_v])
+    }
+}
+
+fn clamp_latitude(v: Scalar) -> Scalar {
+    v.clamp(-Scalar::PI / 2., Scalar::PI / 2.)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar};
+
+    use crate::{Core, geometry::traits::GenTriMesh};
+
+    use super::Sphere;
+
+    #[test]
+    fn point_from_surface_coords() {
+        let sphere = Sphere::new([1., 1., 1.], 2.);
+
+        assert_close(sphere.point_from_surface_coords([0., 0.]), [3., 1., 1.]);
+        assert_close(
+            sphere.point_from_surface_coords([Scalar::PI / 2., Scalar::ZERO]),
+            [1., 3., 1.],
+        );
+        assert_close(
+            sphere.point_from_surface_coords([Scalar::ZERO, Scalar::PI / 2.]),
+            [1., 1., 3.],
+        );
+        assert_close(
+            sphere.point_from_surface_coords([Scalar::ZERO, -Scalar::PI / 2.]),
+            [1., 1., -1.],
+        );
+    }
+
+    #[test]
+    fn point_to_surface_coords_and_back() {
+        let sphere = Sphere::new([1., 1., 1.], 2.);
+
+        for point_surface in
+            [[0., 0.], [1., 0.5], [3., -1.], [6., 1.5], [4.5, -1.2]]
+        {
+            let point_surface = Point::from(point_surface);
+            let point = sphere.point_from_surface_coords(point_surface);
+
+            assert_close(sphere.point_to_surface_coords(point), point_surface);
+        }
+    }
+
+    #[test]
+    fn equator_maps_to_circle() {
+        let sphere = Sphere::new([1., 1., 1.], 2.);
+
+        for u in [0., 1., 2., 3., 4., 5., 6.] {
+            let point = sphere.point_from_surface_coords([u, 0.]);
+
+            assert!(
+                (point.distance_to(&sphere.center) - sphere.radius).abs()
+                    < Scalar::from(1e-12)
+            );
+            assert!((point.z - sphere.center.z).abs() < Scalar::from(1e-12));
+        }
+    }
+
+    #[test]
+    fn poles() {
+        let sphere = Sphere::new([1., 1., 1.], 2.);
+
+        for (v, pole) in [
+            (Scalar::PI / 2., Point::from([1., 1., 3.])),
+            (-Scalar::PI / 2., Point::from([1., 1., -1.])),
+        ] {
+            // All longitudes map to the same pole.
+            for u in [0., 1., 4.] {
+                let point = sphere.point_from_surface_coords([u.into(), v]);
+                assert_close(point, pole);
+            }
+
+            // And the pole maps back to a longitude of zero.
+            assert_close(
+                sphere.point_to_surface_coords(pole),
+                [Scalar::ZERO, v],
+            );
+        }
+    }
+
+    #[test]
+    fn triangle_at() -> anyhow::Result<()> {
+        let sphere = Sphere::new([0., 0., 0.], 1.);
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let core = Core::new();
+
+        for point_surface in [[0.3, 0.2], [2., -0.7], [5., 1.55]] {
+            let point_surface = Point::from(point_surface);
+
+            let (triangle, barycentric_coords) = sphere.triangle_at(
+                point_surface,
+                tolerance,
+                &core.layers.geometry,
+            );
+
+            // The point on the triangle must be close to the point on the
+            // sphere.
+            let point_on_triangle = triangle
+                .points
+                .into_iter()
+                .zip(barycentric_coords)
+                .fold(Point::origin(), |point, (corner, weight)| {
+                    point + corner.coords * weight
+                });
+            let point_on_sphere =
+                sphere.point_from_surface_coords(point_surface);
+
+            assert!(
+                point_on_triangle.distance_to(&point_on_sphere)
+                    < tolerance.inner() * 2.
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn normal_points_away_from_center() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let sphere = Sphere::new([1., 1., 1.], 2.);
+
+        for point_surface in [
+            [0., 0.],
+            [1., 0.5],
+            [4.5, -1.2],
+            [0., Scalar::PI.into_f64() / 2.],
+            [2., -Scalar::PI.into_f64() / 2.],
+        ] {
+            let point_surface = Point::from(point_surface);
+            let normal = sphere.normal_at(
+                point_surface,
+                tolerance,
+                &core.layers.geometry,
+            );
+
+            let point = sphere.point_from_surface_coords(point_surface);
+            let expected = (point - sphere.center) / sphere.radius;
+            assert!((normal - expected).magnitude() < Scalar::from(1e-12));
+        }
+
+        Ok(())
+    }
+
+    fn assert_close<const D: usize>(
+        actual: Point<D>,
+        expected: impl Into<Point<D>>,
+    ) {
+        let expected = expected.into();
+        assert!(
+            actual.distance_to(&expected) < Scalar::from(1e-12),
+            "Expected {expected:?}, got {actual:?}",
+        );
+    }
+}
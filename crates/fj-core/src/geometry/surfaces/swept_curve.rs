@@ -50,17 +50,237 @@ impl GenTriMesh for SweptCurve {
         let points_curve =
             self.u.generate_polyline(boundary_curve.into(), tolerance);
 
-        points_curve
-            .iter()
-            .copied()
-            .map(|point| [point.t, Scalar::ZERO])
-            .chain(
-                points_curve
-                    .iter()
-                    .copied()
-                    .map(|point| [point.t, self.v.magnitude()]),
-            )
+        // The surface is linear along `v`, so the points at the boundary of
+        // that range are all we need.
+        [boundary.min.v, boundary.max.v]
+            .into_iter()
+            .flat_map(|v| points_curve.iter().map(move |point| [point.t, v]))
             .map(Point::from)
             .collect()
     }
+
+    fn project_point(
+        &self,
+        point: Point<3>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        let point_surface = match self.u {
+            Path::Line(line) => {
+                // The surface is a plane. The projection is the point in the
+                // plane, for which the vector to the provided point is
+                // perpendicular to both axes. The axes are not necessarily
+                // perpendicular to each other, so we need to solve a system of
+                // linear equations for that.
+                let [a, b] = [line.direction(), self.v];
+                let to_point = point - line.origin();
+
+                let [aa, ab, bb] = [a.dot(&a), a.dot(&b), b.dot(&b)];
+                let [pa, pb] = [to_point.dot(&a), to_point.dot(&b)];
+                let determinant = aa * bb - ab * ab;
+
+                Point::from([
+                    (pa * bb - pb * ab) / determinant,
+                    (pb * aa - pa * ab) / determinant,
+                ])
+            }
+            Path::Circle(_) => {
+                // There is no general closed-form solution. Alternate between
+                // projecting onto the curve, and along the sweep direction,
+                // until the result no longer changes.
+                let v_at = |u: Scalar| {
+                    let to_point = point - self.u.point_from_path_coords([u]);
+                    to_point.dot(&self.v) / self.v.dot(&self.v)
+                };
+
+                let mut v = (point - self.u.origin()).dot(&self.v)
+                    / self.v.dot(&self.v);
+                let mut u = Scalar::ZERO;
+
+                for _ in 0..32 {
+                    let next_u =
+                        self.u.project_point(point - self.v * v, tolerance).t;
+                    let next_v = v_at(next_u);
+
+                    let converged = (next_u - u).abs() < Scalar::from(1e-12)
+                        && (next_v - v).abs() < Scalar::from(1e-12);
+                    [u, v] = [next_u, next_v];
+
+                    if converged {
+                        break;
+                    }
+                }
+
+                Point::from([u, v])
+            }
+        };
+
+        let nearest = self.u.point_from_path_coords([point_surface.u])
+            + self.v * point_surface.v;
+        let normal = self.normal_at(point_surface, tolerance, geometry);
+
+        (point_surface, (point - nearest).dot(&normal))
+    }
+
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        _: &Geometry,
+    ) -> Vector<3> {
+        self.u
+            .tangent_at(Point::from([point_surface.u]), tolerance)
+            .cross(&self.v)
+            .normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Aabb, Circle, Line, Point, Scalar, Vector};
+
+    use crate::{
+        Core,
+        geometry::{
+            Path, SurfaceGeom, repr::tri_mesh::TriMesh, traits::GenTriMesh,
+        },
+    };
+
+    use super::SweptCurve;
+
+    #[test]
+    fn project_point_onto_plane() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // The axes are neither normalized, nor perpendicular to each other.
+        let plane = SweptCurve {
+            u: Path::Line(Line::from_origin_and_direction(
+                [1., 2., 3.],
+                [2., 0., 0.],
+            )),
+            v: Vector::from([1., 1., 0.]),
+        };
+
+        for (point, expected_distance) in [
+            ([1., 2., 3.], 0.),
+            ([4., -1., 5.], 2.),
+            ([-2., 7., 0.], -3.),
+        ] {
+            let point = Point::from(point);
+            let (point_surface, distance) =
+                plane.project_point(point, tolerance, &core.layers.geometry);
+
+            let nearest = point_on(&plane, point_surface);
+            assert!(
+                (point - nearest).cross(&Vector::unit_z()).magnitude()
+                    < Scalar::from(1e-12)
+            );
+            assert!(
+                (distance - Scalar::from(expected_distance)).abs()
+                    < Scalar::from(1e-12)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn project_point_onto_curved_surface() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = SweptCurve {
+            u: Path::Circle(Circle::new(
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [0., 2., 0.],
+            )),
+            v: Vector::from([0., 0., 1.]),
+        };
+
+        let point = Point::from([3., 3., 1.5]);
+        let (point_surface, distance) =
+            surface.project_point(point, tolerance, &core.layers.geometry);
+
+        let nearest = point_on(&surface, point_surface);
+        let expected = Point::from([2_f64.sqrt(), 2_f64.sqrt(), 1.5]);
+        assert!(nearest.distance_to(&expected) < Scalar::from(1e-9));
+        assert!(
+            (distance - (Scalar::from(18.).sqrt() - 2.)).abs()
+                < Scalar::from(1e-9)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn tri_mesh_covers_boundary() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let plane = SweptCurve {
+            u: Path::Line(Line::from_origin_and_direction(
+                [0., 0., 0.],
+                [1., 0., 0.],
+            )),
+            v: Vector::from([0., 2., 0.]),
+        };
+
+        let boundary = Aabb {
+            min: Point::from([1., 3.]),
+            max: Point::from([2., 4.]),
+        };
+        let points =
+            plane.generate_tri_mesh(boundary, tolerance, &core.layers.geometry);
+
+        assert_eq!(Aabb::<2>::from_points(points), boundary);
+
+        Ok(())
+    }
+
+    #[test]
+    fn normal_of_plane_is_constant() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let plane = SweptCurve {
+            u: Path::Line(Line::from_origin_and_direction(
+                [1., 2., 3.],
+                [2., 0., 0.],
+            )),
+            v: Vector::from([1., 1., 0.]),
+        };
+        let reversed = SurfaceGeom {
+            generator: Box::new(plane),
+            geometry: TriMesh::empty(),
+            is_reversed: true,
+        };
+
+        for point_surface in [[0., 0.], [1., -2.], [-30., 50.]] {
+            let point_surface = Point::from(point_surface);
+
+            let normal = plane.normal_at(
+                point_surface,
+                tolerance,
+                &core.layers.geometry,
+            );
+            assert_eq!(normal, Vector::unit_z());
+
+            let normal = reversed.normal_at(
+                point_surface,
+                tolerance,
+                &core.layers.geometry,
+            );
+            assert_eq!(normal, -Vector::unit_z());
+        }
+
+        Ok(())
+    }
+
+    fn point_on(surface: &SweptCurve, point_surface: Point<2>) -> Point<3> {
+        surface.u.point_from_path_coords([point_surface.u])
+            + surface.v * point_surface.v
+    }
 }
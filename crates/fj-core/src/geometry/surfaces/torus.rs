@@ -0,0 +1,462 @@
+use fj_interop::{CircleApproxParams, Tolerance};
+use fj_math::{Aabb, Point, Scalar, Triangle, Vector};
+
+use crate::geometry::{Geometry, traits::GenTriMesh};
+
+/// # A toroidal surface
+///
+/// A torus is the surface created by revolving a circle, the tube, around an
+/// axis. The tube's center moves along a circle around the axis, the
+/// centerline. The radius of the centerline is the major radius, the radius of
+/// the tube is the minor radius.
+///
+/// The surface coordinates of a torus are both angles. `u` is the angle around
+/// the axis, measured from the reference direction, counter-clockwise when
+/// looking against the axis. `v` is the angle around the tube, measured from
+/// the outside of the torus, towards the direction of the axis.
+///
+/// Since both coordinates are angles, they are periodic: Any `u` and `v` map to
+/// the same point as `u + 2π` and `v + 2π`, respectively. Points converted into
+/// surface coordinates always have a `u` and a `v` in the range `[0, 2π)`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Torus {
+    /// # The center of the torus
+    pub center: Point<3>,
+
+    /// # The direction of the axis, normalized
+    pub axis: Vector<3>,
+
+    /// # The radius of the centerline of the tube
+    pub major_radius: Scalar,
+
+    /// # The radius of the tube
+    pub minor_radius: Scalar,
+
+    /// # The direction that corresponds to a `u` of zero, normalized
+    ///
+    /// This is perpendicular to the axis.
+    pub reference: Vector<3>,
+}
+
+impl Torus {
+    /// # Construct a torus
+    ///
+    /// The axis doesn't need to be normalized. Neither does the reference
+    /// direction, nor does it need to be perpendicular to the axis. Only its
+    /// component perpendicular to the axis is used.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the axis has zero length, or if the reference direction is
+    /// parallel to the axis.
+    ///
+    /// Panics, if the minor radius is not positive, or not smaller than the
+    /// major radius. Such a torus would intersect itself.
+    pub fn new(
+        center: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        reference: impl Into<Vector<3>>,
+    ) -> Self {
+        let axis = axis.into();
+        let major_radius = major_radius.into();
+        let minor_radius = minor_radius.into();
+        let reference = reference.into();
+
+        assert!(
+            axis.magnitude() > Scalar::ZERO,
+            "Axis of torus must not have zero length",
+        );
+        let axis = axis.normalize();
+
+        assert!(
+            minor_radius > Scalar::ZERO && minor_radius < major_radius,
+            "Minor radius of torus must be positive and smaller than major \
+            radius",
+        );
+
+        let reference = reference - axis * reference.dot(&axis);
+        assert!(
+            reference.magnitude() > Scalar::ZERO,
+            "Reference direction of torus must not be parallel to axis",
+        );
+        let reference = reference.normalize();
+
+        Self {
+            center: center.into(),
+            axis,
+            major_radius,
+            minor_radius,
+            reference,
+        }
+    }
+
+    /// # Convert a point in surface coordinates into a 3D point
+    pub fn point_from_surface_coords(
+        &self,
+        point_surface: impl Into<Point<2>>,
+    ) -> Point<3> {
+        let point_surface = point_surface.into();
+
+        let (sin_u, cos_u) = point_surface.u.sin_cos();
+        let (sin_v, cos_v) = point_surface.v.sin_cos();
+
+        let radial = self.reference * cos_u + self.binormal() * sin_u;
+
+        self.center
+            + radial * (self.major_radius + self.minor_radius * cos_v)
+            + self.axis * (self.minor_radius * sin_v)
+    }
+
+    /// # Convert a 3D point into surface coordinates
+    ///
+    /// Projects the point onto the torus, if it isn't located on it. The plane
+    /// through the point and the axis intersects the torus in two circles, one
+    /// on each side of the axis. The circle on the same side as the point is
+    /// always nearest, and the point is projected onto that.
+    ///
+    /// A point on the axis is equally near to all circles, and is projected
+    /// onto the one at a `u` of zero. A point on the centerline is equally near
+    /// to all points on that circle, and is projected to a `v` of zero.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let to_point = point.into() - self.center;
+        let height = to_point.dot(&self.axis);
+        let [x, y] =
+            [self.reference, self.binormal()].map(|dir| to_point.dot(&dir));
+        let distance_from_axis = (x * x + y * y).sqrt();
+
+        let u = y.atan2(x);
+        let v = height.atan2(distance_from_axis - self.major_radius);
+
+        let [u, v] = [u, v].map(|angle| {
+            let angle = if angle < Scalar::ZERO {
+                angle + Scalar::TAU
+            } else {
+                angle
+            };
+
+            // Adding `2π` to a tiny negative number can round up to `2π`
+            // exactly, which is equivalent to zero.
+            if angle >= Scalar::TAU {
+                angle - Scalar::TAU
+            } else {
+                angle
+            }
+        });
+
+        Point::from([u, v])
+    }
+
+    /// # Compute the signed distance of a point from the torus
+    ///
+    /// The distance is positive outside of the tube, and negative inside of it.
+    pub fn distance_to(&self, point: impl Into<Point<3>>) -> Scalar {
+        let point = point.into();
+        let nearest_on_centerline = self.centerline_point(point);
+        point.distance_to(&nearest_on_centerline) - self.minor_radius
+    }
+
+    fn binormal(&self) -> Vector<3> {
+        self.axis.cross(&self.reference)
+    }
+
+    fn approx_params(&self, tolerance: Tolerance) -> [CircleApproxParams; 2] {
+        // Along `u`, the torus is widest on the outside. That's where the
+        // deviation is largest, so that's the radius that determines the
+        // approximation.
+        [self.major_radius + self.minor_radius, self.minor_radius]
+            .map(|radius| CircleApproxParams::new(radius, tolerance))
+    }
+
+    fn increments(&self, tolerance: Tolerance) -> [Scalar; 2] {
+        self.approx_params(tolerance)
+            .map(|params| params.increment())
+    }
+
+    fn centerline_point(&self, point: Point<3>) -> Point<3> {
+        let u = self.point_to_surface_coords(point).u;
+        let (sin_u, cos_u) = u.sin_cos();
+        let radial = self.reference * cos_u + self.binormal() * sin_u;
+
+        self.center + radial * self.major_radius
+    }
+}
+
+impl GenTriMesh for Torus {
+    fn origin(&self, _: &Geometry) -> Point<3> {
+        self.point_from_surface_coords([0., 0.])
+    }
+
+    fn triangle_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        _: &Geometry,
+    ) -> (Triangle<3>, [Scalar; 3]) {
+        let increments = self.increments(tolerance);
+
+        // A torus is curved along both axes, so we need to approximate it with
+        // a grid, like a sphere. First, figure out which cell of that grid the
+        // point is in.
+        let [[u_min, u_max], [v_min, v_max]] = [
+            (point_surface.u, increments[0]),
+            (point_surface.v, increments[1]),
+        ]
+        .map(|(coord, increment)| {
+            let t = coord / increment;
+
+            // Same as with circles, make sure floating point inaccuracy
+            // doesn't put us into the wrong cell.
+            let t = if (t - t.round()).abs() < Scalar::from(1e-9) {
+                t.round()
+            } else {
+                t
+            };
+
+            [t.floor(), t.floor() + 1.].map(|t| t * increment)
+        });
+
+        // Now figure out where within the cell the point is located.
+        let u_frac = (point_surface.u - u_min) / (u_max - u_min);
+        let v_frac = (point_surface.v - v_min) / (v_max - v_min);
+
+        let corner =
+            |u: Scalar, v: Scalar| self.point_from_surface_coords([u, v]);
+
+        // Each cell is split into two triangles along its diagonal.
+        if u_frac + v_frac <= Scalar::ONE {
+            let triangle = Triangle::from([
+                corner(u_min, v_min),
+                corner(u_max, v_min),
+                corner(u_min, v_max),
+            ]);
+            let barycentric_coords =
+                [Scalar::ONE - u_frac - v_frac, u_frac, v_frac];
+
+            (triangle, barycentric_coords)
+        } else {
+            let triangle = Triangle::from([
+                corner(u_max, v_max),
+                corner(u_min, v_max),
+                corner(u_max, v_min),
+            ]);
+            let barycentric_coords = [
+                u_frac + v_frac - Scalar::ONE,
+                Scalar::ONE - u_frac,
+                Scalar::ONE - v_frac,
+            ];
+
+            (triangle, barycentric_coords)
+        }
+    }
+
+    fn generate_tri_mesh(
+        &self,
+        boundary: Aabb<2>,
+        tolerance: Tolerance,
+        _: &Geometry,
+    ) -> Vec<Point<2>> {
+        let [params_u, params_v] = self.approx_params(tolerance);
+
+        let points_u = params_u
+            .approx_circle(
+                [[boundary.min.u], [boundary.max.u]].map(Point::from),
+            )
+            .collect::<Vec<_>>();
+        let points_v = params_v
+            .approx_circle(
+                [[boundary.min.v], [boundary.max.v]].map(Point::from),
+            )
+            .collect::<Vec<_>>();
+
+        points_v
+            .iter()
+            .flat_map(|v| points_u.iter().map(|u| Point::from([u.t, v.t])))
+            .collect()
+    }
+
+    fn project_point(
+        &self,
+        point: Point<3>,
+        _: Tolerance,
+        _: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        (self.point_to_surface_coords(point), self.distance_to(point))
+    }
+
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        _: Tolerance,
+        _: &Geometry,
+    ) -> Vector<3> {
+        // The normal points away from the centerline.
+        let (sin_u, cos_u) = point_surface.u.sin_cos();
+        let (sin_v, cos_v) = point_surface.v.sin_cos();
+
+        let radial = self.reference * cos_u + self.binormal() * sin_u;
+
+        radial * cos_v + self.axis * sin_v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar};
+
+    use crate::{Core, geometry::traits::GenTriMesh};
+
+    use super::Torus;
+
+    #[test]
+    fn point_from_surface_coords() {
+        let torus = torus();
+
+        assert_close(torus.point_from_surface_coords([0., 0.]), [3., 0., 0.]);
+        assert_close(
+            torus.point_from_surface_coords([Scalar::PI / 2., Scalar::ZERO]),
+            [0., 3., 0.],
+        );
+        assert_close(
+            torus.point_from_surface_coords([Scalar::ZERO, Scalar::PI / 2.]),
+            [2., 0., 1.],
+        );
+        assert_close(
+            torus.point_from_surface_coords([Scalar::ZERO, Scalar::PI]),
+            [1., 0., 0.],
+        );
+    }
+
+    #[test]
+    fn point_to_surface_coords_and_back() {
+        let torus =
+            Torus::new([1., 2., 3.], [1., 1., 0.], 3., 0.5, [0., 0., 1.]);
+
+        for point_surface in
+            [[0., 0.], [1., 0.5], [3., 4.], [6., 1.5], [4.5, 6.2]]
+        {
+            let point_surface = Point::from(point_surface);
+            let point = torus.point_from_surface_coords(point_surface);
+
+            assert_close(torus.point_to_surface_coords(point), point_surface);
+        }
+    }
+
+    #[test]
+    fn u_and_v_wrap_at_tau() {
+        let torus = torus();
+
+        let half = Scalar::from(0.5);
+        for (point_surface, expected) in [
+            ([Scalar::TAU + half, Scalar::ONE], [half, Scalar::ONE]),
+            ([Scalar::ONE, Scalar::TAU + half], [Scalar::ONE, half]),
+            ([-half, -half], [Scalar::TAU - half, Scalar::TAU - half]),
+            ([Scalar::TAU, Scalar::TAU], [Scalar::ZERO, Scalar::ZERO]),
+        ] {
+            let point = torus.point_from_surface_coords(point_surface);
+            assert_close(torus.point_to_surface_coords(point), expected);
+        }
+    }
+
+    #[test]
+    fn points_are_at_minor_radius_from_centerline() {
+        let torus = torus();
+
+        for u in [0., 1., 2., 3., 4., 5., 6.] {
+            for v in [0., 1., 2., 3., 4., 5., 6.] {
+                let point = torus.point_from_surface_coords([u, v]);
+
+                // The nearest point on the centerline is at the same angle
+                // around the axis.
+                let centerline_point =
+                    Point::from([2. * f64::cos(u), 2. * f64::sin(u), 0.]);
+
+                let distance = point.distance_to(&centerline_point);
+                assert!(
+                    (distance - torus.minor_radius).abs() < Scalar::from(1e-12)
+                );
+                assert!(torus.distance_to(point).abs() < Scalar::from(1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn project_point() -> anyhow::Result<()> {
+        let core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let torus = torus();
+
+        for (point, expected_distance) in [
+            ([0., 3.5, 0.], 0.5),
+            ([0., 2.5, 0.], -0.5),
+            ([0., 2., 3.], 2.),
+        ] {
+            let (point_surface, distance) = torus.project_point(
+                Point::from(point),
+                tolerance,
+                &core.layers.geometry,
+            );
+
+            assert!(
+                (point_surface.u - Scalar::PI / 2.).abs() < Scalar::from(1e-12)
+            );
+            assert!((distance - expected_distance).abs() < Scalar::from(1e-12));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn triangle_at() -> anyhow::Result<()> {
+        let torus = torus();
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let core = Core::new();
+
+        for point_surface in [[0.3, 0.2], [2., 3.7], [5., 6.1]] {
+            let point_surface = Point::from(point_surface);
+
+            let (triangle, barycentric_coords) = torus.triangle_at(
+                point_surface,
+                tolerance,
+                &core.layers.geometry,
+            );
+
+            let point_on_triangle = triangle
+                .points
+                .into_iter()
+                .zip(barycentric_coords)
+                .fold(Point::origin(), |point, (corner, weight)| {
+                    point + corner.coords * weight
+                });
+            let point_on_torus = torus.point_from_surface_coords(point_surface);
+
+            assert!(
+                point_on_triangle.distance_to(&point_on_torus)
+                    < tolerance.inner() * 2.
+            );
+        }
+
+        Ok(())
+    }
+
+    fn torus() -> Torus {
+        Torus::new([0., 0., 0.], [0., 0., 1.], 2., 1., [1., 0., 0.])
+    }
+
+    fn assert_close<const D: usize>(
+        actual: Point<D>,
+        expected: impl Into<Point<D>>,
+    ) {
+        let expected = expected.into();
+        assert!(
+            actual.distance_to(&expected) < Scalar::from(1e-12),
+            "Expected {expected:?}, got {actual:?}",
+        );
+    }
+}
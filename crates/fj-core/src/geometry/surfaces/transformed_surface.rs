@@ -1,8 +1,11 @@
 use fj_interop::Tolerance;
-use fj_math::{Aabb, Point, Scalar, Transform, Triangle};
+use fj_math::{Aabb, Point, Scalar, Transform, Triangle, Vector};
 
 use crate::{
-    geometry::{Geometry, traits::GenTriMesh},
+    geometry::{
+        Geometry, repr::tri_mesh::convert_point_surface_to_global,
+        traits::GenTriMesh,
+    },
     storage::Handle,
     topology::Surface,
 };
@@ -53,4 +56,128 @@ impl GenTriMesh for TransformedSurface {
             .generator
             .generate_tri_mesh(boundary, tolerance, geometry)
     }
+
+    fn project_point(
+        &self,
+        point: Point<3>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        let surface = geometry.of_surface_2(&self.surface).unwrap();
+        let (point_surface, _) = surface.generator.project_point(
+            self.transform.inverse_transform_point(&point),
+            tolerance,
+            geometry,
+        );
+
+        // The distance computed in the space of the original surface is only
+        // meaningful here, if the transform preserves distances. Compute it in
+        // global space instead, along the normal, which works for non-uniform
+        // scaling too.
+        //
+        // Non-uniform scaling doesn't preserve angles either, so the point we
+        // projected onto isn't exactly the closest one, unless the point is
+        // on the surface already. Measuring along the normal keeps the
+        // distance exact for planes, regardless.
+        let point_on_surface = convert_point_surface_to_global(
+            self,
+            point_surface,
+            tolerance,
+            geometry,
+        );
+        let distance = (point - point_on_surface).dot(&self.normal_at(
+            point_surface,
+            tolerance,
+            geometry,
+        ));
+
+        (point_surface, distance)
+    }
+
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> Vector<3> {
+        let surface = geometry.of_surface_2(&self.surface).unwrap();
+
+        // Normals need to be transformed by the inverse transpose, to remain
+        // perpendicular to the surface under non-uniform scaling.
+        let normal = self.transform.inverse().transpose().transform_vector(
+            &surface.normal_at(point_surface, tolerance, geometry),
+        );
+
+        // The normal is the cross product of the derivatives along the u- and
+        // v-axes. Reflections reverse the order of those, which flips the
+        // normal.
+        if self.determinant() < Scalar::ZERO {
+            -normal.normalize()
+        } else {
+            normal.normalize()
+        }
+    }
+}
+
+impl TransformedSurface {
+    fn determinant(&self) -> Scalar {
+        let [x, y, z] = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+            .map(|axis| self.transform.transform_vector(&Vector::from(axis)));
+        x.cross(&y).dot(&z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::{Point, Scalar, Transform, Vector};
+
+    use crate::{
+        Core,
+        geometry::{
+            Path, repr::tri_mesh::convert_vector_surface_to_global,
+            traits::GenTriMesh,
+        },
+        operations::build::BuildSurface,
+        topology::Surface,
+    };
+
+    use super::TransformedSurface;
+
+    #[test]
+    fn non_uniform_scaling() {
+        let mut core = Core::new();
+
+        // A plane at 45 degrees to the x- and z-axes, stretched along z. The
+        // plane of the transformed surface is `2x + z = 2`.
+        let surface = Surface::from_uv(
+            Path::line_from_points([[1., 0., 0.], [0., 0., 1.]]).0,
+            [0., 1., 0.],
+            &mut core,
+        );
+        let surface = TransformedSurface {
+            surface,
+            transform: Transform::scale([1., 1., 2.]),
+        };
+
+        let geometry = &core.layers.geometry;
+        let tolerance = Tolerance::from_scalar(0.001).unwrap();
+
+        let normal = surface.normal_at(Point::origin(), tolerance, geometry);
+        let expected = Vector::from([-2., 0., -1.]).normalize();
+        assert!((normal - expected).magnitude() < Scalar::from(1e-12));
+
+        for tangent in [[1., 0.], [0., 1.]] {
+            let tangent = convert_vector_surface_to_global(
+                &surface, tangent, tolerance, geometry,
+            );
+            assert!(normal.dot(&tangent).abs() < Scalar::from(1e-12));
+        }
+
+        // The point is at distance 1 from the transformed plane, along its
+        // normal. In the space of the original surface, it's not.
+        let point = Point::from([1., 0., 0.]) + expected;
+        let (_, distance) = surface.project_point(point, tolerance, geometry);
+        assert!((distance - Scalar::ONE).abs() < Scalar::from(1e-9));
+    }
 }
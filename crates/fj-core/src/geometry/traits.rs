@@ -20,7 +20,7 @@
 use std::ops::Deref;
 
 use fj_interop::Tolerance;
-use fj_math::{Aabb, LineSegment, Point, Scalar, Triangle};
+use fj_math::{Aabb, LineSegment, Point, Scalar, Triangle, Vector};
 
 use super::{CurveBoundary, Geometry, Path};
 
@@ -38,6 +38,12 @@ pub trait GenPolyline<const D: usize> {
     /// # Access the origin of the curve
     fn origin(&self) -> Point<D>;
 
+    /// # Convert a point in curve coordinates into a point on the curve
+    ///
+    /// Unlike the polyline, this is the exact point on the curve, not its
+    /// approximation.
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<D>;
+
     /// # Compute a line segment to approximate the curve at this point
     ///
     /// ## Degenerate Case
@@ -51,12 +57,254 @@ pub trait GenPolyline<const D: usize> {
         tolerance: Tolerance,
     ) -> LineSegment<D>;
 
+    /// # Indicate whether the curve is a straight line
+    ///
+    /// Lines require no approximation, so operations that work with the
+    /// polyline of a line are exact. The default implementation returns
+    /// `false`, which is the conservative choice for curved geometry.
+    fn is_linear(&self) -> bool {
+        false
+    }
+
     /// # Generate a polyline within the provided boundary
     fn generate_polyline(
         &self,
         boundary: CurveBoundary<Point<1>>,
         tolerance: Tolerance,
     ) -> Vec<Point<1>>;
+
+    /// # Generate a polyline within the boundary, by adaptive subdivision
+    ///
+    /// Starts out with the boundary as a single line segment, then recursively
+    /// bisects each segment, as long as the midpoint of its chord deviates from
+    /// the point on the curve at the middle of the segment by more than the
+    /// tolerance. For lines, that deviation is always zero, and the result only
+    /// consists of the boundary points.
+    ///
+    /// Unlike [`GenPolyline::generate_polyline`], the returned polyline
+    /// includes the boundary points.
+    ///
+    /// ## Determinism
+    ///
+    /// The points that this method generates depend on the boundary. This means
+    /// the result is *not* a uniform representation, as defined in the module
+    /// documentation. It is suitable for cases where a minimal polyline is
+    /// required, but must not be mixed with polylines generated by other means.
+    fn generate_polyline_adaptive(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Vec<Point<1>> {
+        // Limits the recursion, in case the curve is degenerate in some way we
+        // didn't anticipate. Each level halves the segment, so this is plenty.
+        const MAX_DEPTH: u32 = 32;
+
+        fn bisect<const D: usize>(
+            curve: &(impl GenPolyline<D> + ?Sized),
+            [a, b]: [Point<1>; 2],
+            tolerance: Tolerance,
+            depth: u32,
+            points: &mut Vec<Point<1>>,
+        ) {
+            let middle = Point::from([(a.t + b.t) / 2.]);
+
+            let [point_a, point_b, point_middle] = [a, b, middle]
+                .map(|point| curve.point_from_curve_coords(point));
+            let midpoint_of_chord = point_a + (point_b - point_a) / 2.;
+
+            if depth < MAX_DEPTH
+                && midpoint_of_chord.distance_to(&point_middle)
+                    > tolerance.inner()
+            {
+                bisect(curve, [a, middle], tolerance, depth + 1, points);
+                bisect(curve, [middle, b], tolerance, depth + 1, points);
+            } else {
+                points.push(b);
+            }
+        }
+
+        let [start, end] = boundary.inner;
+
+        let mut points = vec![start];
+        bisect(self, [start, end], tolerance, 0, &mut points);
+
+        points
+    }
+
+    /// # Project a point onto the curve
+    ///
+    /// Returns the curve coordinate of the point on the curve that is closest
+    /// to the provided point. If the provided point is located on the curve,
+    /// that is its own curve coordinate.
+    ///
+    /// Points that are not on the curve don't result in an error. They are
+    /// projected onto the curve, and the nearest curve coordinate is returned.
+    ///
+    /// Implementations that can compute the projection exactly, are free to
+    /// ignore the tolerance.
+    fn project_point(&self, point: Point<D>, tolerance: Tolerance) -> Point<1>;
+
+    /// # Compute the length of the curve within the provided boundary
+    ///
+    /// The length is computed by summing up the lengths of the line segments of
+    /// the polyline, that approximates the curve within the boundary. For
+    /// curves that don't require approximation (meaning lines), this is the
+    /// exact length. For curved geometry, the result converges towards the
+    /// exact length, as the tolerance tightens.
+    fn length(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Scalar {
+        let [start, end] = boundary.inner;
+
+        // The generated polyline might not include the boundary points, so we
+        // need to add them ourselves. If it does include them, this results in
+        // degenerate line segments, which don't affect the length.
+        let points = [start]
+            .into_iter()
+            .chain(self.generate_polyline(boundary, tolerance))
+            .chain([end])
+            .map(|point_curve| point_on_polyline(self, point_curve, tolerance))
+            .collect::<Vec<_>>();
+
+        points
+            .windows(2)
+            .map(|segment| segment[0].distance_to(&segment[1]))
+            .fold(Scalar::ZERO, |length, segment| length + segment)
+    }
+
+    /// # Compute the bounding box of the curve within the provided boundary
+    ///
+    /// The bounding box is computed from the points of the polyline that
+    /// approximates the curve within the boundary, as well as the points on the
+    /// curve at the boundary. For lines (see [`GenPolyline::is_linear`]), this
+    /// is the exact extent of the curve.
+    ///
+    /// For curved geometry, the points of the polyline can miss the extremes
+    /// of the curve, as the curve can deviate from the polyline by up to the
+    /// tolerance. To make sure the box contains the whole curve, it is
+    /// expanded by the tolerance in every direction. This makes the box
+    /// conservative: It is larger than the true extent of the curve, by up to
+    /// twice the tolerance along each axis, and it converges towards the true
+    /// extent, as the tolerance tightens.
+    fn bounding_box(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        tolerance: Tolerance,
+    ) -> Aabb<D> {
+        let [start, end] = boundary.inner;
+
+        // The points of the polyline are located on the curve, but the boundary
+        // points might not be among them. Use the exact points on the curve
+        // for all of them, so the polyline between them stays within the
+        // tolerance of the curve.
+        let points = [start]
+            .into_iter()
+            .chain(self.generate_polyline(boundary, tolerance))
+            .chain([end])
+            .map(|point_curve| self.point_from_curve_coords(point_curve));
+
+        let mut min = [Scalar::MAX; D];
+        let mut max = [-Scalar::MAX; D];
+        for point in points {
+            for (i, coord) in point.coords.components.into_iter().enumerate() {
+                min[i] = min[i].min(coord);
+                max[i] = max[i].max(coord);
+            }
+        }
+
+        if !self.is_linear() {
+            min = min.map(|coord| coord - tolerance.inner());
+            max = max.map(|coord| coord + tolerance.inner());
+        }
+
+        Aabb {
+            min: Point::from(min),
+            max: Point::from(max),
+        }
+    }
+
+    /// # Compute the tangent of the curve at the provided point
+    ///
+    /// Returns a normalized vector that points in the direction of increasing
+    /// curve coordinates.
+    ///
+    /// The default implementation approximates the derivative using central
+    /// finite differences on the curve's polyline, with a step size of the
+    /// provided tolerance. Implementations that can compute the tangent exactly
+    /// should override it.
+    ///
+    /// ## Degenerate Case
+    ///
+    /// If the derivative vanishes at the provided point, the tangent is not
+    /// defined. In that case, a zero vector is returned.
+    fn tangent_at(
+        &self,
+        point_curve: Point<1>,
+        tolerance: Tolerance,
+    ) -> Vector<D> {
+        let step = Vector::from([tolerance.inner()]);
+
+        let [before, after] = [point_curve - step, point_curve + step]
+            .map(|point_curve| point_on_polyline(self, point_curve, tolerance));
+        let derivative = after - before;
+
+        if derivative.magnitude() == Scalar::ZERO {
+            return Vector::from([Scalar::ZERO; D]);
+        }
+
+        derivative.normalize()
+    }
+
+    /// # Sample the curve at evenly spaced curve coordinates
+    ///
+    /// Unlike the polyline, whose points depend on a tolerance, this returns
+    /// `n + 1` points, whose curve coordinates divide the boundary into `n`
+    /// segments of equal size. For lines, the points are evenly spaced along
+    /// the line. For circles, they are evenly spaced by angle.
+    ///
+    /// The curve coordinates of the first and last point are exactly the
+    /// boundary points. If `n` is zero, only the first point is returned.
+    fn sample(
+        &self,
+        boundary: CurveBoundary<Point<1>>,
+        n: usize,
+    ) -> Box<dyn Iterator<Item = Point<D>> + '_> {
+        let [start, end] = boundary.inner;
+
+        Box::new((0..=n).map(move |i| {
+            // Computing the last point from the boundary directly, instead of
+            // interpolating, makes sure floating point error can't creep in.
+            let point_curve = if i == n {
+                end
+            } else {
+                start + (end - start) * (i as f64 / n as f64)
+            };
+
+            self.point_from_curve_coords(point_curve)
+        }))
+    }
+}
+
+/// # Convert a point in curve coordinates, using the curve's polyline
+///
+/// Returns the point on the polyline, at the provided curve coordinate.
+fn point_on_polyline<const D: usize>(
+    curve: &(impl GenPolyline<D> + ?Sized),
+    point_curve: Point<1>,
+    tolerance: Tolerance,
+) -> Point<D> {
+    let LineSegment {
+        points: [a, b],
+        points_line: [a_curve, b_curve],
+    } = curve.line_segment_at(point_curve, tolerance);
+
+    if a_curve == b_curve {
+        a
+    } else {
+        a + (b - a) * ((point_curve - a_curve).t / (b_curve - a_curve).t)
+    }
 }
 
 // This implementation is temporary, to ease the transition towards a curve
@@ -69,6 +317,10 @@ impl<const D: usize> GenPolyline<D> for Path<D> {
         }
     }
 
+    fn point_from_curve_coords(&self, point_curve: Point<1>) -> Point<D> {
+        self.point_from_path_coords(point_curve)
+    }
+
     fn line_segment_at(
         &self,
         point_curve: Point<1>,
@@ -82,6 +334,13 @@ impl<const D: usize> GenPolyline<D> for Path<D> {
         }
     }
 
+    fn is_linear(&self) -> bool {
+        match self {
+            Self::Circle(circle) => circle.is_linear(),
+            Self::Line(line) => line.is_linear(),
+        }
+    }
+
     fn generate_polyline(
         &self,
         boundary: CurveBoundary<Point<1>>,
@@ -94,6 +353,24 @@ impl<const D: usize> GenPolyline<D> for Path<D> {
             Self::Line(line) => line.generate_polyline(boundary, tolerance),
         }
     }
+
+    fn project_point(&self, point: Point<D>, tolerance: Tolerance) -> Point<1> {
+        match self {
+            Self::Circle(circle) => circle.project_point(point, tolerance),
+            Self::Line(line) => line.project_point(point, tolerance),
+        }
+    }
+
+    fn tangent_at(
+        &self,
+        point_curve: Point<1>,
+        tolerance: Tolerance,
+    ) -> Vector<D> {
+        match self {
+            Self::Circle(circle) => circle.tangent_at(point_curve, tolerance),
+            Self::Line(line) => line.tangent_at(point_curve, tolerance),
+        }
+    }
 }
 
 /// # Generate triangle meshes, the uniform representation of surface geometry
@@ -151,6 +428,38 @@ pub trait GenTriMesh {
         tolerance: Tolerance,
         geometry: &Geometry,
     ) -> Vec<Point<2>>;
+
+    /// # Project a point onto the surface
+    ///
+    /// Returns the surface coordinates of the point on the surface that is
+    /// closest to the provided point, as well as the signed distance between
+    /// the two. The distance is positive, if the provided point is located on
+    /// the side of the surface that its normal points to. The normal is the
+    /// cross product of the derivatives along the u- and v-axes.
+    ///
+    /// Implementations that can compute the projection exactly, are free to
+    /// ignore the tolerance.
+    fn project_point(
+        &self,
+        point: Point<3>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> (Point<2>, Scalar);
+
+    /// # Compute the normal at the provided point on the surface
+    ///
+    /// Returns the unit normal, the normalized cross product of the derivatives
+    /// along the u- and v-axes. This is the same normal that determines the
+    /// sign of the distance returned by [`GenTriMesh::project_point`].
+    ///
+    /// Implementations that can compute the normal exactly, are free to ignore
+    /// the tolerance.
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> Vector<3>;
 }
 
 impl<T> GenTriMesh for T
@@ -180,4 +489,22 @@ where
         self.deref()
             .generate_tri_mesh(boundary, tolerance, geometry)
     }
+
+    fn project_point(
+        &self,
+        point: Point<3>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> (Point<2>, Scalar) {
+        self.deref().project_point(point, tolerance, geometry)
+    }
+
+    fn normal_at(
+        &self,
+        point_surface: Point<2>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> Vector<3> {
+        self.deref().normal_at(point_surface, tolerance, geometry)
+    }
 }
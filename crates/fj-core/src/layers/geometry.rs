@@ -6,7 +6,7 @@ use crate::{
         surfaces::SweptCurve,
     },
     storage::Handle,
-    topology::{Curve, Surface, Vertex},
+    topology::{AnyObject, Curve, Stored, Surface, Vertex},
 };
 
 use super::{Command, Event, Layer};
@@ -90,6 +90,18 @@ impl Layer<Geometry> {
             geometry,
         });
     }
+
+    /// # Define an object as a rigid copy of another
+    ///
+    /// A rigid copy is derived from its original by a rigid motion, meaning it
+    /// is geometrically identical, save for its position and orientation.
+    pub fn define_rigid_copy(
+        &mut self,
+        original: AnyObject<Stored>,
+        copy: AnyObject<Stored>,
+    ) {
+        self.process_command(DefineRigidCopy { original, copy });
+    }
 }
 
 /// Define the geometry of a curve
@@ -225,3 +237,28 @@ impl Event<Geometry> for DefineVertex {
         state.define_vertex_inner(self.vertex, self.curve, self.geometry);
     }
 }
+
+/// Define an object as a rigid copy of another
+pub struct DefineRigidCopy {
+    original: AnyObject<Stored>,
+    copy: AnyObject<Stored>,
+}
+
+impl Command<Geometry> for DefineRigidCopy {
+    type Result = ();
+    type Event = Self;
+
+    fn decide(
+        self,
+        _: &Geometry,
+        events: &mut Vec<Self::Event>,
+    ) -> Self::Result {
+        events.push(self);
+    }
+}
+
+impl Event<Geometry> for DefineRigidCopy {
+    fn evolve(self, state: &mut Geometry) {
+        state.define_rigid_copy_inner(self.original, self.copy);
+    }
+}
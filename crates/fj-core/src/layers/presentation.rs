@@ -16,6 +16,11 @@ impl Layer<Presentation> {
         self.process_command(SetColor { region, color });
     }
 
+    /// Set the label of an object
+    pub fn set_label(&mut self, object: AnyObject<Stored>, label: String) {
+        self.process_command(SetLabel { object, label });
+    }
+
     /// Mark an object as being derived from another
     pub fn derive_object(
         &mut self,
@@ -54,6 +59,34 @@ impl Event<Presentation> for SetColor {
     }
 }
 
+/// Set the label of an object
+pub struct SetLabel {
+    /// The object to set the label for
+    object: AnyObject<Stored>,
+
+    /// The label to set
+    label: String,
+}
+
+impl Command<Presentation> for SetLabel {
+    type Result = ();
+    type Event = Self;
+
+    fn decide(
+        self,
+        _: &Presentation,
+        events: &mut Vec<Self::Event>,
+    ) -> Self::Result {
+        events.push(self);
+    }
+}
+
+impl Event<Presentation> for SetLabel {
+    fn evolve(self, state: &mut Presentation) {
+        state.label.insert(self.object, self.label);
+    }
+}
+
 /// Handle an object being derived from another
 pub struct DeriveObject {
     /// The original object
@@ -65,18 +98,25 @@ pub struct DeriveObject {
 
 impl Command<Presentation> for DeriveObject {
     type Result = ();
-    type Event = SetColor;
+    type Event = PresentationEvent;
 
     fn decide(
         self,
         state: &Presentation,
         events: &mut Vec<Self::Event>,
     ) -> Self::Result {
+        if let Some(label) = state.label.get(&self.original).cloned() {
+            events.push(PresentationEvent::SetLabel {
+                object: self.derived.clone(),
+                label,
+            });
+        }
+
         if let (AnyObject::Region(original), AnyObject::Region(derived)) =
             (self.original, self.derived)
             && let Some(color) = state.color.get(&original).cloned()
         {
-            events.push(SetColor {
+            events.push(PresentationEvent::SetColor {
                 region: derived,
                 color,
             });
@@ -95,4 +135,59 @@ pub enum PresentationEvent {
         /// The color being set
         color: Color,
     },
+
+    /// The label of an object is being set
+    SetLabel {
+        /// The object the label is being set for
+        object: AnyObject<Stored>,
+
+        /// The label being set
+        label: String,
+    },
+}
+
+impl Event<Presentation> for PresentationEvent {
+    fn evolve(self, state: &mut Presentation) {
+        match self {
+            Self::SetColor { region, color } => {
+                SetColor { region, color }.evolve(state);
+            }
+            Self::SetLabel { object, label } => {
+                SetLabel { object, label }.evolve(state);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{
+            build::BuildFace, insert::Insert, transform::TransformObject,
+        },
+        topology::Face,
+    };
+
+    #[test]
+    fn label_is_carried_over_to_derived_object() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        core.set_label(face.clone(), "bottom");
+
+        let translated = face.clone().translate([0., 0., 1.], &mut core)?;
+
+        assert_ne!(translated.id(), face.id());
+        assert_eq!(core.label(&translated), Some("bottom"));
+        assert_eq!(core.label(translated.region()), None);
+
+        Ok(())
+    }
 }
@@ -111,6 +111,7 @@ pub mod layers;
 pub mod operations;
 pub mod presentation;
 pub mod queries;
+pub mod spatial;
 pub mod storage;
 pub mod topology;
 pub mod validate;
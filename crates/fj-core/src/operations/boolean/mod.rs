@@ -0,0 +1,8 @@
+//! Boolean operations on shapes
+//!
+//! So far, only the union and difference of coplanar sketches are supported.
+//! This is a precursor to boolean operations on solids.
+
+mod sketch;
+
+pub use self::sketch::{DifferenceSketch, UnionSketch};
@@ -0,0 +1,384 @@
+use fj_interop::Tolerance;
+use fj_math::Point;
+use geo::{BooleanOps, Coord, LineString, MultiPolygon, Polygon, Winding};
+
+use crate::{
+    Core,
+    geometry::Geometry,
+    operations::{build::BuildCycle, insert::Insert},
+    storage::Handle,
+    topology::{Cycle, Region, Sketch, Surface},
+};
+
+/// # Compute the union of a [`Sketch`] with another
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait UnionSketch {
+    /// # Compute the union of the sketch with another sketch
+    ///
+    /// Returns a new sketch, whose regions cover the area that is covered by
+    /// either of the input sketches. Regions that overlap are merged. Regions
+    /// that don't overlap any other region are kept as they are.
+    ///
+    /// Both sketches must be defined on the same surface.
+    ///
+    /// ## Implementation Note
+    ///
+    /// The union is computed from the polygons that approximate the regions of
+    /// both sketches, at the provided tolerance. The regions of the resulting
+    /// sketch are made up of line segments exclusively, even if the input
+    /// sketches contain curved half-edges.
+    ///
+    /// The colors of the input regions are not preserved.
+    #[must_use]
+    fn union(
+        &self,
+        other: &Sketch,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Sketch;
+}
+
+impl UnionSketch for Sketch {
+    fn union(
+        &self,
+        other: &Sketch,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Sketch {
+        boolean_op(self, other, tolerance.into(), core, |a, b| a.union(b))
+    }
+}
+
+/// # Subtract a [`Sketch`] from another
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait DifferenceSketch {
+    /// # Subtract another sketch from this one
+    ///
+    /// Returns a new sketch, whose regions cover the area that is covered by
+    /// this sketch, but not by the other one. Where the other sketch overlaps
+    /// the boundary of a region, that region is trimmed. Where it is fully
+    /// enclosed by a region, the region gets a hole (an interior cycle).
+    ///
+    /// If the other sketch fully covers this one, the resulting sketch has no
+    /// regions.
+    ///
+    /// Both sketches must be defined on the same surface.
+    ///
+    /// ## Implementation Note
+    ///
+    /// The same limitations as with [`UnionSketch::union`] apply.
+    #[must_use]
+    fn difference(
+        &self,
+        other: &Sketch,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Sketch;
+}
+
+impl DifferenceSketch for Sketch {
+    fn difference(
+        &self,
+        other: &Sketch,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Sketch {
+        boolean_op(self, other, tolerance.into(), core, |a, b| a.difference(b))
+    }
+}
+
+fn boolean_op(
+    a: &Sketch,
+    b: &Sketch,
+    tolerance: Tolerance,
+    core: &mut Core,
+    op: impl FnOnce(&MultiPolygon, &MultiPolygon) -> MultiPolygon,
+) -> Sketch {
+    assert_eq!(
+        a.surface().id(),
+        b.surface().id(),
+        "Boolean operations require sketches on the same surface",
+    );
+
+    let surface = a.surface();
+
+    let [a_polygons, b_polygons] = [a, b].map(|sketch| {
+        to_multi_polygon(sketch, tolerance, &core.layers.geometry)
+    });
+    let result = op(&a_polygons, &b_polygons);
+
+    let regions = result
+        .into_iter()
+        .map(|polygon| {
+            let exterior =
+                ring_to_cycle(polygon.exterior(), true, surface, core)
+                    .insert(core);
+            let interiors = polygon
+                .interiors()
+                .iter()
+                .map(|interior| {
+                    ring_to_cycle(interior, false, surface, core).insert(core)
+                })
+                .collect::<Vec<_>>();
+
+            Region::new(exterior, interiors).insert(core)
+        })
+        .collect::<Vec<_>>();
+
+    Sketch::new(surface.clone(), regions)
+}
+
+fn to_multi_polygon(
+    sketch: &Sketch,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> MultiPolygon {
+    let polygons = sketch
+        .regions()
+        .iter()
+        .map(|region| {
+            let [exterior, interiors] =
+                [vec![region.exterior()], region.interiors().iter().collect()]
+                    .map(|cycles| {
+                        cycles
+                            .into_iter()
+                            .map(|cycle| {
+                                cycle_to_ring(
+                                    cycle,
+                                    sketch.surface(),
+                                    tolerance,
+                                    geometry,
+                                )
+                            })
+                            .collect::<Vec<_>>()
+                    });
+
+            let exterior = exterior
+                .into_iter()
+                .next()
+                .expect("Just created a `Vec` with one element");
+
+            Polygon::new(exterior, interiors)
+        })
+        .collect::<Vec<_>>();
+
+    // The regions of a sketch might overlap. Computing the union with an empty
+    // multi-polygon merges those.
+    MultiPolygon::new(polygons).union(&MultiPolygon::new(Vec::new()))
+}
+
+fn cycle_to_ring(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> LineString {
+    let polylines = cycle
+        .half_edge_polylines(surface, tolerance, geometry)
+        .expect("Expected geometry of sketch cycle to be defined on surface");
+
+    // Each polyline includes the start and end point of its half-edge. The end
+    // point is the start point of the next one, so we skip it here.
+    let points = polylines.into_iter().flat_map(|(_, mut points)| {
+        points.pop();
+        points
+    });
+
+    LineString::from_iter(points.map(|point| Coord {
+        x: point.u.into_f64(),
+        y: point.v.into_f64(),
+    }))
+}
+
+fn ring_to_cycle(
+    ring: &LineString,
+    ccw: bool,
+    surface: &Handle<Surface>,
+    core: &mut Core,
+) -> Cycle {
+    // Rings are closed, meaning their last point is the same as the first. We
+    // don't need that for the cycle.
+    let mut points = ring
+        .coords()
+        .map(|coord| Point::from([coord.x, coord.y]))
+        .collect::<Vec<_>>();
+    points.pop();
+
+    if ring.is_ccw() != ccw {
+        points.reverse();
+    }
+
+    Cycle::polygon(points, surface.clone(), core)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::Tolerance;
+    use fj_math::Scalar;
+    use geo::{Area, Polygon};
+
+    use crate::{
+        Core,
+        operations::build::BuildSketch,
+        topology::{Cycle, Sketch},
+    };
+
+    use super::{DifferenceSketch, UnionSketch, cycle_to_ring};
+
+    #[test]
+    fn union_of_overlapping_squares() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = Sketch::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        );
+        let b = Sketch::polygon(
+            [[1., 1.], [3., 1.], [3., 3.], [1., 3.]],
+            &mut core,
+        );
+
+        let union = a.union(&b, 0.001, &mut core);
+
+        assert_eq!(union.regions().len(), 1);
+        assert_eq!(union.regions().only().exterior().half_edges().len(), 8);
+        assert_eq!(area(&union, &core), Scalar::from(7.));
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_disjoint_squares() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        let b = Sketch::polygon(
+            [[2., 0.], [3., 0.], [3., 1.], [2., 1.]],
+            &mut core,
+        );
+
+        let union = a.union(&b, 0.001, &mut core);
+
+        assert_eq!(union.regions().len(), 2);
+        assert_eq!(area(&union, &core), Scalar::from(2.));
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_contained_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = Sketch::polygon(
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            &mut core,
+        );
+        let b = Sketch::polygon(
+            [[1., 1.], [2., 1.], [2., 2.], [1., 2.]],
+            &mut core,
+        );
+
+        let union = a.union(&b, 0.001, &mut core);
+
+        assert_eq!(union.regions().len(), 1);
+        assert_eq!(area(&union, &core), Scalar::from(9.));
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn difference_creates_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = Sketch::polygon(
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            &mut core,
+        );
+        let b = Sketch::polygon(
+            [[1., 1.], [2., 1.], [2., 2.], [1., 2.]],
+            &mut core,
+        );
+
+        let difference = a.difference(&b, 0.001, &mut core);
+
+        let region = difference.regions().only();
+        assert_eq!(region.interiors().len(), 1);
+        assert_eq!(area(&difference, &core), Scalar::from(8.));
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn difference_notches_boundary() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = Sketch::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        );
+        let b = Sketch::polygon(
+            [[1., 1.], [3., 1.], [3., 3.], [1., 3.]],
+            &mut core,
+        );
+
+        let difference = a.difference(&b, 0.001, &mut core);
+
+        // The result is L-shaped.
+        let region = difference.regions().only();
+        assert_eq!(region.interiors().len(), 0);
+        assert_eq!(region.exterior().half_edges().len(), 6);
+        assert_eq!(area(&difference, &core), Scalar::from(3.));
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_covering_sketch_is_empty() {
+        let mut core = Core::new();
+
+        let a = Sketch::polygon(
+            [[1., 1.], [2., 1.], [2., 2.], [1., 2.]],
+            &mut core,
+        );
+        let b = Sketch::polygon(
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            &mut core,
+        );
+
+        let difference = a.difference(&b, 0.001, &mut core);
+        assert!(difference.regions().is_empty());
+    }
+
+    fn area(sketch: &Sketch, core: &Core) -> Scalar {
+        // Interior cycles are clockwise, so their signed area is negative.
+        let cycle_area = |cycle: &Cycle| {
+            let ring = cycle_to_ring(
+                cycle,
+                sketch.surface(),
+                Tolerance::from_scalar(0.001).unwrap(),
+                &core.layers.geometry,
+            );
+            Scalar::from(Polygon::new(ring, Vec::new()).signed_area())
+        };
+
+        sketch
+            .regions()
+            .iter()
+            .flat_map(|region| region.all_cycles())
+            .fold(Scalar::ZERO, |area, cycle| area + cycle_area(cycle))
+    }
+}
@@ -1,12 +1,13 @@
 use std::{array, borrow::Borrow};
 
-use fj_math::{Point, Scalar};
+use fj_math::{Point, Scalar, Winding};
 
 use crate::{
     Core,
     operations::{
         build::{BuildCycle, BuildRegion, BuildSurface},
         insert::{Insert, IsInserted, IsInsertedNo},
+        reverse::Reverse,
     },
     storage::Handle,
     topology::{Cycle, Face, HalfEdge, Region, Surface, Vertex},
@@ -85,10 +86,195 @@ pub trait BuildFace {
             Region::polygon(points, surface.clone(), core).insert(core);
         Face::new(surface, region)
     }
+
+    /// Build a rectangle
+    ///
+    /// See [`BuildRegion::rectangle`] for details.
+    fn rectangle(
+        surface: Handle<Surface>,
+        center: impl Into<Point<2>>,
+        width: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        angle: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Face {
+        let region = Region::rectangle(
+            center,
+            width,
+            height,
+            angle,
+            surface.clone(),
+            core,
+        )
+        .insert(core);
+        Face::new(surface, region)
+    }
+
+    /// Build a regular polygon
+    ///
+    /// See [`BuildRegion::regular_polygon`] for details.
+    fn regular_polygon(
+        surface: Handle<Surface>,
+        center: impl Into<Point<2>>,
+        circumradius: impl Into<Scalar>,
+        num_vertices: usize,
+        phase: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Face {
+        let region = Region::regular_polygon(
+            center,
+            circumradius,
+            num_vertices,
+            phase,
+            surface.clone(),
+            core,
+        )
+        .insert(core);
+        Face::new(surface, region)
+    }
 }
 
 impl BuildFace for Face {}
 
+/// # Build a [`Face`] with holes, step by step
+///
+/// Unlike the methods of [`BuildFace`], which build a face with only an
+/// exterior cycle, this builder can also add any number of holes (interior
+/// cycles) to the face.
+///
+/// All points are in the 2D coordinates of the surface that is passed to
+/// [`FaceBuilder::build`]. The winding of the cycles is set automatically: The
+/// exterior is built counter-clockwise and the holes clockwise, regardless of
+/// the order in which their points are provided.
+#[derive(Clone, Debug, Default)]
+pub struct FaceBuilder {
+    exterior: Option<CycleShape>,
+    holes: Vec<CycleShape>,
+}
+
+impl FaceBuilder {
+    /// # Create a new builder, without an exterior or holes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Define a polygonal exterior
+    ///
+    /// Replaces any exterior that was previously defined.
+    pub fn exterior(
+        mut self,
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> Self {
+        self.exterior = Some(CycleShape::polygon(points));
+        self
+    }
+
+    /// # Define a circular exterior
+    ///
+    /// Replaces any exterior that was previously defined.
+    pub fn circular_exterior(
+        mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        self.exterior = Some(CycleShape::circle(center, radius));
+        self
+    }
+
+    /// # Add a polygonal hole
+    pub fn hole(
+        mut self,
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> Self {
+        self.holes.push(CycleShape::polygon(points));
+        self
+    }
+
+    /// # Add a circular hole
+    pub fn circular_hole(
+        mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        self.holes.push(CycleShape::circle(center, radius));
+        self
+    }
+
+    /// # Build the face on the provided surface
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if no exterior has been defined.
+    pub fn build(self, surface: Handle<Surface>, core: &mut Core) -> Face {
+        let exterior = self
+            .exterior
+            .expect("Can't build face without exterior")
+            .build(Winding::Ccw, surface.clone(), core)
+            .insert(core);
+        let interiors = self
+            .holes
+            .into_iter()
+            .map(|hole| {
+                hole.build(Winding::Cw, surface.clone(), core).insert(core)
+            })
+            .collect::<Vec<_>>();
+
+        let region = Region::new(exterior, interiors).insert(core);
+        Face::new(surface, region)
+    }
+}
+
+#[derive(Clone, Debug)]
+enum CycleShape {
+    Polygon { points: Vec<Point<2>> },
+    Circle { center: Point<2>, radius: Scalar },
+}
+
+impl CycleShape {
+    fn polygon(points: impl IntoIterator<Item = impl Into<Point<2>>>) -> Self {
+        let points = points.into_iter().map(Into::into).collect();
+        Self::Polygon { points }
+    }
+
+    fn circle(center: impl Into<Point<2>>, radius: impl Into<Scalar>) -> Self {
+        Self::Circle {
+            center: center.into(),
+            radius: radius.into(),
+        }
+    }
+
+    fn build(
+        self,
+        winding: Winding,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Cycle {
+        match self {
+            Self::Polygon { mut points } => {
+                let is_ccw =
+                    fj_math::Polygon::from_points(points.iter().copied())
+                        .signed_area()
+                        > Scalar::ZERO;
+
+                if is_ccw != winding.is_ccw() {
+                    points.reverse();
+                }
+
+                Cycle::polygon(points, surface, core)
+            }
+            Self::Circle { center, radius } => {
+                // Circles are always built counter-clockwise.
+                let cycle = Cycle::circle(center, radius, surface, core);
+
+                match winding {
+                    Winding::Ccw => cycle,
+                    Winding::Cw => cycle.reverse(core),
+                }
+            }
+        }
+    }
+}
+
 /// A polygon
 ///
 /// # Implementation Note
@@ -143,3 +329,168 @@ impl<const D: usize, I: IsInserted> Polygon<D, I> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Winding};
+
+    use crate::{
+        Core,
+        operations::{build::BuildFace, insert::Insert},
+        queries::BoundingVerticesOfHalfEdge,
+        topology::Face,
+    };
+
+    use super::FaceBuilder;
+
+    #[test]
+    fn rectangle() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::rectangle(surface, [1., 2.], 4., 2., 0., &mut core);
+
+        assert_eq!(
+            corners(&face, &core),
+            [[-1., 1.], [3., 1.], [3., 3.], [-1., 3.]].map(Point::from),
+        );
+
+        // The cycle must be closed, using the same vertex where it starts and
+        // ends, not a duplicate.
+        let exterior = face.region().exterior();
+        let first = exterior.half_edges().first();
+        let last = exterior.half_edges().iter().last().unwrap();
+        let [_, end] =
+            exterior.bounding_vertices_of_half_edge(last).unwrap().inner;
+        assert_eq!(&end, first.start_vertex());
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn rotated_rectangle() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::rectangle(
+            surface,
+            [0., 0.],
+            2.,
+            2.,
+            Scalar::PI / 4.,
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let sqrt_2 = Scalar::from(2.).sqrt();
+        let expected = [
+            [Scalar::ZERO, -sqrt_2],
+            [sqrt_2, Scalar::ZERO],
+            [Scalar::ZERO, sqrt_2],
+            [-sqrt_2, Scalar::ZERO],
+        ];
+        for (corner, expected) in
+            corners(&face, &core).into_iter().zip(expected)
+        {
+            let distance = corner.distance_to(&Point::from(expected));
+            assert!(distance < Scalar::from(1e-12));
+        }
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn regular_hexagon() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let center = Point::from([1., -1.]);
+        let circumradius = Scalar::from(3.);
+        let face = Face::regular_polygon(
+            surface,
+            center,
+            circumradius,
+            6,
+            Scalar::PI / 6.,
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let corners = corners(&face, &core);
+        assert_eq!(corners.len(), 6);
+        for corner in &corners {
+            let distance = corner.distance_to(&center);
+            assert!((distance - circumradius).abs() < Scalar::from(1e-12));
+        }
+
+        // With this phase, the right side is aligned with the v-axis.
+        assert!((corners[5].u - corners[0].u).abs() < Scalar::from(1e-12));
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "at least 3 vertices")]
+    fn regular_polygon_with_too_few_vertices() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let _ = Face::regular_polygon(surface, [0., 0.], 1., 2, 0., &mut core);
+    }
+
+    fn corners(face: &Face, core: &Core) -> Vec<Point<2>> {
+        let geometry = &core.layers.geometry;
+
+        face.region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(face.surface())
+                    .unwrap()
+                    .path;
+                let position = geometry
+                    .of_vertex(half_edge.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position;
+
+                path.point_from_path_coords(position)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn square_with_two_circular_holes() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        // Provide the exterior clockwise, to check that the winding is fixed.
+        let face = FaceBuilder::new()
+            .exterior([[0., 0.], [0., 4.], [4., 4.], [4., 0.]])
+            .circular_hole([1., 1.], 0.5)
+            .circular_hole([3., 3.], 0.5)
+            .build(surface.clone(), &mut core)
+            .insert(&mut core);
+
+        let region = face.region();
+        assert_eq!(region.interiors().len(), 2);
+
+        let geometry = &core.layers.geometry;
+        assert_eq!(region.exterior().winding(geometry, &surface), Winding::Ccw);
+        for interior in region.interiors() {
+            assert_eq!(interior.winding(geometry, &surface), Winding::Cw);
+        }
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+}
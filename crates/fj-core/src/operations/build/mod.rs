@@ -26,15 +26,17 @@ mod shell;
 mod sketch;
 mod solid;
 mod surface;
+mod svg;
 
 pub use self::{
     curve::BuildCurve,
     cycle::BuildCycle,
-    face::{BuildFace, Polygon},
+    face::{BuildFace, FaceBuilder, Polygon},
     half_edge::BuildHalfEdge,
     region::BuildRegion,
     shell::{BuildShell, TetrahedronShell},
     sketch::BuildSketch,
     solid::{BuildSolid, Tetrahedron},
     surface::BuildSurface,
+    svg::SvgPathError,
 };
@@ -1,4 +1,4 @@
-use fj_math::{Point, Scalar};
+use fj_math::{Point, Scalar, Vector};
 
 use crate::{
     Core,
@@ -47,6 +47,75 @@ pub trait BuildRegion {
         let exterior = Cycle::polygon(points, surface, core).insert(core);
         Region::new(exterior, [])
     }
+
+    /// Build a rectangle
+    ///
+    /// The rectangle is rotated counter-clockwise around its center by the
+    /// provided angle, in radians. Pass zero for an axis-aligned rectangle.
+    ///
+    /// The corners of the rectangle are arranged counter-clockwise, starting
+    /// with the one that is bottom-left before the rotation.
+    fn rectangle(
+        center: impl Into<Point<2>>,
+        width: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        angle: impl Into<Scalar>,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Region {
+        let center = center.into();
+        let [half_width, half_height] =
+            [width.into(), height.into()].map(|size| size / Scalar::TWO);
+        let (sin, cos) = angle.into().sin_cos();
+
+        let corners =
+            [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]].map(|[u, v]| {
+                let [u, v] = [half_width * u, half_height * v];
+                center + Vector::from([u * cos - v * sin, u * sin + v * cos])
+            });
+
+        Region::polygon(corners, surface, core)
+    }
+
+    /// Build a regular polygon
+    ///
+    /// Places `num_vertices` vertices evenly on the circle defined by `center`
+    /// and `circumradius`, and connects them counter-clockwise. The first
+    /// vertex is placed at the angle defined by `phase`, in radians. Pass zero
+    /// to place it on the positive u-axis, or half of the angle between
+    /// vertices, to align a flat side with the v-axis.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if `num_vertices` is less than 3.
+    fn regular_polygon(
+        center: impl Into<Point<2>>,
+        circumradius: impl Into<Scalar>,
+        num_vertices: usize,
+        phase: impl Into<Scalar>,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Region {
+        assert!(
+            num_vertices >= 3,
+            "Regular polygon must have at least 3 vertices; got \
+            {num_vertices}",
+        );
+
+        let center = center.into();
+        let circumradius = circumradius.into();
+        let phase = phase.into();
+
+        let increment = Scalar::TAU / num_vertices as f64;
+        let points = (0..num_vertices)
+            .map(|i| {
+                let (sin, cos) = (phase + increment * i as f64).sin_cos();
+                center + Vector::from([cos, sin]) * circumradius
+            })
+            .collect::<Vec<_>>();
+
+        Region::polygon(points, surface, core)
+    }
 }
 
 impl BuildRegion for Region {}
@@ -1,22 +1,23 @@
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, ops::Deref};
 
-use fj_interop::ext::ArrayExt;
-use fj_math::Point;
+use fj_interop::{CircleApproxParams, Tolerance};
+use fj_math::{Line, Point, Scalar, Vector};
 
 use crate::{
     Core,
-    geometry::{CurveBoundary, LocalVertexGeom},
+    geometry::{CurveBoundary, LocalVertexGeom, Path, surfaces::Sphere},
     operations::{
-        build::{BuildFace, BuildHalfEdge, BuildSurface, Polygon},
+        build::{BuildFace, BuildHalfEdge, BuildSketch, BuildSurface, Polygon},
         geometry::UpdateCurveGeometry,
         insert::{Insert, IsInserted, IsInsertedNo, IsInsertedYes},
         join::JoinCycle,
         reverse::ReverseCurveCoordinateSystems,
+        sweep::SweepSketch,
         update::{
             UpdateCycle, UpdateFace, UpdateHalfEdge, UpdateRegion, UpdateShell,
         },
     },
-    topology::{Curve, Face, HalfEdge, Shell, Surface, Vertex},
+    topology::{Curve, Face, HalfEdge, Shell, Sketch, Surface, Vertex},
 };
 
 /// Build a [`Shell`]
@@ -35,6 +36,19 @@ pub trait BuildShell {
         vertices: impl IntoIterator<Item = impl Into<Point<3>>>,
         indices: impl IntoIterator<Item = [usize; 3]>,
         core: &mut Core,
+    ) -> Shell {
+        Self::from_vertices_and_polygons(vertices, indices, core)
+    }
+
+    /// Build a polyhedron by specifying its vertices and the polygons of its faces
+    ///
+    /// Each polygon is specified by the indices of its vertices. All vertices
+    /// of a polygon must lie within a plane, and they must be in
+    /// counter-clockwise order, as viewed from outside of the polyhedron.
+    fn from_vertices_and_polygons(
+        vertices: impl IntoIterator<Item = impl Into<Point<3>>>,
+        polygons: impl IntoIterator<Item = impl IntoIterator<Item = usize>>,
+        core: &mut Core,
     ) -> Shell {
         let vertices = vertices
             .into_iter()
@@ -49,23 +63,37 @@ pub trait BuildShell {
 
         let mut curves = BTreeMap::new();
 
-        let faces = indices
+        let faces = polygons
             .into_iter()
             .map(|indices| {
-                let [(a, a_pos), (b, b_pos), (c, c_pos)] = indices
-                    .map(|index| vertices.get(&index).expect("Invalid index"));
+                let polygon = indices
+                    .into_iter()
+                    .map(|index| vertices.get(&index).expect("Invalid index"))
+                    .collect::<Vec<_>>();
+                let points = polygon
+                    .iter()
+                    .map(|(_, position)| *position)
+                    .collect::<Vec<_>>();
 
-                let (surface, _) = Surface::plane_from_points(
-                    [a_pos, b_pos, c_pos].map(Clone::clone),
-                    core,
-                );
+                let plane_points = plane_points(&points);
+                let (surface, _) =
+                    Surface::plane_from_points(plane_points, core);
 
-                let curves_and_boundaries =
-                    [[a, b], [b, c], [c, a]].map(|vertices| {
-                        let vertices = vertices.map(Clone::clone);
-                        let vertices = CurveBoundary::<Vertex>::from(vertices);
+                let half_edges = polygon
+                    .iter()
+                    .zip(&points)
+                    .enumerate()
+                    .map(|(i, ((vertex, _), point))| {
+                        let j = (i + 1) % polygon.len();
+                        let (vertex_next, _) = polygon[j];
+                        let positions = [point, &points[j]]
+                            .map(|point| plane_coords(*point, plane_points));
 
-                        curves
+                        let vertices = CurveBoundary::<Vertex>::from([
+                            vertex.clone(),
+                            vertex_next.clone(),
+                        ]);
+                        let (curve, boundary) = curves
                             .get(&vertices.clone().reverse())
                             .cloned()
                             .unwrap_or_else(|| {
@@ -78,54 +106,37 @@ pub trait BuildShell {
                                 );
 
                                 (curve, boundary.reverse())
-                            })
-                    });
-
-                let half_edges = {
-                    let vertices = [[a, b], [b, c], [c, a]];
-                    let [a, b, c] = [[0., 0.], [1., 0.], [0., 1.]];
-                    vertices
-                        .zip_ext([[a, b], [b, c], [c, a]])
-                        .zip_ext(curves_and_boundaries)
-                        .map(
-                            |(
-                                ([vertex, vertex_next], positions),
-                                (curve, boundary),
-                            )| {
-                                let boundary = boundary.reverse();
-
-                                let curve = curve.make_line_on_surface(
-                                    positions,
-                                    boundary,
-                                    surface.clone(),
-                                    &mut core.layers.geometry,
-                                );
+                            });
+                        let boundary = boundary.reverse();
 
-                                core.layers.geometry.define_vertex(
-                                    vertex.clone(),
-                                    curve.clone(),
-                                    LocalVertexGeom {
-                                        position: boundary.inner[0],
-                                    },
-                                );
-                                core.layers.geometry.define_vertex(
-                                    vertex_next.clone(),
-                                    curve.clone(),
-                                    LocalVertexGeom {
-                                        position: boundary.inner[1],
-                                    },
-                                );
+                        let curve = curve.make_line_on_surface(
+                            positions,
+                            boundary,
+                            surface.clone(),
+                            &mut core.layers.geometry,
+                        );
 
-                                HalfEdge::unjoined(core)
-                                    .update_start_vertex(
-                                        |_, _| vertex.clone(),
-                                        core,
-                                    )
-                                    .update_curve(|_, _| curve.clone(), core)
-                                    .insert(core)
+                        core.layers.geometry.define_vertex(
+                            vertex.clone(),
+                            curve.clone(),
+                            LocalVertexGeom {
+                                position: boundary.inner[0],
                             },
-                        )
-                };
+                        );
+                        core.layers.geometry.define_vertex(
+                            vertex_next.clone(),
+                            curve.clone(),
+                            LocalVertexGeom {
+                                position: boundary.inner[1],
+                            },
+                        );
+
+                        HalfEdge::unjoined(core)
+                            .update_start_vertex(|_, _| vertex.clone(), core)
+                            .update_curve(|_, _| curve.clone(), core)
+                            .insert(core)
+                    })
+                    .collect::<Vec<_>>();
 
                 Face::unbound(surface, core).update_region(
                     |region, core| {
@@ -189,7 +200,8 @@ pub trait BuildShell {
                                         )
                                             .reverse_curve_coordinate_systems(
                                                 core,
-                                            )]
+                                            )
+                                            .expect("Triangle has geometry")]
                                     },
                                     core,
                                 )
@@ -227,7 +239,8 @@ pub trait BuildShell {
                                         )
                                             .reverse_curve_coordinate_systems(
                                                 core,
-                                            )]
+                                            )
+                                            .expect("Triangle has geometry")]
                                     },
                                     core,
                                 )
@@ -251,7 +264,8 @@ pub trait BuildShell {
                                         )
                                             .reverse_curve_coordinate_systems(
                                                 core,
-                                            )]
+                                            )
+                                            .expect("Triangle has geometry")]
                                     },
                                     core,
                                 )
@@ -289,7 +303,8 @@ pub trait BuildShell {
                                         )
                                             .reverse_curve_coordinate_systems(
                                                 core,
-                                            )]
+                                            )
+                                            .expect("Triangle has geometry")]
                                     },
                                     core,
                                 )
@@ -306,7 +321,8 @@ pub trait BuildShell {
                                         )
                                             .reverse_curve_coordinate_systems(
                                                 core,
-                                            )]
+                                            )
+                                            .expect("Triangle has geometry")]
                                     },
                                     core,
                                 )
@@ -323,7 +339,8 @@ pub trait BuildShell {
                                         )
                                             .reverse_curve_coordinate_systems(
                                                 core,
-                                            )]
+                                            )
+                                            .expect("Triangle has geometry")]
                                     },
                                     core,
                                 )
@@ -371,10 +388,244 @@ pub trait BuildShell {
             cbd,
         }
     }
+
+    /// Build a cuboid
+    ///
+    /// The cuboid is centered on `center`, and its edges are aligned with the
+    /// coordinate axes, having the lengths given by `dimensions`.
+    ///
+    /// Each of the six faces is on its own plane, and its exterior cycle is
+    /// wound counter-clockwise, as viewed from outside of the cuboid. Faces
+    /// share their vertices and curves with each other.
+    fn cuboid(
+        center: impl Into<Point<3>>,
+        dimensions: impl Into<Vector<3>>,
+        core: &mut Core,
+    ) -> Shell {
+        let center = center.into();
+        let dimensions = dimensions.into();
+
+        // The index of each vertex encodes its position: Bits 0, 1, and 2 are
+        // set, if it's on the positive side along x, y, and z respectively.
+        let vertices = (0..8).map(|index| {
+            let offset = [0, 1, 2].map(|axis| {
+                let sign = if index & (1 << axis) == 0 { -0.5 } else { 0.5 };
+                dimensions.components[axis] * sign
+            });
+
+            center + Vector::from(offset)
+        });
+
+        Self::from_vertices_and_polygons(
+            vertices,
+            [
+                [0, 2, 3, 1], // bottom
+                [4, 5, 7, 6], // top
+                [0, 1, 5, 4], // front
+                [2, 6, 7, 3], // back
+                [0, 4, 6, 2], // left
+                [1, 3, 7, 5], // right
+            ],
+            core,
+        )
+    }
+
+    /// Build a capped cylinder
+    ///
+    /// The bottom cap is centered on `center`, and the cylinder extends along
+    /// `axis` by `height`. `axis` doesn't need to be normalized.
+    ///
+    /// All geometry is exact: The caps are bounded by circles, and the side is
+    /// made up of faces on cylindrical surfaces. How the cylinder is
+    /// approximated, is decided later, by the tolerance that is used for that.
+    ///
+    /// The shell is built by sweeping a circle, which is made up of four arcs
+    /// (see [`BuildCycle::circle`]). This means the side consists of four
+    /// faces, one for each arc.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `axis` has a length of zero.
+    ///
+    /// [`BuildCycle::circle`]: super::BuildCycle::circle
+    fn cylinder(
+        center: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Shell {
+        let center = center.into();
+        let axis = axis.into();
+
+        assert!(
+            axis.magnitude() > Scalar::ZERO,
+            "Can't build cylinder with zero-length axis"
+        );
+        let axis = axis.normalize();
+
+        // Choose the coordinate system of the bottom cap, such that its normal
+        // points along the axis. Sweeping towards the back of a surface is
+        // what `SweepSketch` expects.
+        let u = {
+            let other = if axis.x.abs() < Scalar::from(0.9) {
+                Vector::unit_x()
+            } else {
+                Vector::unit_y()
+            };
+            axis.cross(&other).normalize()
+        };
+        let v = axis.cross(&u);
+
+        let surface = Surface::from_uv(
+            Path::Line(Line::from_origin_and_direction(center, u)),
+            v,
+            core,
+        );
+
+        let solid = Sketch::circle([0., 0.], radius, core).sweep_sketch(
+            surface,
+            axis * height.into(),
+            core,
+        );
+
+        solid.shells().only().deref().clone()
+    }
+
+    /// Build a polyhedron that approximates a sphere
+    ///
+    /// The sphere is tessellated along lines of latitude and longitude. The
+    /// faces between two lines of latitude are planar quadrilaterals, the ones
+    /// touching the poles are triangles. This avoids any degenerate faces at
+    /// the poles, and results in a watertight shell.
+    ///
+    /// The number of lines is chosen, such that the distance between the
+    /// edges of the polyhedron and the sphere is within the provided
+    /// tolerance, same as for the approximation of a circle.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `radius` is not positive.
+    ///
+    /// # Implementation Note
+    ///
+    /// Ideally, this would build a face on a spherical surface, and leave the
+    /// approximation to the triangulation. Faces on curved surfaces can only be
+    /// triangulated, if those surfaces are swept along a straight line, which
+    /// isn't the case for a sphere.
+    fn sphere(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Shell {
+        let sphere = Sphere::new(center, radius);
+
+        let increment =
+            CircleApproxParams::new(sphere.radius, tolerance).increment();
+        let num_longitudes = (Scalar::TAU / increment).round().into_u64();
+        let num_latitudes = num_longitudes.div_ceil(2).max(2);
+        let num_longitudes = num_longitudes as usize;
+        let num_rings = num_latitudes as usize - 1;
+
+        let south_pole = 0;
+        let north_pole = num_rings * num_longitudes + 1;
+
+        // The index of the vertex at longitude `i` on ring `j`. Rings are
+        // numbered from south to north, starting at zero.
+        let index =
+            |i: usize, j: usize| 1 + j * num_longitudes + i % num_longitudes;
+
+        let pole = |sign: f64| {
+            sphere.point_from_surface_coords([
+                Scalar::ZERO,
+                Scalar::PI / 2. * sign,
+            ])
+        };
+        let rings = (0..num_rings).flat_map(|j| {
+            (0..num_longitudes).map(move |i| {
+                let u = Scalar::TAU * (i as f64 / num_longitudes as f64);
+                let v =
+                    Scalar::PI * ((j + 1) as f64 / num_latitudes as f64 - 0.5);
+
+                sphere.point_from_surface_coords([u, v])
+            })
+        });
+        let vertices = [pole(-1.)].into_iter().chain(rings).chain([pole(1.)]);
+
+        let mut polygons = Vec::new();
+        for i in 0..num_longitudes {
+            polygons.push(vec![south_pole, index(i + 1, 0), index(i, 0)]);
+
+            for j in 0..num_rings - 1 {
+                polygons.push(vec![
+                    index(i, j),
+                    index(i + 1, j),
+                    index(i + 1, j + 1),
+                    index(i, j + 1),
+                ]);
+            }
+
+            polygons.push(vec![
+                index(i, num_rings - 1),
+                index(i + 1, num_rings - 1),
+                north_pole,
+            ]);
+        }
+
+        Self::from_vertices_and_polygons(vertices, polygons, core)
+    }
 }
 
 impl BuildShell for Shell {}
 
+/// # Select the points that define the plane of a polygon
+///
+/// The first two points of the polygon are always selected. The third one is
+/// the one furthest from the line through those, on the side that keeps the
+/// plane's orientation consistent with the winding of the polygon.
+fn plane_points(points: &[Point<3>]) -> [Point<3>; 3] {
+    let [a, b] = [points[0], points[1]];
+
+    let normal = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .fold(Vector::from([0., 0., 0.]), |normal, (p, q)| {
+            normal + p.coords.cross(&q.coords)
+        });
+
+    let c = points
+        .iter()
+        .copied()
+        .max_by(|p, q| {
+            let [p, q] =
+                [p, q].map(|point| (b - a).cross(&(point - a)).dot(&normal));
+            p.cmp(&q)
+        })
+        .expect("Polygon has at least two points");
+
+    [a, b, c]
+}
+
+/// # Compute the coordinates of a point in the plane built from `plane_points`
+///
+/// The plane's `u` axis points from `a` to `b`, its `v` axis from `a` to `c`.
+/// Those are not necessarily orthogonal, so the coordinates are the solution to
+/// a 2x2 linear system.
+fn plane_coords(point: Point<3>, [a, b, c]: [Point<3>; 3]) -> Point<2> {
+    let [u, v, d] = [b - a, c - a, point - a];
+
+    let uu = u.dot(&u);
+    let uv = u.dot(&v);
+    let vv = v.dot(&v);
+    let du = d.dot(&u);
+    let dv = d.dot(&v);
+
+    let det = uu * vv - uv * uv;
+
+    Point::from([(du * vv - dv * uv) / det, (dv * uu - du * uv) / det])
+}
+
 /// A tetrahedron
 ///
 /// A tetrahedron is constructed from 4 points and has 4 faces. For the purpose
@@ -398,3 +649,121 @@ pub struct TetrahedronShell<I: IsInserted = IsInsertedNo> {
     /// The face formed by the points `c`, `b`, and `d`.
     pub cbd: Polygon<3, IsInsertedYes>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        operations::{
+            build::BuildShell, insert::Insert, measure::MeasureShell,
+        },
+        topology::Shell,
+        validation::{
+            ValidationCheck,
+            checks::{NonManifoldEdge, ShellNotWatertight},
+        },
+    };
+
+    #[test]
+    fn cuboid() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let shell = Shell::cuboid([0., 0., 0.], [1., 1., 1.], &mut core);
+
+        assert_eq!(shell.faces().len(), 6);
+        assert_eq!(
+            shell
+                .all_half_edges()
+                .map(|half_edge| half_edge.curve())
+                .collect::<BTreeSet<_>>()
+                .len(),
+            12
+        );
+        assert_eq!(shell.all_vertices().collect::<BTreeSet<_>>().len(), 8);
+
+        ShellNotWatertight::check_and_return_first_error(
+            &shell,
+            &core.layers.geometry,
+        )?;
+
+        // The centroid is only computed, if all faces point outwards.
+        let shell = Shell::cuboid([1., 2., 3.], [1., 2., 3.], &mut core);
+        assert_eq!(
+            shell.centroid(core.tolerance(), &core.layers.geometry),
+            Some(Point::from([1., 2., 3.]))
+        );
+
+        let _ = shell.insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn sphere() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let expected = Scalar::from(4. / 3.) * Scalar::PI;
+
+        let mut errors = Vec::new();
+        for tolerance in [0.1, 0.03, 0.01] {
+            let shell = Shell::sphere([0., 0., 0.], 1., tolerance, &mut core);
+
+            ShellNotWatertight::check_and_return_first_error(
+                &shell,
+                &core.layers.geometry,
+            )?;
+            NonManifoldEdge::check_and_return_first_error(
+                &shell,
+                &core.layers.geometry,
+            )?;
+
+            let volume =
+                shell.volume(core.tolerance(), &core.layers.geometry)?;
+            errors.push((volume - expected).abs());
+        }
+
+        for pair in errors.windows(2) {
+            assert!(pair[1] < pair[0]);
+        }
+        assert!(errors[2] / expected < Scalar::from(0.05));
+
+        // Full validation is slow for larger shells, so only do it for the
+        // coarsest one.
+        let shell = Shell::sphere([0., 0., 0.], 1., 0.1, &mut core);
+        let _ = shell.insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        for axis in [[0., 0., 1.], [1., 0., 0.], [1., 1., 1.]] {
+            let shell = Shell::cylinder([0., 0., 0.], axis, 1., 1., &mut core);
+
+            // Two caps, and one side face for each arc of the circles.
+            assert_eq!(shell.faces().len(), 6);
+
+            ShellNotWatertight::check_and_return_first_error(
+                &shell,
+                &core.layers.geometry,
+            )?;
+            NonManifoldEdge::check_and_return_first_error(
+                &shell,
+                &core.layers.geometry,
+            )?;
+
+            let _ = shell.insert(&mut core);
+            core.layers.validation.take_errors()?;
+        }
+
+        Ok(())
+    }
+}
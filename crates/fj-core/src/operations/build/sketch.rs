@@ -1,12 +1,16 @@
-use fj_math::{Point, Scalar};
+use fj_math::{Point, Polygon, Scalar};
 
 use crate::{
     Core,
-    operations::update::UpdateSketch,
-    topology::{Region, Sketch, Topology},
+    operations::{
+        insert::Insert,
+        update::{UpdateRegion, UpdateSketch},
+    },
+    storage::Handle,
+    topology::{Cycle, Region, Sketch, Surface, Topology},
 };
 
-use super::BuildRegion;
+use super::{BuildCycle, BuildRegion, SvgPathError, svg::parse_svg_path};
 
 /// Build a [`Sketch`]
 ///
@@ -50,6 +54,138 @@ pub trait BuildSketch {
             core,
         )
     }
+
+    /// Build a rectangle
+    ///
+    /// See [`BuildRegion::rectangle`] for details.
+    fn rectangle(
+        center: impl Into<Point<2>>,
+        width: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        angle: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Sketch {
+        let sketch = Sketch::empty(&core.layers.topology);
+        sketch.add_regions(
+            [Region::rectangle(
+                center,
+                width,
+                height,
+                angle,
+                sketch.surface().clone(),
+                core,
+            )],
+            core,
+        )
+    }
+
+    /// Build a sketch from SVG path data
+    ///
+    /// Supports the path commands `M`, `L`, `H`, `V`, `C`, `Q`, and `Z`, in
+    /// their absolute and relative forms. Cubic and quadratic Bézier curves are
+    /// approximated by line segments, within the core's tolerance.
+    ///
+    /// The first subpath becomes the exterior of the sketch's single region,
+    /// any further subpaths become its interiors. Subpaths are closed, even if
+    /// they don't end with `Z`, and their winding is adjusted as required.
+    ///
+    /// Coordinates are used as-is. SVG's y-axis points down, so paths from an
+    /// SVG document appear mirrored, unless they are transformed accordingly.
+    fn from_svg_path(
+        path_data: &str,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Result<Sketch, SvgPathError> {
+        let mut subpaths =
+            parse_svg_path(path_data, core.tolerance())?.into_iter();
+
+        let mut exterior = subpaths
+            .next()
+            .expect("Successful parse returns at least one subpath");
+        if Polygon::from_points(exterior.iter().copied()).signed_area()
+            < Scalar::ZERO
+        {
+            exterior.reverse();
+        }
+
+        let interiors = subpaths
+            .map(|mut interior| {
+                if Polygon::from_points(interior.iter().copied()).signed_area()
+                    > Scalar::ZERO
+                {
+                    interior.reverse();
+                }
+                Cycle::polygon(interior, surface.clone(), core)
+            })
+            .collect::<Vec<_>>();
+
+        let region = Region::polygon(exterior, surface.clone(), core)
+            .add_interiors(interiors, core)
+            .insert(core);
+
+        Ok(Sketch::new(surface, [region]))
+    }
 }
 
 impl BuildSketch for Sketch {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Core, topology::Sketch};
+
+    use super::{BuildSketch, SvgPathError};
+
+    #[test]
+    fn from_svg_path_triangle() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let sketch =
+            Sketch::from_svg_path("M0 0 L1 0 L1 1 Z", surface, &mut core)?;
+
+        let region = sketch.regions().only();
+        assert_eq!(region.exterior().half_edges().len(), 3);
+        assert_eq!(region.interiors().len(), 0);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn from_svg_path_with_hole_and_curves() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The hole is drawn with the same winding as the exterior, and must be
+        // reversed.
+        let surface = core.layers.topology.surfaces.space_2d();
+        let sketch = Sketch::from_svg_path(
+            "M0,0 H4 V4 Q2 6 0 4 z m1 1 l2 0 0 2 c-1 0.5 -1 0.5 -2 0 Z",
+            surface,
+            &mut core,
+        )?;
+
+        let region = sketch.regions().only();
+        assert!(region.exterior().half_edges().len() > 4);
+        assert_eq!(region.interiors().len(), 1);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn from_svg_path_rejects_unsupported_command() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let result =
+            Sketch::from_svg_path("M0 0 A1 1 0 0 1 1 1 Z", surface, &mut core);
+
+        assert!(matches!(
+            result,
+            Err(SvgPathError::UnsupportedCommand {
+                command: 'A',
+                position: 5,
+            })
+        ));
+    }
+}
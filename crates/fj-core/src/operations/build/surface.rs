@@ -31,6 +31,7 @@ pub trait BuildSurface {
             SurfaceGeom {
                 generator: Box::new(generator),
                 geometry: TriMesh::empty(),
+                is_reversed: false,
             },
         );
 
@@ -0,0 +1,349 @@
+use fj_interop::Tolerance;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::geometry::{
+    CurveBoundary, curves::bezier::Bezier, traits::GenPolyline,
+};
+
+/// # Error parsing SVG path data
+///
+/// See [`BuildSketch::from_svg_path`].
+///
+/// [`BuildSketch::from_svg_path`]: super::BuildSketch::from_svg_path
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SvgPathError {
+    /// # The path data contains a command that is not supported
+    #[error("Unsupported SVG path command `{command}` at position {position}")]
+    UnsupportedCommand {
+        /// # The unsupported command
+        command: char,
+
+        /// # The byte offset of the command within the path data
+        position: usize,
+    },
+
+    /// # The path data contains a character that is not valid at its position
+    #[error("Unexpected character `{character}` at position {position}")]
+    UnexpectedCharacter {
+        /// # The unexpected character
+        character: char,
+
+        /// # The byte offset of the character within the path data
+        position: usize,
+    },
+
+    /// # A command is missing a number
+    #[error("Expected number for command `{command}` at position {position}")]
+    ExpectedNumber {
+        /// # The command that is missing a number
+        command: char,
+
+        /// # The byte offset within the path data, where a number was expected
+        position: usize,
+    },
+
+    /// # A number is not finite
+    #[error("Number at position {position} is not finite")]
+    NonFiniteNumber {
+        /// # The byte offset of the number within the path data
+        position: usize,
+    },
+
+    /// # The path data doesn't start with a move-to command
+    #[error(
+        "Expected path data to start with `M` or `m`; found `{command}` at \
+        position {position}"
+    )]
+    MissingMoveTo {
+        /// # The command that was found instead
+        command: char,
+
+        /// # The byte offset of the command within the path data
+        position: usize,
+    },
+
+    /// # A subpath doesn't enclose an area
+    #[error("Subpath {index} has fewer than three distinct points")]
+    DegenerateSubpath {
+        /// # The index of the subpath, counting from zero
+        index: usize,
+    },
+
+    /// # The path data contains no subpaths
+    #[error("SVG path data contains no subpaths")]
+    Empty,
+}
+
+/// # Parse SVG path data into closed polygons, one per subpath
+///
+/// Supports the commands `M`, `L`, `H`, `V`, `C`, `Q`, and `Z`, in their
+/// absolute (uppercase) and relative (lowercase) forms, including implicitly
+/// repeated parameters. Bézier curves are approximated within the provided
+/// tolerance.
+///
+/// Every subpath is closed, whether it ends with `Z` or not, as SVG does when
+/// filling a path. The point that closes a subpath is not repeated.
+pub(super) fn parse_svg_path(
+    path_data: &str,
+    tolerance: Tolerance,
+) -> Result<Vec<Vec<Point<2>>>, SvgPathError> {
+    let mut tokens = Tokens {
+        data: path_data,
+        position: 0,
+    };
+
+    let mut subpaths = Vec::new();
+    let mut current = Vec::new();
+
+    let mut position = Point::origin();
+    let mut subpath_start = Point::origin();
+    let mut has_move_to = false;
+
+    while let Some((command, command_position)) = tokens.command()? {
+        let upper = command.to_ascii_uppercase();
+
+        if !has_move_to && upper != 'M' {
+            return Err(SvgPathError::MissingMoveTo {
+                command,
+                position: command_position,
+            });
+        }
+        if !matches!(upper, 'M' | 'L' | 'H' | 'V' | 'C' | 'Q' | 'Z') {
+            return Err(SvgPathError::UnsupportedCommand {
+                command,
+                position: command_position,
+            });
+        }
+
+        if upper == 'Z' {
+            finish_subpath(&mut current, &mut subpaths)?;
+            position = subpath_start;
+            continue;
+        }
+
+        let mut is_first = true;
+        while is_first || tokens.has_number() {
+            // Relative coordinates are relative to the current position, which
+            // changes with every repetition of the parameters.
+            let offset = if command.is_ascii_lowercase() {
+                position.coords
+            } else {
+                Vector::from([0., 0.])
+            };
+
+            // After a move-to, repeated parameters are treated as line-to.
+            let upper = if upper == 'M' && !is_first {
+                'L'
+            } else {
+                upper
+            };
+            is_first = false;
+
+            if upper == 'M' {
+                finish_subpath(&mut current, &mut subpaths)?;
+
+                position = tokens.point(command)? + offset;
+                subpath_start = position;
+                has_move_to = true;
+                current.push(position);
+
+                continue;
+            }
+
+            // A drawing command that follows a close-path without a move-to
+            // starts a new subpath at the start of the previous one.
+            if current.is_empty() {
+                current.push(position);
+            }
+
+            let points = match upper {
+                'L' => vec![tokens.point(command)? + offset],
+                'H' => {
+                    let u = tokens.number(command)? + offset.u;
+                    vec![Point::from([u, position.v])]
+                }
+                'V' => {
+                    let v = tokens.number(command)? + offset.v;
+                    vec![Point::from([position.u, v])]
+                }
+                'C' => {
+                    let [a, b, end] = [
+                        tokens.point(command)?,
+                        tokens.point(command)?,
+                        tokens.point(command)?,
+                    ];
+                    approx_bezier(
+                        3,
+                        [position, a + offset, b + offset, end + offset],
+                        tolerance,
+                    )
+                }
+                'Q' => {
+                    let [a, end] =
+                        [tokens.point(command)?, tokens.point(command)?];
+                    approx_bezier(
+                        2,
+                        [position, a + offset, end + offset],
+                        tolerance,
+                    )
+                }
+                _ => unreachable!("Unsupported commands have been rejected"),
+            };
+
+            if let Some(last) = points.last() {
+                position = *last;
+            }
+            current.extend(points);
+        }
+    }
+
+    finish_subpath(&mut current, &mut subpaths)?;
+
+    if subpaths.is_empty() {
+        return Err(SvgPathError::Empty);
+    }
+
+    Ok(subpaths)
+}
+
+/// # Approximate a Bézier curve, returning all points except the first
+fn approx_bezier(
+    degree: usize,
+    control_points: impl IntoIterator<Item = Point<2>>,
+    tolerance: Tolerance,
+) -> Vec<Point<2>> {
+    let curve = Bezier::new(degree, control_points)
+        .expect("Bézier curve from SVG path has matching control points");
+    let boundary = CurveBoundary::from([[0.], [1.]]);
+
+    curve
+        .generate_polyline(boundary, tolerance)
+        .into_iter()
+        .chain([Point::from([1.])])
+        .map(|point_curve| curve.point_from_curve_coords(point_curve))
+        .collect()
+}
+
+fn finish_subpath(
+    current: &mut Vec<Point<2>>,
+    subpaths: &mut Vec<Vec<Point<2>>>,
+) -> Result<(), SvgPathError> {
+    let mut points = std::mem::take(current);
+    if points.is_empty() {
+        return Ok(());
+    }
+
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    if points.len() < 3 {
+        return Err(SvgPathError::DegenerateSubpath {
+            index: subpaths.len(),
+        });
+    }
+
+    subpaths.push(points);
+    Ok(())
+}
+
+struct Tokens<'r> {
+    data: &'r str,
+    position: usize,
+}
+
+impl Tokens<'_> {
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.data[self.position..];
+        let trimmed =
+            rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        self.position += rest.len() - trimmed.len();
+
+        trimmed.chars().next()
+    }
+
+    fn command(&mut self) -> Result<Option<(char, usize)>, SvgPathError> {
+        let Some(character) = self.peek() else {
+            return Ok(None);
+        };
+        if !character.is_ascii_alphabetic() {
+            return Err(SvgPathError::UnexpectedCharacter {
+                character,
+                position: self.position,
+            });
+        }
+
+        let position = self.position;
+        self.position += character.len_utf8();
+
+        Ok(Some((character, position)))
+    }
+
+    fn has_number(&mut self) -> bool {
+        self.peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | '.'))
+    }
+
+    fn number(&mut self, command: char) -> Result<Scalar, SvgPathError> {
+        let start = {
+            self.peek();
+            self.position
+        };
+        let bytes = self.data.as_bytes();
+
+        let digits = |mut position: usize| {
+            while bytes.get(position).is_some_and(u8::is_ascii_digit) {
+                position += 1;
+            }
+            position
+        };
+
+        let mut end = start;
+        if matches!(bytes.get(end), Some(b'+' | b'-')) {
+            end += 1;
+        }
+        let integer_end = digits(end);
+        let mut has_digits = integer_end > end;
+        end = integer_end;
+
+        if bytes.get(end) == Some(&b'.') {
+            let fraction_end = digits(end + 1);
+            has_digits |= fraction_end > end + 1;
+            end = fraction_end;
+        }
+        if !has_digits {
+            return Err(SvgPathError::ExpectedNumber {
+                command,
+                position: start,
+            });
+        }
+
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let mut exponent = end + 1;
+            if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+                exponent += 1;
+            }
+            let exponent_end = digits(exponent);
+            if exponent_end > exponent {
+                end = exponent_end;
+            }
+        }
+
+        let number = self.data[start..end]
+            .parse::<f64>()
+            .expect("Parsed characters form a valid number");
+        if !number.is_finite() {
+            return Err(SvgPathError::NonFiniteNumber { position: start });
+        }
+
+        self.position = end;
+        Ok(Scalar::from(number))
+    }
+
+    fn point(&mut self, command: char) -> Result<Point<2>, SvgPathError> {
+        let u = self.number(command)?;
+        let v = self.number(command)?;
+        Ok(Point::from([u, v]))
+    }
+}
@@ -0,0 +1,280 @@
+use fj_math::Scalar;
+
+use crate::{
+    Core,
+    geometry::{LocalVertexGeom, Path},
+    operations::{build::BuildHalfEdge, derive::DeriveFrom, insert::Insert},
+    storage::Handle,
+    topology::{Cycle, HalfEdge, Surface, Vertex},
+};
+
+/// # Bevel a corner of a [`Cycle`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait ChamferCorner {
+    /// # Replace the corner at the provided vertex with a straight edge
+    ///
+    /// The corner is defined by the half-edge that starts at the provided
+    /// vertex, and the half-edge before it. Both must be line segments. They
+    /// are shortened by the provided setback, and a new line segment is
+    /// inserted between them.
+    ///
+    /// Returns an error, if the vertex is not the start vertex of a half-edge
+    /// in the cycle, if the adjacent half-edges are not line segments, or if
+    /// the setback is too large for them.
+    fn chamfer_corner(
+        &self,
+        vertex: &Handle<Vertex>,
+        setback: impl Into<Scalar>,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Result<Cycle, ChamferError>;
+}
+
+impl ChamferCorner for Cycle {
+    fn chamfer_corner(
+        &self,
+        vertex: &Handle<Vertex>,
+        setback: impl Into<Scalar>,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Result<Cycle, ChamferError> {
+        let setback = setback.into();
+        if setback <= Scalar::ZERO {
+            return Err(ChamferError::NonPositiveSetback { setback });
+        }
+
+        let next = self
+            .half_edges()
+            .iter()
+            .find(|half_edge| half_edge.start_vertex().id() == vertex.id())
+            .ok_or(ChamferError::VertexNotInCycle)?;
+        let prev = self
+            .half_edges()
+            .before(next)
+            .expect("Just found `next` in the cycle");
+        let after_next = self
+            .half_edges()
+            .after(next)
+            .expect("Just found `next` in the cycle");
+
+        let [prev_path, next_path] = [prev, next].map(|half_edge| {
+            core.layers
+                .geometry
+                .of_curve(half_edge.curve())
+                .and_then(|curve| curve.local_on(surface))
+                .map(|curve| curve.path)
+        });
+        let (
+            Some(prev_path @ Path::Line(prev_line)),
+            Some(next_path @ Path::Line(next_line)),
+        ) = (prev_path, next_path)
+        else {
+            return Err(ChamferError::NotLineSegments);
+        };
+
+        let position_on = |vertex: &Handle<Vertex>,
+                           half_edge: &Handle<HalfEdge>,
+                           path: &Path<2>| {
+            let position = core
+                .layers
+                .geometry
+                .of_vertex(vertex)
+                .unwrap()
+                .local_on(half_edge.curve())
+                .unwrap()
+                .position;
+            path.point_from_path_coords(position)
+        };
+
+        let a = position_on(prev.start_vertex(), prev, &prev_path);
+        let corner = position_on(vertex, next, &next_path);
+        let b = position_on(after_next.start_vertex(), next, &next_path);
+
+        let [to_a, to_b] = [a - corner, b - corner];
+
+        let max_setback = to_a.magnitude().min(to_b.magnitude());
+        if setback >= max_setback {
+            return Err(ChamferError::SetbackTooLarge {
+                setback,
+                max_setback,
+            });
+        }
+
+        let start = corner + to_a.normalize() * setback;
+        let end = corner + to_b.normalize() * setback;
+
+        let (bevel, boundary) =
+            HalfEdge::line_segment([start, end], surface.clone(), core);
+        // The bevel replaces part of both adjacent half-edges. Derive it from
+        // the one before the corner, so it shares its presentation.
+        let bevel = bevel.derive_from(prev, core);
+
+        let end_vertex = Vertex::new().insert(core);
+        let next_trimmed =
+            HalfEdge::new(next.curve().clone(), end_vertex.clone())
+                .insert(core)
+                .derive_from(next, core);
+
+        for (vertex, half_edge, position) in [
+            (
+                bevel.start_vertex(),
+                prev,
+                prev_line.point_to_line_coords(start),
+            ),
+            (bevel.start_vertex(), &bevel, boundary.inner[0]),
+            (&end_vertex, &bevel, boundary.inner[1]),
+            (
+                &end_vertex,
+                &next_trimmed,
+                next_line.point_to_line_coords(end),
+            ),
+        ] {
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                half_edge.curve().clone(),
+                LocalVertexGeom { position },
+            );
+        }
+
+        let half_edges = self.half_edges().iter().flat_map(|half_edge| {
+            if half_edge.id() == prev.id() {
+                vec![prev.clone(), bevel.clone()]
+            } else if half_edge.id() == next.id() {
+                vec![next_trimmed.clone()]
+            } else {
+                vec![half_edge.clone()]
+            }
+        });
+
+        Ok(Cycle::new(half_edges.collect::<Vec<_>>()))
+    }
+}
+
+/// # Error chamfering a corner
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ChamferError {
+    /// # The setback is zero or negative
+    #[error("Chamfer setback must be positive; got {setback}")]
+    NonPositiveSetback {
+        /// # The provided setback
+        setback: Scalar,
+    },
+
+    /// # The vertex is not the start vertex of any half-edge in the cycle
+    #[error("Vertex to chamfer is not a start vertex of a half-edge in cycle")]
+    VertexNotInCycle,
+
+    /// # At least one of the half-edges at the corner is not a line segment
+    #[error("Can only chamfer corners between two line segments")]
+    NotLineSegments,
+
+    /// # The setback exceeds the length of an adjacent half-edge
+    #[error(
+        "Chamfer setback {setback} too large for adjacent half-edges; setback \
+        must be smaller than {max_setback}"
+    )]
+    SetbackTooLarge {
+        /// # The provided setback
+        setback: Scalar,
+
+        /// # The length of the shorter adjacent half-edge
+        ///
+        /// A chamfer with this exact setback would consume that half-edge
+        /// completely, so the setback must be smaller than this.
+        max_setback: Scalar,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        operations::{build::BuildCycle, insert::Insert},
+        topology::{Cycle, Face, Region},
+    };
+
+    use super::{ChamferCorner, ChamferError};
+
+    #[test]
+    fn chamfer_corner_of_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let square = Cycle::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            surface.clone(),
+            &mut core,
+        );
+
+        let vertex = square.half_edges().nth(2).unwrap().start_vertex().clone();
+        let chamfered =
+            square.chamfer_corner(&vertex, 0.5, &surface, &mut core)?;
+
+        assert_eq!(chamfered.half_edges().len(), 5);
+
+        let corners = chamfered
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let position = core
+                    .layers
+                    .geometry
+                    .of_vertex(half_edge.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position;
+                core.layers
+                    .geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(&surface)
+                    .unwrap()
+                    .path
+                    .point_from_path_coords(position)
+            })
+            .collect::<Vec<_>>();
+        let expected = [[0., 0.], [2., 0.], [2., 1.5], [1.5, 2.], [0., 2.]]
+            .map(Point::from);
+        for (corner, expected) in corners.into_iter().zip(expected) {
+            assert!(corner.distance_to(&expected) < Scalar::from(1e-12));
+        }
+
+        let exterior = chamfered.insert(&mut core);
+        let region = Region::new(exterior, []).insert(&mut core);
+        let _ = Face::new(surface, region).insert(&mut core);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn reject_setback_too_large() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let square = Cycle::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            surface.clone(),
+            &mut core,
+        );
+
+        let vertex = square.half_edges().nth(2).unwrap().start_vertex().clone();
+        let result = square.chamfer_corner(&vertex, 2.5, &surface, &mut core);
+
+        let Err(ChamferError::SetbackTooLarge {
+            setback,
+            max_setback,
+        }) = result
+        else {
+            panic!("Expected setback to be rejected");
+        };
+        assert_eq!(setback, Scalar::from(2.5));
+        assert!((max_setback - Scalar::from(2.)).abs() < Scalar::from(1e-12));
+    }
+}
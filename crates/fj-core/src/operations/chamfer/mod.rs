@@ -0,0 +1,7 @@
+//! # Operations to bevel corners
+//!
+//! So far, only corners between two line segments in a cycle are supported.
+
+mod cycle;
+
+pub use self::cycle::{ChamferCorner, ChamferError};
@@ -12,6 +12,21 @@ use crate::{
 pub trait DeriveFrom {
     /// Mark this object as derived from the other object provided
     fn derive_from(self, original: &Self, core: &mut Core) -> Self;
+
+    /// Mark this object as derived from the other object by a rigid motion
+    ///
+    /// In addition to what [`DeriveFrom::derive_from`] does, this records that
+    /// the object is geometrically identical to the original, save for its
+    /// position and orientation. Validation results of the original can then
+    /// be reused for this object (see [`validate_incremental`]).
+    ///
+    /// Only call this, if the object is the result of a rotation and/or
+    /// translation of the original. Any other operation, including a
+    /// reflection, can change the outcome of validation.
+    ///
+    /// [`validate_incremental`]: crate::validation::validate_incremental
+    fn derive_from_rigid_motion(self, original: &Self, core: &mut Core)
+    -> Self;
 }
 
 impl<T> DeriveFrom for Handle<T>
@@ -24,4 +39,15 @@ where
             .derive_object(original.clone().into(), self.clone().into());
         self
     }
+
+    fn derive_from_rigid_motion(
+        self,
+        original: &Self,
+        core: &mut Core,
+    ) -> Self {
+        core.layers
+            .geometry
+            .define_rigid_copy(original.clone().into(), self.clone().into());
+        self.derive_from(original, core)
+    }
 }
@@ -0,0 +1,102 @@
+use crate::{
+    geometry::surfaces::SweptCurve,
+    geometry::{Geometry, LocalCurveGeom, LocalVertexGeom},
+    storage::Handle,
+    topology::{Curve, Surface, Vertex},
+};
+
+/// # Error performing an operation
+///
+/// Operations that rely on geometry being defined for the objects they operate
+/// on return this error, if that is not the case. This can happen, if an
+/// operation is applied to an object from an intermediate state, before its
+/// geometry has been defined.
+///
+/// Operations also return this error, if their result can't be represented
+/// with the available geometry.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum OperationError {
+    /// # A vertex has no geometry defined on a curve
+    #[error("Vertex {vertex:?} has no geometry defined on curve {curve:?}")]
+    MissingVertexGeometry {
+        /// # The vertex that is missing geometry
+        vertex: Handle<Vertex>,
+
+        /// # The curve that the vertex geometry was expected on
+        curve: Handle<Curve>,
+    },
+
+    /// # A curve has no geometry defined on a surface
+    #[error("Curve {curve:?} has no geometry defined on surface {surface:?}")]
+    MissingCurveGeometry {
+        /// # The curve that is missing geometry
+        curve: Handle<Curve>,
+
+        /// # The surface that the curve geometry was expected on
+        surface: Handle<Surface>,
+    },
+
+    /// # A surface has no geometry defined
+    #[error("Surface {surface:?} has no geometry defined")]
+    MissingSurfaceGeometry {
+        /// # The surface that is missing geometry
+        surface: Handle<Surface>,
+    },
+
+    /// # A transform would turn the circle defining a surface into an ellipse
+    ///
+    /// This can happen with a non-uniform scaling, or a shear. Representing
+    /// the result would require promoting the circle to a different type of
+    /// curve, which is not supported.
+    #[error(
+        "Transforming surface {surface:?} would turn its circle into an \
+        ellipse, which is not supported. Is the transform a non-uniform \
+        scaling?"
+    )]
+    CircleWouldBecomeEllipse {
+        /// # The surface that is defined by the circle
+        surface: Handle<Surface>,
+    },
+}
+
+/// # Access the local geometry of a curve on a surface, or return an error
+pub(super) fn local_curve_geom<'r>(
+    curve: &Handle<Curve>,
+    surface: &Handle<Surface>,
+    geometry: &'r Geometry,
+) -> Result<&'r LocalCurveGeom, OperationError> {
+    geometry
+        .of_curve(curve)
+        .and_then(|curve_geom| curve_geom.local_on(surface))
+        .ok_or_else(|| OperationError::MissingCurveGeometry {
+            curve: curve.clone(),
+            surface: surface.clone(),
+        })
+}
+
+/// # Access the local geometry of a vertex on a curve, or return an error
+pub(super) fn local_vertex_geom<'r>(
+    vertex: &Handle<Vertex>,
+    curve: &Handle<Curve>,
+    geometry: &'r Geometry,
+) -> Result<&'r LocalVertexGeom, OperationError> {
+    geometry
+        .of_vertex(vertex)
+        .and_then(|vertex_geom| vertex_geom.local_on(curve))
+        .ok_or_else(|| OperationError::MissingVertexGeometry {
+            vertex: vertex.clone(),
+            curve: curve.clone(),
+        })
+}
+
+/// # Access the geometry of a surface, or return an error
+pub(super) fn surface_geom<'r>(
+    surface: &Handle<Surface>,
+    geometry: &'r Geometry,
+) -> Result<&'r SweptCurve, OperationError> {
+    geometry.of_surface_if_defined(surface).ok_or_else(|| {
+        OperationError::MissingSurfaceGeometry {
+            surface: surface.clone(),
+        }
+    })
+}
@@ -0,0 +1,296 @@
+use fj_math::Scalar;
+
+use crate::{
+    Core,
+    geometry::{LocalVertexGeom, Path},
+    operations::{build::BuildHalfEdge, derive::DeriveFrom, insert::Insert},
+    storage::Handle,
+    topology::{Cycle, HalfEdge, Surface, Vertex},
+};
+
+/// # Round off a corner of a [`Cycle`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait FilletCorner {
+    /// # Replace the corner at the provided vertex with a tangent arc
+    ///
+    /// The corner is defined by the half-edge that starts at the provided
+    /// vertex, and the half-edge before it. Both must be line segments. They
+    /// are trimmed, and a new circular half-edge with the provided radius is
+    /// inserted between them, which is tangent to both.
+    ///
+    /// Returns an error, if the vertex is not the start vertex of a half-edge
+    /// in the cycle, if the adjacent half-edges are not line segments that
+    /// form a corner, or if the radius is too large for them.
+    fn fillet_corner(
+        &self,
+        vertex: &Handle<Vertex>,
+        radius: impl Into<Scalar>,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Result<Cycle, FilletError>;
+}
+
+impl FilletCorner for Cycle {
+    fn fillet_corner(
+        &self,
+        vertex: &Handle<Vertex>,
+        radius: impl Into<Scalar>,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Result<Cycle, FilletError> {
+        let radius = radius.into();
+        if radius <= Scalar::ZERO {
+            return Err(FilletError::NonPositiveRadius { radius });
+        }
+
+        let next = self
+            .half_edges()
+            .iter()
+            .find(|half_edge| half_edge.start_vertex().id() == vertex.id())
+            .ok_or(FilletError::VertexNotInCycle)?;
+        let prev = self
+            .half_edges()
+            .before(next)
+            .expect("Just found `next` in the cycle");
+        let after_next = self
+            .half_edges()
+            .after(next)
+            .expect("Just found `next` in the cycle");
+
+        let [prev_path, next_path] = [prev, next].map(|half_edge| {
+            core.layers
+                .geometry
+                .of_curve(half_edge.curve())
+                .and_then(|curve| curve.local_on(surface))
+                .map(|curve| curve.path)
+        });
+        let (
+            Some(prev_path @ Path::Line(prev_line)),
+            Some(next_path @ Path::Line(next_line)),
+        ) = (prev_path, next_path)
+        else {
+            return Err(FilletError::NotLineSegments);
+        };
+
+        let position_on = |vertex: &Handle<Vertex>,
+                           half_edge: &Handle<HalfEdge>,
+                           path: &Path<2>| {
+            let position = core
+                .layers
+                .geometry
+                .of_vertex(vertex)
+                .unwrap()
+                .local_on(half_edge.curve())
+                .unwrap()
+                .position;
+            path.point_from_path_coords(position)
+        };
+
+        let a = position_on(prev.start_vertex(), prev, &prev_path);
+        let corner = position_on(vertex, next, &next_path);
+        let b = position_on(after_next.start_vertex(), next, &next_path);
+
+        let [to_a, to_b] = [a - corner, b - corner];
+        let [dir_a, dir_b] = [to_a, to_b].map(|v| v.normalize());
+
+        // The angle between the two half-edges at the corner. If it is zero or
+        // a half turn, there is no corner to round off.
+        let angle = dir_a.dot(&dir_b).clamp(-Scalar::ONE, Scalar::ONE).acos();
+        if angle < Scalar::from(1e-9) || Scalar::PI - angle < Scalar::from(1e-9)
+        {
+            return Err(FilletError::NoCorner);
+        }
+
+        // The arc touches each half-edge at this distance from the corner.
+        let (sin, cos) = (angle / 2.).sin_cos();
+        let tan_half_angle = sin / cos;
+        let tangent_distance = radius / tan_half_angle;
+
+        let available = to_a.magnitude().min(to_b.magnitude());
+        if tangent_distance >= available {
+            return Err(FilletError::RadiusTooLarge {
+                radius,
+                max_radius: available * tan_half_angle,
+            });
+        }
+
+        let start = corner + dir_a * tangent_distance;
+        let end = corner + dir_b * tangent_distance;
+
+        // The arc turns in the same direction as the half-edges do at the
+        // corner, by the amount that they change direction.
+        let turn = (corner - a).cross2d(&(b - corner));
+        let arc_angle = if turn > Scalar::ZERO {
+            Scalar::PI - angle
+        } else {
+            -(Scalar::PI - angle)
+        };
+
+        let (arc, boundary) =
+            HalfEdge::arc(start, end, arc_angle, surface.clone(), core);
+        // The arc replaces part of both adjacent half-edges. Derive it from the
+        // one before the corner, so it shares its presentation.
+        let arc = arc.derive_from(prev, core);
+
+        let end_vertex = Vertex::new().insert(core);
+        let next_trimmed =
+            HalfEdge::new(next.curve().clone(), end_vertex.clone())
+                .insert(core)
+                .derive_from(next, core);
+
+        for (vertex, half_edge, position) in [
+            (
+                arc.start_vertex(),
+                prev,
+                prev_line.point_to_line_coords(start),
+            ),
+            (arc.start_vertex(), &arc, boundary.inner[0]),
+            (&end_vertex, &arc, boundary.inner[1]),
+            (
+                &end_vertex,
+                &next_trimmed,
+                next_line.point_to_line_coords(end),
+            ),
+        ] {
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                half_edge.curve().clone(),
+                LocalVertexGeom { position },
+            );
+        }
+
+        let half_edges = self.half_edges().iter().flat_map(|half_edge| {
+            if half_edge.id() == prev.id() {
+                vec![prev.clone(), arc.clone()]
+            } else if half_edge.id() == next.id() {
+                vec![next_trimmed.clone()]
+            } else {
+                vec![half_edge.clone()]
+            }
+        });
+
+        Ok(Cycle::new(half_edges.collect::<Vec<_>>()))
+    }
+}
+
+/// # Error filleting a corner
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum FilletError {
+    /// # The radius is zero or negative
+    #[error("Fillet radius must be positive; got {radius}")]
+    NonPositiveRadius {
+        /// # The provided radius
+        radius: Scalar,
+    },
+
+    /// # The vertex is not the start vertex of any half-edge in the cycle
+    #[error("Vertex to fillet is not a start vertex of a half-edge in cycle")]
+    VertexNotInCycle,
+
+    /// # At least one of the half-edges at the corner is not a line segment
+    #[error("Can only fillet corners between two line segments")]
+    NotLineSegments,
+
+    /// # The half-edges at the vertex don't form a corner
+    #[error("Half-edges at vertex are collinear; there is no corner to fillet")]
+    NoCorner,
+
+    /// # The radius is too large for the adjacent half-edges
+    #[error(
+        "Fillet radius {radius} too large for adjacent half-edges; maximum \
+        radius for this corner is {max_radius}"
+    )]
+    RadiusTooLarge {
+        /// # The provided radius
+        radius: Scalar,
+
+        /// # The largest radius that would still fit
+        ///
+        /// A fillet with this exact radius would consume one of the adjacent
+        /// half-edges completely, so the radius must be smaller than this.
+        max_radius: Scalar,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        geometry::Path,
+        operations::{build::BuildCycle, insert::Insert},
+        topology::{Cycle, Face, Region},
+    };
+
+    use super::{FilletCorner, FilletError};
+
+    #[test]
+    fn fillet_corner_of_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let square = Cycle::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            surface.clone(),
+            &mut core,
+        );
+
+        let vertex = square.half_edges().nth(2).unwrap().start_vertex().clone();
+        let filleted =
+            square.fillet_corner(&vertex, 0.5, &surface, &mut core)?;
+
+        assert_eq!(filleted.half_edges().len(), 5);
+
+        // The arc is tangent to both adjacent sides of the square.
+        let arc = filleted.half_edges().nth(2).unwrap();
+        let Path::Circle(circle) = core
+            .layers
+            .geometry
+            .of_curve(arc.curve())
+            .unwrap()
+            .local_on(&surface)
+            .unwrap()
+            .path
+        else {
+            panic!("Expected fillet to be an arc");
+        };
+        let epsilon = Scalar::from(1e-12);
+        assert!(
+            circle.center().distance_to(&Point::from([1.5, 1.5])) < epsilon
+        );
+        assert!((circle.radius() - Scalar::from(0.5)).abs() < epsilon);
+
+        let exterior = filleted.insert(&mut core);
+        let region = Region::new(exterior, []).insert(&mut core);
+        let _ = Face::new(surface, region).insert(&mut core);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn reject_radius_too_large() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let square = Cycle::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            surface.clone(),
+            &mut core,
+        );
+
+        let vertex = square.half_edges().nth(2).unwrap().start_vertex().clone();
+        let result = square.fillet_corner(&vertex, 3., &surface, &mut core);
+
+        let Err(FilletError::RadiusTooLarge { radius, max_radius }) = result
+        else {
+            panic!("Expected radius to be rejected");
+        };
+        assert_eq!(radius, Scalar::from(3.));
+        assert!((max_radius - Scalar::from(2.)).abs() < Scalar::from(1e-12));
+    }
+}
@@ -55,6 +55,7 @@ impl AddHole for Shell {
                 location.face.surface().clone(),
                 None,
                 path,
+                Scalar::ZERO,
                 &mut SweepCache::default(),
                 core,
             )
@@ -116,6 +117,7 @@ impl AddHole for Shell {
                 entry_location.face.surface().clone(),
                 None,
                 path,
+                Scalar::ZERO,
                 &mut SweepCache::default(),
                 core,
             );
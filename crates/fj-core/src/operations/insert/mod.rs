@@ -2,8 +2,10 @@
 
 mod insert_trait;
 mod is_inserted;
+mod vertex_index;
 
 pub use self::{
     insert_trait::Insert,
     is_inserted::{IsInserted, IsInsertedNo, IsInsertedYes},
+    vertex_index::VertexIndex,
 };
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use fj_interop::Tolerance;
+use fj_math::Point;
+
+use crate::{Core, storage::Handle, topology::Vertex};
+
+use super::Insert;
+
+/// # Insert vertices, reusing existing ones at the same position
+///
+/// [`Insert`] always creates a new object. Vertices don't store a position
+/// themselves, so there's no way for the stores to tell whether a vertex at a
+/// given position already exists. This index keeps track of the positions of
+/// the vertices inserted through it, and returns the existing vertex, if one
+/// was already inserted at (approximately) the same position.
+///
+/// ## Tolerance
+///
+/// A position is considered to be the same as a previously inserted one, if the
+/// distance between them is no greater than the tolerance. If multiple
+/// previously inserted positions are within the tolerance, any of them might be
+/// returned.
+///
+/// The comparison is not transitive: Two positions that are within the
+/// tolerance of a third position, are not necessarily within the tolerance of
+/// each other. Since only the position of the first vertex at a location is
+/// tracked, vertices don't drift away from their original position, as more
+/// positions are added nearby.
+///
+/// ## Implementation Note
+///
+/// Positions are sorted into a grid of cells with the size of the tolerance,
+/// which serve as the keys of the index. A lookup only needs to compare the
+/// position with the ones in the neighboring cells.
+pub struct VertexIndex {
+    tolerance: Tolerance,
+    cells: BTreeMap<[i64; 3], Vec<(Point<3>, Handle<Vertex>)>>,
+}
+
+impl VertexIndex {
+    /// # Create an empty index
+    pub fn new(tolerance: impl Into<Tolerance>) -> Self {
+        Self {
+            tolerance: tolerance.into(),
+            cells: BTreeMap::new(),
+        }
+    }
+
+    /// # Get the vertex at the provided position, or insert a new one
+    pub fn get_or_insert(
+        &mut self,
+        position: impl Into<Point<3>>,
+        core: &mut Core,
+    ) -> Handle<Vertex> {
+        let position = position.into();
+        let cell = self.cell(position);
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let neighbor = [cell[0] + x, cell[1] + y, cell[2] + z];
+                    let Some(vertices) = self.cells.get(&neighbor) else {
+                        continue;
+                    };
+
+                    for (existing, vertex) in vertices {
+                        if existing.distance_to(&position)
+                            <= self.tolerance.inner()
+                        {
+                            return vertex.clone();
+                        }
+                    }
+                }
+            }
+        }
+
+        let vertex = Vertex::new().insert(core);
+        self.cells
+            .entry(cell)
+            .or_default()
+            .push((position, vertex.clone()));
+
+        vertex
+    }
+
+    fn cell(&self, position: Point<3>) -> [i64; 3] {
+        position.coords.components.map(|coord| {
+            (coord / self.tolerance.inner()).floor().into_f64() as i64
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Core;
+
+    use super::VertexIndex;
+
+    #[test]
+    fn get_or_insert() {
+        let mut core = Core::new();
+        let mut index = VertexIndex::new(0.001);
+
+        let a = index.get_or_insert([1., 2., 3.], &mut core);
+        let b = index.get_or_insert([1., 2., 3.], &mut core);
+        assert_eq!(a.id(), b.id());
+
+        // Not the same position, but within the tolerance.
+        let c = index.get_or_insert([1., 2., 3.0009], &mut core);
+        assert_eq!(a.id(), c.id());
+
+        let d = index.get_or_insert([1., 2., 3.002], &mut core);
+        assert_ne!(a.id(), d.id());
+    }
+}
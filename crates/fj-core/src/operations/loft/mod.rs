@@ -0,0 +1,9 @@
+//! # Connect two profiles, to create a 3D object
+//!
+//! A loft connects the exterior of a region in one sketch with the exterior of
+//! a region in another, with side faces that are ruled between them. The two
+//! sketches must be located on parallel planes.
+
+mod sketch;
+
+pub use self::sketch::{Loft, LoftError};
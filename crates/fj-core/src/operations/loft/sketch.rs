@@ -0,0 +1,426 @@
+use fj_interop::Tolerance;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    Core,
+    algorithms::triangulate::triangulate_polygon,
+    geometry::{
+        CurveBoundary, Geometry,
+        intersection::{
+            IntersectionError, SurfaceIntersection, intersect_surfaces,
+        },
+        surfaces::SweptCurve,
+        traits::GenPolyline,
+    },
+    operations::build::BuildShell,
+    storage::Handle,
+    topology::{Cycle, Shell, Sketch, Surface},
+};
+
+/// # Loft a [`Sketch`] to another
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait Loft {
+    /// # Connect this sketch with another one, creating a closed shell
+    ///
+    /// This sketch is placed on `surface`, the other on `other_surface`. Both
+    /// sketches must consist of a single region without holes, and both
+    /// surfaces must be parallel planes.
+    ///
+    /// The exteriors of both regions are approximated as polylines, using the
+    /// provided tolerance. If the polylines have different numbers of points,
+    /// the one with fewer points is resampled, by splitting its longest
+    /// segments, until both match. Corresponding points are then connected by
+    /// triangular side faces, and both ends are closed by triangulated caps.
+    ///
+    /// The start of the other polyline is chosen to be the point closest to the
+    /// start of this one, which prevents the side faces from twisting, as long
+    /// as both profiles are similar.
+    fn loft(
+        &self,
+        surface: &Handle<Surface>,
+        other: &Sketch,
+        other_surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Shell, LoftError>;
+}
+
+impl Loft for Sketch {
+    fn loft(
+        &self,
+        surface: &Handle<Surface>,
+        other: &Sketch,
+        other_surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Shell, LoftError> {
+        let tolerance = tolerance.into();
+        let geometry = &core.layers.geometry;
+
+        let [surface_geom, other_surface_geom] = [surface, other_surface]
+            .map(|surface| *geometry.of_surface(surface));
+        match intersect_surfaces(&surface_geom, &other_surface_geom, tolerance)
+        {
+            Ok(Some(SurfaceIntersection::Line(_))) => {
+                return Err(LoftError::NotParallel);
+            }
+            Ok(Some(SurfaceIntersection::Coincident)) => {
+                return Err(LoftError::Coincident);
+            }
+            Ok(None) => {}
+            Err(IntersectionError::UnsupportedSurfaces) => {
+                return Err(LoftError::NotPlanar);
+            }
+            Err(err) => {
+                unreachable!("Intersecting surfaces can't return `{err}`")
+            }
+        }
+
+        let mut a = Profile::new(self, &surface_geom, tolerance, geometry)?;
+        let mut b =
+            Profile::new(other, &other_surface_geom, tolerance, geometry)?;
+
+        let num_points = a.points.len().max(b.points.len());
+        a.resample(num_points);
+        b.resample(num_points);
+
+        // Make sure both profiles wind around the same axis in the same
+        // direction, and start at corresponding points.
+        if a.normal().dot(&b.normal()) < Scalar::ZERO {
+            b.reverse();
+        }
+        b.align_start_with(&a);
+
+        let n = num_points;
+        let mut triangles = Vec::new();
+
+        for i in 0..n {
+            let j = (i + 1) % n;
+            triangles.push([i, j, n + j]);
+            triangles.push([i, n + j, n + i]);
+        }
+
+        // The side faces traverse the edges of `a` in order, and those of `b`
+        // in reverse. The caps need to do the opposite, for the faces to be
+        // consistently oriented.
+        let [cap_a, cap_b] = [&a, &b].map(|profile| {
+            triangulate_polygon(&profile.points_surface)
+                .ok_or(LoftError::DegenerateProfile)
+        });
+        triangles.extend(cap_a?.into_iter().map(|[i, j, k]| [i, k, j]));
+        triangles
+            .extend(cap_b?.into_iter().map(|triangle| triangle.map(|i| n + i)));
+
+        let vertices = a.points.into_iter().chain(b.points).collect::<Vec<_>>();
+
+        // The faces are consistently oriented, but they might all be facing
+        // inwards. In that case, the signed volume is negative.
+        let volume = triangles
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = triangle.map(|i| vertices[i].coords);
+                a.dot(&b.cross(&c))
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+        if volume < Scalar::ZERO {
+            for triangle in &mut triangles {
+                triangle.swap(1, 2);
+            }
+        }
+
+        Ok(Shell::from_vertices_and_indices(vertices, triangles, core))
+    }
+}
+
+/// # Error lofting between two sketches
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum LoftError {
+    /// # A sketch doesn't consist of exactly one region
+    #[error("Can only loft sketches with a single region; found {regions}")]
+    NotSingleRegion {
+        /// # The number of regions in the sketch
+        regions: usize,
+    },
+
+    /// # The region of a sketch has holes
+    #[error("Lofting regions with holes is not supported")]
+    RegionHasHoles,
+
+    /// # A surface is not a plane
+    #[error("Can only loft between sketches on planes")]
+    NotPlanar,
+
+    /// # The surfaces are not parallel
+    #[error("Can only loft between sketches on parallel planes")]
+    NotParallel,
+
+    /// # The surfaces are coincident
+    #[error("Can't loft between sketches on the same plane")]
+    Coincident,
+
+    /// # The region of a sketch doesn't enclose any area
+    #[error("Can't loft sketches whose region doesn't enclose any area")]
+    DegenerateProfile,
+}
+
+/// # The exterior of a sketch's region, approximated as a polyline
+struct Profile {
+    points_surface: Vec<Point<2>>,
+    points: Vec<Point<3>>,
+}
+
+impl Profile {
+    fn new(
+        sketch: &Sketch,
+        surface: &SweptCurve,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> Result<Self, LoftError> {
+        let regions = sketch.regions().len();
+        if regions != 1 {
+            return Err(LoftError::NotSingleRegion { regions });
+        }
+
+        let region = sketch.regions().first();
+        if !region.interiors().is_empty() {
+            return Err(LoftError::RegionHasHoles);
+        }
+
+        let points_surface = approx_cycle(
+            region.exterior(),
+            sketch.surface(),
+            tolerance,
+            geometry,
+        );
+        let points = points_surface
+            .iter()
+            .map(|point| {
+                surface.u.point_from_path_coords([point.u])
+                    + surface.v * point.v
+            })
+            .collect();
+
+        Ok(Self {
+            points_surface,
+            points,
+        })
+    }
+
+    /// # Split the longest segments, until the number of points matches
+    fn resample(&mut self, num_points: usize) {
+        while self.points.len() < num_points {
+            let n = self.points.len();
+            let i = (0..n)
+                .max_by_key(|&i| {
+                    self.points[i].distance_to(&self.points[(i + 1) % n])
+                })
+                .expect("Profile has points");
+            let j = (i + 1) % n;
+
+            let surface = self.points_surface[i]
+                + (self.points_surface[j] - self.points_surface[i]) / 2.;
+            let global =
+                self.points[i] + (self.points[j] - self.points[i]) / 2.;
+
+            self.points_surface.insert(i + 1, surface);
+            self.points.insert(i + 1, global);
+        }
+    }
+
+    /// # Compute the normal of the polyline, scaled by its area
+    fn normal(&self) -> Vector<3> {
+        let n = self.points.len();
+        (0..n)
+            .map(|i| {
+                self.points[i]
+                    .coords
+                    .cross(&self.points[(i + 1) % n].coords)
+            })
+            .fold(Vector::from([0., 0., 0.]), |sum, v| sum + v)
+    }
+
+    fn reverse(&mut self) {
+        self.points_surface.reverse();
+        self.points.reverse();
+    }
+
+    /// # Rotate the polyline, so its points best match those of `other`
+    fn align_start_with(&mut self, other: &Self) {
+        let n = self.points.len();
+        let offset = (0..n)
+            .min_by_key(|&offset| {
+                (0..n)
+                    .map(|i| {
+                        let distance = other.points[i]
+                            .distance_to(&self.points[(i + offset) % n]);
+                        distance * distance
+                    })
+                    .fold(Scalar::ZERO, |sum, d| sum + d)
+            })
+            .expect("Profile has points");
+
+        self.points_surface.rotate_left(offset);
+        self.points.rotate_left(offset);
+    }
+}
+
+/// # Approximate a cycle, without repeating the first point at the end
+fn approx_cycle(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> Vec<Point<2>> {
+    cycle
+        .half_edges()
+        .pairs()
+        .flat_map(|(half_edge, next_half_edge)| {
+            let path = geometry
+                .of_curve(half_edge.curve())
+                .unwrap()
+                .local_on(surface)
+                .unwrap()
+                .path;
+            let boundary = CurveBoundary {
+                inner: [half_edge, next_half_edge].map(|start| {
+                    geometry
+                        .of_vertex(start.start_vertex())
+                        .unwrap()
+                        .local_on(half_edge.curve())
+                        .unwrap()
+                        .position
+                }),
+            };
+
+            // Depending on the type of curve, the polyline may or may not
+            // include the boundary points. Make sure each point is only added
+            // once.
+            let [start, end] = boundary.inner;
+            let interior = path
+                .generate_polyline(boundary, tolerance)
+                .into_iter()
+                .filter(move |point| *point != start && *point != end);
+
+            [start]
+                .into_iter()
+                .chain(interior)
+                .map(move |point| path.point_from_path_coords(point))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        geometry::Path,
+        operations::{
+            build::{BuildSketch, BuildSurface},
+            insert::Insert,
+        },
+        topology::{Sketch, Surface},
+    };
+
+    use super::{Loft, LoftError};
+
+    #[test]
+    fn loft_offset_squares_into_prism() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        let bottom = Sketch::polygon(square, &mut core);
+        let top = Sketch::polygon(square, &mut core);
+
+        let bottom_surface = core.layers.topology.surfaces.xy_plane();
+        let top_surface = Surface::from_uv(
+            Path::line_from_points([[0., 0., 1.], [1., 0., 1.]]).0,
+            [0., 1., 0.],
+            &mut core,
+        );
+
+        let shell = bottom
+            .loft(&bottom_surface, &top, &top_surface, 0.01, &mut core)?
+            .insert(&mut core);
+
+        // Each side is split into two triangles, as is each cap.
+        assert_eq!(shell.faces().len(), 4 * 2 + 2 * 2);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn loft_profiles_with_different_number_of_points() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let bottom = Sketch::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            &mut core,
+        );
+        let top = Sketch::polygon([[0., 0.], [2., 0.], [1., 2.]], &mut core);
+
+        let bottom_surface = core.layers.topology.surfaces.xy_plane();
+        let top_surface = Surface::from_uv(
+            Path::line_from_points([[0., 0., 3.], [1., 0., 3.]]).0,
+            [0., 1., 0.],
+            &mut core,
+        );
+
+        let shell = bottom
+            .loft(&bottom_surface, &top, &top_surface, 0.01, &mut core)?
+            .insert(&mut core);
+
+        // The triangle is resampled to 4 points. Both caps are then
+        // triangulated into 2 triangles.
+        assert_eq!(shell.faces().len(), 4 * 2 + 2 * 2);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn reject_non_parallel_surfaces() {
+        let mut core = Core::new();
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        let a = Sketch::polygon(square, &mut core);
+        let b = Sketch::polygon(square, &mut core);
+
+        let xy_plane = core.layers.topology.surfaces.xy_plane();
+        let xz_plane = core.layers.topology.surfaces.xz_plane();
+
+        let result = a.loft(&xy_plane, &b, &xz_plane, 0.01, &mut core);
+        let Err(err) = result else {
+            panic!("Expected non-parallel surfaces to be rejected");
+        };
+        assert_eq!(err, LoftError::NotParallel);
+    }
+
+    #[test]
+    fn reject_degenerate_profile() {
+        let mut core = Core::new();
+
+        let square = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        let line = Sketch::polygon([[0., 0.], [1., 0.], [2., 0.]], &mut core);
+
+        let bottom_surface = core.layers.topology.surfaces.xy_plane();
+        let top_surface = Surface::from_uv(
+            Path::line_from_points([[0., 0., 1.], [1., 0., 1.]]).0,
+            [0., 1., 0.],
+            &mut core,
+        );
+
+        let result =
+            square.loft(&bottom_surface, &line, &top_surface, 0.01, &mut core);
+        let Err(err) = result else {
+            panic!("Expected degenerate profile to be rejected");
+        };
+        assert_eq!(err, LoftError::DegenerateProfile);
+    }
+}
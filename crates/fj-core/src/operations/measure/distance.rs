@@ -0,0 +1,225 @@
+use fj_interop::Tolerance;
+use fj_math::{Aabb, Point, Scalar, Triangle, Vector};
+
+use crate::{
+    algorithms::triangulate::triangulate, geometry::Geometry, storage::Handle,
+    topology::Face,
+};
+
+/// # Compute the minimum distance between two faces
+///
+/// Both faces are triangulated at the provided tolerance, and the distance is
+/// computed between the resulting sets of triangles. The result is therefore
+/// exact for planar faces, and an approximation otherwise.
+///
+/// Returns zero, if the faces intersect or touch.
+pub fn distance(
+    a: &Handle<Face>,
+    b: &Handle<Face>,
+    geometry: &Geometry,
+    tolerance: impl Into<Tolerance>,
+) -> Scalar {
+    let tolerance = tolerance.into();
+
+    let [a, b] = [a, b].map(|face| {
+        triangulate(face, geometry, tolerance)
+            .triangles()
+            .map(|points| {
+                let aabb = Aabb::<3>::from_points(points);
+                (Triangle::from_points(points), aabb)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut min_distance: Option<Scalar> = None;
+
+    for (triangle_a, aabb_a) in &a {
+        for (triangle_b, aabb_b) in &b {
+            // The distance between the bounding boxes is a lower bound for the
+            // distance between the triangles. If it's not smaller than what we
+            // already have, the triangles can't be any closer.
+            if let Some(min_distance) = min_distance
+                && aabb_distance(aabb_a, aabb_b) >= min_distance
+            {
+                continue;
+            }
+
+            let distance = triangle_distance(triangle_a, triangle_b);
+            if distance == Scalar::ZERO {
+                return distance;
+            }
+
+            min_distance =
+                Some(min_distance.map_or(distance, |min| min.min(distance)));
+        }
+    }
+
+    // Faces without any triangles have no points that could be close to
+    // anything.
+    min_distance.unwrap_or(Scalar::ZERO)
+}
+
+fn aabb_distance(a: &Aabb<3>, b: &Aabb<3>) -> Scalar {
+    let gaps = [0, 1, 2].map(|i| {
+        (a.min.coords.components[i] - b.max.coords.components[i])
+            .max(b.min.coords.components[i] - a.max.coords.components[i])
+            .max(Scalar::ZERO)
+    });
+
+    Vector::from(gaps).magnitude()
+}
+
+fn triangle_distance(a: &Triangle<3>, b: &Triangle<3>) -> Scalar {
+    let edges = |triangle: &Triangle<3>| {
+        let [p, q, r] = triangle.points;
+        [[p, q], [q, r], [r, p]]
+    };
+
+    let mut distances = Vec::new();
+
+    // If the triangles don't intersect, the closest points are either a vertex
+    // of one triangle and a point on the other, or points on two edges.
+    for (triangle, other) in [(a, b), (b, a)] {
+        for point in triangle.points {
+            distances.push(point.distance_to(&other.closest_point(point)));
+        }
+    }
+    for edge_a in edges(a) {
+        for edge_b in edges(b) {
+            distances.push(segment_distance(edge_a, edge_b));
+        }
+    }
+
+    // If they do intersect, an edge of one triangle pierces the other.
+    for (triangle, other) in [(a, b), (b, a)] {
+        for edge in edges(triangle) {
+            if let Some(point) = segment_plane_intersection(edge, other) {
+                distances.push(point.distance_to(&other.closest_point(point)));
+            }
+        }
+    }
+
+    distances
+        .into_iter()
+        .min()
+        .expect("Collected distances for all vertices and edges")
+}
+
+/// # Compute the intersection of a segment with the plane of a triangle
+///
+/// Returns `None`, if the segment doesn't cross the plane.
+fn segment_plane_intersection(
+    [p, q]: [Point<3>; 2],
+    triangle: &Triangle<3>,
+) -> Option<Point<3>> {
+    let [a, b, c] = triangle.points;
+    let normal = (b - a).cross(&(c - a));
+
+    let [dp, dq] = [p, q].map(|point| normal.dot(&(point - a)));
+    if dp * dq >= Scalar::ZERO {
+        return None;
+    }
+
+    Some(p + (q - p) * (dp / (dp - dq)))
+}
+
+/// # Compute the distance between two line segments
+fn segment_distance(
+    [p1, q1]: [Point<3>; 2],
+    [p2, q2]: [Point<3>; 2],
+) -> Scalar {
+    // From Real-Time Collision Detection by Christer Ericson, pages 149-151.
+
+    let d1 = q1 - p1;
+    let d2 = q2 - p2;
+    let r = p1 - p2;
+
+    let a = d1.dot(&d1);
+    let e = d2.dot(&d2);
+    let f = d2.dot(&r);
+
+    let (s, t) = if a == Scalar::ZERO && e == Scalar::ZERO {
+        (Scalar::ZERO, Scalar::ZERO)
+    } else if a == Scalar::ZERO {
+        (Scalar::ZERO, (f / e).clamp(Scalar::ZERO, Scalar::ONE))
+    } else {
+        let c = d1.dot(&r);
+
+        if e == Scalar::ZERO {
+            ((-c / a).clamp(Scalar::ZERO, Scalar::ONE), Scalar::ZERO)
+        } else {
+            let b = d1.dot(&d2);
+            let denom = a * e - b * b;
+
+            let s = if denom != Scalar::ZERO {
+                ((b * f - c * e) / denom).clamp(Scalar::ZERO, Scalar::ONE)
+            } else {
+                Scalar::ZERO
+            };
+            let t = (b * s + f) / e;
+
+            if t < Scalar::ZERO {
+                ((-c / a).clamp(Scalar::ZERO, Scalar::ONE), Scalar::ZERO)
+            } else if t > Scalar::ONE {
+                (((b - c) / a).clamp(Scalar::ZERO, Scalar::ONE), Scalar::ONE)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    let c1 = p1 + d1 * s;
+    let c2 = p2 + d2 * t;
+
+    c1.distance_to(&c2)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildFace, BuildSurface},
+            insert::Insert,
+        },
+        storage::Handle,
+        topology::{Face, Surface},
+    };
+
+    use super::distance;
+
+    #[test]
+    fn parallel_squares() {
+        let mut core = Core::new();
+
+        let a = square([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]], &mut core);
+        let b = square([[0., 0., 1.], [1., 0., 1.], [0., 1., 1.]], &mut core);
+
+        let distance =
+            distance(&a, &b, &core.layers.geometry, core.tolerance());
+        assert_eq!(distance, Scalar::ONE);
+    }
+
+    #[test]
+    fn intersecting_squares() {
+        let mut core = Core::new();
+
+        let a = square([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]], &mut core);
+        let b = square(
+            [[0., 0.5, -0.5], [1., 0.5, -0.5], [0., 0.5, 0.5]],
+            &mut core,
+        );
+
+        let distance =
+            distance(&a, &b, &core.layers.geometry, core.tolerance());
+        assert_eq!(distance, Scalar::ZERO);
+    }
+
+    fn square(plane: [[f64; 3]; 3], core: &mut Core) -> Handle<Face> {
+        let (surface, _) = Surface::plane_from_points(plane, core);
+        Face::polygon(surface, [[0., 0.], [1., 0.], [1., 1.], [0., 1.]], core)
+            .insert(core)
+    }
+}
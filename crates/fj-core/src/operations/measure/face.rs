@@ -0,0 +1,300 @@
+use fj_interop::Tolerance;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::approx::{ApproxCache, cycle::approx_cycle},
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Cycle, Face, Surface},
+};
+
+/// Measure a [`Face`]
+pub trait MeasureFace {
+    /// # Compute the area of the face
+    ///
+    /// The area is computed from the polygons that approximate the face's
+    /// cycles at the provided tolerance. The areas of the interior cycles are
+    /// subtracted from the area of the exterior cycle.
+    ///
+    /// ## Implementation Note
+    ///
+    /// The result is only meaningful for planar faces.
+    fn area(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Scalar;
+
+    /// # Compute the centroid of the face
+    ///
+    /// The centroid is computed from the same polygons as the area. The
+    /// centroid of each polygon is weighted by its area, and the weighted
+    /// centroids of the interior cycles are subtracted from that of the
+    /// exterior cycle.
+    ///
+    /// Returns `None`, if the area of the face is zero.
+    ///
+    /// ## Implementation Note
+    ///
+    /// The result is only meaningful for planar faces.
+    fn centroid(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Point<3>>;
+}
+
+impl MeasureFace for Face {
+    fn area(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Scalar {
+        let tolerance = tolerance.into();
+        let mut cache = ApproxCache::default();
+
+        let mut area = |cycle: &Cycle| {
+            cycle_area(cycle, self.surface(), tolerance, &mut cache, geometry)
+        };
+
+        let exterior = area(self.region().exterior());
+        let interiors = self
+            .region()
+            .interiors()
+            .iter()
+            .map(|interior| area(interior))
+            .fold(Scalar::ZERO, |sum, area| sum + area);
+
+        exterior - interiors
+    }
+
+    fn centroid(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Point<3>> {
+        let tolerance = tolerance.into();
+        let mut cache = ApproxCache::default();
+
+        let mut moments = |cycle: &Cycle| {
+            cycle_moments(
+                cycle,
+                self.surface(),
+                tolerance,
+                &mut cache,
+                geometry,
+            )
+        };
+
+        let (mut area, mut moment) = moments(self.region().exterior());
+        for interior in self.region().interiors() {
+            let (interior_area, interior_moment) = moments(interior);
+            area -= interior_area;
+            moment = moment - interior_moment;
+        }
+
+        if area <= Scalar::ZERO {
+            return None;
+        }
+
+        Some(Point {
+            coords: moment / area,
+        })
+    }
+}
+
+/// # Compute the area enclosed by a cycle
+///
+/// Sums up the vector areas of the triangles that are spanned by the global
+/// origin and each segment of the approximated cycle. This works for any
+/// polygon that lies within a plane, regardless of whether it is convex, or how
+/// the plane is parametrized.
+fn cycle_area(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    cache: &mut ApproxCache,
+    geometry: &Geometry,
+) -> Scalar {
+    let approx = approx_cycle(cycle, surface, tolerance, cache, geometry);
+
+    let vector_area = approx.points().windows(2).fold(
+        Vector::from([0., 0., 0.]),
+        |vector_area, segment| {
+            let [a, b] =
+                [segment[0], segment[1]].map(|point| point.global_form.coords);
+            vector_area + a.cross(&b)
+        },
+    );
+
+    vector_area.magnitude() / 2.
+}
+
+/// # Compute the area and first moment of area of a cycle
+///
+/// Splits the approximated cycle into a fan of triangles, which all share its
+/// first point. Triangles that are wound against the cycle have negative area,
+/// which makes this work for non-convex polygons. Dividing the moment by the
+/// area results in the centroid.
+fn cycle_moments(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    cache: &mut ApproxCache,
+    geometry: &Geometry,
+) -> (Scalar, Vector<3>) {
+    let approx = approx_cycle(cycle, surface, tolerance, cache, geometry);
+    let points = approx
+        .points()
+        .into_iter()
+        .map(|point| point.global_form)
+        .collect::<Vec<_>>();
+
+    let Some(&origin) = points.first() else {
+        return (Scalar::ZERO, Vector::from([0., 0., 0.]));
+    };
+
+    let triangles = points
+        .windows(2)
+        .map(|segment| {
+            let [a, b] = [segment[0], segment[1]];
+            let vector_area = (a - origin).cross(&(b - origin)) / 2.;
+            let centroid = (origin.coords + a.coords + b.coords) / 3.;
+            (vector_area, centroid)
+        })
+        .collect::<Vec<_>>();
+
+    let vector_area = triangles
+        .iter()
+        .fold(Vector::from([0., 0., 0.]), |sum, (vector_area, _)| {
+            sum + *vector_area
+        });
+    let area = vector_area.magnitude();
+    if area == Scalar::ZERO {
+        return (Scalar::ZERO, Vector::from([0., 0., 0.]));
+    }
+    let normal = vector_area / area;
+
+    let moment = triangles.into_iter().fold(
+        Vector::from([0., 0., 0.]),
+        |moment, (vector_area, centroid)| {
+            moment + centroid * vector_area.dot(&normal)
+        },
+    );
+
+    (area, moment)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildCycle, BuildFace},
+            measure::MeasureFace,
+            update::{UpdateFace, UpdateRegion},
+        },
+        topology::{Cycle, Face},
+    };
+
+    #[test]
+    fn area_of_unit_square() {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+
+        assert_eq!(face.area(0.001, &core.layers.geometry), Scalar::ONE);
+    }
+
+    #[test]
+    fn area_of_non_convex_face() {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xz_plane(),
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            &mut core,
+        );
+
+        assert_eq!(face.area(0.001, &core.layers.geometry), Scalar::from(3.));
+    }
+
+    #[test]
+    fn area_of_face_with_holes() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [4., 0.], [4., 4.], [0., 4.]],
+            &mut core,
+        )
+        .update_region(
+            |region, core| {
+                region.add_interiors(
+                    [
+                        Cycle::polygon(
+                            [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+                            surface.clone(),
+                            core,
+                        ),
+                        Cycle::polygon(
+                            [[2., 3.], [3., 3.], [3., 2.]],
+                            surface.clone(),
+                            core,
+                        ),
+                    ],
+                    core,
+                )
+            },
+            &mut core,
+        );
+
+        assert_eq!(
+            face.area(0.001, &core.layers.geometry),
+            Scalar::from(16. - 1. - 0.5),
+        );
+    }
+
+    #[test]
+    fn centroid_of_face_with_hole() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let face = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [4., 0.], [4., 4.], [0., 4.]],
+            &mut core,
+        )
+        .update_region(
+            |region, core| {
+                region.add_interiors(
+                    [Cycle::polygon(
+                        [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+                        surface.clone(),
+                        core,
+                    )],
+                    core,
+                )
+            },
+            &mut core,
+        );
+
+        let centroid = face
+            .centroid(0.001, &core.layers.geometry)
+            .expect("Face has an area");
+
+        // The hole moves the centroid away from the center of the square.
+        let coord = (16. * 2. - 1.5) / 15.;
+        assert!(
+            centroid.distance_to(&Point::from([coord, 0., coord]))
+                < Scalar::from(1e-12)
+        );
+    }
+}
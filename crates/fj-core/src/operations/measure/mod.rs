@@ -0,0 +1,15 @@
+//! # Measure properties of shapes
+//!
+//! See [`MeasureFace`], [`MeasureShell`], [`MeasureSolid`], and [`distance`].
+
+mod distance;
+mod face;
+mod shell;
+mod solid;
+
+pub use self::{
+    distance::distance,
+    face::MeasureFace,
+    shell::{MeasureShell, VolumeError},
+    solid::MeasureSolid,
+};
@@ -0,0 +1,254 @@
+use fj_interop::Tolerance;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{approx::ApproxCache, triangulate::triangulate_with_cache},
+    geometry::Geometry,
+    topology::Shell,
+    validation::{
+        ValidationCheck, ValidationConfig, checks::ShellNotWatertight,
+    },
+};
+
+/// Measure a [`Shell`]
+pub trait MeasureShell {
+    /// # Compute the volume enclosed by the shell
+    ///
+    /// Uses the same sum of signed tetrahedron volumes as
+    /// [`MeasureShell::centroid`], and returns its absolute value. This assumes
+    /// that all faces of the shell point outwards (or all inwards).
+    ///
+    /// If the shell is not watertight, it doesn't enclose a volume, and the
+    /// result of the computation is meaningless. This method returns
+    /// [`VolumeError::NotWatertight`] in that case, which still carries the
+    /// result, for callers that want to use it anyway.
+    fn volume(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Result<Scalar, VolumeError>;
+
+    /// # Compute the centroid of the volume enclosed by the shell
+    ///
+    /// Triangulates the faces of the shell at the provided tolerance. Each
+    /// triangle spans a tetrahedron with the global origin, and the centroid
+    /// of each tetrahedron is weighted by its signed volume. By the divergence
+    /// theorem, those signed volumes add up to the volume of the shell, which
+    /// makes this work for non-convex shells.
+    ///
+    /// Requires the shell to be closed, with all faces pointing outwards.
+    /// Returns `None`, if the enclosed volume is not positive.
+    fn centroid(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Point<3>>;
+}
+
+impl MeasureShell for Shell {
+    fn volume(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Result<Scalar, VolumeError> {
+        let (volume, _) = shell_moments(self, tolerance.into(), geometry);
+        let volume = volume.abs();
+
+        let config = ValidationConfig::default();
+        if let Some(err) =
+            ShellNotWatertight::check(self, geometry, &config).next()
+        {
+            return Err(VolumeError::NotWatertight {
+                volume,
+                source: err,
+            });
+        }
+
+        Ok(volume)
+    }
+
+    fn centroid(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Point<3>> {
+        let (volume, moment) = shell_moments(self, tolerance.into(), geometry);
+
+        if volume <= Scalar::ZERO {
+            return None;
+        }
+
+        Some(Point {
+            coords: moment / volume,
+        })
+    }
+}
+
+/// # Error computing the volume of a shell
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum VolumeError {
+    /// # The shell is not watertight
+    #[error(
+        "Shell is not watertight; computed volume ({volume}) is meaningless"
+    )]
+    NotWatertight {
+        /// # The computed volume
+        ///
+        /// This is the result of the computation, as if the shell were
+        /// watertight.
+        volume: Scalar,
+
+        /// # The open boundaries of the shell
+        source: ShellNotWatertight,
+    },
+}
+
+/// # Compute the signed volume and first moment of volume of a shell
+pub(super) fn shell_moments(
+    shell: &Shell,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> (Scalar, Vector<3>) {
+    let mut volume = Scalar::ZERO;
+    let mut moment = Vector::from([0., 0., 0.]);
+
+    // Faces share their edges, so sharing the cache avoids approximating each
+    // edge twice.
+    let mut cache = ApproxCache::default();
+
+    for face in shell.faces() {
+        let mesh =
+            triangulate_with_cache(face, geometry, tolerance, &mut cache);
+
+        for [a, b, c] in mesh.triangles() {
+            let [a, b, c] = [a, b, c].map(|point| point.coords);
+
+            let tetrahedron_volume = a.dot(&b.cross(&c)) / 6.;
+            let tetrahedron_centroid = (a + b + c) / 4.;
+
+            volume += tetrahedron_volume;
+            moment = moment + tetrahedron_centroid * tetrahedron_volume;
+        }
+    }
+
+    (volume, moment)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildShell, BuildSketch},
+            measure::{MeasureShell, VolumeError},
+            shell_hollow::Hollow,
+            sweep::SweepSketch,
+            update::UpdateShell,
+        },
+        topology::{Shell, Sketch},
+    };
+
+    #[test]
+    fn volume_of_unit_cube() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(1., &mut core);
+        let volume = cube.volume(core.tolerance(), &core.layers.geometry)?;
+        assert!((volume - 1.).abs() < Scalar::from(1e-12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn volume_of_cube_with_cubic_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // Hollowing out a cube with a side length of 3 leaves a cubic hole with
+        // a side length of 1.
+        let shell = cube(3., &mut core).hollow(1., &[], &mut core)?;
+
+        let volume = shell.volume(core.tolerance(), &core.layers.geometry)?;
+        assert!((volume - 26.).abs() < Scalar::from(1e-12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn volume_of_open_shell() {
+        let mut core = Core::new();
+
+        let cube = cube(1., &mut core);
+        let open = cube.remove_face(cube.faces().first());
+
+        let result = open.volume(core.tolerance(), &core.layers.geometry);
+        assert!(matches!(result, Err(VolumeError::NotWatertight { .. })));
+    }
+
+    #[test]
+    fn centroid_of_unit_cube() {
+        let mut core = Core::new();
+
+        let cube = cube(1., &mut core);
+
+        let centroid = cube
+            .centroid(core.tolerance(), &core.layers.geometry)
+            .expect("Cube has a volume");
+        assert!(
+            centroid.distance_to(&Point::from([0.5, 0.5, 0.5]))
+                < Scalar::from(1e-12)
+        );
+    }
+
+    #[test]
+    fn centroid_of_non_convex_shell() {
+        let mut core = Core::new();
+
+        // An L-shaped prism, made up of a 2x1 and a 1x1 block.
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., -1.], &mut core);
+
+        let centroid = solid
+            .shells()
+            .only()
+            .centroid(core.tolerance(), &core.layers.geometry)
+            .expect("Shell has a volume");
+
+        let [x, y] = [(2. * 1. + 0.5 * 1.) / 3.; 2];
+        assert!(
+            centroid.distance_to(&Point::from([x, y, -0.5]))
+                < Scalar::from(1e-12)
+        );
+    }
+
+    fn cube(size: f64, core: &mut Core) -> Shell {
+        let [a, b] = [0., size];
+
+        Shell::from_vertices_and_polygons(
+            [
+                [a, a, a],
+                [b, a, a],
+                [a, b, a],
+                [b, b, a],
+                [a, a, b],
+                [b, a, b],
+                [a, b, b],
+                [b, b, b],
+            ],
+            [
+                [0, 2, 3, 1],
+                [4, 5, 7, 6],
+                [0, 1, 5, 4],
+                [2, 6, 7, 3],
+                [0, 4, 6, 2],
+                [1, 3, 7, 5],
+            ],
+            core,
+        )
+    }
+}
@@ -0,0 +1,51 @@
+use fj_interop::Tolerance;
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{geometry::Geometry, topology::Solid};
+
+use super::shell::shell_moments;
+
+/// Measure a [`Solid`]
+pub trait MeasureSolid {
+    /// # Compute the centroid of the solid
+    ///
+    /// Works like [`MeasureShell::centroid`], but combines all shells of the
+    /// solid. Shells that bound voids within the solid, and therefore point
+    /// inwards, contribute a negative volume.
+    ///
+    /// Returns `None`, if the volume of the solid is not positive.
+    ///
+    /// [`MeasureShell::centroid`]: super::MeasureShell::centroid
+    fn centroid(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Point<3>>;
+}
+
+impl MeasureSolid for Solid {
+    fn centroid(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Option<Point<3>> {
+        let tolerance = tolerance.into();
+
+        let (volume, moment) = self.shells().iter().fold(
+            (Scalar::ZERO, Vector::from([0., 0., 0.])),
+            |(volume, moment), shell| {
+                let (shell_volume, shell_moment) =
+                    shell_moments(shell, tolerance, geometry);
+                (volume + shell_volume, moment + shell_moment)
+            },
+        );
+
+        if volume <= Scalar::ZERO {
+            return None;
+        }
+
+        Some(Point {
+            coords: moment / volume,
+        })
+    }
+}
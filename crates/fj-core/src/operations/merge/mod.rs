@@ -0,0 +1,9 @@
+//! # Operations to merge objects
+//!
+//! See [`Merge`], to merge solids, and [`MergeCoplanar`], to merge the faces of
+//! a shell.
+
+mod shell;
+mod solid;
+
+pub use self::{shell::MergeCoplanar, solid::Merge};
@@ -0,0 +1,614 @@
+use std::collections::BTreeMap;
+
+use fj_interop::Tolerance;
+use fj_math::{Line, Point, Polygon, Scalar, Vector};
+
+use crate::{
+    Core,
+    algorithms::approx::{ApproxCache, cycle::approx_cycle},
+    geometry::{
+        CurveBoundary, Geometry, LocalCurveGeom, LocalVertexGeom, Path,
+        intersection::{SurfaceIntersection, intersect_surfaces},
+        surfaces::SweptCurve,
+    },
+    operations::{
+        derive::DeriveFrom, geometry::UpdateCurveGeometry, insert::Insert,
+    },
+    storage::{Handle, HandleMap, HandleSet},
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Shell, Surface},
+};
+
+/// # Merge adjacent, coplanar faces of a [`Shell`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait MergeCoplanar {
+    /// # Merge faces that lie in the same plane and share an edge
+    ///
+    /// Two faces are merged, if they are defined on the same surface, or on
+    /// coincident planes that face the same way, and if they share at least
+    /// one edge. The shared half-edges are removed, and the remaining
+    /// half-edges of both faces are joined into the cycles of the merged
+    /// face. Holes of either face are preserved. This is repeated, until no
+    /// more faces can be merged.
+    ///
+    /// Afterwards, line segments that meet at a vertex which no other edge of
+    /// the shell touches, and which continue in the same direction, are joined
+    /// into one.
+    ///
+    /// Faces are only merged, if the combined region is valid, meaning it has
+    /// exactly one exterior cycle. Faces on different surfaces are only merged,
+    /// if all edges of the face that is merged into the other are line
+    /// segments. Faces that don't meet these requirements are left as they
+    /// are.
+    #[must_use]
+    fn merge_coplanar(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Shell;
+}
+
+impl MergeCoplanar for Shell {
+    fn merge_coplanar(
+        &self,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Shell {
+        let tolerance = tolerance.into();
+        let mut shell = self.clone();
+
+        while let Some(merged) = merge_any_faces(&shell, tolerance, core) {
+            shell = merged;
+        }
+        while let Some(joined) = join_any_edges(&shell, core) {
+            shell = joined;
+        }
+
+        shell
+    }
+}
+
+fn merge_any_faces(
+    shell: &Shell,
+    tolerance: Tolerance,
+    core: &mut Core,
+) -> Option<Shell> {
+    for (i, a) in shell.faces().iter().enumerate() {
+        for b in shell.faces().iter().skip(i + 1) {
+            if !are_coplanar(a, b, tolerance, &core.layers.geometry) {
+                continue;
+            }
+
+            if let Some(merged) = merge_faces(a, b, tolerance, core) {
+                let faces = shell.faces().iter().filter_map(|face| {
+                    if face.id() == a.id() {
+                        Some(merged.clone())
+                    } else if face.id() == b.id() {
+                        None
+                    } else {
+                        Some(face.clone())
+                    }
+                });
+
+                return Some(Shell::new(faces));
+            }
+        }
+    }
+
+    None
+}
+
+fn are_coplanar(
+    a: &Face,
+    b: &Face,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> bool {
+    if a.surface().id() == b.surface().id() {
+        return true;
+    }
+
+    let (Some(a), Some(b)) = (
+        geometry.of_surface_if_defined(a.surface()),
+        geometry.of_surface_if_defined(b.surface()),
+    ) else {
+        return false;
+    };
+    let (Some(normal_a), Some(normal_b)) = (plane_normal(a), plane_normal(b))
+    else {
+        return false;
+    };
+
+    matches!(
+        intersect_surfaces(a, b, tolerance),
+        Ok(Some(SurfaceIntersection::Coincident))
+    ) && normal_a.dot(&normal_b) > Scalar::ZERO
+}
+
+fn plane_normal(surface: &SweptCurve) -> Option<Vector<3>> {
+    let Path::Line(line) = surface.u else {
+        return None;
+    };
+
+    Some(line.direction().cross(&surface.v))
+}
+
+/// # Merge face `b` into face `a`
+///
+/// Returns `None`, if the faces don't share an edge, or if they can't be
+/// merged into a valid face.
+fn merge_faces(
+    a: &Handle<Face>,
+    b: &Handle<Face>,
+    tolerance: Tolerance,
+    core: &mut Core,
+) -> Option<Handle<Face>> {
+    // For each half-edge of both faces, the half-edge that follows it in its
+    // cycle.
+    let mut next = BTreeMap::new();
+    let mut half_edges = Vec::new();
+    for face in [a, b] {
+        for cycle in face.region().all_cycles() {
+            for (half_edge, following) in cycle.half_edges().pairs() {
+                next.insert(half_edge.clone(), following.clone());
+                half_edges.push((face.id() == a.id(), half_edge.clone()));
+            }
+        }
+    }
+
+    let end_vertex =
+        |half_edge: &Handle<HalfEdge>| next[half_edge].start_vertex().clone();
+
+    let mut siblings = HandleMap::new();
+    for (in_a, half_edge) in &half_edges {
+        for (other_in_a, other) in &half_edges {
+            if in_a == other_in_a {
+                continue;
+            }
+
+            let is_sibling = half_edge.curve().id() == other.curve().id()
+                && half_edge.start_vertex().id() == end_vertex(other).id()
+                && end_vertex(half_edge).id() == other.start_vertex().id();
+            if is_sibling {
+                siblings.insert(half_edge.clone(), other.clone());
+            }
+        }
+    }
+    if siblings.is_empty() {
+        return None;
+    }
+
+    // Walk the remaining half-edges, jumping over to the other face wherever a
+    // shared half-edge is encountered.
+    let mut visited = HandleSet::new();
+    let mut cycles = Vec::new();
+    for (_, start) in &half_edges {
+        if siblings.contains_key(start) || visited.contains(start) {
+            continue;
+        }
+
+        let mut cycle = Vec::new();
+        let mut current = start.clone();
+        loop {
+            if !visited.insert(current.clone()) {
+                return None;
+            }
+            cycle.push(current.clone());
+
+            let mut following = next[&current].clone();
+            let mut jumps = 0;
+            while let Some(sibling) = siblings.get(&following) {
+                following = next[sibling].clone();
+
+                jumps += 1;
+                if jumps > siblings.len() {
+                    return None;
+                }
+            }
+
+            if following.id() == start.id() {
+                break;
+            }
+            current = following;
+        }
+
+        cycles.push(cycle);
+    }
+
+    // The half-edges of `b` need to be defined on the surface of `a`.
+    if a.surface().id() != b.surface().id() {
+        for (in_a, half_edge) in &half_edges {
+            if *in_a || siblings.contains_key(half_edge) {
+                continue;
+            }
+
+            define_on_surface(
+                half_edge,
+                b.surface(),
+                a.surface(),
+                tolerance,
+                core,
+            )?;
+        }
+    }
+
+    let mut exterior = None;
+    let mut interiors = Vec::new();
+    for half_edges in cycles {
+        let cycle = Cycle::new(half_edges);
+        let area =
+            signed_area(&cycle, a.surface(), tolerance, &core.layers.geometry);
+
+        if area > Scalar::ZERO {
+            if exterior.is_some() {
+                return None;
+            }
+            exterior = Some(cycle);
+        } else {
+            interiors.push(cycle);
+        }
+    }
+
+    let exterior = exterior?.insert(core);
+    let interiors = interiors
+        .into_iter()
+        .map(|cycle| cycle.insert(core))
+        .collect::<Vec<_>>();
+
+    let region = Region::new(exterior, interiors)
+        .insert(core)
+        .derive_from(a.region(), core);
+    let face = Face::new(a.surface().clone(), region)
+        .insert(core)
+        .derive_from(a, core);
+
+    Some(face)
+}
+
+/// # Define the geometry of a line segment on another, coincident plane
+///
+/// Returns `None`, if the half-edge is not a line segment.
+fn define_on_surface(
+    half_edge: &Handle<HalfEdge>,
+    from: &Handle<Surface>,
+    to: &Handle<Surface>,
+    tolerance: Tolerance,
+    core: &mut Core,
+) -> Option<()> {
+    let geometry = &core.layers.geometry;
+
+    let Path::Line(line) = geometry
+        .of_curve(half_edge.curve())
+        .and_then(|curve| curve.local_on(from))
+        .expect("Expected geometry of half-edge's curve")
+        .path
+    else {
+        return None;
+    };
+
+    let from_geom = geometry.of_surface_if_defined(from)?;
+    let to_geom = geometry.of_surface_2(to)?;
+
+    // Converting the points at line coordinates 0 and 1 preserves the line's
+    // coordinate system, which the positions of its vertices are defined in.
+    let [origin, direction] = [line.origin(), line.origin() + line.direction()]
+        .map(|point| {
+            let point = from_geom.u.point_from_path_coords([point.u])
+                + from_geom.v * point.v;
+            let (point, _) = to_geom.project_point(point, tolerance, geometry);
+            point
+        });
+    let path =
+        Path::Line(Line::from_origin_and_direction(origin, direction - origin));
+
+    core.layers.geometry.define_curve(
+        half_edge.curve().clone(),
+        to.clone(),
+        LocalCurveGeom { path },
+    );
+
+    Some(())
+}
+
+fn signed_area(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> Scalar {
+    let approx = approx_cycle(
+        cycle,
+        surface,
+        tolerance,
+        &mut ApproxCache::default(),
+        geometry,
+    );
+
+    Polygon::from_points(approx.points().iter().map(|point| point.local_form))
+        .signed_area()
+}
+
+/// # Join two line segments that continue each other into one
+///
+/// Looks for a vertex that is the start of exactly two half-edges in the
+/// shell, which are siblings of the two half-edges that end there. If all of
+/// those are collinear line segments, they are replaced by a single edge.
+fn join_any_edges(shell: &Shell, core: &mut Core) -> Option<Shell> {
+    let mut starting_at = BTreeMap::<_, Vec<_>>::new();
+    for face in shell.faces() {
+        for cycle in face.region().all_cycles() {
+            for (half_edge, next) in cycle.half_edges().pairs() {
+                starting_at
+                    .entry(half_edge.start_vertex().id())
+                    .or_default()
+                    .push((
+                        face.clone(),
+                        cycle.clone(),
+                        half_edge.clone(),
+                        next.clone(),
+                    ));
+            }
+        }
+    }
+
+    for (face, cycle, before, after) in starting_at.values().flatten() {
+        let vertex = after.start_vertex();
+        let [(face_a, cycle_a, first_a, _), (face_b, cycle_b, first_b, _)] =
+            starting_at[&vertex.id()].as_slice()
+        else {
+            continue;
+        };
+
+        // `before` ends at the vertex, `after` starts there. The sibling of
+        // `before` must be the only other half-edge that starts there, and it
+        // must be preceded by the sibling of `after`.
+        let (other_face, other_cycle, other_after) =
+            if first_a.id() == after.id() {
+                (face_b, cycle_b, first_b)
+            } else if first_b.id() == after.id() {
+                (face_a, cycle_a, first_a)
+            } else {
+                continue;
+            };
+        let Some(other_before) = other_cycle.half_edges().before(other_after)
+        else {
+            continue;
+        };
+
+        let geometry = &core.layers.geometry;
+        let Some([start, middle, end]) =
+            line_points(face, cycle, before, after, geometry)
+        else {
+            continue;
+        };
+        let Some([other_start, _, other_end]) = line_points(
+            other_face,
+            other_cycle,
+            other_before,
+            other_after,
+            geometry,
+        ) else {
+            continue;
+        };
+
+        let is_sibling = before.curve().id() == other_after.curve().id()
+            && after.curve().id() == other_before.curve().id();
+        if !is_sibling || !are_collinear([start, middle, end]) {
+            continue;
+        }
+
+        let curve = Curve::new().insert(core);
+        let boundary = CurveBoundary::default();
+
+        let start_vertex = before.start_vertex().clone();
+        let end_vertex = other_before.start_vertex().clone();
+
+        let curve = curve
+            .make_line_on_surface(
+                [start.local, end.local],
+                boundary,
+                face.surface().clone(),
+                &mut core.layers.geometry,
+            )
+            .make_line_on_surface(
+                [other_start.local, other_end.local],
+                boundary.reverse(),
+                other_face.surface().clone(),
+                &mut core.layers.geometry,
+            );
+        for (vertex, position) in [
+            (&start_vertex, boundary.inner[0]),
+            (&end_vertex, boundary.inner[1]),
+        ] {
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                curve.clone(),
+                LocalVertexGeom { position },
+            );
+        }
+
+        let joined = HalfEdge::new(curve.clone(), start_vertex)
+            .insert(core)
+            .derive_from(before, core);
+        let other_joined = HalfEdge::new(curve, end_vertex)
+            .insert(core)
+            .derive_from(other_before, core);
+
+        let mut replacements = BTreeMap::new();
+        replacements.insert(before.clone(), Some(joined));
+        replacements.insert(after.clone(), None);
+        replacements.insert(other_before.clone(), Some(other_joined));
+        replacements.insert(other_after.clone(), None);
+
+        let faces = shell
+            .faces()
+            .iter()
+            .map(|face| replace_half_edges(face, &replacements, core))
+            .collect::<Vec<_>>();
+
+        return Some(Shell::new(faces));
+    }
+
+    None
+}
+
+/// # A point on a face, in surface and global coordinates
+#[derive(Clone, Copy)]
+struct FacePoint {
+    local: Point<2>,
+    global: Point<3>,
+}
+
+/// # The start of `before`, the vertex between both, and the end of `after`
+///
+/// Returns `None`, if either half-edge is not a line segment, or if the face is
+/// not on a surface that is defined by a swept curve.
+fn line_points(
+    face: &Face,
+    cycle: &Cycle,
+    before: &Handle<HalfEdge>,
+    after: &Handle<HalfEdge>,
+    geometry: &Geometry,
+) -> Option<[FacePoint; 3]> {
+    let end = cycle.half_edges().after(after)?;
+
+    let point = |half_edge: &Handle<HalfEdge>, vertex_of: &Handle<HalfEdge>| {
+        let path = geometry
+            .of_curve(half_edge.curve())
+            .and_then(|curve| curve.local_on(face.surface()))
+            .expect("Expected geometry of half-edge's curve")
+            .path;
+        let Path::Line(_) = path else {
+            return None;
+        };
+
+        let position = geometry
+            .of_vertex(vertex_of.start_vertex())
+            .and_then(|vertex| vertex.local_on(half_edge.curve()))
+            .expect("Expected geometry of half-edge's vertex")
+            .position;
+        let local = path.point_from_path_coords(position);
+
+        let surface = geometry.of_surface_if_defined(face.surface())?;
+        let global =
+            surface.u.point_from_path_coords([local.u]) + surface.v * local.v;
+
+        Some(FacePoint { local, global })
+    };
+
+    Some([
+        point(before, before)?,
+        point(after, after)?,
+        point(after, end)?,
+    ])
+}
+
+fn are_collinear([a, b, c]: [FacePoint; 3]) -> bool {
+    let [ab, bc] = [b.global - a.global, c.global - b.global];
+
+    ab.normalize().cross(&bc.normalize()).magnitude() < Scalar::from(1e-9)
+        && ab.dot(&bc) > Scalar::ZERO
+}
+
+/// # Replace or remove half-edges in the cycles of a face
+///
+/// Returns the original face, if none of its half-edges are affected.
+fn replace_half_edges(
+    face: &Handle<Face>,
+    replacements: &BTreeMap<Handle<HalfEdge>, Option<Handle<HalfEdge>>>,
+    core: &mut Core,
+) -> Handle<Face> {
+    let is_affected = face.region().all_cycles().any(|cycle| {
+        cycle
+            .half_edges()
+            .iter()
+            .any(|half_edge| replacements.contains_key(half_edge))
+    });
+    if !is_affected {
+        return face.clone();
+    }
+
+    let mut replace = |cycle: &Handle<Cycle>| {
+        let half_edges = cycle
+            .half_edges()
+            .iter()
+            .filter_map(|half_edge| match replacements.get(half_edge) {
+                Some(replacement) => replacement.clone(),
+                None => Some(half_edge.clone()),
+            })
+            .collect::<Vec<_>>();
+
+        Cycle::new(half_edges).insert(core).derive_from(cycle, core)
+    };
+
+    let exterior = replace(face.region().exterior());
+    let interiors = face
+        .region()
+        .interiors()
+        .iter()
+        .map(&mut replace)
+        .collect::<Vec<_>>();
+
+    let region = Region::new(exterior, interiors)
+        .insert(core)
+        .derive_from(face.region(), core);
+
+    Face::new(face.surface().clone(), region)
+        .insert(core)
+        .derive_from(face, core)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{build::BuildShell, insert::Insert},
+        topology::Shell,
+    };
+
+    use super::MergeCoplanar;
+
+    #[test]
+    fn merge_adjacent_rectangles() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A box made from two unit cubes, side by side. All faces along the
+        // long side are split in two rectangles.
+        let vertices = (0..2).flat_map(|z| {
+            (0..2).flat_map(move |y| {
+                (0..3).map(move |x| [x, y, z].map(f64::from))
+            })
+        });
+        let shell = Shell::from_vertices_and_polygons(
+            vertices,
+            [
+                [0, 3, 4, 1],
+                [1, 4, 5, 2],
+                [6, 7, 10, 9],
+                [7, 8, 11, 10],
+                [0, 1, 7, 6],
+                [1, 2, 8, 7],
+                [3, 9, 10, 4],
+                [4, 10, 11, 5],
+                [0, 6, 9, 3],
+                [2, 5, 11, 8],
+            ],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let merged = shell.merge_coplanar(0.001, &mut core).insert(&mut core);
+
+        assert_eq!(merged.faces().len(), 6);
+        for face in merged.faces() {
+            assert!(face.region().interiors().is_empty());
+            assert_eq!(face.region().exterior().half_edges().len(), 4);
+        }
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+}
@@ -1,11 +1,4 @@
-//! # Operations to merge objects
-//!
-//! See [`Merge`], which is currently the only trait in this module, for more
-//! information.
-
-use crate::{Core, topology::Solid};
-
-use super::update::UpdateSolid;
+use crate::{Core, operations::update::UpdateSolid, topology::Solid};
 
 /// Merge two [`Solid`]s
 pub trait Merge {
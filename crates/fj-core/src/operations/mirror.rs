@@ -0,0 +1,264 @@
+//! # Mirror objects across a plane
+//!
+//! See [`Mirror`].
+
+use fj_math::{Point, Transform, Vector};
+
+use crate::{
+    Core,
+    operations::{
+        OperationError,
+        derive::DeriveFrom,
+        insert::Insert,
+        reverse::Reverse,
+        transform::{TransformCache, TransformObject},
+    },
+    storage::Handle,
+    topology::{Face, Sketch},
+};
+
+/// # Mirror an object across a plane
+///
+/// The plane is defined by a point on it and its normal.
+///
+/// Mirroring is implemented as a transformation, using [`TransformObject`].
+/// Like a transformation, it creates new objects for all objects referenced by
+/// the mirrored one. Mirroring multiple objects that share objects (like the
+/// vertices of adjacent faces) using the same [`TransformCache`] results in
+/// mirrored objects that share the mirrored objects in the same way.
+pub trait Mirror {
+    /// The result of the mirroring
+    type Mirrored;
+
+    /// # Mirror the object
+    ///
+    /// Returns an error, if the geometry of the object is not defined. See
+    /// [`TransformObject`].
+    fn mirror(
+        &self,
+        point: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+        core: &mut Core,
+    ) -> Result<Self::Mirrored, OperationError> {
+        let mut cache = TransformCache::default();
+        self.mirror_with_cache(point, normal, core, &mut cache)
+    }
+
+    /// # Mirror the object using the provided cache
+    fn mirror_with_cache(
+        &self,
+        point: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+        core: &mut Core,
+        cache: &mut TransformCache,
+    ) -> Result<Self::Mirrored, OperationError>;
+}
+
+impl Mirror for Handle<Face> {
+    type Mirrored = Handle<Face>;
+
+    /// # Mirror the face
+    ///
+    /// A reflection reverses the orientation of the face's surface. The cycles
+    /// of the mirrored face are reversed too, which restores the orientation of
+    /// the face. If the original face pointed outwards, so does the mirrored
+    /// one.
+    fn mirror_with_cache(
+        &self,
+        point: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+        core: &mut Core,
+        cache: &mut TransformCache,
+    ) -> Result<Self::Mirrored, OperationError> {
+        let reflection = Transform::reflection(point, normal);
+
+        // Only insert the reversed face. Inserting the reflected one too
+        // would leave it in the store, without anything referencing it.
+        let face = self
+            .clone_object()
+            .transform_with_cache(&reflection, core, cache)?
+            .reverse(core)
+            .insert(core)
+            .derive_from(self, core);
+
+        Ok(face)
+    }
+}
+
+impl Mirror for Handle<Sketch> {
+    type Mirrored = Handle<Sketch>;
+
+    /// # Mirror the sketch
+    ///
+    /// Requires the surface of the sketch to have geometry, which means it
+    /// can't be the special surface that represents 2D space.
+    ///
+    /// The coordinate system of the mirrored surface is the mirror image of the
+    /// original one. Within that coordinate system, the cycles of the sketch
+    /// keep their winding, so the mirrored sketch is valid, if the original one
+    /// is. Keep in mind, that this makes the front of the mirrored surface the
+    /// mirror image of the original's back, when sweeping the mirrored sketch.
+    fn mirror_with_cache(
+        &self,
+        point: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+        core: &mut Core,
+        cache: &mut TransformCache,
+    ) -> Result<Self::Mirrored, OperationError> {
+        let reflection = Transform::reflection(point, normal);
+
+        let surface =
+            self.surface()
+                .transform_with_cache(&reflection, core, cache)?;
+        let regions = self
+            .regions()
+            .iter()
+            .map(|region| {
+                (region, self.surface()).transform_with_cache(
+                    &reflection,
+                    core,
+                    cache,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Sketch::new(surface, regions).insert(core))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        geometry::repr::tri_mesh::convert_point_surface_to_global,
+        operations::{
+            build::{BuildFace, BuildRegion, BuildSketch},
+            insert::Insert,
+            sweep::SweepSketch,
+            transform::TransformCache,
+        },
+        storage::Handle,
+        topology::{Face, Region, Shell, Sketch, Solid},
+    };
+
+    use super::Mirror;
+
+    #[test]
+    fn mirror_l_shaped_sketch() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let region = Region::polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            surface.clone(),
+            &mut core,
+        )
+        .insert(&mut core);
+        let sketch = Sketch::new(surface, [region]).insert(&mut core);
+
+        let mirrored = sketch.mirror([1., 0., 0.], [1., 0., 0.], &mut core)?;
+
+        let [original, mirrored] =
+            [&sketch, &mirrored].map(|sketch| vertex_positions(sketch, &core));
+        let expected = original
+            .into_iter()
+            .map(|point| Point::from([Scalar::TWO - point.x, point.y, point.z]))
+            .collect::<Vec<_>>();
+        assert_eq!(mirrored, expected);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn mirror_faces_of_shell() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., -1.], &mut core);
+
+        // Mirroring all faces using the same cache must result in a valid
+        // shell. This requires the mirrored faces to share vertices and curves.
+        let mut cache = TransformCache::default();
+        let faces = solid
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .map(|face| {
+                face.mirror_with_cache(
+                    [0., 0., 0.],
+                    [1., 1., 0.],
+                    &mut core,
+                    &mut cache,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mirrored =
+            Solid::new([Shell::new(faces).insert(&mut core)]).insert(&mut core);
+        assert_eq!(mirrored.shells().only().faces().len(), 6);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn mirror_face_inserts_only_mirrored_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let num_faces = core.layers.topology.faces.iter().count();
+        face.mirror([0., 0., 0.], [1., 0., 0.], &mut core)?;
+        assert_eq!(core.layers.topology.faces.iter().count(), num_faces + 1,);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    fn vertex_positions(sketch: &Handle<Sketch>, core: &Core) -> Vec<Point<3>> {
+        let geometry = &core.layers.geometry;
+        let surface = sketch.surface();
+        let surface_geom = geometry.of_surface_2(surface).unwrap();
+
+        sketch
+            .regions()
+            .only()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(surface)
+                    .unwrap()
+                    .path;
+                let position = geometry
+                    .of_vertex(half_edge.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position;
+
+                convert_point_surface_to_global(
+                    &surface_geom.generator,
+                    path.point_from_path_coords(position),
+                    0.001,
+                    geometry,
+                )
+            })
+            .collect()
+    }
+}
@@ -38,17 +38,31 @@
 //! assume that the code in question is outdated. Feel free to open an issue or
 //! send a pull request!
 
+pub mod boolean;
 pub mod build;
+pub mod chamfer;
 pub mod derive;
+pub mod fillet;
 pub mod geometry;
 pub mod holes;
 pub mod insert;
 pub mod join;
+pub mod loft;
+pub mod measure;
 pub mod merge;
+pub mod mirror;
+pub mod offset_2d;
+pub mod pattern;
 pub mod presentation;
 pub mod replace;
 pub mod reverse;
+pub mod revolve;
+pub mod shell_hollow;
 pub mod split;
 pub mod sweep;
 pub mod transform;
 pub mod update;
+
+mod error;
+
+pub use self::error::OperationError;
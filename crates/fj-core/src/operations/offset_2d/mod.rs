@@ -0,0 +1,8 @@
+//! # Offset the outlines of 2D shapes by a constant distance
+//!
+//! So far, only sketches whose cycles consist exclusively of line segments are
+//! supported.
+
+mod sketch;
+
+pub use self::sketch::{OffsetError, OffsetJoin, OffsetSketch};
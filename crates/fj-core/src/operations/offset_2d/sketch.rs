@@ -0,0 +1,663 @@
+use fj_math::{Line, Point, Polygon, Scalar, Vector};
+
+use crate::{
+    Core,
+    geometry::{
+        CurveBoundary, Geometry, Path,
+        intersection::line_coords_of_intersection,
+    },
+    operations::{
+        build::{BuildCycle, BuildHalfEdge},
+        insert::Insert,
+    },
+    storage::Handle,
+    topology::{Cycle, HalfEdge, Region, Sketch, Surface},
+};
+
+/// # Offset a [`Sketch`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait OffsetSketch {
+    /// # Offset the outlines of all regions in the sketch
+    ///
+    /// Each line segment is moved along its normal by `distance`. A positive
+    /// distance grows the regions (moving exteriors outwards and shrinking
+    /// holes), a negative one shrinks them.
+    ///
+    /// Where moving the line segments opens a gap at a corner, the gap is
+    /// closed as specified by `join`. Where the line segments overlap at a
+    /// corner instead, they are trimmed at their intersection.
+    ///
+    /// ## Self-Intersections
+    ///
+    /// Large negative offsets can cause short line segments to vanish, as their
+    /// neighbors' trimmed ends pass each other. Such line segments are removed,
+    /// and their neighbors are connected directly. If a cycle vanishes entirely
+    /// as a result, it is removed from its region. A region whose exterior
+    /// vanishes is removed from the sketch.
+    ///
+    /// Other self-intersections, for example where a narrow section of a
+    /// region is split in two, are detected but not resolved. In that case,
+    /// [`OffsetError::SelfIntersection`] is returned. Overlaps between
+    /// different regions are not detected.
+    ///
+    /// ## Implementation Note
+    ///
+    /// Miter joins are not limited in length. At very acute corners, they can
+    /// extend far beyond the original outline.
+    fn offset(
+        &self,
+        distance: impl Into<Scalar>,
+        join: OffsetJoin,
+        core: &mut Core,
+    ) -> Result<Sketch, OffsetError>;
+}
+
+impl OffsetSketch for Sketch {
+    fn offset(
+        &self,
+        distance: impl Into<Scalar>,
+        join: OffsetJoin,
+        core: &mut Core,
+    ) -> Result<Sketch, OffsetError> {
+        let distance = distance.into();
+        let surface = self.surface();
+
+        let mut regions = Vec::new();
+
+        for region in self.regions() {
+            // Growing a region moves its exterior outwards, but its interiors
+            // inwards.
+            let offset = |cycle: &Cycle, distance: Scalar| {
+                let points =
+                    cycle_points(cycle, surface, &core.layers.geometry)?;
+                offset_polygon(points, distance, join)
+            };
+
+            let Some(exterior) = offset(region.exterior(), distance)? else {
+                continue;
+            };
+            let mut interiors = Vec::new();
+            for interior in region.interiors() {
+                if let Some(interior) = offset(interior, -distance)? {
+                    interiors.push(interior);
+                }
+            }
+
+            let chords = [&exterior]
+                .into_iter()
+                .chain(&interiors)
+                .map(|elements| {
+                    elements
+                        .iter()
+                        .map(|element| element.chord())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            if intersects(&chords) {
+                return Err(OffsetError::SelfIntersection);
+            }
+
+            let exterior = build_cycle(exterior, surface, core).insert(core);
+            let interiors = interiors
+                .into_iter()
+                .map(|interior| {
+                    build_cycle(interior, surface, core).insert(core)
+                })
+                .collect::<Vec<_>>();
+
+            regions.push(Region::new(exterior, interiors).insert(core));
+        }
+
+        if regions.is_empty() {
+            return Err(OffsetError::Collapsed);
+        }
+
+        Ok(Sketch::new(surface.clone(), regions))
+    }
+}
+
+/// # How to close the gaps at corners, when offsetting outlines
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OffsetJoin {
+    /// # Extend the adjacent line segments until they meet
+    Miter,
+
+    /// # Connect the adjacent line segments with an arc
+    ///
+    /// The arc is centered on the original corner, and its radius is the
+    /// offset distance.
+    Arc,
+}
+
+/// # Error offsetting a sketch
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum OffsetError {
+    /// # A cycle contains a half-edge that is not a line segment
+    #[error("Can only offset cycles that consist of line segments")]
+    NotLineSegments,
+
+    /// # The offset outline intersects itself
+    #[error("Offset outline intersects itself")]
+    SelfIntersection,
+
+    /// # All regions of the sketch vanished
+    #[error("Offset is so large that all regions of the sketch vanish")]
+    Collapsed,
+}
+
+/// # Part of an offset outline
+#[derive(Clone, Copy)]
+enum Element {
+    Line {
+        start: Point<2>,
+        end: Point<2>,
+    },
+    Arc {
+        start: Point<2>,
+        end: Point<2>,
+        angle: Scalar,
+    },
+}
+
+impl Element {
+    fn chord(&self) -> [Point<2>; 2] {
+        match *self {
+            Self::Line { start, end } | Self::Arc { start, end, .. } => {
+                [start, end]
+            }
+        }
+    }
+
+    fn reverse(self) -> Self {
+        match self {
+            Self::Line { start, end } => Self::Line {
+                start: end,
+                end: start,
+            },
+            Self::Arc { start, end, angle } => Self::Arc {
+                start: end,
+                end: start,
+                angle: -angle,
+            },
+        }
+    }
+}
+
+/// # A line segment of a polygon, moved along its normal
+struct OffsetLine {
+    origin: Point<2>,
+    direction: Vector<2>,
+
+    /// # The normal of the line, scaled by the offset distance
+    offset: Vector<2>,
+
+    /// # The original corner between this line and the next one
+    ///
+    /// `None`, if the next line was not originally adjacent to this one.
+    corner: Option<Point<2>>,
+}
+
+/// # Offset a polygon, growing it by `distance`
+///
+/// Returns `None`, if the polygon vanishes.
+fn offset_polygon(
+    mut points: Vec<Point<2>>,
+    distance: Scalar,
+    join: OffsetJoin,
+) -> Result<Option<Vec<Element>>, OffsetError> {
+    // The following code assumes a counter-clockwise polygon, so the outside
+    // is always to the right.
+    let is_clockwise = Polygon::from_points(points.iter().copied())
+        .signed_area()
+        < Scalar::ZERO;
+    if is_clockwise {
+        points.reverse();
+    }
+
+    let mut lines = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(&a, &b)| {
+            let direction = (b - a).normalize();
+            let offset = Vector::from([direction.v, -direction.u]) * distance;
+
+            OffsetLine {
+                origin: a + offset,
+                direction,
+                offset,
+                corner: Some(b),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let elements = loop {
+        if lines.len() < 3 {
+            return Ok(None);
+        }
+
+        if merge_parallel_lines(&mut lines)? {
+            continue;
+        }
+
+        let n = lines.len();
+        let joints = (0..n)
+            .map(|i| joint(&lines[i], &lines[(i + 1) % n], distance, join))
+            .collect::<Vec<_>>();
+
+        // Find the line whose ends have passed each other by the largest
+        // amount, if any.
+        let vanished = (0..n)
+            .map(|i| {
+                let start = joints[(i + n - 1) % n].end;
+                let end = joints[i].start;
+                (i, (end - start).dot(&lines[i].direction))
+            })
+            .filter(|&(_, length)| length <= Scalar::ZERO)
+            .min_by_key(|&(_, length)| length);
+
+        if let Some((i, _)) = vanished {
+            lines.remove(i);
+            let prev = (i + lines.len() - 1) % lines.len();
+            lines[prev].corner = None;
+            continue;
+        }
+
+        let mut elements = Vec::new();
+        for i in 0..n {
+            elements.push(Element::Line {
+                start: joints[(i + n - 1) % n].end,
+                end: joints[i].start,
+            });
+            if let Some(angle) = joints[i].arc {
+                elements.push(Element::Arc {
+                    start: joints[i].start,
+                    end: joints[i].end,
+                    angle,
+                });
+            }
+        }
+
+        break elements;
+    };
+
+    let chords = elements.iter().map(|element| element.chord()[0]);
+    if Polygon::from_points(chords).signed_area() <= Scalar::ZERO {
+        return Ok(None);
+    }
+
+    if is_clockwise {
+        return Ok(Some(
+            elements.into_iter().rev().map(Element::reverse).collect(),
+        ));
+    }
+
+    Ok(Some(elements))
+}
+
+/// # Merge adjacent lines that are parallel
+///
+/// Lines that coincide and point in the same direction are merged into one.
+/// Lines that point in opposite directions have no space between them, and
+/// are removed.
+///
+/// Returns whether any lines have been merged or removed.
+fn merge_parallel_lines(
+    lines: &mut Vec<OffsetLine>,
+) -> Result<bool, OffsetError> {
+    let n = lines.len();
+
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let [a, b] = [&lines[i], &lines[j]];
+
+        if a.direction.cross2d(&b.direction).abs() > Scalar::from(1e-9) {
+            continue;
+        }
+
+        if a.direction.dot(&b.direction) > Scalar::ZERO {
+            let gap = (b.origin - a.origin).cross2d(&a.direction);
+            if gap.abs() > Scalar::from(1e-9) {
+                // Parallel lines that don't coincide can't be connected by
+                // trimming them.
+                return Err(OffsetError::SelfIntersection);
+            }
+
+            lines[i].corner = lines[j].corner;
+            lines.remove(j);
+        } else {
+            let [first, second] = if j > i { [j, i] } else { [i, j] };
+            lines.remove(first);
+            lines.remove(second);
+
+            if !lines.is_empty() {
+                let prev = if j > i {
+                    (i + lines.len() - 1) % lines.len()
+                } else {
+                    lines.len() - 1
+                };
+                lines[prev].corner = None;
+            }
+        }
+
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+/// # The connection between two consecutive offset lines
+struct Joint {
+    /// # The end of the first line
+    start: Point<2>,
+
+    /// # The start of the second line
+    end: Point<2>,
+
+    /// # The angle of the arc that connects both, if any
+    arc: Option<Scalar>,
+}
+
+fn joint(
+    a: &OffsetLine,
+    b: &OffsetLine,
+    distance: Scalar,
+    join: OffsetJoin,
+) -> Joint {
+    let turn = a.direction.cross2d(&b.direction);
+
+    if let (Some(corner), OffsetJoin::Arc) = (a.corner, join) {
+        // The lines only leave a gap, if they move away from the corner's
+        // inside.
+        if turn * distance > Scalar::ZERO {
+            let angle = turn.atan2(a.direction.dot(&b.direction));
+
+            return Joint {
+                start: corner + a.offset,
+                end: corner + b.offset,
+                arc: Some(angle),
+            };
+        }
+    }
+
+    let [t, _] = line_coords_of_intersection(
+        &Line::from_origin_and_direction(a.origin, a.direction),
+        &Line::from_origin_and_direction(b.origin, b.direction),
+    )
+    .expect("Lines are not parallel, or they would have been merged");
+    let intersection = a.origin + a.direction * t;
+
+    Joint {
+        start: intersection,
+        end: intersection,
+        arc: None,
+    }
+}
+
+fn cycle_points(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+) -> Result<Vec<Point<2>>, OffsetError> {
+    cycle
+        .half_edges()
+        .iter()
+        .map(|half_edge| {
+            let path = geometry
+                .of_curve(half_edge.curve())
+                .and_then(|curve| curve.local_on(surface))
+                .expect("Expected geometry of half-edge's curve")
+                .path;
+            let Path::Line(_) = path else {
+                return Err(OffsetError::NotLineSegments);
+            };
+
+            let position = geometry
+                .of_vertex(half_edge.start_vertex())
+                .and_then(|vertex| vertex.local_on(half_edge.curve()))
+                .expect("Expected geometry of half-edge's vertex")
+                .position;
+
+            Ok(path.point_from_path_coords(position))
+        })
+        .collect()
+}
+
+fn build_cycle(
+    elements: Vec<Element>,
+    surface: &Handle<Surface>,
+    core: &mut Core,
+) -> Cycle {
+    let half_edges_and_boundaries: Vec<(Handle<HalfEdge>, CurveBoundary<_>)> =
+        elements
+            .into_iter()
+            .map(|element| match element {
+                Element::Line { start, end } => {
+                    HalfEdge::line_segment([start, end], surface.clone(), core)
+                }
+                Element::Arc { start, end, angle } => {
+                    HalfEdge::arc(start, end, angle, surface.clone(), core)
+                }
+            })
+            .collect();
+
+    Cycle::from_half_edges_and_boundaries(half_edges_and_boundaries, core)
+}
+
+/// # Determine whether any non-adjacent segments of the cycles intersect
+fn intersects(cycles: &[Vec<[Point<2>; 2]>]) -> bool {
+    let segments = cycles
+        .iter()
+        .enumerate()
+        .flat_map(|(c, segments)| {
+            let n = segments.len();
+            segments
+                .iter()
+                .enumerate()
+                .map(move |(i, segment)| ((c, i, n), *segment))
+        })
+        .collect::<Vec<_>>();
+
+    segments.iter().enumerate().any(|(k, &((c, i, n), a))| {
+        segments[k + 1..].iter().any(|&((d, j, _), b)| {
+            let is_adjacent = c == d && (j == i + 1 || (i == 0 && j == n - 1));
+            !is_adjacent && segments_intersect(a, b)
+        })
+    })
+}
+
+fn segments_intersect([a, b]: [Point<2>; 2], [c, d]: [Point<2>; 2]) -> bool {
+    let side =
+        |p: Point<2>, q: Point<2>, r: Point<2>| (q - p).cross2d(&(r - p));
+
+    let [s1, s2] = [c, d].map(|point| side(a, b, point));
+    let [s3, s4] = [a, b].map(|point| side(c, d, point));
+
+    s1 * s2 < Scalar::ZERO && s3 * s4 < Scalar::ZERO
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        operations::{build::BuildSketch, insert::Insert},
+        topology::Sketch,
+    };
+
+    use super::{OffsetError, OffsetJoin, OffsetSketch, cycle_points};
+
+    #[test]
+    fn offset_square_outwards() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let square = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        let offset = square
+            .offset(0.5, OffsetJoin::Miter, &mut core)?
+            .insert(&mut core);
+
+        assert_eq!(
+            exterior_points(&offset, &core),
+            [[-0.5, -0.5], [1.5, -0.5], [1.5, 1.5], [-0.5, 1.5]]
+                .map(Point::from),
+        );
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_square_outwards_with_arcs() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let square = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        let offset = square
+            .offset(0.5, OffsetJoin::Arc, &mut core)?
+            .insert(&mut core);
+
+        let exterior = offset.regions().only().exterior();
+        assert_eq!(exterior.half_edges().len(), 4 + 4);
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn offset_concave_l_inwards() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let l = Sketch::polygon(
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            &mut core,
+        );
+
+        let miter = l
+            .offset(-0.25, OffsetJoin::Miter, &mut core)?
+            .insert(&mut core);
+        assert_eq!(
+            exterior_points(&miter, &core),
+            [
+                [0.25, 0.25],
+                [1.75, 0.25],
+                [1.75, 0.75],
+                [0.75, 0.75],
+                [0.75, 1.75],
+                [0.25, 1.75],
+            ]
+            .map(Point::from),
+        );
+
+        // Only the concave corner opens up, when moving the edges inwards.
+        let arc = l
+            .offset(-0.25, OffsetJoin::Arc, &mut core)?
+            .insert(&mut core);
+        let exterior = arc.regions().only().exterior();
+        assert_eq!(exterior.half_edges().len(), 6 + 1);
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn trim_vanishing_edges() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A rectangle with a narrow tab on top. The tab is narrower than twice
+        // the offset, so it vanishes.
+        let tab = Sketch::polygon(
+            [
+                [0., 0.],
+                [4., 0.],
+                [4., 3.],
+                [2.2, 3.],
+                [2.2, 3.2],
+                [1.8, 3.2],
+                [1.8, 3.],
+                [0., 3.],
+            ],
+            &mut core,
+        );
+        let offset = tab
+            .offset(-0.5, OffsetJoin::Miter, &mut core)?
+            .insert(&mut core);
+
+        assert_eq!(
+            exterior_points(&offset, &core),
+            [[0.5, 0.5], [3.5, 0.5], [3.5, 2.5], [0.5, 2.5]].map(Point::from),
+        );
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_collapse_and_self_intersection() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let square = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        assert_eq!(
+            square.offset(-0.6, OffsetJoin::Miter, &mut core).err(),
+            Some(OffsetError::Collapsed),
+        );
+
+        // Two squares, connected by a narrow bridge. Shrinking them would
+        // split the region in two.
+        let dumbbell = Sketch::polygon(
+            [
+                [0., 0.],
+                [2., 0.],
+                [2., 0.9],
+                [3., 0.9],
+                [3., 0.],
+                [5., 0.],
+                [5., 2.],
+                [3., 2.],
+                [3., 1.1],
+                [2.2, 1.1],
+                [2.2, 2.],
+                [0., 2.],
+            ],
+            &mut core,
+        );
+        assert_eq!(
+            dumbbell.offset(-0.5, OffsetJoin::Miter, &mut core).err(),
+            Some(OffsetError::SelfIntersection),
+        );
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    fn exterior_points(sketch: &Sketch, core: &Core) -> Vec<Point<2>> {
+        let exterior = sketch.regions().only().exterior();
+        cycle_points(exterior, sketch.surface(), &core.layers.geometry)
+            .unwrap()
+            .into_iter()
+            .map(|point| {
+                // Round away floating-point noise from the intersections.
+                point
+                    .coords
+                    .components
+                    .map(|c| Scalar::from((c.into_f64() * 1e9).round() / 1e9))
+            })
+            .map(Point::from)
+            .collect()
+    }
+}
@@ -0,0 +1,523 @@
+//! # Repeat objects in a regular pattern
+//!
+//! See [`LinearPattern`] and [`CircularPattern`].
+
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    Core,
+    operations::{
+        OperationError,
+        insert::Insert,
+        transform::{TransformCache, TransformObject},
+    },
+    storage::Handle,
+    topology::{Face, Sketch},
+};
+
+/// # Repeat an object along one or two axes
+///
+/// Each copy is created by translating the original object, using
+/// [`TransformObject`]. All faces that make up a single copy are translated
+/// using the same [`TransformCache`], so any objects they share (like the
+/// surface of a sketch) are shared by the translated faces too.
+///
+/// The first copy is located at the position of the original object, and
+/// consists of the original faces.
+pub trait LinearPattern {
+    /// # Repeat the object along the provided axes
+    ///
+    /// Returns the faces of all copies. If a second axis is provided, the
+    /// copies form a grid, with the copies along the first axis coming first
+    /// for each step along the second axis.
+    ///
+    /// Returns an error, if the geometry of the object is not defined. See
+    /// [`TransformObject`].
+    fn linear_pattern(
+        &self,
+        axis: PatternAxis,
+        second_axis: Option<PatternAxis>,
+        core: &mut Core,
+    ) -> Result<Vec<Handle<Face>>, OperationError>;
+}
+
+impl LinearPattern for Handle<Face> {
+    fn linear_pattern(
+        &self,
+        axis: PatternAxis,
+        second_axis: Option<PatternAxis>,
+        core: &mut Core,
+    ) -> Result<Vec<Handle<Face>>, OperationError> {
+        let copies = linear_copies(axis, second_axis);
+        pattern_faces(std::slice::from_ref(self), copies, core)
+    }
+}
+
+impl LinearPattern for Handle<Sketch> {
+    /// # Repeat the sketch along the provided axes
+    ///
+    /// Creates a face for each region of each copy of the sketch. Requires the
+    /// surface of the sketch to have geometry, which means it can't be the
+    /// special surface that represents 2D space.
+    fn linear_pattern(
+        &self,
+        axis: PatternAxis,
+        second_axis: Option<PatternAxis>,
+        core: &mut Core,
+    ) -> Result<Vec<Handle<Face>>, OperationError> {
+        let faces = faces_of_sketch(self, core);
+        let copies = linear_copies(axis, second_axis);
+        pattern_faces(&faces, copies, core)
+    }
+}
+
+/// # An axis along which an object is repeated
+///
+/// See [`LinearPattern`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PatternAxis {
+    /// # The direction of the axis
+    pub direction: Vector<3>,
+
+    /// # The distance between neighboring copies
+    pub spacing: Scalar,
+
+    /// # The number of copies, including the original
+    pub count: usize,
+}
+
+impl PatternAxis {
+    /// # Construct an instance of `PatternAxis`
+    ///
+    /// The direction doesn't need to be normalized.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the direction has zero length.
+    pub fn new(
+        direction: impl Into<Vector<3>>,
+        spacing: impl Into<Scalar>,
+        count: usize,
+    ) -> Self {
+        let direction = direction.into();
+        assert!(
+            direction.magnitude() > Scalar::ZERO,
+            "Direction of pattern axis must not have zero length",
+        );
+
+        Self {
+            direction: direction.normalize(),
+            spacing: spacing.into(),
+            count,
+        }
+    }
+
+    fn offsets(&self) -> impl Iterator<Item = Vector<3>> + '_ {
+        (0..self.count).map(|i| self.direction * self.spacing * i as f64)
+    }
+}
+
+/// # Repeat an object around an axis
+///
+/// Each copy is created by rotating the original object, using
+/// [`TransformObject`]. As with [`LinearPattern`], all faces that make up a
+/// single copy are rotated using the same [`TransformCache`].
+///
+/// The first copy is located at the position of the original object, and
+/// consists of the original faces.
+pub trait CircularPattern {
+    /// # Repeat the object around the provided axis
+    ///
+    /// Returns the faces of all copies, in the order of increasing angle.
+    ///
+    /// Returns an error, if the geometry of the object is not defined. See
+    /// [`TransformObject`].
+    fn circular_pattern(
+        &self,
+        rotation: PatternRotation,
+        core: &mut Core,
+    ) -> Result<Vec<Handle<Face>>, OperationError>;
+}
+
+impl CircularPattern for Handle<Face> {
+    fn circular_pattern(
+        &self,
+        rotation: PatternRotation,
+        core: &mut Core,
+    ) -> Result<Vec<Handle<Face>>, OperationError> {
+        pattern_faces(std::slice::from_ref(self), rotation.copies(), core)
+    }
+}
+
+impl CircularPattern for Handle<Sketch> {
+    /// # Repeat the sketch around the provided axis
+    ///
+    /// Creates a face for each region of each copy of the sketch. Requires the
+    /// surface of the sketch to have geometry, which means it can't be the
+    /// special surface that represents 2D space.
+    fn circular_pattern(
+        &self,
+        rotation: PatternRotation,
+        core: &mut Core,
+    ) -> Result<Vec<Handle<Face>>, OperationError> {
+        let faces = faces_of_sketch(self, core);
+        pattern_faces(&faces, rotation.copies(), core)
+    }
+}
+
+/// # An axis around which an object is repeated
+///
+/// See [`CircularPattern`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PatternRotation {
+    /// # A point on the axis
+    pub origin: Point<3>,
+
+    /// # The direction of the axis
+    ///
+    /// Copies are placed counter-clockwise, when looking against this
+    /// direction.
+    pub axis: Vector<3>,
+
+    /// # The number of copies, including the original
+    pub count: usize,
+
+    /// # The angle that the copies are distributed over
+    pub total_angle: Scalar,
+
+    /// # Whether to place a copy at the end of a full revolution
+    ///
+    /// If the total angle is a full revolution, a copy at the end of it would
+    /// coincide with the original. By default, the copies are distributed such
+    /// that there is no copy at that seam. If this is set, the last copy is
+    /// placed there anyway.
+    ///
+    /// Has no effect, if the total angle is less than a full revolution. Then
+    /// the last copy is always placed at the end.
+    pub include_seam: bool,
+}
+
+impl PatternRotation {
+    /// # Construct an instance of `PatternRotation`
+    ///
+    /// Distributes the copies over a full revolution, without a copy at the
+    /// seam. Use [`PatternRotation::with_total_angle`] and
+    /// [`PatternRotation::with_seam`] to change that.
+    ///
+    /// The axis doesn't need to be normalized.
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the axis has zero length.
+    pub fn new(
+        origin: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        count: usize,
+    ) -> Self {
+        let axis = axis.into();
+        assert!(
+            axis.magnitude() > Scalar::ZERO,
+            "Axis of pattern rotation must not have zero length",
+        );
+
+        Self {
+            origin: origin.into(),
+            axis: axis.normalize(),
+            count,
+            total_angle: Scalar::TAU,
+            include_seam: false,
+        }
+    }
+
+    /// # Distribute the copies over the provided angle
+    pub fn with_total_angle(mut self, total_angle: impl Into<Scalar>) -> Self {
+        self.total_angle = total_angle.into();
+        self
+    }
+
+    /// # Place a copy at the seam of a full revolution
+    ///
+    /// See [`PatternRotation::include_seam`].
+    pub fn with_seam(mut self) -> Self {
+        self.include_seam = true;
+        self
+    }
+
+    fn copies(&self) -> impl Iterator<Item = Option<Transform>> + '_ {
+        let is_full_revolution = self.total_angle.abs() >= Scalar::TAU;
+        let num_steps = if is_full_revolution && !self.include_seam {
+            self.count.max(1)
+        } else {
+            self.count.saturating_sub(1).max(1)
+        };
+        let step = self.total_angle / num_steps as f64;
+
+        let to_origin = Transform::translation(-self.origin.coords);
+        let from_origin = Transform::translation(self.origin.coords);
+
+        (0..self.count).map(move |i| {
+            (i > 0).then(|| {
+                let rotation =
+                    Transform::rotation(self.axis * (step * i as f64));
+                from_origin * rotation * to_origin
+            })
+        })
+    }
+}
+
+/// # Compute the transforms for the copies of a linear pattern
+///
+/// `None` stands for the original object.
+fn linear_copies(
+    axis: PatternAxis,
+    second_axis: Option<PatternAxis>,
+) -> Vec<Option<Transform>> {
+    let second_offsets = match second_axis {
+        Some(second_axis) => second_axis.offsets().collect(),
+        None => vec![Vector::from([0., 0., 0.])],
+    };
+
+    second_offsets
+        .into_iter()
+        .flat_map(|second_offset| {
+            axis.offsets().map(move |offset| offset + second_offset)
+        })
+        .map(|offset| {
+            (offset != Vector::from([0., 0., 0.]))
+                .then(|| Transform::translation(offset))
+        })
+        .collect()
+}
+
+fn faces_of_sketch(sketch: &Sketch, core: &mut Core) -> Vec<Handle<Face>> {
+    sketch
+        .regions()
+        .iter()
+        .map(|region| {
+            Face::new(sketch.surface().clone(), region.clone()).insert(core)
+        })
+        .collect()
+}
+
+/// # Create a copy of the faces for each transform
+///
+/// `None` stands for the original faces, which are returned unchanged.
+fn pattern_faces(
+    faces: &[Handle<Face>],
+    copies: impl IntoIterator<Item = Option<Transform>>,
+    core: &mut Core,
+) -> Result<Vec<Handle<Face>>, OperationError> {
+    let mut patterned = Vec::new();
+
+    for transform in copies {
+        let Some(transform) = transform else {
+            patterned.extend(faces.iter().cloned());
+            continue;
+        };
+
+        let mut cache = TransformCache::default();
+
+        for face in faces {
+            patterned.push(
+                face.clone()
+                    .transform_with_cache(&transform, core, &mut cache)?,
+            );
+        }
+    }
+
+    Ok(patterned)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        Core,
+        geometry::repr::tri_mesh::convert_point_surface_to_global,
+        operations::{
+            build::{BuildFace, BuildRegion},
+            insert::Insert,
+        },
+        storage::Handle,
+        topology::{Face, Region, Sketch},
+    };
+
+    use super::{CircularPattern, LinearPattern, PatternAxis, PatternRotation};
+
+    #[test]
+    fn pattern_face_in_grid() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let faces = face.linear_pattern(
+            PatternAxis::new([1., 0., 0.], 2., 3),
+            Some(PatternAxis::new([0., 2., 0.], 3., 2)),
+            &mut core,
+        )?;
+
+        assert_eq!(faces.len(), 6);
+        assert_eq!(faces[0].id(), face.id());
+
+        let origins = faces
+            .iter()
+            .map(|face| vertex_positions(face, &core)[0])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            origins,
+            [
+                [0., 0., 0.],
+                [2., 0., 0.],
+                [4., 0., 0.],
+                [0., 3., 0.],
+                [2., 3., 0.],
+                [4., 3., 0.],
+            ]
+            .map(Point::from),
+        );
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn faces_of_pattern_copy_share_surface() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let regions = [[0., 0.], [2., 0.]].map(|[x, y]| {
+            Region::polygon(
+                [[x, y], [x + 1., y], [x + 1., y + 1.], [x, y + 1.]],
+                surface.clone(),
+                &mut core,
+            )
+            .insert(&mut core)
+        });
+        let sketch = Sketch::new(surface, regions).insert(&mut core);
+
+        let faces = sketch.linear_pattern(
+            PatternAxis::new([0., 0., 1.], 1., 2),
+            None,
+            &mut core,
+        )?;
+
+        assert_eq!(faces.len(), 4);
+        assert_eq!(faces[0].surface().id(), sketch.surface().id());
+        assert_eq!(faces[2].surface().id(), faces[3].surface().id());
+        assert_ne!(faces[2].surface().id(), sketch.surface().id());
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn pattern_face_around_axis() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[1.5, -0.5], [2.5, -0.5], [2.5, 0.5], [1.5, 0.5]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let faces = face.circular_pattern(
+            PatternRotation::new([0., 0., 0.], [0., 0., 1.], 6),
+            &mut core,
+        )?;
+        assert_eq!(faces.len(), 6);
+
+        for (i, face) in faces.iter().enumerate() {
+            let angle = Scalar::TAU / 6. * i as f64;
+            let (sin, cos) = angle.sin_cos();
+            let expected = Point::from([cos * 2., sin * 2., Scalar::ZERO]);
+
+            let centroid = centroid(face, &core);
+            assert!(centroid.distance_to(&expected) < Scalar::from(1e-12));
+        }
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn pattern_face_over_partial_revolution() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[1.5, -0.5], [2.5, -0.5], [2.5, 0.5], [1.5, 0.5]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        // Around an axis that doesn't go through the origin.
+        let faces = face.circular_pattern(
+            PatternRotation::new([1., 0., 0.], [0., 0., 1.], 3)
+                .with_total_angle(Scalar::PI),
+            &mut core,
+        )?;
+
+        let centroids = faces
+            .iter()
+            .map(|face| centroid(face, &core))
+            .collect::<Vec<_>>();
+        let expected =
+            [[2., 0., 0.], [1., 1., 0.], [0., 0., 0.]].map(Point::from);
+        for (centroid, expected) in centroids.into_iter().zip(expected) {
+            assert!(centroid.distance_to(&expected) < Scalar::from(1e-12));
+        }
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    fn centroid(face: &Handle<Face>, core: &Core) -> Point<3> {
+        let positions = vertex_positions(face, core);
+        let sum = positions
+            .iter()
+            .fold(Vector::from([0., 0., 0.]), |sum, point| sum + point.coords);
+
+        Point {
+            coords: sum / positions.len() as f64,
+        }
+    }
+
+    fn vertex_positions(face: &Handle<Face>, core: &Core) -> Vec<Point<3>> {
+        let geometry = &core.layers.geometry;
+        let surface_geom = geometry.of_surface_2(face.surface()).unwrap();
+
+        face.region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(face.surface())
+                    .unwrap()
+                    .path;
+                let position = geometry
+                    .of_vertex(half_edge.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position;
+
+                convert_point_surface_to_global(
+                    &surface_geom.generator,
+                    path.point_from_path_coords(position),
+                    0.001,
+                    geometry,
+                )
+            })
+            .collect()
+    }
+}
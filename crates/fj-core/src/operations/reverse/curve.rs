@@ -1,6 +1,9 @@
 use crate::{
     Core,
-    operations::{derive::DeriveFrom, insert::Insert},
+    operations::{
+        OperationError, derive::DeriveFrom, error::local_curve_geom,
+        insert::Insert,
+    },
     storage::Handle,
     topology::{Curve, Surface},
 };
@@ -13,7 +16,7 @@ impl ReverseCurveCoordinateSystems for (&Handle<Curve>, &Handle<Surface>) {
     fn reverse_curve_coordinate_systems(
         self,
         core: &mut Core,
-    ) -> Self::Reversed {
+    ) -> Result<Self::Reversed, OperationError> {
         // We don't *actually* need a surface here, because *all* local
         // definitions need to be reversed in sync anyway. However, then this
         // method couldn't be called in `HalfEdge`'s implementation, meaning the
@@ -31,14 +34,8 @@ impl ReverseCurveCoordinateSystems for (&Handle<Curve>, &Handle<Surface>) {
         // [1]: https://github.com/hannobraun/fornjot/issues/2290
         let (curve, surface) = self;
 
-        let mut curve_geom = core
-            .layers
-            .geometry
-            .of_curve(curve)
-            .unwrap()
-            .local_on(surface)
-            .unwrap()
-            .clone();
+        let mut curve_geom =
+            local_curve_geom(curve, surface, &core.layers.geometry)?.clone();
         curve_geom.path = curve_geom.path.reverse();
 
         let curve = Curve::new().insert(core).derive_from(curve, core);
@@ -49,6 +46,6 @@ impl ReverseCurveCoordinateSystems for (&Handle<Curve>, &Handle<Surface>) {
             curve_geom,
         );
 
-        curve
+        Ok(curve)
     }
 }
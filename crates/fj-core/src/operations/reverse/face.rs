@@ -1,16 +1,24 @@
 use std::borrow::Borrow;
 
+use fj_math::Transform;
+
 use crate::{
     Core,
+    geometry::{
+        SurfaceGeom, repr::tri_mesh::TriMesh, surfaces::TransformedSurface,
+    },
     operations::{
+        OperationError,
         build::Polygon,
         derive::DeriveFrom,
+        error::{local_curve_geom, surface_geom},
         insert::{Insert, IsInsertedNo, IsInsertedYes},
     },
-    topology::Face,
+    storage::Handle,
+    topology::{Face, Surface},
 };
 
-use super::Reverse;
+use super::{Flip, Reverse};
 
 impl Reverse for Face {
     fn reverse(&self, core: &mut Core) -> Self {
@@ -41,3 +49,160 @@ impl<const D: usize> Reverse for Polygon<D, IsInsertedYes> {
         self.replace_face(face)
     }
 }
+
+impl Flip for Handle<Face> {
+    type Flipped = Handle<Face>;
+
+    /// # Flip the face
+    ///
+    /// The face is placed on a flipped copy of its surface, and its cycles are
+    /// reversed. The reversed cycles share their vertices and curves with the
+    /// original ones, so the outline of the face stays the same, while its
+    /// normal points the opposite way.
+    fn flip(&self, core: &mut Core) -> Result<Self::Flipped, OperationError> {
+        let surface = flip_surface(self.surface(), core)?;
+
+        // The flipped surface has the same coordinate system as the original
+        // one. The local definitions of the curves carry over unchanged.
+        for cycle in self.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let local = local_curve_geom(
+                    half_edge.curve(),
+                    self.surface(),
+                    &core.layers.geometry,
+                )?
+                .clone();
+
+                core.layers.geometry.define_curve(
+                    half_edge.curve().clone(),
+                    surface.clone(),
+                    local,
+                );
+            }
+        }
+
+        let region = self
+            .region()
+            .reverse(core)
+            .insert(core)
+            .derive_from(self.region(), core);
+
+        Ok(Face::new(surface, region)
+            .insert(core)
+            .derive_from(self, core))
+    }
+}
+
+fn flip_surface(
+    surface: &Handle<Surface>,
+    core: &mut Core,
+) -> Result<Handle<Surface>, OperationError> {
+    let geometry = *surface_geom(surface, &core.layers.geometry)?;
+    let is_reversed = core
+        .layers
+        .geometry
+        .of_surface_2(surface)
+        .ok_or_else(|| OperationError::MissingSurfaceGeometry {
+            surface: surface.clone(),
+        })?
+        .is_reversed;
+
+    let flipped = Surface::new().insert(core);
+    core.layers
+        .geometry
+        .define_surface(flipped.clone(), geometry);
+    core.layers.geometry.define_surface_2(
+        flipped.clone(),
+        SurfaceGeom {
+            generator: Box::new(TransformedSurface {
+                surface: surface.clone(),
+                transform: Transform::identity(),
+            }),
+            geometry: TriMesh::empty(),
+            is_reversed: !is_reversed,
+        },
+    );
+
+    Ok(flipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use crate::{
+        Core,
+        geometry::repr::tri_mesh::convert_point_surface_to_global,
+        operations::{build::BuildFace, insert::Insert},
+        storage::Handle,
+        topology::Face,
+    };
+
+    use super::Flip;
+
+    #[test]
+    fn flip_square_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let flipped = face.flip(&mut core)?;
+
+        let [normal, flipped_normal] = [&face, &flipped].map(|face| {
+            core.layers
+                .geometry
+                .of_surface_2(face.surface())
+                .unwrap()
+                .normal_at([0.5, 0.5], core.tolerance(), &core.layers.geometry)
+        });
+        assert_eq!(normal, Vector::from([0., 0., 1.]));
+        assert_eq!(flipped_normal, -normal);
+
+        let [mut outline, mut flipped_outline] =
+            [&face, &flipped].map(|face| outline(face, &core));
+        outline.sort();
+        flipped_outline.sort();
+        assert_eq!(outline, flipped_outline);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    fn outline(face: &Handle<Face>, core: &Core) -> Vec<Point<3>> {
+        let geometry = &core.layers.geometry;
+        let surface_geom = geometry.of_surface_2(face.surface()).unwrap();
+
+        face.region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(face.surface())
+                    .unwrap()
+                    .path;
+                let position = geometry
+                    .of_vertex(half_edge.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position;
+
+                convert_point_surface_to_global(
+                    &*surface_geom.generator,
+                    path.point_from_path_coords(position),
+                    core.tolerance(),
+                    geometry,
+                )
+            })
+            .collect()
+    }
+}
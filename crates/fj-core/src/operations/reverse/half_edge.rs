@@ -1,6 +1,12 @@
 use crate::{
     Core,
-    operations::{derive::DeriveFrom, insert::Insert},
+    geometry::LocalVertexGeom,
+    operations::{
+        OperationError,
+        derive::DeriveFrom,
+        error::{local_curve_geom, local_vertex_geom},
+        insert::Insert,
+    },
     storage::Handle,
     topology::{HalfEdge, Surface, Vertex},
 };
@@ -15,28 +21,35 @@ impl ReverseCurveCoordinateSystems
     fn reverse_curve_coordinate_systems(
         self,
         core: &mut Core,
-    ) -> Self::Reversed {
+    ) -> Result<Self::Reversed, OperationError> {
         let (half_edge, end_vertex, surface) = self;
 
-        let vertex_geom_start = core
-            .layers
-            .geometry
-            .of_vertex(half_edge.start_vertex())
-            .unwrap()
-            .local_on(half_edge.curve())
-            .unwrap()
-            .clone();
-        let vertex_geom_end = core
-            .layers
-            .geometry
-            .of_vertex(end_vertex)
-            .unwrap()
-            .local_on(half_edge.curve())
-            .unwrap()
-            .clone();
+        // The vertices stay where they are. But their positions are defined in
+        // curve coordinates, and those change along with the curve.
+        let path = local_curve_geom(
+            half_edge.curve(),
+            surface,
+            &core.layers.geometry,
+        )?
+        .path;
+        let [vertex_geom_start, vertex_geom_end] =
+            [half_edge.start_vertex(), end_vertex].map(|vertex| {
+                let position = local_vertex_geom(
+                    vertex,
+                    half_edge.curve(),
+                    &core.layers.geometry,
+                )?
+                .position;
+
+                Ok(LocalVertexGeom {
+                    position: path.reverse_path_coords(position),
+                })
+            });
+        let [vertex_geom_start, vertex_geom_end] =
+            [vertex_geom_start?, vertex_geom_end?];
 
-        let curve =
-            (half_edge.curve(), surface).reverse_curve_coordinate_systems(core);
+        let curve = (half_edge.curve(), surface)
+            .reverse_curve_coordinate_systems(core)?;
 
         let half_edge = HalfEdge::new(curve, half_edge.start_vertex().clone())
             .insert(core)
@@ -45,14 +58,149 @@ impl ReverseCurveCoordinateSystems
         core.layers.geometry.define_vertex(
             half_edge.start_vertex().clone(),
             half_edge.curve().clone(),
-            vertex_geom_end,
+            vertex_geom_start,
         );
         core.layers.geometry.define_vertex(
             end_vertex.clone(),
             half_edge.curve().clone(),
-            vertex_geom_start,
+            vertex_geom_end,
+        );
+
+        Ok(half_edge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        geometry::{
+            LocalVertexGeom, repr::tri_mesh::convert_point_surface_to_global,
+        },
+        operations::{OperationError, build::BuildHalfEdge, insert::Insert},
+        storage::Handle,
+        topology::{HalfEdge, Surface, Vertex},
+    };
+
+    use super::ReverseCurveCoordinateSystems;
+
+    #[test]
+    fn reverse_arc() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let (half_edge, boundary) = HalfEdge::arc(
+            [1., 0.],
+            [0., 1.],
+            Scalar::PI / 2.,
+            surface.clone(),
+            &mut core,
+        );
+
+        let end_vertex = Vertex::new().insert(&mut core);
+        for (vertex, position) in [half_edge.start_vertex(), &end_vertex]
+            .into_iter()
+            .zip(boundary.inner)
+        {
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                half_edge.curve().clone(),
+                LocalVertexGeom { position },
+            );
+        }
+
+        let before = vertex_positions(&half_edge, &end_vertex, &surface, &core);
+
+        let reversed = (&half_edge, &end_vertex, &surface)
+            .reverse_curve_coordinate_systems(&mut core)?;
+        let after = vertex_positions(&reversed, &end_vertex, &surface, &core);
+
+        // Both vertices are still at the same global positions.
+        let [
+            (start_before, start_before_curve),
+            (end_before, end_before_curve),
+        ] = before;
+        let [
+            (start_after, start_after_curve),
+            (end_after, end_after_curve),
+        ] = after;
+        assert_close(start_before, [1., 0., 0.]);
+        assert_close(end_before, [0., 1., 0.]);
+        assert_close(start_after, start_before);
+        assert_close(end_after, end_before);
+
+        // But their curve coordinates are now in reverse order.
+        assert!(start_before_curve < end_before_curve);
+        assert!(start_after_curve > end_after_curve);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reverse_with_missing_vertex_geometry() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let (half_edge, _) = HalfEdge::line_segment(
+            [[0., 0.], [1., 0.]],
+            surface.clone(),
+            &mut core,
+        );
+
+        // Building a line segment defines the curve, but not the vertices.
+        let end_vertex = Vertex::new().insert(&mut core);
+
+        let result = (&half_edge, &end_vertex, &surface)
+            .reverse_curve_coordinate_systems(&mut core);
+        assert_eq!(
+            result,
+            Err(OperationError::MissingVertexGeometry {
+                vertex: half_edge.start_vertex().clone(),
+                curve: half_edge.curve().clone(),
+            }),
         );
+    }
+
+    fn vertex_positions(
+        half_edge: &Handle<HalfEdge>,
+        end_vertex: &Handle<Vertex>,
+        surface: &Handle<Surface>,
+        core: &Core,
+    ) -> [(Point<3>, Point<1>); 2] {
+        let geometry = &core.layers.geometry;
+        let path = geometry
+            .of_curve(half_edge.curve())
+            .unwrap()
+            .local_on(surface)
+            .unwrap()
+            .path;
 
-        half_edge
+        [half_edge.start_vertex(), end_vertex].map(|vertex| {
+            let position = geometry
+                .of_vertex(vertex)
+                .unwrap()
+                .local_on(half_edge.curve())
+                .unwrap()
+                .position;
+
+            let point_global = convert_point_surface_to_global(
+                &geometry.of_surface_2(surface).unwrap().generator,
+                path.point_from_path_coords(position),
+                0.001,
+                geometry,
+            );
+
+            (point_global, position)
+        })
+    }
+
+    fn assert_close(actual: Point<3>, expected: impl Into<Point<3>>) {
+        let expected = expected.into();
+        assert!(
+            actual.distance_to(&expected) < Scalar::from(1e-12),
+            "Expected {expected:?}, got {actual:?}",
+        );
     }
 }
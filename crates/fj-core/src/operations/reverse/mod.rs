@@ -2,6 +2,8 @@
 
 use crate::Core;
 
+use super::OperationError;
+
 mod curve;
 mod cycle;
 mod face;
@@ -23,9 +25,27 @@ pub trait ReverseCurveCoordinateSystems {
     /// Reverse the direction of the curve coordinate systems within an object
     ///
     /// This will not have any effect on object positions in global coordinates.
-    #[must_use]
+    ///
+    /// Returns an error, if the geometry that needs to be reversed is not
+    /// defined.
     fn reverse_curve_coordinate_systems(
         self,
         core: &mut Core,
-    ) -> Self::Reversed;
+    ) -> Result<Self::Reversed, OperationError>;
+}
+
+/// # Flip the orientation of an object, including that of its surface
+///
+/// Unlike [`Reverse`], which only reverses the orientation of an object
+/// relative to its surface, this also flips the orientation of the surface
+/// itself. The normal of the flipped surface points the opposite way.
+pub trait Flip {
+    /// # The type of the flipped object
+    type Flipped;
+
+    /// # Flip the orientation of the object
+    ///
+    /// Returns an error, if the geometry of the object's surface, or of the
+    /// curves bounding the object, is not defined.
+    fn flip(&self, core: &mut Core) -> Result<Self::Flipped, OperationError>;
 }
@@ -0,0 +1,8 @@
+//! Revolve objects around an axis to create new objects
+//!
+//! Revolves 2D objects around an axis, creating 3D objects. The axis must lie
+//! within the plane of the revolved object.
+
+mod sketch;
+
+pub use self::sketch::{RevolveError, RevolveSketch};
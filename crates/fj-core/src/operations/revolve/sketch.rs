@@ -0,0 +1,1018 @@
+use fj_interop::Color;
+use fj_math::{Circle, Line, Point, Polygon, Scalar, Vector};
+
+use crate::{
+    Core,
+    geometry::{
+        LocalVertexGeom, Path, SurfaceGeom,
+        repr::tri_mesh::TriMesh,
+        surfaces::{Cone, Torus},
+        traits::GenTriMesh,
+    },
+    operations::{
+        build::BuildSurface,
+        error::{
+            OperationError, local_curve_geom, local_vertex_geom, surface_geom,
+        },
+        geometry::UpdateCurveGeometry,
+        insert::Insert,
+        presentation::{GetColor, SetColor},
+    },
+    queries::BoundingVerticesOfHalfEdge,
+    storage::Handle,
+    topology::{
+        Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface,
+        Vertex,
+    },
+};
+
+/// # Revolve a [`Sketch`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait RevolveSketch {
+    /// # Revolve the [`Sketch`]
+    ///
+    /// Revolves the sketch, which is defined on `surface`, around `axis`. The
+    /// `angle` is given in radians and must be within `(0, 2π]`. The sketch is
+    /// revolved in the positive direction, as defined by the right-hand rule
+    /// around the direction of `axis`.
+    ///
+    /// Each region of the sketch results in one shell. Each edge of a region
+    /// results in one face per started quarter turn. If the revolution is
+    /// partial, the shell is closed by two caps, copies of the region at the
+    /// start and end of the revolution.
+    ///
+    /// Line segments that are perpendicular to the axis result in planar faces,
+    /// line segments that are parallel to it in cylindrical ones. All other
+    /// line segments result in conical faces, arcs in toroidal ones.
+    ///
+    /// Requires `surface` to be a plane that contains `axis`, and the sketch to
+    /// be located fully on one side of `axis`, without touching it. Returns an
+    /// error, if that is not the case.
+    ///
+    /// ## Implementation Note
+    ///
+    /// A torus can't intersect itself, so the circle of each arc must not
+    /// touch the axis either, even where the arc itself doesn't reach that far.
+    /// Arcs require `surface` to be orthonormal, as they would turn into
+    /// elliptical arcs otherwise.
+    fn revolve_sketch(
+        &self,
+        surface: Handle<Surface>,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<Solid, RevolveError>;
+}
+
+impl RevolveSketch for Sketch {
+    fn revolve_sketch(
+        &self,
+        surface: Handle<Surface>,
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<Solid, RevolveError> {
+        let angle = angle.into();
+        if angle <= Scalar::ZERO || angle > Scalar::TAU {
+            return Err(RevolveError::InvalidAngle { angle });
+        }
+
+        let surface_geom = *surface_geom(&surface, &core.layers.geometry)?;
+        let Path::Line(surface_u) = surface_geom.u else {
+            return Err(RevolveError::NotPlanar);
+        };
+
+        let normal = surface_u.direction().cross(&surface_geom.v).normalize();
+        let is_in_plane = |point: Point<3>| {
+            (point - surface_u.origin()).dot(&normal).abs()
+                <= core.tolerance().inner()
+        };
+        if !is_in_plane(axis.origin())
+            || !is_in_plane(axis.origin() + axis.direction().normalize())
+        {
+            return Err(RevolveError::AxisNotInPlane);
+        }
+
+        // Let's figure out the local geometry of all half-edges, per cycle, per
+        // region.
+        let regions = self
+            .regions()
+            .iter()
+            .map(|region| {
+                let cycles = region
+                    .all_cycles()
+                    .map(|cycle| {
+                        cycle
+                            .half_edges()
+                            .iter()
+                            .map(|half_edge| {
+                                sketch_edge(
+                                    cycle,
+                                    half_edge,
+                                    self.surface(),
+                                    core,
+                                )
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((region.clone(), cycles))
+            })
+            .collect::<Result<Vec<_>, RevolveError>>()?;
+
+        let to_global = |point: Point<2>| {
+            surface_u.point_from_line_coords([point.u])
+                + surface_geom.v * point.v
+        };
+
+        let Some(&(path, [start, _])) = regions
+            .iter()
+            .flat_map(|(_, cycles)| cycles)
+            .flatten()
+            .next()
+        else {
+            return Ok(Solid::new([]));
+        };
+
+        let frame = AxisFrame::new(
+            axis,
+            normal,
+            to_global(path.point_from_path_coords(start)),
+        );
+
+        let profiles = regions
+            .into_iter()
+            .map(|(region, cycles)| {
+                let cycles = cycles
+                    .into_iter()
+                    .map(|cycle| {
+                        cycle
+                            .into_iter()
+                            .map(|(path, boundary)| {
+                                ProfileEdge::new(
+                                    path,
+                                    boundary,
+                                    |point| {
+                                        frame.profile_point(to_global(point))
+                                    },
+                                    |vector| {
+                                        frame.profile_vector(
+                                            surface_u.direction() * vector.u
+                                                + surface_geom.v * vector.v,
+                                        )
+                                    },
+                                )
+                            })
+                            .collect::<Result<Vec<_>, _>>()
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((region, Profile::new(cycles)?))
+            })
+            .collect::<Result<Vec<_>, RevolveError>>()?;
+
+        // The revolution is split into segments of at most a quarter turn. The
+        // faces that result from revolving a single edge can't form a full
+        // circle, and smaller faces result in better approximations.
+        let num_segments = (angle / (Scalar::TAU / 4.)).ceil().into_u64();
+        let angles = (0..=num_segments)
+            .map(|k| angle * k as f64 / num_segments as f64)
+            .collect::<Vec<_>>();
+        let is_full_revolution = angle == Scalar::TAU;
+
+        let shells = profiles.into_iter().map(|(region, profile)| {
+            revolve_profile(
+                profile,
+                &frame,
+                &angles,
+                is_full_revolution,
+                region.get_color(core),
+                core,
+            )
+            .insert(core)
+        });
+
+        Ok(Solid::new(shells.collect::<Vec<_>>()))
+    }
+}
+
+/// # Error revolving a sketch
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum RevolveError {
+    /// # The angle of the revolution is not within `(0, 2π]`
+    #[error("Revolve angle must be within (0, 2π] radians; got {angle}")]
+    InvalidAngle {
+        /// # The invalid angle
+        angle: Scalar,
+    },
+
+    /// # The surface of the sketch is not a plane
+    #[error("Revolving a sketch is only supported from a plane")]
+    NotPlanar,
+
+    /// # The axis of revolution does not lie within the plane of the sketch
+    #[error("Axis of revolution must lie within the plane of the sketch")]
+    AxisNotInPlane,
+
+    /// # The sketch touches or crosses the axis of revolution
+    #[error("Sketch must not touch or cross the axis of revolution")]
+    TouchesAxis,
+
+    /// # The circle of an arc touches or crosses the axis of revolution
+    #[error(
+        "The circle of an arc must not touch or cross the axis of revolution, \
+        as that would result in a self-intersecting torus"
+    )]
+    ArcCircleTouchesAxis,
+
+    /// # An arc would turn into an elliptical arc
+    ///
+    /// This happens, if the plane of the sketch is not orthonormal.
+    #[error(
+        "Revolving arcs from a plane that is not orthonormal is not supported"
+    )]
+    EllipticalArc,
+
+    /// # The geometry of an object is not available
+    #[error(transparent)]
+    Operation(#[from] OperationError),
+}
+
+/// Access the local geometry of a half-edge of a sketch
+///
+/// Returns the path of the half-edge, and the positions of its bounding
+/// vertices on that path.
+fn sketch_edge(
+    cycle: &Cycle,
+    half_edge: &Handle<HalfEdge>,
+    surface: &Handle<Surface>,
+    core: &Core,
+) -> Result<(Path<2>, [Point<1>; 2]), OperationError> {
+    let geometry = &core.layers.geometry;
+
+    let path = local_curve_geom(half_edge.curve(), surface, geometry)?.path;
+    let vertices = cycle
+        .bounding_vertices_of_half_edge(half_edge)
+        .expect("Half-edge is part of the cycle it was taken from");
+    let [start, end] = vertices
+        .inner
+        .each_ref()
+        .map(|vertex| local_vertex_geom(vertex, half_edge.curve(), geometry));
+
+    Ok((path, [start?.position, end?.position]))
+}
+
+/// A coordinate system that is aligned with the axis of revolution
+struct AxisFrame {
+    origin: Point<3>,
+    axis: Vector<3>,
+    radial: Vector<3>,
+    tangential: Vector<3>,
+}
+
+impl AxisFrame {
+    fn new(axis: Line<3>, normal: Vector<3>, point: Point<3>) -> Self {
+        let origin = axis.origin();
+        let axis = axis.direction().normalize();
+
+        // Choose the radial direction, such that the sketch is located in its
+        // positive direction.
+        let radial = axis.cross(&normal).normalize();
+        let radial = if (point - origin).dot(&radial) < Scalar::ZERO {
+            -radial
+        } else {
+            radial
+        };
+        let tangential = axis.cross(&radial);
+
+        Self {
+            origin,
+            axis,
+            radial,
+            tangential,
+        }
+    }
+
+    /// Convert a point into profile coordinates, radius and height
+    fn profile_point(&self, point: Point<3>) -> Point<2> {
+        Point::origin() + self.profile_vector(point - self.origin)
+    }
+
+    /// Convert a vector into profile coordinates, radius and height
+    fn profile_vector(&self, vector: Vector<3>) -> Vector<2> {
+        Vector::from([vector.dot(&self.radial), vector.dot(&self.axis)])
+    }
+
+    /// Compute the radial direction at the provided angle of revolution
+    fn radial_at(&self, angle: Scalar) -> Vector<3> {
+        let (sin, cos) = angle.sin_cos();
+        self.radial * cos + self.tangential * sin
+    }
+}
+
+/// The profile of a region, in radius and height coordinates
+///
+/// The exterior cycle is always counter-clockwise, the interior cycles are
+/// always clockwise. This makes the orientation of the revolved faces
+/// independent of the orientation of the sketch's surface.
+struct Profile {
+    cycles: Vec<Vec<ProfileEdge>>,
+}
+
+impl Profile {
+    fn new(mut cycles: Vec<Vec<ProfileEdge>>) -> Result<Self, RevolveError> {
+        for edge in cycles.iter().flatten() {
+            if edge.start.u <= Scalar::ZERO {
+                return Err(RevolveError::TouchesAxis);
+            }
+            if let Some(arc) = &edge.arc
+                && arc.radius >= arc.center.u
+            {
+                return Err(RevolveError::ArcCircleTouchesAxis);
+            }
+        }
+
+        // Arcs are represented by a few points each. That's not a precise
+        // approximation, but enough to tell the winding of the cycle.
+        let exterior = cycles[0]
+            .iter()
+            .flat_map(|edge| {
+                let [start, end] = edge.curve_coords();
+                (0..4).map(move |i| {
+                    edge.point_at(start + (end - start) * (i as f64 / 4.))
+                })
+            })
+            .collect::<Vec<_>>();
+        if Polygon::from_points(exterior).signed_area() < Scalar::ZERO {
+            for cycle in &mut cycles {
+                cycle.reverse();
+                for edge in cycle {
+                    edge.reverse();
+                }
+            }
+        }
+
+        Ok(Self { cycles })
+    }
+}
+
+/// An edge of a profile, in radius and height coordinates
+struct ProfileEdge {
+    start: Point<2>,
+    end: Point<2>,
+
+    /// The arc that the edge follows, if it isn't a line segment
+    arc: Option<Arc>,
+}
+
+impl ProfileEdge {
+    fn new(
+        path: Path<2>,
+        boundary: [Point<1>; 2],
+        profile_point: impl Fn(Point<2>) -> Point<2>,
+        profile_vector: impl Fn(Vector<2>) -> Vector<2>,
+    ) -> Result<Self, RevolveError> {
+        let circle = match path {
+            Path::Line(_) => {
+                let [start, end] = boundary.map(|point| {
+                    profile_point(path.point_from_path_coords(point))
+                });
+                return Ok(Self {
+                    start,
+                    end,
+                    arc: None,
+                });
+            }
+            Path::Circle(circle) => circle,
+        };
+
+        let [a, b] = [circle.a(), circle.b()].map(profile_vector);
+        let radius = a.magnitude();
+
+        let epsilon = radius * 1e-9;
+        if (b.magnitude() - radius).abs() > epsilon
+            || a.dot(&b).abs() > epsilon * radius
+        {
+            return Err(RevolveError::EllipticalArc);
+        }
+
+        // Angles in profile coordinates are measured from the radial
+        // direction towards the axis. The circle might run the other way.
+        let direction = if a.cross2d(&b) > Scalar::ZERO {
+            Scalar::ONE
+        } else {
+            -Scalar::ONE
+        };
+        let angle_of_a = a.v.atan2(a.u);
+        let [start_angle, end_angle] =
+            boundary.map(|point| angle_of_a + point.t * direction);
+
+        let arc = Arc {
+            center: profile_point(circle.center()),
+            radius,
+            start_angle,
+            end_angle,
+        };
+
+        Ok(Self {
+            start: arc.point_at(start_angle),
+            end: arc.point_at(end_angle),
+            arc: Some(arc),
+        })
+    }
+
+    /// The coordinates of the start and end of the edge on its curve
+    ///
+    /// Those are the angles of an arc, or zero and one for a line segment.
+    fn curve_coords(&self) -> [Scalar; 2] {
+        match &self.arc {
+            Some(arc) => [arc.start_angle, arc.end_angle],
+            None => [Scalar::ZERO, Scalar::ONE],
+        }
+    }
+
+    fn point_at(&self, coord: Scalar) -> Point<2> {
+        match &self.arc {
+            Some(arc) => arc.point_at(coord),
+            None => self.start + (self.end - self.start) * coord,
+        }
+    }
+
+    /// The path of the edge on a cap, in profile coordinates
+    fn path(&self) -> Path<2> {
+        match &self.arc {
+            Some(arc) => Path::Circle(Circle::from_center_and_radius(
+                arc.center, arc.radius,
+            )),
+            None => Path::line_from_points_with_coords([
+                ([0.], self.start),
+                ([1.], self.end),
+            ]),
+        }
+    }
+
+    fn reverse(&mut self) {
+        std::mem::swap(&mut self.start, &mut self.end);
+        if let Some(arc) = &mut self.arc {
+            std::mem::swap(&mut arc.start_angle, &mut arc.end_angle);
+        }
+    }
+}
+
+/// An arc in profile coordinates
+///
+/// Angles are measured from the radial direction towards the axis.
+struct Arc {
+    center: Point<2>,
+    radius: Scalar,
+    start_angle: Scalar,
+    end_angle: Scalar,
+}
+
+impl Arc {
+    fn point_at(&self, angle: Scalar) -> Point<2> {
+        let (sin, cos) = angle.sin_cos();
+        self.center + Vector::from([cos, sin]) * self.radius
+    }
+}
+
+fn revolve_profile(
+    profile: Profile,
+    frame: &AxisFrame,
+    angles: &[Scalar],
+    is_full_revolution: bool,
+    color: Option<Color>,
+    core: &mut Core,
+) -> Shell {
+    let num_segments = angles.len() - 1;
+
+    // All of the following are indexed by cycle, then by vertex (or edge) of
+    // the cycle, then by the boundary (or segment) of the revolution. If the
+    // revolution is full, the objects at the last boundary are the same as
+    // those at the first.
+    let mut vertices = Vec::new();
+    let mut arcs = Vec::new();
+    let mut profile_curves = Vec::new();
+
+    for cycle in &profile.cycles {
+        let cycle_vertices = cycle
+            .iter()
+            .map(|_| {
+                let mut vertices = Vec::<Handle<Vertex>>::new();
+                for k in 0..=num_segments {
+                    let vertex = if is_full_revolution && k == num_segments {
+                        vertices[0].clone()
+                    } else {
+                        Vertex::new().insert(core)
+                    };
+                    vertices.push(vertex);
+                }
+                vertices
+            })
+            .collect::<Vec<_>>();
+
+        let cycle_arcs = cycle_vertices
+            .iter()
+            .map(|vertices| {
+                (0..num_segments)
+                    .map(|k| {
+                        let curve = Curve::new().insert(core);
+                        for i in [k, k + 1] {
+                            core.layers.geometry.define_vertex(
+                                vertices[i].clone(),
+                                curve.clone(),
+                                LocalVertexGeom {
+                                    position: Point::from([angles[i]]),
+                                },
+                            );
+                        }
+                        curve
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let cycle_profile_curves = (0..cycle.len())
+            .map(|e| {
+                let [a, b] = [e, (e + 1) % cycle.len()];
+                let [coord_a, coord_b] = cycle[e].curve_coords();
+
+                let mut curves = Vec::<Handle<Curve>>::new();
+                for k in 0..=num_segments {
+                    if is_full_revolution && k == num_segments {
+                        curves.push(curves[0].clone());
+                        continue;
+                    }
+
+                    let curve = Curve::new().insert(core);
+                    for (i, position) in [(a, coord_a), (b, coord_b)] {
+                        core.layers.geometry.define_vertex(
+                            cycle_vertices[i][k].clone(),
+                            curve.clone(),
+                            LocalVertexGeom {
+                                position: Point::from([position]),
+                            },
+                        );
+                    }
+                    curves.push(curve);
+                }
+                curves
+            })
+            .collect::<Vec<_>>();
+
+        vertices.push(cycle_vertices);
+        arcs.push(cycle_arcs);
+        profile_curves.push(cycle_profile_curves);
+    }
+
+    let mut faces = Vec::new();
+
+    for (c, cycle) in profile.cycles.iter().enumerate() {
+        for e in 0..cycle.len() {
+            let [a, b] = [e, (e + 1) % cycle.len()];
+
+            for k in 0..num_segments {
+                let (surface, paths) = side_surface(
+                    &cycle[e],
+                    [angles[k], angles[k + 1]],
+                    frame,
+                    core,
+                );
+
+                let half_edges = [
+                    (&vertices[c][a][k], &arcs[c][a][k]),
+                    (&vertices[c][a][k + 1], &profile_curves[c][e][k + 1]),
+                    (&vertices[c][b][k + 1], &arcs[c][b][k]),
+                    (&vertices[c][b][k], &profile_curves[c][e][k]),
+                ];
+
+                for ((_, curve), path) in half_edges.iter().zip(paths) {
+                    (*curve).clone().make_path_on_surface(
+                        path,
+                        surface.clone(),
+                        &mut core.layers.geometry,
+                    );
+                }
+
+                let exterior = build_cycle(half_edges, core);
+                faces.push(build_face(surface, exterior, [], color, core));
+            }
+        }
+    }
+
+    if !is_full_revolution {
+        for (k, is_end) in [(0, false), (num_segments, true)] {
+            // The profile is counter-clockwise, when viewed from the side that
+            // the revolution moves away from. This is the right orientation
+            // for the start cap, but the end cap must face the other way.
+            let surface = Surface::from_uv(
+                Path::Line(Line::from_origin_and_direction(
+                    frame.origin,
+                    frame.radial_at(angles[k]),
+                )),
+                frame.axis,
+                core,
+            );
+
+            let mut cycles = profile
+                .cycles
+                .iter()
+                .enumerate()
+                .map(|(c, cycle)| {
+                    let mut half_edges = (0..cycle.len())
+                        .map(|e| {
+                            let [a, b] = [e, (e + 1) % cycle.len()];
+
+                            let curve = &profile_curves[c][e][k];
+                            curve.clone().make_path_on_surface(
+                                cycle[e].path(),
+                                surface.clone(),
+                                &mut core.layers.geometry,
+                            );
+
+                            let start = if is_end { b } else { a };
+                            (&vertices[c][start][k], curve)
+                        })
+                        .collect::<Vec<_>>();
+
+                    if is_end {
+                        half_edges.reverse();
+                    }
+
+                    build_cycle(half_edges, core)
+                })
+                .collect::<Vec<_>>();
+
+            let exterior = cycles.remove(0);
+            faces.push(build_face(surface, exterior, cycles, color, core));
+        }
+    }
+
+    Shell::new(faces)
+}
+
+/// Build the surface that results from revolving a profile edge
+///
+/// Also returns the paths of the face's boundary on that surface: the arc at
+/// the start of the edge, the edge at the end of the segment, the arc at the
+/// end of the edge, and the edge at the start of the segment.
+fn side_surface(
+    edge: &ProfileEdge,
+    [angle_start, angle_end]: [Scalar; 2],
+    frame: &AxisFrame,
+    core: &mut Core,
+) -> (Handle<Surface>, [Path<2>; 4]) {
+    let [a, b] = [edge.start, edge.end];
+
+    if let Some(arc) = &edge.arc {
+        // The edge is an arc. Its revolution is part of a torus, whose surface
+        // coordinates are the angle of revolution and the angle of the arc.
+        let surface = surface_from_generator(
+            Torus::new(
+                frame.origin + frame.axis * arc.center.v,
+                frame.axis,
+                arc.center.u,
+                arc.radius,
+                frame.radial,
+            ),
+            core,
+        );
+
+        let paths = side_paths(
+            |angle, coord| Point::from([angle, coord]),
+            edge.curve_coords(),
+            [angle_start, angle_end],
+        );
+
+        (surface, paths)
+    } else if a.v == b.v {
+        // The edge is perpendicular to the axis. Its revolution is part of an
+        // annulus on a plane.
+        let surface = Surface::from_uv(
+            Path::Line(Line::from_origin_and_direction(
+                frame.origin + frame.axis * a.v,
+                frame.radial,
+            )),
+            frame.tangential,
+            core,
+        );
+
+        let point_at = |radius: Scalar, angle: Scalar| {
+            let (sin, cos) = angle.sin_cos();
+            Point::from([radius * cos, radius * sin])
+        };
+        let edge_at = |angle| {
+            Path::line_from_points_with_coords([
+                ([0.], point_at(a.u, angle)),
+                ([1.], point_at(b.u, angle)),
+            ])
+        };
+
+        let paths = [
+            Path::circle_from_radius(a.u),
+            edge_at(angle_end),
+            Path::circle_from_radius(b.u),
+            edge_at(angle_start),
+        ];
+
+        (surface, paths)
+    } else if a.u == b.u {
+        // The edge is parallel to the axis. Its revolution is part of a
+        // cylinder.
+        let radius = a.u;
+        let surface = Surface::from_uv(
+            Path::Circle(Circle::new(
+                frame.origin + frame.axis * a.v,
+                frame.radial * radius,
+                frame.tangential * radius,
+            )),
+            frame.axis * (b.v - a.v),
+            core,
+        );
+
+        let paths = side_paths(
+            |angle, coord| Point::from([angle, coord]),
+            edge.curve_coords(),
+            [angle_start, angle_end],
+        );
+
+        (surface, paths)
+    } else {
+        // The edge is inclined towards the axis. Its revolution is part of a
+        // cone. The axis of the cone points towards where it gets wider, which
+        // might be the opposite direction of the axis of revolution. Then, the
+        // surface coordinates run the other way.
+        let slope = (b.u - a.u) / (b.v - a.v);
+        let sign = slope.sign().to_scalar();
+        let apex = a.v - a.u / slope;
+
+        let surface = surface_from_generator(
+            Cone::new(
+                frame.origin + frame.axis * apex,
+                frame.axis * sign,
+                (b.u - a.u).abs().atan2((b.v - a.v).abs()),
+                frame.radial,
+            ),
+            core,
+        );
+
+        let paths = side_paths(
+            |angle, coord| {
+                let height = a.v + (b.v - a.v) * coord;
+                Point::from([angle * sign, (height - apex) * sign])
+            },
+            edge.curve_coords(),
+            [angle_start, angle_end],
+        );
+
+        (surface, paths)
+    }
+}
+
+/// Build the boundary paths of a face on a curved side surface
+///
+/// `point_at` converts an angle of revolution and a coordinate on the profile
+/// edge into surface coordinates. Both are expected to map to straight lines
+/// in surface coordinates.
+fn side_paths(
+    point_at: impl Fn(Scalar, Scalar) -> Point<2>,
+    [coord_start, coord_end]: [Scalar; 2],
+    [angle_start, angle_end]: [Scalar; 2],
+) -> [Path<2>; 4] {
+    let arc_at = |coord: Scalar| {
+        Path::line_from_points_with_coords([
+            ([angle_start], point_at(angle_start, coord)),
+            ([angle_end], point_at(angle_end, coord)),
+        ])
+    };
+    let edge_at = |angle: Scalar| {
+        Path::line_from_points_with_coords([
+            ([coord_start], point_at(angle, coord_start)),
+            ([coord_end], point_at(angle, coord_end)),
+        ])
+    };
+
+    [
+        arc_at(coord_start),
+        edge_at(angle_end),
+        arc_at(coord_end),
+        edge_at(angle_start),
+    ]
+}
+
+fn surface_from_generator(
+    generator: impl GenTriMesh + Send + Sync + 'static,
+    core: &mut Core,
+) -> Handle<Surface> {
+    let surface = Surface::new().insert(core);
+    core.layers.geometry.define_surface_2(
+        surface.clone(),
+        SurfaceGeom {
+            generator: Box::new(generator),
+            geometry: TriMesh::empty(),
+            is_reversed: false,
+        },
+    );
+
+    surface
+}
+
+fn build_cycle<'r>(
+    half_edges: impl IntoIterator<Item = (&'r Handle<Vertex>, &'r Handle<Curve>)>,
+    core: &mut Core,
+) -> Handle<Cycle> {
+    let half_edges = half_edges
+        .into_iter()
+        .map(|(start_vertex, curve)| {
+            HalfEdge::new(curve.clone(), start_vertex.clone()).insert(core)
+        })
+        .collect::<Vec<_>>();
+
+    Cycle::new(half_edges).insert(core)
+}
+
+fn build_face(
+    surface: Handle<Surface>,
+    exterior: Handle<Cycle>,
+    interiors: impl IntoIterator<Item = Handle<Cycle>>,
+    color: Option<Color>,
+    core: &mut Core,
+) -> Handle<Face> {
+    let region = Region::new(exterior, interiors).insert(core);
+
+    if let Some(color) = color {
+        region.set_color(color, core);
+    }
+
+    Face::new(surface, region).insert(core)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        Core,
+        operations::{build::BuildSketch, insert::Insert},
+        topology::Sketch,
+    };
+
+    use super::{RevolveError, RevolveSketch};
+
+    fn axis() -> Line<3> {
+        Line::from_origin_and_direction(Point::origin(), Vector::unit_z())
+    }
+
+    #[test]
+    fn revolve_rectangle_into_cylindrical_shell() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The radii are chosen such that the circles' approximations at the
+        // default validation tolerance have points at each quarter turn. The
+        // conversion of points on curved surfaces into global coordinates is
+        // only exact at those points, so vertices of the cylindrical faces
+        // wouldn't coincide with those of the planar faces otherwise.
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let solid = Sketch::polygon(
+            [[2., 0.], [2.5, 0.], [2.5, 1.], [2., 1.]],
+            &mut core,
+        )
+        .revolve_sketch(surface, axis(), Scalar::TAU, &mut core)?
+        .insert(&mut core);
+
+        // Inner and outer cylinder, plus top and bottom annulus, each made up
+        // of four quarters.
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 16);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_rectangle_partially() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let solid = Sketch::polygon(
+            [[2., 0.], [2.5, 0.], [2.5, 1.], [2., 1.]],
+            &mut core,
+        )
+        .revolve_sketch(surface, axis(), Scalar::PI / 2., &mut core)?
+        .insert(&mut core);
+
+        // One quarter of each of the four revolved edges, plus two caps.
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_trapezoid_into_conical_shell() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let solid = Sketch::polygon(
+            [[2., 0.], [3., 0.], [2.5, 1.], [2., 1.]],
+            &mut core,
+        )
+        .revolve_sketch(surface, axis(), Scalar::PI, &mut core)?
+        .insert(&mut core);
+
+        // Two quarters of each of the four revolved edges, one of them
+        // resulting in a cone, plus two caps.
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 10);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_circle_into_torus() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let solid = Sketch::circle([3., 0.], 1., &mut core)
+            .revolve_sketch(surface, axis(), Scalar::TAU, &mut core)?
+            .insert(&mut core);
+
+        // The circle is made up of four arcs, each resulting in four quarters
+        // of a torus.
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 16);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_circle_partially() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let solid = Sketch::circle([3., 1.], 1., &mut core)
+            .revolve_sketch(surface, axis(), Scalar::PI / 2., &mut core)?
+            .insert(&mut core);
+
+        // One quarter of each of the four revolved arcs, plus two caps.
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn return_error_on_invalid_input() {
+        let mut core = Core::new();
+
+        let xz_plane = core.layers.topology.surfaces.xz_plane();
+        let xy_plane = core.layers.topology.surfaces.xy_plane();
+        let rectangle = |core: &mut Core| {
+            Sketch::polygon([[1., 0.], [2., 0.], [2., 1.], [1., 1.]], core)
+        };
+
+        let result = rectangle(&mut core).revolve_sketch(
+            xz_plane.clone(),
+            axis(),
+            Scalar::ZERO,
+            &mut core,
+        );
+        assert!(matches!(result, Err(RevolveError::InvalidAngle { .. })));
+
+        let result = rectangle(&mut core).revolve_sketch(
+            xy_plane,
+            axis(),
+            Scalar::TAU,
+            &mut core,
+        );
+        assert!(matches!(result, Err(RevolveError::AxisNotInPlane)));
+
+        let result = Sketch::polygon(
+            [[-1., 0.], [1., 0.], [1., 1.], [-1., 1.]],
+            &mut core,
+        )
+        .revolve_sketch(
+            xz_plane.clone(),
+            axis(),
+            Scalar::TAU,
+            &mut core,
+        );
+        assert!(matches!(result, Err(RevolveError::TouchesAxis)));
+
+        let result = Sketch::circle([1., 0.], 1.5, &mut core).revolve_sketch(
+            xz_plane,
+            axis(),
+            Scalar::TAU,
+            &mut core,
+        );
+        assert!(matches!(result, Err(RevolveError::ArcCircleTouchesAxis)));
+    }
+}
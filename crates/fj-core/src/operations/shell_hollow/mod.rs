@@ -0,0 +1,10 @@
+//! # Hollow out a shell, to create a thin-walled part
+//!
+//! Hollowing offsets the faces of a shell inwards, by the wall thickness, and
+//! connects the resulting inner shell to the outer one at the openings. So far,
+//! only polyhedral shells are supported, meaning all faces must be planar and
+//! bounded by straight edges.
+
+mod shell;
+
+pub use self::shell::{Hollow, HollowError};
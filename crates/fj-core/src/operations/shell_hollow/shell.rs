@@ -0,0 +1,495 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{
+    Core,
+    geometry::{Geometry, Path, surfaces::SweptCurve},
+    operations::build::BuildShell,
+    storage::Handle,
+    topology::{Face, Shell, Vertex},
+};
+
+/// # Hollow out a [`Shell`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: super
+pub trait Hollow {
+    /// # Hollow out the shell, leaving walls of the provided thickness
+    ///
+    /// Every face of the shell, except those listed in `openings`, is offset
+    /// inwards by `thickness`, along its normal. Each vertex of the inner shell
+    /// is placed at the intersection of the offset planes of the faces that
+    /// meet at the original vertex. The faces listed in `openings` are not
+    /// offset, and are replaced by rims that connect the outer shell to the
+    /// inner one.
+    ///
+    /// The returned shell consists of the outer faces that are not openings,
+    /// their inner counterparts (which face the other way), and the rims.
+    ///
+    /// ## Thickness Exceeding the Local Feature Size
+    ///
+    /// If the thickness exceeds the local feature size of the shell (for
+    /// example, half the width of a narrow section), offset faces collapse or
+    /// turn inside out. Where that is detected for a single face, because its
+    /// inner counterpart or its rim would face the wrong way, this method
+    /// returns [`HollowError::ThicknessTooLarge`].
+    ///
+    /// Offset faces that don't share any vertices can still end up
+    /// intersecting each other, without either of them being inverted. This is
+    /// not detected, and results in a self-intersecting shell.
+    ///
+    /// ## Implementation Note
+    ///
+    /// Openings that share an edge are not supported, as the rims that would
+    /// connect them at that edge degenerate.
+    fn hollow(
+        &self,
+        thickness: impl Into<Scalar>,
+        openings: &[Handle<Face>],
+        core: &mut Core,
+    ) -> Result<Shell, HollowError>;
+}
+
+impl Hollow for Shell {
+    fn hollow(
+        &self,
+        thickness: impl Into<Scalar>,
+        openings: &[Handle<Face>],
+        core: &mut Core,
+    ) -> Result<Shell, HollowError> {
+        let thickness = thickness.into();
+        if thickness <= Scalar::ZERO {
+            return Err(HollowError::InvalidThickness { thickness });
+        }
+        for opening in openings {
+            if !self.faces().contains(opening) {
+                return Err(HollowError::UnknownOpening);
+            }
+        }
+
+        let polyhedron = Polyhedron::new(self, &core.layers.geometry)?;
+        let is_opening = self
+            .faces()
+            .iter()
+            .map(|face| openings.contains(face))
+            .collect::<Vec<_>>();
+
+        let mut edges = BTreeMap::new();
+        for (polygon, &is_opening) in
+            polyhedron.polygons.iter().zip(&is_opening)
+        {
+            for edge in edges_of(polygon) {
+                let [a, b] = edge;
+                if is_opening && edges.contains_key(&[b, a]) {
+                    return Err(HollowError::AdjacentOpenings);
+                }
+                edges.insert(edge, is_opening);
+            }
+        }
+
+        // The planes of the faces, with the normals pointing outwards. The
+        // planes of the inner shell are those of the faces that aren't
+        // openings, moved inwards.
+        let planes = polyhedron.planes();
+        let inner_planes = planes
+            .iter()
+            .zip(&is_opening)
+            .map(|(&(normal, distance), &is_opening)| {
+                if is_opening {
+                    (normal, distance)
+                } else {
+                    (normal, distance - thickness)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut faces_at_vertex = vec![Vec::new(); polyhedron.points.len()];
+        for (i, polygon) in polyhedron.polygons.iter().enumerate() {
+            for &vertex in polygon {
+                faces_at_vertex[vertex].push(i);
+            }
+        }
+
+        let inner_points = polyhedron
+            .points
+            .iter()
+            .zip(&faces_at_vertex)
+            .map(|(&point, faces)| {
+                let planes =
+                    faces.iter().map(|&i| inner_planes[i]).collect::<Vec<_>>();
+                intersect_planes(&planes, point)
+            })
+            .collect::<Vec<_>>();
+
+        let n = polyhedron.points.len();
+        let mut polygons = Vec::new();
+
+        for ((polygon, &is_opening), &(normal, _)) in
+            polyhedron.polygons.iter().zip(&is_opening).zip(&planes)
+        {
+            if is_opening {
+                for [a, b] in edges_of(polygon) {
+                    let rim = [a, b, n + b, n + a];
+                    let points = rim.map(|i| {
+                        if i < n {
+                            polyhedron.points[i]
+                        } else {
+                            inner_points[i - n]
+                        }
+                    });
+                    if newell_normal(&points).dot(&normal) <= Scalar::ZERO {
+                        return Err(HollowError::ThicknessTooLarge {
+                            thickness,
+                        });
+                    }
+
+                    polygons.push(rim.to_vec());
+                }
+            } else {
+                let inner = polygon.iter().rev().map(|&i| n + i).collect();
+                let points = polygon
+                    .iter()
+                    .map(|&i| inner_points[i])
+                    .collect::<Vec<_>>();
+                if newell_normal(&points).dot(&normal) <= Scalar::ZERO {
+                    return Err(HollowError::ThicknessTooLarge { thickness });
+                }
+
+                polygons.push(polygon.clone());
+                polygons.push(inner);
+            }
+        }
+
+        let vertices = polyhedron.points.into_iter().chain(inner_points);
+
+        Ok(Shell::from_vertices_and_polygons(vertices, polygons, core))
+    }
+}
+
+/// # Error hollowing out a shell
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum HollowError {
+    /// # The wall thickness is not positive
+    #[error("Wall thickness must be positive; got {thickness}")]
+    InvalidThickness {
+        /// # The invalid thickness
+        thickness: Scalar,
+    },
+
+    /// # A face listed as an opening is not part of the shell
+    #[error("Opening is not a face of the shell")]
+    UnknownOpening,
+
+    /// # Two openings share an edge
+    #[error("Openings that share an edge are not supported")]
+    AdjacentOpenings,
+
+    /// # A face of the shell is not a plane
+    #[error("Can only hollow shells with planar faces")]
+    NotPlanar,
+
+    /// # An edge of the shell is not straight
+    #[error("Can only hollow shells with straight edges")]
+    CurvedEdge,
+
+    /// # A face of the shell has holes
+    #[error("Hollowing shells with faces that have holes is not supported")]
+    FaceHasHoles,
+
+    /// # The thickness exceeds the local feature size
+    #[error("Wall thickness {thickness} exceeds the local feature size")]
+    ThicknessTooLarge {
+        /// # The thickness that was too large
+        thickness: Scalar,
+    },
+}
+
+/// # A shell, as a set of points and the polygons that connect them
+struct Polyhedron {
+    points: Vec<Point<3>>,
+    polygons: Vec<Vec<usize>>,
+}
+
+impl Polyhedron {
+    fn new(shell: &Shell, geometry: &Geometry) -> Result<Self, HollowError> {
+        let mut indices = BTreeMap::<Handle<Vertex>, usize>::new();
+        let mut points = Vec::new();
+        let mut polygons = Vec::new();
+
+        for face in shell.faces() {
+            let Some(
+                surface @ SweptCurve {
+                    u: Path::Line(_), ..
+                },
+            ) = geometry.of_surface_if_defined(face.surface())
+            else {
+                return Err(HollowError::NotPlanar);
+            };
+            if !face.region().interiors().is_empty() {
+                return Err(HollowError::FaceHasHoles);
+            }
+
+            let mut polygon = Vec::new();
+
+            for half_edge in face.region().exterior().half_edges() {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .and_then(|curve| curve.local_on(face.surface()))
+                    .expect("Expected geometry of half-edge's curve")
+                    .path;
+                let Path::Line(_) = path else {
+                    return Err(HollowError::CurvedEdge);
+                };
+
+                let index = match indices.get(half_edge.start_vertex()) {
+                    Some(&index) => index,
+                    None => {
+                        let position = geometry
+                            .of_vertex(half_edge.start_vertex())
+                            .and_then(|vertex| {
+                                vertex.local_on(half_edge.curve())
+                            })
+                            .expect("Expected geometry of half-edge's vertex")
+                            .position;
+                        let point_surface =
+                            path.point_from_path_coords(position);
+                        let point =
+                            surface.u.point_from_path_coords([point_surface.u])
+                                + surface.v * point_surface.v;
+
+                        let index = points.len();
+                        points.push(point);
+                        indices.insert(half_edge.start_vertex().clone(), index);
+
+                        index
+                    }
+                };
+
+                polygon.push(index);
+            }
+
+            polygons.push(polygon);
+        }
+
+        Ok(Self { points, polygons })
+    }
+
+    /// # Compute the plane of each polygon, with the normal pointing outwards
+    ///
+    /// Returns the unit normal and the distance from the origin along it.
+    fn planes(&self) -> Vec<(Vector<3>, Scalar)> {
+        let normals = self
+            .polygons
+            .iter()
+            .map(|polygon| {
+                let points =
+                    polygon.iter().map(|&i| self.points[i]).collect::<Vec<_>>();
+                newell_normal(&points)
+            })
+            .collect::<Vec<_>>();
+
+        // If the polygons are consistently oriented, but facing inwards, the
+        // signed volume is negative.
+        let volume = self
+            .polygons
+            .iter()
+            .zip(&normals)
+            .map(|(polygon, normal)| self.points[polygon[0]].coords.dot(normal))
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+        let orientation = if volume < Scalar::ZERO {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+
+        self.polygons
+            .iter()
+            .zip(normals)
+            .map(|(polygon, normal)| {
+                let normal = normal.normalize() * orientation;
+                (normal, self.points[polygon[0]].coords.dot(&normal))
+            })
+            .collect()
+    }
+}
+
+fn edges_of(polygon: &[usize]) -> impl Iterator<Item = [usize; 2]> {
+    polygon
+        .iter()
+        .zip(polygon.iter().cycle().skip(1))
+        .map(|(&a, &b)| [a, b])
+}
+
+/// # Compute the normal of a polygon, scaled by twice its area
+fn newell_normal(points: &[Point<3>]) -> Vector<3> {
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .fold(Vector::from([0., 0., 0.]), |normal, (p, q)| {
+            normal + p.coords.cross(&q.coords)
+        })
+}
+
+/// # Find the point that lies on all of the provided planes
+///
+/// Planes are provided as unit normal and distance from the origin. Planes with
+/// parallel normals are only considered once. If the remaining planes don't
+/// determine a single point, the point closest to `near` is returned.
+fn intersect_planes(
+    planes: &[(Vector<3>, Scalar)],
+    near: Point<3>,
+) -> Point<3> {
+    let mut distinct: Vec<(Vector<3>, Scalar)> = Vec::new();
+    for &(normal, distance) in planes {
+        let is_parallel = distinct.iter().any(|(other, _)| {
+            normal.dot(other).abs() > Scalar::ONE - Scalar::from(1e-9)
+        });
+        if !is_parallel {
+            distinct.push((normal, distance));
+        }
+    }
+
+    match distinct.as_slice() {
+        [] => near,
+        [(normal, distance)] => {
+            near + *normal * (*distance - normal.dot(&near.coords))
+        }
+        [(a, _), (b, _)] => {
+            let direction = a.cross(b).normalize();
+            distinct.push((direction, direction.dot(&near.coords)));
+            solve_least_squares(&distinct)
+                .expect("Planes with distinct normals intersect")
+        }
+        [(a, _), (b, _), ..] => {
+            solve_least_squares(&distinct).unwrap_or_else(|| {
+                // All normals are perpendicular to a common direction.
+                let direction = a.cross(b).normalize();
+                let mut planes = distinct[..2].to_vec();
+                planes.push((direction, direction.dot(&near.coords)));
+                solve_least_squares(&planes)
+                    .expect("Planes with distinct normals intersect")
+            })
+        }
+    }
+}
+
+/// # Find the point with the smallest squared distance to all planes
+///
+/// Solves the normal equations using Cramer's rule. Returns `None`, if the
+/// normals don't span 3D space.
+fn solve_least_squares(planes: &[(Vector<3>, Scalar)]) -> Option<Point<3>> {
+    let mut columns = [Vector::from([0., 0., 0.]); 3];
+    let mut rhs = Vector::from([0., 0., 0.]);
+
+    for &(normal, distance) in planes {
+        for (j, column) in columns.iter_mut().enumerate() {
+            *column = *column + normal * normal.components[j];
+        }
+        rhs = rhs + normal * distance;
+    }
+
+    let [c0, c1, c2] = columns;
+    let det = c0.dot(&c1.cross(&c2));
+    if det.abs() < Scalar::from(1e-12) {
+        return None;
+    }
+
+    Some(Point::from([
+        rhs.dot(&c1.cross(&c2)) / det,
+        c0.dot(&rhs.cross(&c2)) / det,
+        c0.dot(&c1.cross(&rhs)) / det,
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        Core,
+        operations::{build::BuildShell, insert::Insert},
+        topology::Shell,
+    };
+
+    use super::{Hollow, HollowError, Polyhedron, newell_normal};
+
+    #[test]
+    fn hollow_cube_into_open_box() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let top = cube.faces().nth(1).unwrap().clone();
+
+        let open_box = cube.hollow(0.1, &[top], &mut core)?.insert(&mut core);
+        assert_eq!(open_box.faces().len(), 5 + 5 + 4);
+
+        // The cavity spans the inner 0.8 x 0.8 of the cube, down to 0.1 above
+        // the bottom.
+        let volume = volume(&open_box, &core);
+        assert!(
+            (volume - Scalar::from(1. - 0.8 * 0.8 * 0.9)).abs()
+                < Scalar::from(1e-9)
+        );
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_thickness_exceeding_feature_size() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core).insert(&mut core);
+        let top = cube.faces().nth(1).unwrap().clone();
+
+        let result = cube.hollow(0.6, &[top], &mut core);
+        assert!(matches!(result, Err(HollowError::ThicknessTooLarge { .. })));
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    fn cube(core: &mut Core) -> Shell {
+        Shell::from_vertices_and_polygons(
+            [
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 1., 0.],
+                [1., 1., 0.],
+                [0., 0., 1.],
+                [1., 0., 1.],
+                [0., 1., 1.],
+                [1., 1., 1.],
+            ],
+            [
+                [0, 2, 3, 1],
+                [4, 5, 7, 6],
+                [0, 1, 5, 4],
+                [2, 6, 7, 3],
+                [0, 4, 6, 2],
+                [1, 3, 7, 5],
+            ],
+            core,
+        )
+    }
+
+    fn volume(shell: &Shell, core: &Core) -> Scalar {
+        let polyhedron = Polyhedron::new(shell, &core.layers.geometry).unwrap();
+
+        polyhedron
+            .polygons
+            .iter()
+            .map(|polygon| {
+                let points = polygon
+                    .iter()
+                    .map(|&i| polyhedron.points[i])
+                    .collect::<Vec<_>>();
+                points[0].coords.dot(&newell_normal(&points)) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume)
+    }
+}
@@ -17,7 +17,9 @@ pub trait SplitEdge: Sized {
     ///
     /// # Panics
     ///
-    /// Panics, if the provided half-edge is not a part of this shell.
+    /// Panics, if the provided half-edge is not a part of this shell, or if the
+    /// point is not strictly between the half-edge's vertices. See
+    /// [`SplitHalfEdge::split_half_edge`].
     #[must_use]
     fn split_edge(
         &self,
@@ -43,11 +45,14 @@ impl SplitEdge for Shell {
         let [half_edge_a, half_edge_b] = self
             .find_cycle_of_half_edge(half_edge)
             .expect("Expected half-edge to be part of shell")
-            .split_half_edge(half_edge, point, core);
+            .split_half_edge(half_edge, point, core)
+            .expect("Expected point to be within half-edge");
 
         let siblings = {
-            let [sibling_a, sibling_b] =
-                sibling.cycle.split_half_edge(&sibling.sibling, point, core);
+            let [sibling_a, sibling_b] = sibling
+                .cycle
+                .split_half_edge(&sibling.sibling, point, core)
+                .expect("Expected point to be within sibling");
             let sibling_b = sibling_b
                 .update_start_vertex(
                     |_, _| half_edge_b.start_vertex().clone(),
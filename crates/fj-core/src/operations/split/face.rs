@@ -1,9 +1,9 @@
-use fj_math::Point;
+use fj_math::{Line, Point, Scalar};
 use itertools::Itertools;
 
 use crate::{
     Core,
-    geometry::LocalVertexGeom,
+    geometry::{LocalVertexGeom, Path},
     operations::{
         build::{BuildCycle, BuildHalfEdge},
         derive::DeriveFrom,
@@ -42,6 +42,32 @@ pub trait SplitFace: Sized {
         line: [(&Handle<HalfEdge>, impl Into<Point<1>>); 2],
         core: &mut Core,
     ) -> (Self, [Handle<Face>; 2]);
+
+    /// # Split the face into two, along a line
+    ///
+    /// The line is defined in the coordinates of the face's surface. Where it
+    /// crosses the exterior of the face, the half-edges there are split, unless
+    /// the line crosses at a vertex. The two resulting faces share an edge,
+    /// which is the segment of the line that is clipped to the face.
+    ///
+    /// Returns an error, if the line doesn't cross the interior of the face,
+    /// if it crosses the exterior more than twice, if the face has holes, or
+    /// if its exterior is not made up of line segments.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the face is not part of this shell.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only lines are supported for now. Other curves could be supported by
+    /// computing their intersections with the half-edges of the face.
+    fn split_face_along_line(
+        &self,
+        face: &Handle<Face>,
+        line: Line<2>,
+        core: &mut Core,
+    ) -> Result<(Self, [Handle<Face>; 2]), SplitFaceError>;
 }
 
 impl SplitFace for Shell {
@@ -100,158 +126,305 @@ impl SplitFace for Shell {
         let updated_face_after_split_edges = updated_face_after_split_edges
             .expect("Updated shell must contain updated face");
 
-        // Build the edge that's going to divide the new faces.
-        let dividing_half_edge_a_to_d = {
-            let start = core
-                .layers
-                .geometry
-                .of_curve(b.curve())
-                .unwrap()
-                .local_on(face.surface())
-                .unwrap()
-                .path
-                .point_from_path_coords(
-                    core.layers
-                        .geometry
-                        .of_vertex(b.start_vertex())
-                        .unwrap()
-                        .local_on(b.curve())
-                        .unwrap()
-                        .position,
-                );
-            let end = core
-                .layers
-                .geometry
-                .of_curve(d.curve())
-                .unwrap()
-                .local_on(face.surface())
-                .unwrap()
+        divide_face(
+            &self_,
+            updated_face_after_split_edges,
+            [b.clone(), d.clone()],
+            core,
+        )
+    }
+
+    fn split_face_along_line(
+        &self,
+        face: &Handle<Face>,
+        line: Line<2>,
+        core: &mut Core,
+    ) -> Result<(Self, [Handle<Face>; 2]), SplitFaceError> {
+        assert!(
+            self.faces().contains(face),
+            "Face to split must be part of shell"
+        );
+        if !face.region().interiors().is_empty() {
+            return Err(SplitFaceError::HasHoles);
+        }
+
+        let geometry = &core.layers.geometry;
+        let line = Line::from_origin_and_direction(
+            line.origin(),
+            line.direction().normalize(),
+        );
+        let side = |point: Point<2>| {
+            line.direction().cross2d(&(point - line.origin()))
+        };
+
+        let mut polygon = Vec::new();
+        let mut crossings = Vec::new();
+
+        for (half_edge, next) in face.region().exterior().half_edges().pairs() {
+            let Path::Line(path) = geometry
+                .of_curve(half_edge.curve())
+                .and_then(|curve| curve.local_on(face.surface()))
+                .expect("Expected geometry of half-edge's curve")
                 .path
-                .point_from_path_coords(
-                    core.layers
-                        .geometry
-                        .of_vertex(d.start_vertex())
-                        .unwrap()
-                        .local_on(d.curve())
-                        .unwrap()
-                        .position,
-                );
+            else {
+                return Err(SplitFaceError::NotLineSegments);
+            };
 
-            let (half_edge, boundary) = HalfEdge::line_segment(
-                [start, end],
-                face.surface().clone(),
-                core,
-            );
+            let [start, end] = [half_edge, next].map(|h| {
+                geometry
+                    .of_vertex(h.start_vertex())
+                    .and_then(|vertex| vertex.local_on(half_edge.curve()))
+                    .expect("Expected geometry of half-edge's vertex")
+                    .position
+            });
+            let [a, b] = [start, end]
+                .map(|position| path.point_from_line_coords(position));
+            polygon.push(a);
+
+            let [side_a, side_b] = [a, b].map(side);
+            if side_a.abs() < Scalar::from(1e-9) {
+                crossings.push((Crossing::Vertex(half_edge.clone()), a));
+            } else if side_b.abs() >= Scalar::from(1e-9)
+                && side_a * side_b < Scalar::ZERO
+            {
+                let t = side_a / (side_a - side_b);
+                crossings.push((
+                    Crossing::Edge(
+                        half_edge.clone(),
+                        start + (end - start) * t,
+                    ),
+                    a + (b - a) * t,
+                ));
+            }
+        }
+
+        let [first, second] = crossings.as_slice() else {
+            return Err(SplitFaceError::NotCrossing);
+        };
+
+        // With exactly two crossings, the segment between them is either fully
+        // inside of the face, or runs along its boundary or outside of it.
+        let midpoint = first.1 + (second.1 - first.1) / 2.;
+        if !is_strictly_inside(midpoint, &polygon) {
+            return Err(SplitFaceError::NotCrossing);
+        }
+
+        let mut shell = self.clone();
+        let [b, d] = [first, second].map(|(crossing, _)| match crossing {
+            Crossing::Vertex(half_edge) => half_edge.clone(),
+            Crossing::Edge(half_edge, point) => {
+                let (updated, [[_, b], _]) =
+                    shell.split_edge(half_edge, *point, core);
+                shell = updated;
+                b
+            }
+        });
+
+        let updated_face = shell
+            .faces()
+            .iter()
+            .find(|f| {
+                let half_edges = f.region().exterior().half_edges();
+                half_edges.contains(&b) && half_edges.contains(&d)
+            })
+            .expect("Updated shell must contain updated face")
+            .clone();
 
-            core.layers.geometry.define_vertex(
-                b.start_vertex().clone(),
-                half_edge.curve().clone(),
-                LocalVertexGeom {
-                    position: boundary.inner[0],
-                },
+        Ok(divide_face(&shell, &updated_face, [b, d], core))
+    }
+}
+
+/// # Error splitting a face along a line
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SplitFaceError {
+    /// # The face has holes
+    #[error("Splitting faces with holes along a line is not supported")]
+    HasHoles,
+
+    /// # The exterior of the face contains a half-edge that is not a line
+    #[error("Can only split faces bounded by line segments along a line")]
+    NotLineSegments,
+
+    /// # The line doesn't cross the interior of the face
+    #[error("Line doesn't cross the interior of the face")]
+    NotCrossing,
+}
+
+/// # A point where the splitting line crosses the exterior of a face
+enum Crossing {
+    /// # The line crosses at the start vertex of the half-edge
+    Vertex(Handle<HalfEdge>),
+
+    /// # The line crosses the half-edge at the provided point on its curve
+    Edge(Handle<HalfEdge>, Point<1>),
+}
+
+/// # Divide a face with a new edge between the start vertices of two half-edges
+fn divide_face(
+    self_: &Shell,
+    face: &Handle<Face>,
+    [b, d]: [Handle<HalfEdge>; 2],
+    core: &mut Core,
+) -> (Shell, [Handle<Face>; 2]) {
+    // Build the edge that's going to divide the new faces.
+    let dividing_half_edge_a_to_d = {
+        let start = core
+            .layers
+            .geometry
+            .of_curve(b.curve())
+            .unwrap()
+            .local_on(face.surface())
+            .unwrap()
+            .path
+            .point_from_path_coords(
+                core.layers
+                    .geometry
+                    .of_vertex(b.start_vertex())
+                    .unwrap()
+                    .local_on(b.curve())
+                    .unwrap()
+                    .position,
             );
-            core.layers.geometry.define_vertex(
-                d.start_vertex().clone(),
-                half_edge.curve().clone(),
-                LocalVertexGeom {
-                    position: boundary.inner[1],
-                },
+        let end = core
+            .layers
+            .geometry
+            .of_curve(d.curve())
+            .unwrap()
+            .local_on(face.surface())
+            .unwrap()
+            .path
+            .point_from_path_coords(
+                core.layers
+                    .geometry
+                    .of_vertex(d.start_vertex())
+                    .unwrap()
+                    .local_on(d.curve())
+                    .unwrap()
+                    .position,
             );
 
-            half_edge
-                .update_start_vertex(|_, _| b.start_vertex().clone(), core)
-                .insert(core)
-        };
-        let dividing_half_edge_c_to_b = HalfEdge::from_sibling(
-            &dividing_half_edge_a_to_d,
+        let (half_edge, boundary) =
+            HalfEdge::line_segment([start, end], face.surface().clone(), core);
+
+        core.layers.geometry.define_vertex(
+            b.start_vertex().clone(),
+            half_edge.curve().clone(),
+            LocalVertexGeom {
+                position: boundary.inner[0],
+            },
+        );
+        core.layers.geometry.define_vertex(
             d.start_vertex().clone(),
-            core,
+            half_edge.curve().clone(),
+            LocalVertexGeom {
+                position: boundary.inner[1],
+            },
         );
 
-        let mut half_edges_of_face_starting_at_b =
-            updated_face_after_split_edges
-                .region()
-                .exterior()
-                .half_edges()
-                .iter()
-                .cloned()
-                .cycle()
-                .skip_while(|half_edge| half_edge != &b);
-
-        let half_edges_b_to_c_inclusive = half_edges_of_face_starting_at_b
-            .take_while_ref(|half_edge| half_edge != &d);
-        let split_face_a = updated_face_after_split_edges
-            .update_region(
-                |region, core| {
-                    region.update_exterior(
-                        |_, core| {
-                            Cycle::empty()
-                                .add_half_edges(
-                                    half_edges_b_to_c_inclusive,
-                                    core,
-                                )
-                                .add_half_edges(
-                                    [dividing_half_edge_c_to_b],
-                                    core,
-                                )
-                        },
-                        core,
-                    )
-                },
-                core,
-            )
+        half_edge
+            .update_start_vertex(|_, _| b.start_vertex().clone(), core)
             .insert(core)
-            .derive_from(updated_face_after_split_edges, core);
-
-        // The previous operation has moved the iterator along.
-        let half_edges_of_face_starting_at_d = half_edges_of_face_starting_at_b;
-
-        let half_edges_d_to_a_inclusive = half_edges_of_face_starting_at_d
-            .take_while(|half_edge| half_edge != &b);
-        let split_face_b = updated_face_after_split_edges
-            .update_region(
-                |region, core| {
-                    region.update_exterior(
-                        |_, core| {
-                            Cycle::empty()
-                                .add_half_edges(
-                                    half_edges_d_to_a_inclusive,
-                                    core,
-                                )
-                                .add_half_edges(
-                                    [dividing_half_edge_a_to_d],
-                                    core,
-                                )
-                        },
-                        core,
-                    )
-                },
-                core,
-            )
-            .insert(core)
-            .derive_from(updated_face_after_split_edges, core);
+    };
+    let dividing_half_edge_c_to_b = HalfEdge::from_sibling(
+        &dividing_half_edge_a_to_d,
+        d.start_vertex().clone(),
+        core,
+    );
 
-        let faces = [split_face_a, split_face_b];
-        let self_ = self_.update_face(
-            updated_face_after_split_edges,
-            |_, _| faces.clone(),
+    let mut half_edges_of_face_starting_at_b = face
+        .region()
+        .exterior()
+        .half_edges()
+        .iter()
+        .cloned()
+        .cycle()
+        .skip_while(|half_edge| half_edge != &b);
+
+    let half_edges_b_to_c_inclusive = half_edges_of_face_starting_at_b
+        .take_while_ref(|half_edge| half_edge != &d);
+    let split_face_a = face
+        .update_region(
+            |region, core| {
+                region.update_exterior(
+                    |_, core| {
+                        Cycle::empty()
+                            .add_half_edges(half_edges_b_to_c_inclusive, core)
+                            .add_half_edges([dividing_half_edge_c_to_b], core)
+                    },
+                    core,
+                )
+            },
             core,
-        );
+        )
+        .insert(core)
+        .derive_from(face, core);
+
+    // The previous operation has moved the iterator along.
+    let half_edges_of_face_starting_at_d = half_edges_of_face_starting_at_b;
+
+    let half_edges_d_to_a_inclusive = half_edges_of_face_starting_at_d
+        .take_while(|half_edge| half_edge != &b);
+    let split_face_b = face
+        .update_region(
+            |region, core| {
+                region.update_exterior(
+                    |_, core| {
+                        Cycle::empty()
+                            .add_half_edges(half_edges_d_to_a_inclusive, core)
+                            .add_half_edges([dividing_half_edge_a_to_d], core)
+                    },
+                    core,
+                )
+            },
+            core,
+        )
+        .insert(core)
+        .derive_from(face, core);
+
+    let faces = [split_face_a, split_face_b];
+    let self_ = self_.update_face(face, |_, _| faces.clone(), core);
+
+    (self_, faces)
+}
+
+/// # Determine whether a point is inside a polygon, and not on its boundary
+fn is_strictly_inside(point: Point<2>, polygon: &[Point<2>]) -> bool {
+    let mut inside = false;
+
+    for (a, b) in polygon.iter().zip(polygon.iter().cycle().skip(1)) {
+        let distance = {
+            let ab = *b - *a;
+            let t = ((point - *a).dot(&ab) / ab.dot(&ab))
+                .clamp(Scalar::ZERO, Scalar::ONE);
+            (*a + ab * t).distance_to(&point)
+        };
+        if distance < Scalar::from(1e-9) {
+            return false;
+        }
 
-        (self_, faces)
+        if (a.v > point.v) != (b.v > point.v) {
+            let u = a.u + (point.v - a.v) / (b.v - a.v) * (b.u - a.u);
+            if point.u < u {
+                inside = !inside;
+            }
+        }
     }
+
+    inside
 }
 
 #[cfg(test)]
 mod tests {
     use fj_interop::Color;
+    use fj_math::{Line, Point, Vector};
 
     use crate::{
         Core,
         operations::{
             build::BuildShell,
+            insert::Insert,
             presentation::{GetColor, SetColor},
-            split::SplitFace,
+            split::{SplitFace, SplitFaceError},
         },
         topology::Shell,
     };
@@ -281,4 +454,112 @@ mod tests {
         assert_eq!(face_a.region().get_color(&mut core), Some(color));
         assert_eq!(face_b.region().get_color(&mut core), Some(color));
     }
+
+    #[test]
+    fn split_square_along_diagonal() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let top = cube.faces().nth(1).unwrap().clone();
+
+        // The surface of the top face has its origin at `[0, 0, 1]`, and its
+        // axes along x and y.
+        let diagonal = Line::from_origin_and_direction(
+            Point::from([0., 0.]),
+            Vector::from([1., 1.]),
+        );
+        let (shell, faces) =
+            cube.split_face_along_line(&top, diagonal, &mut core)?;
+        let shell = shell.insert(&mut core);
+
+        assert_eq!(shell.faces().len(), 7);
+        for face in faces {
+            assert_eq!(face.region().exterior().half_edges().len(), 3);
+        }
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_square_across_edges() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let top = cube.faces().nth(1).unwrap().clone();
+
+        let line = Line::from_origin_and_direction(
+            Point::from([0.5, 0.]),
+            Vector::from([0., 1.]),
+        );
+        let (shell, faces) =
+            cube.split_face_along_line(&top, line, &mut core)?;
+        let shell = shell.insert(&mut core);
+
+        assert_eq!(shell.faces().len(), 7);
+        for face in faces {
+            assert_eq!(face.region().exterior().half_edges().len(), 4);
+        }
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_line_not_crossing_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cube(&mut core);
+        let top = cube.faces().nth(1).unwrap().clone();
+
+        for line in [
+            // Misses the face entirely.
+            ([2., 0.], [0., 1.]),
+            // Only touches a corner.
+            ([0., 1.], [1., 1.]),
+            // Runs along an edge.
+            ([0., 0.], [1., 0.]),
+        ] {
+            let (origin, direction) = line;
+            let line = Line::from_origin_and_direction(
+                Point::from(origin),
+                Vector::from(direction),
+            );
+
+            assert_eq!(
+                cube.split_face_along_line(&top, line, &mut core).err(),
+                Some(SplitFaceError::NotCrossing),
+            );
+        }
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+
+    fn cube(core: &mut Core) -> Shell {
+        Shell::from_vertices_and_polygons(
+            [
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 1., 0.],
+                [1., 1., 0.],
+                [0., 0., 1.],
+                [1., 0., 1.],
+                [0., 1., 1.],
+                [1., 1., 1.],
+            ],
+            [
+                [0, 2, 3, 1],
+                [4, 5, 7, 6],
+                [0, 1, 5, 4],
+                [2, 6, 7, 3],
+                [0, 4, 6, 2],
+                [1, 3, 7, 5],
+            ],
+            core,
+        )
+    }
 }
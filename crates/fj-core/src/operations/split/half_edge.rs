@@ -3,7 +3,11 @@ use fj_math::Point;
 use crate::{
     Core,
     geometry::LocalVertexGeom,
-    operations::{derive::DeriveFrom, insert::Insert},
+    operations::{
+        OperationError, derive::DeriveFrom, error::local_vertex_geom,
+        insert::Insert,
+    },
+    queries::BoundingVerticesOfHalfEdge,
     storage::Handle,
     topology::{Cycle, HalfEdge, Vertex},
 };
@@ -17,6 +21,16 @@ use crate::{
 pub trait SplitHalfEdge {
     /// Split the half-edge into two
     ///
+    /// The point where the half-edge is split is provided in curve
+    /// coordinates, and must be strictly between the positions of the
+    /// half-edge's start and end vertices. Both new half-edges share the
+    /// original curve. They meet at a new vertex, whose geometry is defined on
+    /// that curve. Its position on the surface follows from evaluating the
+    /// curve's path at the provided point.
+    ///
+    /// The new half-edges are returned, but not put into the cycle. Use
+    /// [`ReplaceHalfEdge`] for that.
+    ///
     /// # Validity
     ///
     /// Within a valid shell, a [`HalfEdge`] must have an equal but opposite
@@ -25,13 +39,14 @@ pub trait SplitHalfEdge {
     ///
     /// The caller is responsible for also split this half-edge's sibling, if
     /// appropriate, to preserve validity.
-    #[must_use]
+    ///
+    /// [`ReplaceHalfEdge`]: crate::operations::replace::ReplaceHalfEdge
     fn split_half_edge(
         &self,
         half_edge: &Handle<HalfEdge>,
         point: impl Into<Point<1>>,
         core: &mut Core,
-    ) -> [Handle<HalfEdge>; 2];
+    ) -> Result<[Handle<HalfEdge>; 2], SplitHalfEdgeError>;
 }
 
 impl SplitHalfEdge for Cycle {
@@ -40,9 +55,32 @@ impl SplitHalfEdge for Cycle {
         half_edge: &Handle<HalfEdge>,
         point: impl Into<Point<1>>,
         core: &mut Core,
-    ) -> [Handle<HalfEdge>; 2] {
+    ) -> Result<[Handle<HalfEdge>; 2], SplitHalfEdgeError> {
         let point = point.into();
 
+        let vertices = self
+            .bounding_vertices_of_half_edge(half_edge)
+            .ok_or_else(|| SplitHalfEdgeError::HalfEdgeNotInCycle {
+                half_edge: half_edge.clone(),
+            })?;
+        let [start, end] = vertices.inner.each_ref().map(|vertex| {
+            local_vertex_geom(vertex, half_edge.curve(), &core.layers.geometry)
+                .map(|geometry| geometry.position)
+        });
+        let boundary = [start?, end?];
+
+        let [min, max] = {
+            let mut boundary = boundary;
+            boundary.sort();
+            boundary
+        };
+        if point <= min || point >= max {
+            return Err(SplitHalfEdgeError::PointOutsideHalfEdge {
+                point,
+                boundary,
+            });
+        }
+
         let a = HalfEdge::new(
             half_edge.curve().clone(),
             half_edge.start_vertex().clone(),
@@ -62,6 +100,114 @@ impl SplitHalfEdge for Cycle {
             LocalVertexGeom { position: point },
         );
 
-        [a, b]
+        Ok([a, b])
+    }
+}
+
+/// # Error splitting a half-edge
+///
+/// See [`SplitHalfEdge::split_half_edge`].
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SplitHalfEdgeError {
+    /// # The half-edge is not part of the cycle
+    #[error("Half-edge {half_edge:?} is not part of the cycle")]
+    HalfEdgeNotInCycle {
+        /// # The half-edge that was supposed to be split
+        half_edge: Handle<HalfEdge>,
+    },
+
+    /// # The point is not strictly between the half-edge's vertices
+    #[error(
+        "Can't split half-edge at {point:?}, which is not strictly within its \
+        boundary {boundary:?}"
+    )]
+    PointOutsideHalfEdge {
+        /// # The point at which the half-edge was supposed to be split
+        point: Point<1>,
+
+        /// # The positions of the half-edge's start and end vertices
+        boundary: [Point<1>; 2],
+    },
+
+    /// # Geometry that is required for the split is not defined
+    #[error(transparent)]
+    MissingGeometry(#[from] OperationError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use fj_math::Point;
+
+    use crate::{
+        Core,
+        operations::{
+            build::BuildCycle, insert::Insert, replace::ReplaceHalfEdge,
+        },
+        topology::Cycle,
+    };
+
+    use super::{SplitHalfEdge, SplitHalfEdgeError};
+
+    #[test]
+    fn split_line_segment_at_midpoint() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let cycle = Cycle::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            surface.clone(),
+            &mut core,
+        )
+        .insert(&mut core);
+        let half_edge = cycle.half_edges().first().clone();
+
+        let [a, b] = cycle.split_half_edge(&half_edge, [0.5], &mut core)?;
+        let split = cycle
+            .replace_half_edge(&half_edge, [a.clone(), b.clone()], &mut core)
+            .map_original(|cycle| cycle.deref().clone())
+            .into_inner();
+        assert_eq!(split.half_edges().len(), 5);
+
+        let geometry = &core.layers.geometry;
+        let path = geometry
+            .of_curve(b.curve())
+            .unwrap()
+            .local_on(&surface)
+            .unwrap()
+            .path;
+        let position = geometry
+            .of_vertex(b.start_vertex())
+            .unwrap()
+            .local_on(b.curve())
+            .unwrap()
+            .position;
+        assert_eq!(
+            path.point_from_path_coords(position),
+            Point::from([1., 0.])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_at_vertex() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let cycle = Cycle::polygon(
+            [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+            surface,
+            &mut core,
+        )
+        .insert(&mut core);
+        let half_edge = cycle.half_edges().first().clone();
+
+        let result = cycle.split_half_edge(&half_edge, [0.], &mut core);
+        assert!(matches!(
+            result,
+            Err(SplitHalfEdgeError::PointOutsideHalfEdge { .. })
+        ));
     }
 }
@@ -9,4 +9,8 @@ mod edge;
 mod face;
 mod half_edge;
 
-pub use self::{edge::SplitEdge, face::SplitFace, half_edge::SplitHalfEdge};
+pub use self::{
+    edge::SplitEdge,
+    face::{SplitFace, SplitFaceError},
+    half_edge::{SplitHalfEdge, SplitHalfEdgeError},
+};
@@ -1,10 +1,9 @@
 use fj_interop::Color;
-use fj_math::Vector;
-use itertools::Itertools;
+use fj_math::{Scalar, Vector};
 
 use crate::{
     Core,
-    geometry::LocalVertexGeom,
+    geometry::LocalCurveGeom,
     operations::{
         build::BuildCycle, join::JoinCycle, sweep::half_edge::SweepHalfEdge,
     },
@@ -12,7 +11,7 @@ use crate::{
     topology::{Cycle, Face, Surface},
 };
 
-use super::SweepCache;
+use super::{SweepCache, draft::top_offsets};
 
 /// # Sweep a [`Cycle`]
 ///
@@ -38,12 +37,34 @@ pub trait SweepCycle {
     /// For the same reason, there also is no "bottom" face. Additionally,
     /// whether a bottom face is even desirable depends on the context this
     /// operation is called in, and therefore falls outside of its scope.
+    ///
+    /// ## Draft
+    ///
+    /// With a non-zero `draft` angle (in radians), the side walls are tilted
+    /// by that angle. For a positive angle, each top half-edge is moved to the
+    /// right of its bottom half-edge, as seen on the bottom surface, by the
+    /// length of `path` times the tangent of the angle.
+    ///
+    /// This assumes that `path` is perpendicular to the bottom surface, and
+    /// that the coordinate system of the bottom surface is orthonormal. If
+    /// that's not the case, the side walls are still planar, but their angle
+    /// differs from `draft`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `draft` is not zero and the cycle has curved half-edges, if
+    /// `draft` is not within `(-π/2, π/2)`, or if it would collapse the top
+    /// cycle. See [`DraftError`] for more information.
+    ///
+    /// [`DraftError`]: super::DraftError
+    #[allow(clippy::too_many_arguments)]
     fn sweep_cycle(
         &self,
         bottom_surface: Handle<Surface>,
         top_surface: Handle<Surface>,
         color: Option<Color>,
         path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptCycle;
@@ -56,15 +77,26 @@ impl SweepCycle for Cycle {
         top_surface: Handle<Surface>,
         color: Option<Color>,
         path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptCycle {
         let path = path.into();
 
+        let top_offsets = top_offsets(
+            self,
+            &bottom_surface,
+            path,
+            draft.into(),
+            &core.layers.geometry,
+        )
+        .unwrap_or_else(|err| panic!("Can't sweep cycle with draft: {err}"));
+
         let mut faces = Vec::new();
         let mut top_half_edges = Vec::new();
 
-        for bottom_half_edge_pair in self.half_edges().pairs() {
+        for (i, bottom_half_edge_pair) in self.half_edges().pairs().enumerate()
+        {
             let (bottom_half_edge, bottom_half_edge_next) =
                 bottom_half_edge_pair;
 
@@ -73,6 +105,7 @@ impl SweepCycle for Cycle {
                 bottom_surface.clone(),
                 color,
                 path,
+                [top_offsets[i], top_offsets[(i + 1) % top_offsets.len()]],
                 cache,
                 core,
             );
@@ -97,45 +130,18 @@ impl SweepCycle for Cycle {
             //     appear within a cycle eventually.
             //   - Each half-edge ends where the _previous_ one (in the list)
             //     starts.
+            //
+            // The positions of the vertices on the curves of the top half-edges
+            // have already been defined while sweeping, so all that's left to
+            // do here is to collect them.
             top_half_edges.push((
                 swept_half_edge.top_half_edge,
-                swept_half_edge.top_boundary,
-                core.layers
-                    .geometry
-                    .of_curve(bottom_half_edge.curve())
-                    .unwrap()
-                    .local_on(&bottom_surface)
-                    .unwrap()
-                    .clone(),
+                LocalCurveGeom {
+                    path: swept_half_edge.top_path,
+                },
             ));
         }
 
-        let top_half_edges = top_half_edges
-            .into_iter()
-            .circular_tuple_windows()
-            .map(
-                |(
-                    (half_edge, boundary, curve_geom),
-                    (next_half_edge, _, _),
-                )| {
-                    let [start, end] = boundary.inner;
-
-                    for (point, vertex) in [
-                        (start, half_edge.start_vertex()),
-                        (end, next_half_edge.start_vertex()),
-                    ] {
-                        core.layers.geometry.define_vertex(
-                            vertex.clone(),
-                            half_edge.curve().clone(),
-                            LocalVertexGeom { position: point },
-                        );
-                    }
-
-                    (half_edge, curve_geom)
-                },
-            )
-            .collect::<Vec<_>>();
-
         // The half-edges within `top_half_edges` which we're passing into
         // `add_joined_edges` are in unnatural order, as per the comment above.
         // This happens to be exactly the order that `add_joined_edges` wants
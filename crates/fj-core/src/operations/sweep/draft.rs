@@ -0,0 +1,119 @@
+use fj_math::{Scalar, Vector};
+
+use crate::{
+    geometry::{Geometry, Path},
+    storage::Handle,
+    topology::{Cycle, Surface},
+};
+
+/// # Error sweeping with a draft angle
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum DraftError {
+    /// # The draft angle is not within the open interval `(-π/2, π/2)`
+    #[error("Draft angle must be less than a right angle; got {angle}")]
+    InvalidAngle {
+        /// # The invalid angle
+        angle: Scalar,
+    },
+
+    /// # A half-edge of a drafted cycle is not straight
+    #[error("Can only apply draft to cycles with straight half-edges")]
+    CurvedEdge,
+
+    /// # The draft angle collapses the top profile
+    #[error("Draft angle {angle} collapses the top profile")]
+    ProfileCollapses {
+        /// # The angle that was too large
+        angle: Scalar,
+    },
+}
+
+/// # Compute the offsets of the top vertices of a drafted sweep
+///
+/// Returns one offset per half-edge of `cycle`, for the half-edge's start
+/// vertex. Offsets are in the coordinates of `surface`, and are relative to the
+/// translation along `path`.
+///
+/// With a positive draft angle, each top half-edge is moved to the right of the
+/// respective bottom half-edge, as seen on `surface`. The distance is the
+/// length of `path`, times the tangent of the angle. Each top vertex is placed
+/// where the moved top half-edges that meet there intersect.
+pub(super) fn top_offsets(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    path: Vector<3>,
+    draft: Scalar,
+    geometry: &Geometry,
+) -> Result<Vec<Vector<2>>, DraftError> {
+    if draft.abs() >= Scalar::PI / 2. {
+        return Err(DraftError::InvalidAngle { angle: draft });
+    }
+    if draft == Scalar::ZERO {
+        return Ok(vec![Vector::from([0., 0.]); cycle.half_edges().len()]);
+    }
+
+    let (sin, cos) = draft.sin_cos();
+    let distance = path.magnitude() * sin / cos;
+
+    let segments = cycle
+        .half_edges()
+        .pairs()
+        .map(|(half_edge, next)| {
+            let path = geometry
+                .of_curve(half_edge.curve())
+                .and_then(|curve| curve.local_on(surface))
+                .expect("Expected geometry of half-edge's curve")
+                .path;
+            let Path::Line(line) = path else {
+                return Err(DraftError::CurvedEdge);
+            };
+
+            let points =
+                [half_edge.start_vertex(), next.start_vertex()].map(|vertex| {
+                    let position = geometry
+                        .of_vertex(vertex)
+                        .and_then(|vertex| vertex.local_on(half_edge.curve()))
+                        .expect("Expected geometry of half-edge's vertex")
+                        .position;
+                    line.point_from_line_coords(position)
+                });
+
+            Ok(points)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let normals = segments
+        .iter()
+        .map(|&[a, b]| {
+            let direction = (b - a).normalize();
+            Vector::from([direction.v, -direction.u])
+        })
+        .collect::<Vec<_>>();
+
+    let mut offsets = Vec::new();
+    for (i, normal) in normals.iter().enumerate() {
+        let previous = normals[(i + normals.len() - 1) % normals.len()];
+
+        // Both moved half-edges must be at `distance` from the vertex, along
+        // their respective normals. If the half-edges double back on each
+        // other, there is no such point.
+        let denominator = Scalar::ONE + previous.dot(normal);
+        if denominator < Scalar::from(1e-12) {
+            return Err(DraftError::ProfileCollapses { angle: draft });
+        }
+
+        offsets.push((previous + *normal) * (distance / denominator));
+    }
+
+    for (i, &[a, b]) in segments.iter().enumerate() {
+        let [offset_a, offset_b] =
+            [offsets[i], offsets[(i + 1) % offsets.len()]];
+        let [top_a, top_b] = [a + offset_a, b + offset_b];
+
+        if (top_b - top_a).dot(&(b - a)) <= Scalar::ZERO {
+            return Err(DraftError::ProfileCollapses { angle: draft });
+        }
+    }
+
+    Ok(offsets)
+}
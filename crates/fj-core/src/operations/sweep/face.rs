@@ -1,4 +1,4 @@
-use fj_math::Vector;
+use fj_math::{Scalar, Vector};
 
 use crate::{
     Core,
@@ -16,9 +16,12 @@ use super::{SweepCache, SweepRegion};
 /// [module documentation]: super
 pub trait SweepFace {
     /// # Sweep the [`Face`] into a [`Shell`]
+    ///
+    /// See [`SweepRegion::sweep_region`] for the meaning of `draft`.
     fn sweep_face(
         &self,
         path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> Shell;
@@ -28,6 +31,7 @@ impl SweepFace for Handle<Face> {
     fn sweep_face(
         &self,
         path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> Shell {
@@ -53,6 +57,7 @@ impl SweepFace for Handle<Face> {
                 bottom_face.surface().clone(),
                 bottom_face.region().get_color(core),
                 path,
+                draft,
                 cache,
                 core,
             )
@@ -1,9 +1,12 @@
 use fj_interop::{Color, ext::ArrayExt};
-use fj_math::{Point, Scalar, Vector};
+use fj_math::{Line, Point, Scalar, Vector};
 
 use crate::{
     Core,
-    geometry::{CurveBoundary, LocalVertexGeom},
+    geometry::{
+        CurveBoundary, LocalVertexGeom, Path,
+        repr::tri_mesh::convert_vector_surface_to_global,
+    },
     operations::{
         build::{BuildCycle, BuildHalfEdge},
         geometry::UpdateCurveGeometry,
@@ -36,12 +39,24 @@ pub trait SweepHalfEdge {
     ///   vertex of the next half-edge in the cycle.
     /// - The `surface` that the half-edge is defined on.
     /// - The `color` of the resulting face, if applicable
+    /// - `top_offsets`, the offsets of the top vertices that correspond to the
+    ///   start and end vertex, in the coordinates of `surface`. These are
+    ///   applied in addition to the translation along `path`, and are zero,
+    ///   unless the sweep has draft.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the half-edge is not straight, but the offsets are not zero.
+    /// Panics, if the offsets are not parallel to the half-edge, after
+    /// subtracting their common component perpendicular to it.
+    #[allow(clippy::too_many_arguments)]
     fn sweep_half_edge(
         &self,
         end_vertex: Handle<Vertex>,
         surface: Handle<Surface>,
         color: Option<Color>,
         path: impl Into<Vector<3>>,
+        top_offsets: [Vector<2>; 2],
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptHalfEdge;
@@ -54,27 +69,13 @@ impl SweepHalfEdge for Handle<HalfEdge> {
         surface: Handle<Surface>,
         color: Option<Color>,
         path: impl Into<Vector<3>>,
+        top_offsets: [Vector<2>; 2],
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptHalfEdge {
         let path = path.into();
 
-        let boundary = [
-            core.layers
-                .geometry
-                .of_vertex(self.start_vertex())
-                .unwrap()
-                .local_on(self.curve())
-                .unwrap()
-                .position,
-            core.layers
-                .geometry
-                .of_vertex(&end_vertex)
-                .unwrap()
-                .local_on(self.curve())
-                .unwrap()
-                .position,
-        ];
+        let boundary = self.boundary(&end_vertex, &core.layers.geometry).inner;
         let curve_geom = core
             .layers
             .geometry
@@ -84,6 +85,52 @@ impl SweepHalfEdge for Handle<HalfEdge> {
             .unwrap()
             .clone();
         let surface_geom = *core.layers.geometry.of_surface(&surface);
+
+        // Split the offsets of the top vertices into a component perpendicular
+        // to the half-edge, which moves the whole top half-edge and tilts the
+        // side face, and components along the half-edge, which move the top
+        // vertices along the top half-edge's curve.
+        let (top_path, perpendicular, [offset_a, offset_b]) = match curve_geom
+            .path
+        {
+            Path::Line(line) => {
+                let along = top_offsets
+                    .map(|offset| line.vector_to_line_coords(offset));
+                let [perpendicular, other] =
+                    top_offsets.zip_ext(along).map(|(offset, along)| {
+                        offset - line.vector_from_line_coords(along)
+                    });
+                assert!(
+                    (perpendicular - other).magnitude() < Scalar::from(1e-9),
+                    "Top offsets of swept half-edge are not parallel to it",
+                );
+
+                let top_path = Path::Line(Line::from_origin_and_direction(
+                    line.origin() + perpendicular,
+                    line.direction(),
+                ));
+
+                (top_path, perpendicular, along.map(|along| along.t))
+            }
+            Path::Circle(_) => {
+                assert!(
+                    top_offsets
+                        .iter()
+                        .all(|offset| offset.magnitude() < Scalar::from(1e-12)),
+                    "Can't apply draft to a curved half-edge",
+                );
+
+                (curve_geom.path, Vector::from([0., 0.]), [Scalar::ZERO; 2])
+            }
+        };
+
+        let path = path
+            + convert_vector_surface_to_global(
+                &surface_geom,
+                perpendicular,
+                core.tolerance(),
+                &core.layers.geometry,
+            );
         let surface =
             curve_geom
                 .path
@@ -114,8 +161,8 @@ impl SweepHalfEdge for Handle<HalfEdge> {
             [
                 [a.t, Scalar::ZERO],
                 [b.t, Scalar::ZERO],
-                [b.t, Scalar::ONE],
-                [a.t, Scalar::ONE],
+                [b.t + offset_b, Scalar::ONE],
+                [a.t + offset_a, Scalar::ONE],
             ]
             .map(Point::from)
         };
@@ -128,9 +175,11 @@ impl SweepHalfEdge for Handle<HalfEdge> {
         // Now, the boundaries of each edge.
         let boundaries = {
             let [a, b] = boundary;
+            let [top_a, top_b] = [(a, offset_a), (b, offset_b)]
+                .map(|(point, offset)| Point::from([point.t + offset]));
             let [c, d] = [0., 1.].map(|coord| Point::from([coord]));
 
-            [[a, b], [c, d], [b, a], [d, c]]
+            [[a, b], [c, d], [top_b, top_a], [d, c]]
         };
 
         let vertices_pairs = {
@@ -206,6 +255,7 @@ impl SweepHalfEdge for Handle<HalfEdge> {
         SweptHalfEdge {
             face,
             top_half_edge: edge_top,
+            top_path,
             top_boundary: CurveBoundary {
                 inner: top_boundary,
             },
@@ -223,6 +273,13 @@ pub struct SweptHalfEdge {
     /// The top half-edge of the created face
     pub top_half_edge: Handle<HalfEdge>,
 
+    /// The path of the top half-edge's curve
+    ///
+    /// Defined in the coordinates of the surface that the swept half-edge is
+    /// defined on. The top surface is a translated version of that surface,
+    /// so the path is valid there too.
+    pub top_path: Path<2>,
+
     /// The boundary of the top half-edge
     pub top_boundary: CurveBoundary<Point<1>>,
 }
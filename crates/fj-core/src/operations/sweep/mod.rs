@@ -4,6 +4,7 @@
 //! respectively.
 
 mod cycle;
+mod draft;
 mod face;
 mod half_edge;
 mod path;
@@ -14,6 +15,7 @@ mod vertex;
 
 pub use self::{
     cycle::{SweepCycle, SweptCycle},
+    draft::DraftError,
     face::SweepFace,
     half_edge::{SweepHalfEdge, SweptHalfEdge},
     path::SweepSurfacePath,
@@ -1,5 +1,5 @@
 use fj_interop::Color;
-use fj_math::Vector;
+use fj_math::{Scalar, Vector};
 
 use crate::{
     Core,
@@ -29,11 +29,17 @@ pub trait SweepRegion {
     /// There no "bottom" face. Whether having one is desirable depends on the
     /// context of the caller of this operation, and falls outside of this
     /// operation's scope.
+    ///
+    /// A positive `draft` angle (in radians) tilts the side walls towards the
+    /// inside of the region, making the top face smaller than the region. Pass
+    /// zero for straight side walls. See [`SweepCycle::sweep_cycle`] for more
+    /// information, including the conditions under which this method panics.
     fn sweep_region(
         &self,
         bottom_surface: Handle<Surface>,
         color: Option<Color>,
         path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptRegion;
@@ -45,15 +51,20 @@ impl SweepRegion for Region {
         bottom_surface: Handle<Surface>,
         color: Option<Color>,
         path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptRegion {
         let path = path.into();
+        let draft = draft.into();
 
         let mut faces = Vec::new();
 
-        let top_surface =
-            bottom_surface.clone().translate(path, core).insert(core);
+        let top_surface = bottom_surface
+            .clone()
+            .translate(path, core)
+            .expect("Bottom surface of swept region must have geometry")
+            .insert(core);
 
         let top_exterior = sweep_cycle(
             self.exterior(),
@@ -62,6 +73,7 @@ impl SweepRegion for Region {
             color,
             &mut faces,
             path,
+            draft,
             cache,
             core,
         );
@@ -77,6 +89,7 @@ impl SweepRegion for Region {
                     color,
                     &mut faces,
                     path,
+                    draft,
                     cache,
                     core,
                 )
@@ -105,14 +118,19 @@ fn sweep_cycle(
     color: Option<Color>,
     faces: &mut Vec<Face>,
     path: Vector<3>,
+    draft: Scalar,
     cache: &mut SweepCache,
     core: &mut Core,
 ) -> Handle<Cycle> {
+    // The cycle is reversed before sweeping, which makes the inside of the
+    // region the right side of its half-edges. That's where a positive draft
+    // angle moves the top cycle.
     let swept_cycle = bottom_cycle.reverse(core).sweep_cycle(
         bottom_surface,
         top_surface,
         color,
         path,
+        draft,
         cache,
         core,
     );
@@ -1,4 +1,4 @@
-use fj_math::Vector;
+use fj_math::{Scalar, Vector};
 
 use crate::{
     Core,
@@ -69,6 +69,7 @@ impl SweepFaceOfShell for Shell {
             face.surface().clone(),
             face.region().get_color(core),
             path,
+            Scalar::ZERO,
             &mut cache,
             core,
         );
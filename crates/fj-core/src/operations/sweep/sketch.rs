@@ -1,4 +1,4 @@
-use fj_math::Vector;
+use fj_math::{Scalar, Vector};
 
 use crate::{
     Core,
@@ -7,7 +7,7 @@ use crate::{
     topology::{Face, Sketch, Solid, Surface},
 };
 
-use super::{SweepCache, face::SweepFace};
+use super::{DraftError, SweepCache, draft::top_offsets, face::SweepFace};
 
 /// # Sweep a [`Sketch`]
 ///
@@ -43,6 +43,36 @@ pub trait SweepSketch {
         path: impl Into<Vector<3>>,
         core: &mut Core,
     ) -> Solid;
+
+    /// # Sweep the [`Sketch`], tilting the side walls by a draft angle
+    ///
+    /// Works like [`SweepSketch::sweep_sketch`], with the same requirements,
+    /// but each top half-edge is moved towards the inside of its region, by
+    /// the length of `path` times the tangent of `draft` (an angle in
+    /// radians). The side walls are planar, but tilted by the draft angle.
+    /// Interior cycles are moved outwards, making holes wider towards the top.
+    ///
+    /// A negative angle tilts the side walls the other way, making the top
+    /// face larger than the bottom face.
+    ///
+    /// All half-edges of the sketch must be straight, unless `draft` is zero.
+    /// Angles that would collapse the top profile, because a top half-edge
+    /// would vanish or point the other way, are rejected. Top half-edges that
+    /// don't share a vertex can still end up intersecting each other. This is
+    /// not detected, and results in an invalid shape.
+    ///
+    /// ## Implementation Note
+    ///
+    /// The offsets are computed in the coordinates of the sketch. They only
+    /// result in the exact draft angle, if `path` is perpendicular to
+    /// `surface`, and the coordinate system of `surface` is orthonormal.
+    fn sweep_sketch_with_draft(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<Solid, DraftError>;
 }
 
 impl SweepSketch for Sketch {
@@ -52,7 +82,34 @@ impl SweepSketch for Sketch {
         path: impl Into<Vector<3>>,
         core: &mut Core,
     ) -> Solid {
+        self.sweep_sketch_with_draft(surface, path, Scalar::ZERO, core)
+            .expect("Sweeping without draft can't fail")
+    }
+
+    fn sweep_sketch_with_draft(
+        &self,
+        surface: Handle<Surface>,
+        path: impl Into<Vector<3>>,
+        draft: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Result<Solid, DraftError> {
         let path = path.into();
+        let draft = draft.into();
+
+        // Check the draft before creating any objects. The cycles are swept in
+        // reverse, so negating the angle results in the same offsets here.
+        for region in self.regions() {
+            for cycle in region.all_cycles() {
+                top_offsets(
+                    cycle,
+                    self.surface(),
+                    path,
+                    -draft,
+                    &core.layers.geometry,
+                )?;
+            }
+        }
+
         let mut cache = SweepCache::default();
 
         let mut shells = Vec::new();
@@ -76,10 +133,204 @@ impl SweepSketch for Sketch {
             }
 
             let face = Face::new(surface.clone(), region.clone()).insert(core);
-            let shell = face.sweep_face(path, &mut cache, core).insert(core);
+            let shell =
+                face.sweep_face(path, draft, &mut cache, core).insert(core);
             shells.push(shell);
         }
 
-        Solid::new(shells)
+        Ok(Solid::new(shells))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Scalar, Vector};
+
+    use crate::{
+        Core,
+        algorithms::bounding_volume::BoundingVolume,
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, Region, Sketch},
+    };
+
+    use super::{DraftError, SweepSketch};
+
+    #[test]
+    fn sweep_square_into_cube() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., -1.], &mut core)
+        .insert(&mut core);
+
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_square_with_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let space_2d = core.layers.topology.surfaces.space_2d();
+        let region = Region::polygon(
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            space_2d.clone(),
+            &mut core,
+        )
+        .add_interiors(
+            [Cycle::polygon(
+                [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+                space_2d,
+                &mut core,
+            )],
+            &mut core,
+        );
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([region], &mut core)
+            .sweep_sketch(surface, [0., 0., -1.], &mut core)
+            .insert(&mut core);
+
+        // Top and bottom face, plus four outer and four inner side walls.
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 10);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_square_with_draft() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch_with_draft(
+            surface,
+            [0., 0., -1.],
+            Scalar::PI / 36.,
+            &mut core,
+        )?
+        .insert(&mut core);
+
+        let shell = solid.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        // The bottom face comes first, the top face last. Their bounding boxes
+        // are enlarged by the tolerance, which we undo here.
+        let margin = Vector::from([core.tolerance().inner(); 3]);
+        let [bottom, top] = [0, 5].map(|i| {
+            let aabb: Aabb<3> = shell
+                .faces()
+                .nth(i)
+                .unwrap()
+                .aabb_with_tolerance(core.tolerance(), &core.layers.geometry)
+                .expect("Face has a boundary");
+
+            Aabb {
+                min: aabb.min + margin,
+                max: aabb.max - margin,
+            }
+        });
+
+        let offset = 5_f64.to_radians().tan();
+        let epsilon = Scalar::from(1e-12);
+        let expected_top = Aabb {
+            min: Point::from([offset, offset, -1.]),
+            max: Point::from([1. - offset, 1. - offset, -1.]),
+        };
+        assert!(bottom.min.distance_to(&Point::from([0., 0., 0.])) < epsilon);
+        assert!(bottom.max.distance_to(&Point::from([1., 1., 0.])) < epsilon);
+        assert!(top.min.distance_to(&expected_top.min) < epsilon);
+        assert!(top.max.distance_to(&expected_top.max) < epsilon);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_square_with_hole_and_draft() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let space_2d = core.layers.topology.surfaces.space_2d();
+        let region = Region::polygon(
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            space_2d.clone(),
+            &mut core,
+        )
+        .add_interiors(
+            [Cycle::polygon(
+                [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+                space_2d,
+                &mut core,
+            )],
+            &mut core,
+        );
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([region], &mut core)
+            .sweep_sketch_with_draft(surface, [0., 0., -1.], 0.1, &mut core)?
+            .insert(&mut core);
+
+        let shell = solid.shells().only();
+        let top: Aabb<3> = shell
+            .faces()
+            .nth(9)
+            .unwrap()
+            .aabb_with_tolerance(core.tolerance(), &core.layers.geometry)
+            .expect("Face has a boundary");
+
+        // The outer walls are tilted inwards, making the top face smaller.
+        let offset = Scalar::from(0.1_f64.tan());
+        assert!(top.min.x > offset * 0.5);
+        assert!(top.max.x < Scalar::from(3.) - offset * 0.5);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn reject_draft_that_collapses_top_profile() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let sketch = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+
+        // Moving each side of the square inwards by its full length leaves
+        // nothing of the top face.
+        let result = sketch.sweep_sketch_with_draft(
+            surface.clone(),
+            [0., 0., -1.],
+            Scalar::PI / 4.,
+            &mut core,
+        );
+        assert!(matches!(result, Err(DraftError::ProfileCollapses { .. })));
+
+        let result = sketch.sweep_sketch_with_draft(
+            surface,
+            [0., 0., -1.],
+            Scalar::PI / 2.,
+            &mut core,
+        );
+        assert!(matches!(result, Err(DraftError::InvalidAngle { .. })));
     }
 }
@@ -2,7 +2,8 @@ use fj_math::Transform;
 
 use crate::{
     Core,
-    operations::insert::Insert,
+    geometry::CurveGeom,
+    operations::{OperationError, insert::Insert},
     storage::Handle,
     topology::{Curve, Surface},
 };
@@ -17,37 +18,162 @@ impl TransformObject for (&Handle<Curve>, &Handle<Surface>) {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         let (curve, surface) = self;
 
-        // We don't actually need to transform the curve, as its geometry is
-        // locally defined on a surface. We need to transform that surface
-        // though, or what we do here will have no consequence.
+        // The curve's geometry is transformed along with the curve itself, by
+        // transforming all surfaces it is defined on. We make sure the surface
+        // of the half-edge we were called for is among those, even if the
+        // curve's geometry has no definition on it yet.
         //
         // If this transformation is only one element in the transformation of
-        // a whole object graph, using the cache here ensures that the surface
-        // doesn't get transformed multiple times.
-        let transformed_surface = cache
-            .entry(surface)
-            .or_insert_with(|| surface.transform(transform, core))
-            .clone();
-        let transformed_curve = cache
-            .entry(curve)
-            .or_insert_with(|| Curve::new().insert(core))
-            .clone();
-
-        core.layers.geometry.define_curve(
-            transformed_curve.clone(),
-            transformed_surface,
-            core.layers
+        // a whole object graph, using the cache here ensures that neither the
+        // surface nor the curve get transformed multiple times.
+        let transformed_surface =
+            surface.transform_with_cache(transform, core, cache)?;
+
+        if let Some(transformed_curve) = cache.get(curve) {
+            return Ok(transformed_curve.clone());
+        }
+
+        let missing_geometry = || OperationError::MissingCurveGeometry {
+            curve: curve.clone(),
+            surface: surface.clone(),
+        };
+        let geometry = core
+            .layers
+            .geometry
+            .of_curve(curve)
+            .ok_or_else(missing_geometry)?
+            .clone()
+            .transform_with_cache(transform, core, cache)?;
+        if geometry.local_on(&transformed_surface).is_none() {
+            return Err(missing_geometry());
+        }
+
+        let transformed_curve = Curve::new().insert(core);
+        cache.insert(curve.clone(), transformed_curve.clone());
+
+        for (surface, local) in geometry.definitions {
+            core.layers.geometry.define_curve(
+                transformed_curve.clone(),
+                surface,
+                local,
+            );
+        }
+
+        Ok(transformed_curve)
+    }
+}
+
+/// # Transform the geometry of a curve
+///
+/// Curve geometry is defined locally on surfaces. Transforming it means
+/// transforming those surfaces, while the local definitions stay the same. Lines
+/// and circles are transformed rigidly that way: The center of a circle and the
+/// origin and direction of a line move along with the surface, and under an
+/// isometry, the radius of a circle is preserved.
+///
+/// Definitions on the surface that represents 2D space (as used by sketches)
+/// are not affected by the transform, and are carried over as-is.
+///
+/// Returns an error, if the geometry of one of those surfaces is not defined.
+///
+/// Non-uniform scaling can turn a circle into an ellipse, which would require
+/// promoting the circle to a different type of curve. This is not supported,
+/// and transforming the surface of such a circle returns an error too. See
+/// [`Scale`].
+///
+/// [`Scale`]: super::Scale
+impl TransformObject for CurveGeom {
+    type Transformed = Self;
+
+    fn transform_with_cache(
+        self,
+        transform: &Transform,
+        core: &mut Core,
+        cache: &mut TransformCache,
+    ) -> Result<Self::Transformed, OperationError> {
+        let definitions = self
+            .definitions
+            .into_iter()
+            .map(|(surface, local)| {
+                let has_global_geometry = core
+                    .layers
+                    .geometry
+                    .of_surface_if_defined(&surface)
+                    .is_some()
+                    || core.layers.geometry.of_surface_2(&surface).is_some();
+
+                let surface = if has_global_geometry {
+                    surface.transform_with_cache(transform, core, cache)?
+                } else {
+                    surface
+                };
+
+                Ok((surface, local))
+            })
+            .collect::<Result<_, OperationError>>()?;
+
+        Ok(Self { definitions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        geometry::{Path, repr::tri_mesh::convert_point_surface_to_global},
+        operations::{
+            build::BuildFace, insert::Insert, transform::TransformObject,
+        },
+        topology::Face,
+    };
+
+    #[test]
+    fn rotate_face_with_circular_edge() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face =
+            Face::circle(surface, [1., 0.], 0.5, &mut core).insert(&mut core);
+
+        let rotated = face.rotate([0., 0., FRAC_PI_2], &mut core)?;
+
+        let half_edge = rotated.region().exterior().half_edges().first();
+        let Path::Circle(circle) = core
+            .layers
+            .geometry
+            .of_curve(half_edge.curve())
+            .unwrap()
+            .local_on(rotated.surface())
+            .unwrap()
+            .path
+        else {
+            panic!("Expected rotated curve to still be a circle");
+        };
+        assert!((circle.radius() - 0.5).abs() < Scalar::from(1e-12));
+
+        let center = convert_point_surface_to_global(
+            &*core
+                .layers
                 .geometry
-                .of_curve(curve)
-                .unwrap()
-                .local_on(surface)
+                .of_surface_2(rotated.surface())
                 .unwrap()
-                .clone(),
+                .generator,
+            circle.center(),
+            core.tolerance(),
+            &core.layers.geometry,
+        );
+        assert!(
+            center.distance_to(&Point::from([0., 1., 0.]))
+                < Scalar::from(1e-12)
         );
 
-        transformed_curve
+        Ok(())
     }
 }
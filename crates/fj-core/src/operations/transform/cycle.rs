@@ -3,7 +3,7 @@ use itertools::Itertools;
 
 use crate::{
     Core,
-    operations::insert::Insert,
+    operations::{OperationError, error::local_vertex_geom, insert::Insert},
     storage::Handle,
     topology::{Cycle, Surface},
 };
@@ -18,36 +18,32 @@ impl TransformObject for (&Handle<Cycle>, &Handle<Surface>) {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         let (cycle, surface) = self;
 
         let half_edges_and_old_vertex_geometries = cycle
             .half_edges()
             .pairs()
             .map(|(half_edge, next_half_edge)| {
-                let vertex_a_geom = core
-                    .layers
-                    .geometry
-                    .of_vertex(half_edge.start_vertex())
-                    .unwrap()
-                    .local_on(half_edge.curve())
-                    .unwrap()
-                    .clone();
-                let vertex_b_geom = core
-                    .layers
-                    .geometry
-                    .of_vertex(next_half_edge.start_vertex())
-                    .unwrap()
-                    .local_on(half_edge.curve())
-                    .unwrap()
-                    .clone();
+                let [vertex_a_geom, vertex_b_geom] =
+                    [half_edge.start_vertex(), next_half_edge.start_vertex()]
+                        .map(|vertex| {
+                            local_vertex_geom(
+                                vertex,
+                                half_edge.curve(),
+                                &core.layers.geometry,
+                            )
+                            .cloned()
+                        });
+                let [vertex_a_geom, vertex_b_geom] =
+                    [vertex_a_geom?, vertex_b_geom?];
 
                 let half_edge = (half_edge, surface)
-                    .transform_with_cache(transform, core, cache);
+                    .transform_with_cache(transform, core, cache)?;
 
-                (half_edge, vertex_a_geom, vertex_b_geom)
+                Ok((half_edge, vertex_a_geom, vertex_b_geom))
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, OperationError>>()?;
 
         // That we're transforming the vertex geometry here, instead of down in
         // the vertex transform implementation, presents an inconsistency in the
@@ -98,6 +94,6 @@ impl TransformObject for (&Handle<Cycle>, &Handle<Surface>) {
                 },
             );
 
-        Cycle::new(half_edges).insert(core)
+        Ok(Cycle::new(half_edges).insert(core))
     }
 }
@@ -1,6 +1,6 @@
 use fj_math::Transform;
 
-use crate::{Core, topology::Face};
+use crate::{Core, operations::OperationError, topology::Face};
 
 use super::{TransformCache, TransformObject};
 
@@ -12,14 +12,118 @@ impl TransformObject for Face {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         let surface = self
             .surface()
             .clone()
-            .transform_with_cache(transform, core, cache);
+            .transform_with_cache(transform, core, cache)?;
         let region = (self.region(), self.surface())
-            .transform_with_cache(transform, core, cache);
+            .transform_with_cache(transform, core, cache)?;
 
-        Self::new(surface, region)
+        Ok(Self::new(surface, region))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        Core,
+        geometry::repr::tri_mesh::convert_point_surface_to_global,
+        operations::{
+            OperationError, build::BuildFace, transform::TransformObject,
+        },
+        topology::Face,
+    };
+
+    #[test]
+    fn translate_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .translate([1., 2., 3.], &mut core)?;
+
+        assert_eq!(
+            vertex_positions(&face, &core),
+            [[1., 2., 3.], [2., 2., 3.], [2., 3., 3.], [1., 3., 3.]]
+                .map(Point::from),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn scale_face_non_uniformly() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let face = Face::polygon(
+            core.layers.topology.surfaces.xy_plane(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .scale([2., 1., 1.], &mut core)?;
+
+        assert_eq!(
+            vertex_positions(&face, &core),
+            [[0., 0., 0.], [2., 0., 0.], [2., 1., 0.], [0., 1., 0.]]
+                .map(Point::from),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_face_without_surface_geometry() {
+        let mut core = Core::new();
+
+        // The surface that represents 2D space has no geometry.
+        let surface = core.layers.topology.surfaces.space_2d();
+        let face = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+
+        assert_eq!(
+            face.translate([1., 2., 3.], &mut core).map(|_| ()),
+            Err(OperationError::MissingSurfaceGeometry { surface }),
+        );
+    }
+
+    fn vertex_positions(face: &Face, core: &Core) -> Vec<Point<3>> {
+        let geometry = &core.layers.geometry;
+        let surface = geometry.of_surface_2(face.surface()).unwrap();
+
+        face.region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(face.surface())
+                    .unwrap()
+                    .path;
+                let position = geometry
+                    .of_vertex(half_edge.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position;
+
+                convert_point_surface_to_global(
+                    &surface.generator,
+                    path.point_from_path_coords(position),
+                    0.001,
+                    geometry,
+                )
+            })
+            .collect()
     }
 }
@@ -2,7 +2,7 @@ use fj_math::Transform;
 
 use crate::{
     Core,
-    operations::insert::Insert,
+    operations::{OperationError, insert::Insert},
     storage::Handle,
     topology::{HalfEdge, Surface},
 };
@@ -17,16 +17,16 @@ impl TransformObject for (&Handle<HalfEdge>, &Handle<Surface>) {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         let (half_edge, surface) = self;
 
         let curve = (half_edge.curve(), surface)
-            .transform_with_cache(transform, core, cache);
+            .transform_with_cache(transform, core, cache)?;
         let start_vertex = half_edge
             .start_vertex()
             .clone()
-            .transform_with_cache(transform, core, cache);
+            .transform_with_cache(transform, core, cache)?;
 
-        HalfEdge::new(curve, start_vertex).insert(core)
+        Ok(HalfEdge::new(curve, start_vertex).insert(core))
     }
 }
@@ -5,12 +5,16 @@ mod cycle;
 mod face;
 mod half_edge;
 mod region;
+mod scale;
 mod shell;
+mod sketch;
 mod solid;
 mod surface;
 mod vertex;
 
-use std::collections::{BTreeMap, btree_map};
+pub use self::{scale::Scale, sketch::TranslateOnSurface};
+
+use std::collections::BTreeMap;
 
 use fj_math::{Transform, Vector};
 use type_map::TypeMap;
@@ -22,10 +26,13 @@ use crate::{
     topology::{AnyObject, Stored},
 };
 
-use super::derive::DeriveFrom;
+use super::{OperationError, derive::DeriveFrom};
 
 /// Transform an object
 ///
+/// Transforming an object requires the geometry of all objects it references
+/// to be defined. If that is not the case, an [`OperationError`] is returned.
+///
 /// # Implementation Note
 ///
 /// So far, a general `transform` method is available, along some convenience
@@ -42,7 +49,7 @@ pub trait TransformObject: Sized {
         self,
         transform: &Transform,
         core: &mut Core,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         let mut cache = TransformCache::default();
         self.transform_with_cache(transform, core, &mut cache)
     }
@@ -53,7 +60,7 @@ pub trait TransformObject: Sized {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed;
+    ) -> Result<Self::Transformed, OperationError>;
 
     /// Translate the object
     ///
@@ -62,7 +69,7 @@ pub trait TransformObject: Sized {
         self,
         offset: impl Into<Vector<3>>,
         core: &mut Core,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         self.transform(&Transform::translation(offset), core)
     }
 
@@ -73,9 +80,21 @@ pub trait TransformObject: Sized {
         self,
         axis_angle: impl Into<Vector<3>>,
         core: &mut Core,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         self.transform(&Transform::rotation(axis_angle), core)
     }
+
+    /// Scale the object
+    ///
+    /// Convenience wrapper around [`TransformObject::transform`]. See [`Scale`]
+    /// for the limitations of non-uniform scaling.
+    fn scale(
+        self,
+        scale: impl Into<Scale>,
+        core: &mut Core,
+    ) -> Result<Self::Transformed, OperationError> {
+        self.transform(&scale.into().into(), core)
+    }
 }
 
 impl<T> TransformObject for Handle<T>
@@ -93,20 +112,24 @@ where
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         if let Some(object) = cache.get(&self) {
-            return object.clone();
+            return Ok(object.clone());
         }
 
         let transformed = self
             .clone_object()
-            .transform_with_cache(transform, core, cache)
-            .insert(core)
-            .derive_from(&self, core);
+            .transform_with_cache(transform, core, cache)?
+            .insert(core);
+        let transformed = if transform.is_rigid() {
+            transformed.derive_from_rigid_motion(&self, core)
+        } else {
+            transformed.derive_from(&self, core)
+        };
 
         cache.insert(self.clone(), transformed.clone());
 
-        transformed
+        Ok(transformed)
     }
 }
 
@@ -117,18 +140,6 @@ where
 pub struct TransformCache(TypeMap);
 
 impl TransformCache {
-    fn entry<T: 'static>(
-        &mut self,
-        key: &Handle<T>,
-    ) -> btree_map::Entry<'_, ObjectId, Handle<T>> {
-        let map = self
-            .0
-            .entry::<BTreeMap<ObjectId, Handle<T>>>()
-            .or_insert_with(BTreeMap::new);
-
-        map.entry(key.id())
-    }
-
     fn get<T: 'static>(&mut self, key: &Handle<T>) -> Option<&Handle<T>> {
         let map = self
             .0
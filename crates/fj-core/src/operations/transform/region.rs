@@ -1,6 +1,6 @@
 use crate::{
     Core,
-    operations::insert::Insert,
+    operations::{OperationError, insert::Insert},
     storage::Handle,
     topology::{Region, Surface},
 };
@@ -15,15 +15,19 @@ impl TransformObject for (&Handle<Region>, &Handle<Surface>) {
         transform: &fj_math::Transform,
         core: &mut Core,
         cache: &mut super::TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         let (region, surface) = self;
 
         let exterior = (region.exterior(), surface)
-            .transform_with_cache(transform, core, cache);
-        let interiors = region.interiors().iter().map(|interior| {
-            (interior, surface).transform_with_cache(transform, core, cache)
-        });
+            .transform_with_cache(transform, core, cache)?;
+        let interiors = region
+            .interiors()
+            .iter()
+            .map(|interior| {
+                (interior, surface).transform_with_cache(transform, core, cache)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Region::new(exterior, interiors).insert(core)
+        Ok(Region::new(exterior, interiors).insert(core))
     }
 }
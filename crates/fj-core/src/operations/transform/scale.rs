@@ -0,0 +1,70 @@
+use fj_math::{Scalar, Transform, Vector};
+
+/// # A scaling, with a separate factor for each axis
+///
+/// Can be converted into a [`Transform`], to be used with
+/// [`TransformObject`]. See [`TransformObject::scale`].
+///
+/// ## Non-Uniform Scaling
+///
+/// If the factors are not all the same, the scaling is non-uniform, and the
+/// resulting transform is no longer orthonormal. Not all geometry survives
+/// this:
+///
+/// - Lines stay lines. This means planes stay planes, and since curves are
+///   defined in surface coordinates, anything that is defined on a plane can
+///   be scaled. A circle on a plane is still a circle in the plane's surface
+///   coordinates, but in global space, it becomes an ellipse.
+/// - Circles in global space, as they are used to define curved surfaces,
+///   become ellipses, which can't be represented. Transforming such a surface
+///   with a non-uniform scaling returns an error, unless the scaling happens
+///   to be uniform within the plane of the circle.
+///
+/// The normal of a plane is not stored, but computed from the vectors that
+/// define the plane, as required. Those vectors are transformed as-is, which
+/// means the normal is recomputed implicitly, and remains correct after a
+/// non-uniform scaling.
+///
+/// [`TransformObject`]: super::TransformObject
+/// [`TransformObject::scale`]: super::TransformObject::scale
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Scale {
+    /// The scaling factor for each axis
+    pub factors: Vector<3>,
+}
+
+impl Scale {
+    /// Construct a scaling with a separate factor for each axis
+    pub fn new(factors: impl Into<Vector<3>>) -> Self {
+        Self {
+            factors: factors.into(),
+        }
+    }
+
+    /// Construct a scaling with the same factor for each axis
+    pub fn uniform(factor: impl Into<Scalar>) -> Self {
+        let factor = factor.into();
+        Self::new([factor, factor, factor])
+    }
+
+    /// Indicate whether the scaling has the same factor for each axis
+    pub fn is_uniform(&self) -> bool {
+        let [x, y, z] = self.factors.components;
+        x == y && y == z
+    }
+}
+
+impl From<Scale> for Transform {
+    fn from(scale: Scale) -> Self {
+        Transform::scale(scale.factors)
+    }
+}
+
+impl<T> From<T> for Scale
+where
+    T: Into<Vector<3>>,
+{
+    fn from(factors: T) -> Self {
+        Self::new(factors)
+    }
+}
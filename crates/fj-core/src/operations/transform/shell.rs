@@ -1,6 +1,6 @@
 use fj_math::Transform;
 
-use crate::{Core, topology::Shell};
+use crate::{Core, operations::OperationError, topology::Shell};
 
 use super::{TransformCache, TransformObject};
 
@@ -12,13 +12,14 @@ impl TransformObject for Shell {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         let faces = self
             .faces()
             .iter()
             .cloned()
-            .map(|face| face.transform_with_cache(transform, core, cache));
+            .map(|face| face.transform_with_cache(transform, core, cache))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self::new(faces)
+        Ok(Self::new(faces))
     }
 }
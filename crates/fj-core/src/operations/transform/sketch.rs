@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use fj_math::{Circle, Line, Vector};
+
+use crate::{
+    Core,
+    geometry::{LocalCurveGeom, Path},
+    operations::{
+        OperationError,
+        error::{local_curve_geom, local_vertex_geom},
+        insert::Insert,
+    },
+    storage::Handle,
+    topology::{Curve, Cycle, HalfEdge, Region, Sketch, Vertex},
+};
+
+/// # Translate a sketch within its surface
+///
+/// Moving a sketch within the surface it is defined on doesn't require a full
+/// 3D transform, nor does it create a new surface. See
+/// [`TranslateOnSurface::translate_on_surface`].
+pub trait TranslateOnSurface {
+    /// # Translate the sketch by an offset in surface coordinates
+    ///
+    /// Creates a new sketch on the same surface, whose curves are the original
+    /// ones, shifted by the provided offset. The positions of the vertices on
+    /// those curves stay the same, which moves them by the offset too.
+    ///
+    /// Returns an error, if the geometry of any curve or vertex of the sketch
+    /// is not defined.
+    fn translate_on_surface(
+        &self,
+        offset: impl Into<Vector<2>>,
+        core: &mut Core,
+    ) -> Result<Handle<Sketch>, OperationError>;
+}
+
+impl TranslateOnSurface for Handle<Sketch> {
+    fn translate_on_surface(
+        &self,
+        offset: impl Into<Vector<2>>,
+        core: &mut Core,
+    ) -> Result<Handle<Sketch>, OperationError> {
+        let mut translation = Translation {
+            offset: offset.into(),
+            sketch: self,
+            curves: BTreeMap::new(),
+            vertices: BTreeMap::new(),
+        };
+
+        let regions = self
+            .regions()
+            .iter()
+            .map(|region| {
+                let exterior = translation.cycle(region.exterior(), core)?;
+                let interiors = region
+                    .interiors()
+                    .iter()
+                    .map(|interior| translation.cycle(interior, core))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Region::new(exterior, interiors).insert(core))
+            })
+            .collect::<Result<Vec<_>, OperationError>>()?;
+
+        Ok(Sketch::new(self.surface().clone(), regions).insert(core))
+    }
+}
+
+struct Translation<'r> {
+    offset: Vector<2>,
+    sketch: &'r Handle<Sketch>,
+
+    // Curves and vertices can be shared between cycles. Tracking the ones
+    // we've already translated makes sure they are still shared afterwards.
+    curves: BTreeMap<Handle<Curve>, Handle<Curve>>,
+    vertices: BTreeMap<Handle<Vertex>, Handle<Vertex>>,
+}
+
+impl Translation<'_> {
+    fn cycle(
+        &mut self,
+        cycle: &Handle<Cycle>,
+        core: &mut Core,
+    ) -> Result<Handle<Cycle>, OperationError> {
+        let half_edges = cycle
+            .half_edges()
+            .pairs()
+            .map(|(half_edge, next)| {
+                let curve = self.curve(half_edge.curve(), core)?;
+
+                // The positions of the vertices on the translated curve are
+                // the same as on the original one.
+                let [start, end] =
+                    [half_edge.start_vertex(), next.start_vertex()].map(
+                        |vertex| {
+                            let geometry = local_vertex_geom(
+                                vertex,
+                                half_edge.curve(),
+                                &core.layers.geometry,
+                            )?
+                            .clone();
+                            Ok((vertex, geometry))
+                        },
+                    );
+                let [start, _] = [start?, end?].map(|(vertex, geometry)| {
+                    let vertex = self.vertex(vertex, core);
+                    core.layers.geometry.define_vertex(
+                        vertex.clone(),
+                        curve.clone(),
+                        geometry,
+                    );
+                    vertex
+                });
+
+                Ok(HalfEdge::new(curve, start).insert(core))
+            })
+            .collect::<Result<Vec<_>, OperationError>>()?;
+
+        Ok(Cycle::new(half_edges).insert(core))
+    }
+
+    fn curve(
+        &mut self,
+        curve: &Handle<Curve>,
+        core: &mut Core,
+    ) -> Result<Handle<Curve>, OperationError> {
+        if let Some(translated) = self.curves.get(curve) {
+            return Ok(translated.clone());
+        }
+
+        let surface = self.sketch.surface();
+        let path =
+            local_curve_geom(curve, surface, &core.layers.geometry)?.path;
+        let path = match path {
+            Path::Circle(circle) => Path::Circle(Circle::new(
+                circle.center() + self.offset,
+                circle.a(),
+                circle.b(),
+            )),
+            Path::Line(line) => Path::Line(Line::from_origin_and_direction(
+                line.origin() + self.offset,
+                line.direction(),
+            )),
+        };
+
+        let translated = Curve::new().insert(core);
+        core.layers.geometry.define_curve(
+            translated.clone(),
+            surface.clone(),
+            LocalCurveGeom { path },
+        );
+        self.curves.insert(curve.clone(), translated.clone());
+
+        Ok(translated)
+    }
+
+    fn vertex(
+        &mut self,
+        vertex: &Handle<Vertex>,
+        core: &mut Core,
+    ) -> Handle<Vertex> {
+        self.vertices
+            .entry(vertex.clone())
+            .or_insert_with(|| Vertex::new().insert(core))
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Vector};
+
+    use crate::{
+        Core,
+        operations::{build::BuildSketch, insert::Insert},
+        storage::Handle,
+        topology::Sketch,
+    };
+
+    use super::TranslateOnSurface;
+
+    #[test]
+    fn translate_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let sketch = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let translated = sketch.translate_on_surface([1., 1.], &mut core)?;
+
+        let offset = Vector::from([1., 1.]);
+        let expected = vertex_positions(&sketch, &core)
+            .into_iter()
+            .map(|point| point + offset)
+            .collect::<Vec<_>>();
+        assert_eq!(vertex_positions(&translated, &core), expected);
+        assert_eq!(
+            expected,
+            [[1., 1.], [2., 1.], [2., 2.], [1., 2.]].map(Point::from),
+        );
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    fn vertex_positions(sketch: &Handle<Sketch>, core: &Core) -> Vec<Point<2>> {
+        let geometry = &core.layers.geometry;
+
+        sketch
+            .regions()
+            .only()
+            .exterior()
+            .half_edges()
+            .iter()
+            .map(|half_edge| {
+                let path = geometry
+                    .of_curve(half_edge.curve())
+                    .unwrap()
+                    .local_on(sketch.surface())
+                    .unwrap()
+                    .path;
+                let position = geometry
+                    .of_vertex(half_edge.start_vertex())
+                    .unwrap()
+                    .local_on(half_edge.curve())
+                    .unwrap()
+                    .position;
+
+                path.point_from_path_coords(position)
+            })
+            .collect()
+    }
+}
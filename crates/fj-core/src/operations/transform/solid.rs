@@ -1,6 +1,6 @@
 use fj_math::Transform;
 
-use crate::{Core, topology::Solid};
+use crate::{Core, operations::OperationError, topology::Solid};
 
 use super::{TransformCache, TransformObject};
 
@@ -12,12 +12,14 @@ impl TransformObject for Solid {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
-        let shells =
-            self.shells().iter().cloned().map(|shell| {
-                shell.transform_with_cache(transform, core, cache)
-            });
+    ) -> Result<Self::Transformed, OperationError> {
+        let shells = self
+            .shells()
+            .iter()
+            .cloned()
+            .map(|shell| shell.transform_with_cache(transform, core, cache))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self::new(shells)
+        Ok(Self::new(shells))
     }
 }
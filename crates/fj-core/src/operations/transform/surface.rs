@@ -1,13 +1,13 @@
-use fj_math::Transform;
+use fj_math::{Scalar, Transform};
 
 use crate::{
     Core,
     geometry::{
-        SurfaceGeom,
+        Path, SurfaceGeom,
         repr::tri_mesh::TriMesh,
         surfaces::{SweptCurve, TransformedSurface},
     },
-    operations::insert::Insert,
+    operations::{OperationError, error::surface_geom, insert::Insert},
     storage::Handle,
     topology::Surface,
 };
@@ -22,38 +22,197 @@ impl TransformObject for &Handle<Surface> {
         transform: &Transform,
         core: &mut Core,
         cache: &mut TransformCache,
-    ) -> Self::Transformed {
-        cache
-            .entry(self)
-            .or_insert_with(|| {
-                let surface = Surface::new().insert(core);
-
-                let geometry = {
-                    let SweptCurve { u, v } =
-                        core.layers.geometry.of_surface(self);
-
-                    SweptCurve {
-                        u: u.transform(transform),
-                        v: transform.transform_vector(v),
+    ) -> Result<Self::Transformed, OperationError> {
+        if let Some(surface) = cache.get(self) {
+            return Ok(surface.clone());
+        }
+
+        // Surfaces that are defined by one of the newer surface types, like
+        // cones and tori, only have new-style geometry. That is transformed
+        // below.
+        let geometry = match core.layers.geometry.of_surface_if_defined(self) {
+            Some(SweptCurve { u, v }) => Some(SweptCurve {
+                u: transform_path(*u, transform).ok_or_else(|| {
+                    OperationError::CircleWouldBecomeEllipse {
+                        surface: self.clone(),
                     }
-                };
-                core.layers
-                    .geometry
-                    .define_surface(surface.clone(), geometry);
-
-                core.layers.geometry.define_surface_2(
-                    surface.clone(),
-                    SurfaceGeom {
-                        generator: Box::new(TransformedSurface {
-                            surface: self.clone(),
-                            transform: *transform,
-                        }),
-                        geometry: TriMesh::empty(),
-                    },
-                );
-
-                surface
-            })
-            .clone()
+                })?,
+                v: transform.transform_vector(v),
+            }),
+            None => {
+                if core.layers.geometry.of_surface_2(self).is_none() {
+                    surface_geom(self, &core.layers.geometry)?;
+                }
+
+                None
+            }
+        };
+
+        let surface = Surface::new().insert(core);
+        if let Some(geometry) = geometry {
+            core.layers
+                .geometry
+                .define_surface(surface.clone(), geometry);
+        }
+        core.layers.geometry.define_surface_2(
+            surface.clone(),
+            SurfaceGeom {
+                generator: Box::new(TransformedSurface {
+                    surface: self.clone(),
+                    transform: *transform,
+                }),
+                geometry: TriMesh::empty(),
+                is_reversed: false,
+            },
+        );
+
+        cache.insert(self.clone(), surface.clone());
+
+        Ok(surface)
+    }
+}
+
+/// # Transform the path that defines a surface
+///
+/// Returns `None`, if the path is a circle that would turn into an ellipse.
+/// This can happen with a non-uniform scaling (see [`Scale`]) or a shear.
+///
+/// The transformed axes of the circle are compared using an epsilon value that
+/// is relative to their length, as the components of any transform that isn't
+/// trivial are subject to floating point inaccuracies.
+///
+/// [`Scale`]: super::Scale
+fn transform_path(path: Path<3>, transform: &Transform) -> Option<Path<3>> {
+    if let Path::Circle(circle) = path {
+        let [a, b] = [circle.a(), circle.b()]
+            .map(|vector| transform.transform_vector(&vector));
+        let [a_mag, b_mag] = [a.magnitude(), b.magnitude()];
+
+        let epsilon = Scalar::from(1e-9) * a_mag.max(b_mag);
+        let is_still_circle = (a_mag - b_mag).abs() <= epsilon
+            && a.dot(&b).abs() <= epsilon * a_mag.max(b_mag);
+        if !is_still_circle {
+            return None;
+        }
+    }
+
+    Some(path.transform(transform))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Transform, Vector};
+
+    use crate::{
+        Core,
+        geometry::{
+            Path, SurfaceGeom, repr::tri_mesh::TriMesh, surfaces::Torus,
+        },
+        operations::{
+            OperationError, build::BuildSurface, insert::Insert,
+            transform::TransformObject,
+        },
+        topology::Surface,
+    };
+
+    #[test]
+    fn scale_curved_surface_uniformly_within_plane_of_circle() {
+        let mut core = Core::new();
+
+        let surface = Surface::from_uv(
+            Path::circle_from_radius(1.),
+            [0., 0., 1.],
+            &mut core,
+        );
+        let scaled = (&surface).scale([2., 2., 3.], &mut core).unwrap();
+
+        let geometry = core.layers.geometry.of_surface(&scaled);
+        assert_eq!(geometry.u, Path::circle_from_radius(2.));
+        assert_eq!(geometry.v, [0., 0., 3.].into());
+    }
+
+    #[test]
+    fn scale_curved_surface_non_uniformly_within_plane_of_circle() {
+        let mut core = Core::new();
+
+        let surface = Surface::from_uv(
+            Path::circle_from_radius(1.),
+            [0., 0., 1.],
+            &mut core,
+        );
+        let result = (&surface).scale([2., 1., 1.], &mut core);
+
+        assert_eq!(
+            result,
+            Err(OperationError::CircleWouldBecomeEllipse { surface }),
+        );
+    }
+
+    #[test]
+    fn shear_curved_surface() {
+        let mut core = Core::new();
+
+        let surface = Surface::from_uv(
+            Path::circle_from_radius(1.),
+            [0., 0., 1.],
+            &mut core,
+        );
+
+        // Rotating the circle and then scaling it non-uniformly tilts its
+        // axes towards each other, without changing their length.
+        let shear = Transform::scale([2., 1., 1.])
+            * Transform::rotation(Vector::unit_z() * Scalar::PI / 4.);
+        let result = (&surface).transform(&shear, &mut core);
+
+        assert_eq!(
+            result,
+            Err(OperationError::CircleWouldBecomeEllipse { surface }),
+        );
+    }
+
+    #[test]
+    fn rotate_curved_surface() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = Surface::from_uv(
+            Path::circle_from_radius(1.),
+            [0., 0., 1.],
+            &mut core,
+        );
+
+        for axis_angle in [[1., 0., 0.], [0.3, 0.5, 0.7], [0., 0., 1.]] {
+            (&surface).rotate(axis_angle, &mut core)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn transform_surface_without_swept_curve() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = Surface::new().insert(&mut core);
+        core.layers.geometry.define_surface_2(
+            surface.clone(),
+            SurfaceGeom {
+                generator: Box::new(Torus::new(
+                    [0., 0., 0.],
+                    [0., 0., 1.],
+                    2.,
+                    1.,
+                    [1., 0., 0.],
+                )),
+                geometry: TriMesh::empty(),
+                is_reversed: false,
+            },
+        );
+
+        let translated = (&surface).translate([1., 0., 0.], &mut core)?;
+
+        let geometry = &core.layers.geometry;
+        assert!(geometry.of_surface_if_defined(&translated).is_none());
+        assert!(geometry.of_surface_2(&translated).is_some());
+
+        Ok(())
     }
 }
@@ -1,6 +1,6 @@
 use fj_math::Transform;
 
-use crate::{Core, topology::Vertex};
+use crate::{Core, operations::OperationError, topology::Vertex};
 
 use super::{TransformCache, TransformObject};
 
@@ -12,10 +12,10 @@ impl TransformObject for Vertex {
         _: &Transform,
         _: &mut Core,
         _: &mut TransformCache,
-    ) -> Self::Transformed {
+    ) -> Result<Self::Transformed, OperationError> {
         // There's nothing to actually transform here, as `Vertex` holds no
         // data. We still need this implementation though, as a new `Vertex`
         // object must be created to represent the new and transformed vertex.
-        Self::new()
+        Ok(Self::new())
     }
 }
@@ -1,8 +1,17 @@
+use fj_interop::Tolerance;
+
 use crate::{
     Core,
-    operations::{derive::DeriveFrom, insert::Insert},
+    geometry::LocalVertexGeom,
+    operations::{
+        OperationError,
+        build::BuildHalfEdge,
+        derive::DeriveFrom,
+        error::{local_curve_geom, local_vertex_geom},
+        insert::Insert,
+    },
     storage::Handle,
-    topology::{Cycle, HalfEdge},
+    topology::{Cycle, HalfEdge, Surface},
 };
 
 /// Update a [`Cycle`]
@@ -34,6 +43,29 @@ pub trait UpdateCycle {
     where
         T: Insert<Inserted = Handle<HalfEdge>>,
         R: IntoIterator<Item = T>;
+
+    /// Close the cycle, if its last half-edge doesn't end at its start
+    ///
+    /// A cycle is built incrementally by adding half-edges to it, and the last
+    /// half-edge implicitly ends at the start vertex of the first one. If the
+    /// last half-edge's curve doesn't actually pass through that vertex, the
+    /// cycle is open. This is checked on the provided surface, within the
+    /// provided tolerance.
+    ///
+    /// An open cycle is closed by appending a line segment that goes from the
+    /// end of the last half-edge to the start of the first one. The end of the
+    /// last half-edge is then bounded by the start vertex of that line segment.
+    ///
+    /// If the cycle is already closed, or if it is empty, it is returned
+    /// unchanged.
+    fn close(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Self, OperationError>
+    where
+        Self: Sized;
 }
 
 impl UpdateCycle for Cycle {
@@ -73,4 +105,117 @@ impl UpdateCycle for Cycle {
             .expect("Half-edge not found");
         Cycle::new(edges)
     }
+
+    fn close(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Result<Self, OperationError> {
+        let half_edges = self.half_edges();
+        if half_edges.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let first = half_edges.first();
+        let last = half_edges.nth_circular(half_edges.len() - 1);
+        let vertex = first.start_vertex();
+
+        // The last half-edge ends wherever the start vertex of the first one
+        // is located on its curve. For an open cycle, that is somewhere else
+        // than where the vertex is located on the curve of the first one.
+        let [start, end] = [first, last].map(|half_edge| {
+            let path = local_curve_geom(
+                half_edge.curve(),
+                surface,
+                &core.layers.geometry,
+            )?
+            .path;
+            let position = local_vertex_geom(
+                vertex,
+                half_edge.curve(),
+                &core.layers.geometry,
+            )?
+            .position;
+
+            Ok((position, path.point_from_path_coords(position)))
+        });
+        let [(_, start), (end_curve, end)] = [start?, end?];
+
+        if start.distance_to(&end) <= tolerance.into().inner() {
+            return Ok(self.clone());
+        }
+
+        let (closing, boundary) =
+            HalfEdge::line_segment([end, start], surface.clone(), core);
+
+        core.layers.geometry.define_vertex(
+            closing.start_vertex().clone(),
+            last.curve().clone(),
+            LocalVertexGeom {
+                position: end_curve,
+            },
+        );
+        for (vertex, position) in [closing.start_vertex(), vertex]
+            .into_iter()
+            .zip(boundary.inner)
+        {
+            core.layers.geometry.define_vertex(
+                vertex.clone(),
+                closing.curve().clone(),
+                LocalVertexGeom { position },
+            );
+        }
+
+        Ok(self.add_half_edges([closing], core))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildCycle, BuildHalfEdge},
+            update::UpdateCycle,
+        },
+        topology::{Cycle, HalfEdge},
+    };
+
+    #[test]
+    fn close_open_chain() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        // A chain of three vertices, with the last half-edge ending at
+        // `[0, 1]`, instead of going back to the start of the first one.
+        let half_edges_and_boundaries =
+            [[[0., 0.], [1., 0.]], [[1., 0.], [0., 1.]]].map(|points| {
+                HalfEdge::line_segment(points, surface.clone(), &mut core)
+            });
+        let open = Cycle::from_half_edges_and_boundaries(
+            half_edges_and_boundaries,
+            &mut core,
+        );
+
+        let closed = open.close(&surface, 0.001, &mut core)?;
+        assert_eq!(closed.half_edges().len(), 3);
+
+        let points = closed
+            .to_polyline_2d(&surface, &core.layers.geometry, 0.001)
+            .expect("Geometry is defined");
+        assert_eq!(
+            points,
+            [[0., 0.], [1., 0.], [0., 1.], [0., 0.]].map(Point::from)
+        );
+
+        // Closing the cycle again doesn't change anything.
+        let closed_again = closed.close(&surface, 0.001, &mut core)?;
+        assert_eq!(closed_again.half_edges().len(), 3);
+
+        Ok(())
+    }
 }
@@ -6,12 +6,16 @@ use std::collections::BTreeMap;
 
 use fj_interop::Color;
 
-use crate::{storage::Handle, topology::Region};
+use crate::{
+    storage::Handle,
+    topology::{AnyObject, Region, Stored},
+};
 
 /// Presentation data for the object graph
 ///
-/// Assigns attributes relating to the presentation of objects (currently just a
-/// color) to those objects (currently only to regions).
+/// Assigns attributes relating to the presentation of objects to those objects.
+/// Currently, that is a color (only assigned to regions) and a label (which can
+/// be assigned to any object).
 ///
 /// This data is made available through [`Layers`].
 ///
@@ -23,4 +27,10 @@ pub struct Presentation {
     /// Having a color is optional, so map does not necessarily contain
     /// assignments for all existing regions.
     pub color: BTreeMap<Handle<Region>, Color>,
+
+    /// Human-readable labels assigned to objects
+    ///
+    /// Having a label is optional, so map does not necessarily contain
+    /// assignments for all existing objects.
+    pub label: BTreeMap<AnyObject<Stored>, String>,
 }
@@ -0,0 +1,341 @@
+use std::ops::Range;
+
+use fj_interop::Tolerance;
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::bounding_volume::BoundingVolume, geometry::Geometry,
+    storage::Handle, topology::Face,
+};
+
+/// # A bounding volume hierarchy over faces
+///
+/// Indexes faces by their axis-aligned bounding boxes in global coordinates,
+/// so that the faces near a point, along a ray, or within a box can be found
+/// without checking every single face.
+///
+/// The tree is built top-down. At each node, the faces are sorted along the
+/// longest axis of the bounds of their boxes' centers, and split at the
+/// median.
+///
+/// Queries return candidates, meaning faces whose bounding boxes match the
+/// query. Whether the faces themselves match, needs to be checked by the
+/// caller, if that is relevant.
+#[derive(Clone, Debug)]
+pub struct AabbTree {
+    faces: Vec<(Aabb<3>, Handle<Face>)>,
+    nodes: Vec<Node>,
+}
+
+impl AabbTree {
+    /// # The maximum number of faces in a leaf node
+    const LEAF_SIZE: usize = 4;
+
+    /// # Build a tree from the provided faces
+    ///
+    /// The bounding box of each face is computed using [`BoundingVolume`], at the
+    /// provided tolerance. Faces that have no bounding box are skipped.
+    pub fn new(
+        faces: impl IntoIterator<Item = Handle<Face>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> Self {
+        let tolerance = tolerance.into();
+
+        Self::from_boxes(faces.into_iter().filter_map(|face| {
+            let aabb = face.aabb_with_tolerance(tolerance, geometry)?;
+            Some((aabb, face))
+        }))
+    }
+
+    /// # Build a tree from faces with precomputed bounding boxes
+    pub fn from_boxes(
+        faces: impl IntoIterator<Item = (Aabb<3>, Handle<Face>)>,
+    ) -> Self {
+        let mut tree = Self {
+            faces: faces.into_iter().collect(),
+            nodes: Vec::new(),
+        };
+
+        if !tree.faces.is_empty() {
+            tree.build(0..tree.faces.len());
+        }
+
+        tree
+    }
+
+    /// # Access the number of faces in the tree
+    pub fn len(&self) -> usize {
+        self.faces.len()
+    }
+
+    /// # Indicate whether the tree contains no faces
+    pub fn is_empty(&self) -> bool {
+        self.faces.is_empty()
+    }
+
+    /// # Find all faces whose bounding box contains the point
+    pub fn query_point(&self, point: impl Into<Point<3>>) -> Vec<Handle<Face>> {
+        let point = point.into();
+        self.query(|aabb| aabb.contains(point))
+    }
+
+    /// # Find all faces whose bounding box is pierced by the ray
+    ///
+    /// The ray starts at `origin` and extends infinitely in `direction`.
+    pub fn query_ray(
+        &self,
+        origin: impl Into<Point<3>>,
+        direction: impl Into<Vector<3>>,
+    ) -> Vec<Handle<Face>> {
+        let origin = origin.into();
+        let direction = direction.into();
+
+        self.query(|aabb| ray_hits_aabb(origin, direction, aabb))
+    }
+
+    /// # Find all faces whose bounding box overlaps the provided box
+    pub fn query_box(&self, aabb: &Aabb<3>) -> Vec<Handle<Face>> {
+        self.query(|other| aabbs_overlap(aabb, other))
+    }
+
+    fn query(&self, matches: impl Fn(&Aabb<3>) -> bool) -> Vec<Handle<Face>> {
+        let mut result = Vec::new();
+        if self.nodes.is_empty() {
+            return result;
+        }
+
+        let mut stack = vec![0];
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !matches(&node.aabb) {
+                continue;
+            }
+
+            match &node.kind {
+                NodeKind::Leaf { faces } => {
+                    result.extend(
+                        self.faces[faces.clone()]
+                            .iter()
+                            .filter(|(aabb, _)| matches(aabb))
+                            .map(|(_, face)| face.clone()),
+                    );
+                }
+                NodeKind::Branch { children } => {
+                    stack.extend(children);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// # Build the node for the provided range of faces
+    ///
+    /// Returns the index of the node.
+    fn build(&mut self, faces: Range<usize>) -> usize {
+        let aabb = self.faces[faces.clone()]
+            .iter()
+            .map(|(aabb, _)| *aabb)
+            .reduce(|a, b| a.merged(&b))
+            .expect("Range of faces is not empty");
+
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            aabb,
+            kind: NodeKind::Leaf {
+                faces: faces.clone(),
+            },
+        });
+
+        if faces.len() <= Self::LEAF_SIZE {
+            return index;
+        }
+
+        let centers = Aabb::<3>::from_points(
+            self.faces[faces.clone()]
+                .iter()
+                .map(|(aabb, _)| aabb.center()),
+        );
+        let size = centers.size();
+        let axis = (0..3)
+            .max_by_key(|&axis| size.components[axis])
+            .expect("Range is not empty");
+
+        self.faces[faces.clone()]
+            .sort_by_key(|(aabb, _)| aabb.center().coords.components[axis]);
+
+        let middle = faces.start + faces.len() / 2;
+        let children = [
+            self.build(faces.start..middle),
+            self.build(middle..faces.end),
+        ];
+        self.nodes[index].kind = NodeKind::Branch { children };
+
+        index
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    aabb: Aabb<3>,
+    kind: NodeKind,
+}
+
+#[derive(Clone, Debug)]
+enum NodeKind {
+    Leaf { faces: Range<usize> },
+    Branch { children: [usize; 2] },
+}
+
+fn aabbs_overlap(a: &Aabb<3>, b: &Aabb<3>) -> bool {
+    (0..3).all(|axis| {
+        a.min.coords.components[axis] <= b.max.coords.components[axis]
+            && b.min.coords.components[axis] <= a.max.coords.components[axis]
+    })
+}
+
+/// # Determine whether a ray hits a box, using the slab method
+fn ray_hits_aabb(
+    origin: Point<3>,
+    direction: Vector<3>,
+    aabb: &Aabb<3>,
+) -> bool {
+    // The range of ray parameters within all slabs checked so far. The ray
+    // is infinite, so there's no upper bound initially.
+    let mut t_min = Scalar::ZERO;
+    let mut t_max: Option<Scalar> = None;
+
+    for axis in 0..3 {
+        let o = origin.coords.components[axis];
+        let d = direction.components[axis];
+        let min = aabb.min.coords.components[axis];
+        let max = aabb.max.coords.components[axis];
+
+        if d == Scalar::ZERO {
+            if o < min || o > max {
+                return false;
+            }
+            continue;
+        }
+
+        let [a, b] = [(min - o) / d, (max - o) / d];
+        let (near, far) = if a < b { (a, b) } else { (b, a) };
+
+        t_min = t_min.max(near);
+        let t = t_max.map_or(far, |t_max| t_max.min(far));
+        t_max = Some(t);
+
+        if t_min > t {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::Aabb;
+
+    use crate::{
+        Core,
+        operations::{build::BuildFace, insert::Insert},
+        storage::{Handle, ObjectId},
+        topology::Face,
+    };
+
+    use super::AabbTree;
+
+    #[test]
+    fn ray_query_returns_pierced_boxes() {
+        let mut core = Core::new();
+        let (tree, grid) = grid(&mut core);
+
+        // Straight down, through a single face.
+        assert_eq!(
+            ids(tree.query_ray([6.5, 8.5, 5.], [0., 0., -1.])),
+            ids([grid[3][4].clone()]),
+        );
+
+        // Along a row of faces.
+        assert_eq!(
+            ids(tree.query_ray([-5., 8.5, 0.], [1., 0., 0.])),
+            ids(grid.iter().map(|column| column[4].clone())),
+        );
+
+        // Diagonally, through the faces along the diagonal of the grid.
+        assert_eq!(
+            ids(tree.query_ray([0.5, 0.5, 0.], [1., 1., 0.])),
+            ids((0..10).map(|i| grid[i][i].clone())),
+        );
+
+        // Pointing away from the grid.
+        assert!(tree.query_ray([6.5, 8.5, 5.], [0., 0., 1.]).is_empty());
+    }
+
+    #[test]
+    fn point_and_box_queries() {
+        let mut core = Core::new();
+        let (tree, grid) = grid(&mut core);
+
+        assert_eq!(
+            ids(tree.query_point([4.5, 2.5, 0.])),
+            ids([grid[2][1].clone()]),
+        );
+        assert!(tree.query_point([5.5, 2.5, 0.]).is_empty());
+
+        let aabb = Aabb {
+            min: [0.5, 0.5, -1.].into(),
+            max: [4.5, 2.5, 1.].into(),
+        };
+        assert_eq!(
+            ids(tree.query_box(&aabb)),
+            ids([(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+                .map(|(i, j)| grid[i][j].clone())),
+        );
+    }
+
+    /// # Build a tree over a 10x10 grid of unit squares, spaced 2 apart
+    fn grid(core: &mut Core) -> (AabbTree, Vec<Vec<Handle<Face>>>) {
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        let grid = (0..10)
+            .map(|i| {
+                (0..10)
+                    .map(|j| {
+                        let [x, y] = [i, j].map(|n| f64::from(n) * 2.);
+                        Face::polygon(
+                            surface.clone(),
+                            [
+                                [x, y],
+                                [x + 1., y],
+                                [x + 1., y + 1.],
+                                [x, y + 1.],
+                            ],
+                            core,
+                        )
+                        .insert(core)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let tree = AabbTree::new(
+            grid.iter().flatten().cloned(),
+            0.001,
+            &core.layers.geometry,
+        );
+        assert_eq!(tree.len(), 100);
+
+        (tree, grid)
+    }
+
+    fn ids(
+        faces: impl IntoIterator<Item = Handle<Face>>,
+    ) -> BTreeSet<ObjectId> {
+        faces.into_iter().map(|face| face.id()).collect()
+    }
+}
@@ -0,0 +1,7 @@
+//! # Spatial indices for fast queries over many objects
+//!
+//! See [`AabbTree`].
+
+mod aabb_tree;
+
+pub use self::aabb_tree::AabbTree;
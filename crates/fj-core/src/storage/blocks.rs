@@ -50,6 +50,26 @@ impl<T> Blocks<T> {
         block.insert(index.object_index, object);
     }
 
+    /// # Compute the position of the slot at the provided index
+    ///
+    /// All blocks have the same size, and slots are reserved in order. This
+    /// means the position counts up, in the order slots have been reserved.
+    pub fn position(&self, index: Index) -> u64 {
+        (index.block_index.0 * self.block_size + index.object_index.0) as u64
+    }
+
+    /// # Compute the index of the slot at the provided position
+    ///
+    /// This is the inverse of [`Blocks::position`].
+    pub fn index_at(&self, position: u64) -> Index {
+        let position = position as usize;
+
+        Index {
+            block_index: BlockIndex(position / self.block_size),
+            object_index: ObjectIndex(position % self.block_size),
+        }
+    }
+
     pub fn get_and_inc(&self, index: &mut Index) -> Option<&Option<T>> {
         let block = self.inner.get(index.block_index.0)?;
         let object = block.get(index.object_index);
@@ -1,6 +1,6 @@
 use std::{any::type_name, borrow::Borrow, fmt, hash::Hash, ops::Deref};
 
-use super::{blocks::Index, store::StoreInner};
+use super::store::StoreInner;
 
 /// # A handle that references a stored object
 ///
@@ -39,6 +39,15 @@ use super::{blocks::Index, store::StoreInner};
 /// You can compare the identity of two `Handle`s, by comparing the values
 /// returned by [`Handle::id`].
 ///
+/// IDs are derived from the location of the object in memory, which makes them
+/// unique, even across stores. Since memory addresses differ between runs,
+/// they are not suitable for display. The [`Debug`] output of `Handle` shows
+/// a separate number instead, returned by [`Handle::position`], which is
+/// assigned in the order in which objects are reserved in their store, starting
+/// at zero. Building the same objects in the same order results in the same
+/// numbers, which makes that output reproducible. Those numbers are only unique
+/// within a store, and are not used to determine identity.
+///
 /// ### Validation Must Use Identity
 ///
 /// To prevent situations where everything looks fine during development, but
@@ -48,8 +57,13 @@ use super::{blocks::Index, store::StoreInner};
 /// never expect non-identical objects to be equal.
 pub struct Handle<T> {
     pub(super) store: StoreInner<T>,
-    pub(super) index: Index,
     pub(super) ptr: *const Option<T>,
+
+    /// # The position of the object in its store
+    ///
+    /// Only unique within the store, and not used to determine identity. See
+    /// the documentation of [`Handle`].
+    pub(super) position: u64,
 }
 
 impl<T> Handle<T> {
@@ -58,6 +72,16 @@ impl<T> Handle<T> {
         ObjectId::from_ptr(self.ptr)
     }
 
+    /// Access the position of the object in its store
+    ///
+    /// Positions are assigned in the order in which objects are reserved,
+    /// starting at zero, so they are reproducible between runs. They are only
+    /// unique within a store, and are not used to determine identity. Use
+    /// [`Handle::id`] for that.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
     /// Return a bare object, which is a clone of the referenced stored object
     pub fn clone_object(&self) -> T
     where
@@ -117,8 +141,8 @@ impl<T> Clone for Handle<T> {
     fn clone(&self) -> Self {
         Self {
             store: self.store.clone(),
-            index: self.index,
             ptr: self.ptr,
+            position: self.position,
         }
     }
 }
@@ -161,13 +185,13 @@ where
                 None => type_name,
             }
         };
-        let id = self.id().0;
+        let position = self.position;
         let object = self.deref();
 
         if f.alternate() {
-            write!(f, "{name} @ {id:#x} => {object:#?}")?;
+            write!(f, "{name} #{position} => {object:#?}")?;
         } else {
-            write!(f, "{name} @ {id:#x}")?;
+            write!(f, "{name} #{position}")?;
         }
 
         Ok(())
@@ -196,3 +220,71 @@ impl fmt::Debug for ObjectId {
         write!(f, "object id {id:#x}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{build::BuildFace, insert::Insert},
+        topology::Face,
+    };
+
+    #[test]
+    fn ids_are_reproducible() {
+        let build = || {
+            let mut core = Core::new();
+
+            let surface = core.layers.topology.surfaces.xy_plane();
+            let face = Face::polygon(
+                surface,
+                [[0., 0.], [1., 0.], [0., 1.]],
+                &mut core,
+            )
+            .insert(&mut core);
+
+            (face.position(), format!("{face:#?}"))
+        };
+
+        let [a, b] = [build(), build()];
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn positions_count_up_in_order_of_reservation() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let a = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        let b =
+            Face::polygon(surface, [[0., 0.], [1., 0.], [0., 1.]], &mut core)
+                .insert(&mut core);
+
+        assert_eq!(b.position(), a.position() + 1);
+        assert_eq!(format!("{b:?}"), format!("Face #{}", b.position()));
+    }
+
+    #[test]
+    fn handles_from_different_stores_are_not_identical() {
+        let [a, b] = [[0., 0.], [1., 1.]].map(|offset| {
+            let mut core = Core::new();
+
+            let surface = core.layers.topology.surfaces.xy_plane();
+            Face::polygon(
+                surface,
+                [[0., 0.], [1., 0.], [0., 1.]]
+                    .map(|[x, y]: [f64; 2]| [x + offset[0], y + offset[1]]),
+                &mut core,
+            )
+            .insert(&mut core)
+        });
+
+        assert_eq!(a.position(), b.position());
+        assert_ne!(a.id(), b.id());
+        assert_ne!(a, b);
+    }
+}
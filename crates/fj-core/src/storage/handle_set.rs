@@ -0,0 +1,336 @@
+use std::{collections::BTreeMap, fmt, slice};
+
+use super::Handle;
+
+/// # A set of handles that preserves insertion order
+///
+/// Membership is determined by identity, as defined by [`Handle`]'s [`Ord`]
+/// implementation. Iteration happens in the order in which the handles were
+/// first inserted, which keeps any output derived from the set deterministic.
+///
+/// Unlike `ObjectSet`, which is an immutable part of objects, this is meant as
+/// a working collection for code that operates on objects.
+pub struct HandleSet<T> {
+    inner: HandleMap<T, ()>,
+}
+
+impl<T> HandleSet<T> {
+    /// # Create an empty set
+    pub fn new() -> Self {
+        Self {
+            inner: HandleMap::new(),
+        }
+    }
+
+    /// # Insert a handle into the set
+    ///
+    /// Returns `true`, if the handle was not already in the set. If it was,
+    /// its position in the iteration order does not change.
+    pub fn insert(&mut self, handle: Handle<T>) -> bool {
+        self.inner.insert(handle, ()).is_none()
+    }
+
+    /// # Indicate whether the set contains the provided handle
+    pub fn contains(&self, handle: &Handle<T>) -> bool {
+        self.inner.contains_key(handle)
+    }
+
+    /// # Return the number of handles in the set
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// # Indicate whether the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// # Iterate over the handles, in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = &Handle<T>> {
+        self.inner.keys()
+    }
+
+    /// # Create a set with all handles that are in `self` or `other`
+    ///
+    /// The handles from `self` come first, in their order, followed by the
+    /// handles that are only in `other`, in the order of `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).cloned().collect()
+    }
+
+    /// # Create a set with all handles that are in both `self` and `other`
+    ///
+    /// The handles are in the order of `self`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.iter()
+            .filter(|handle| other.contains(handle))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<T> Clone for HandleSet<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for HandleSet<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Default for HandleSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Eq for HandleSet<T> {}
+
+impl<T> PartialEq for HandleSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T> Extend<Handle<T>> for HandleSet<T> {
+    fn extend<I: IntoIterator<Item = Handle<T>>>(&mut self, handles: I) {
+        for handle in handles {
+            self.insert(handle);
+        }
+    }
+}
+
+impl<T> FromIterator<Handle<T>> for HandleSet<T> {
+    fn from_iter<I: IntoIterator<Item = Handle<T>>>(handles: I) -> Self {
+        let mut set = Self::new();
+        set.extend(handles);
+        set
+    }
+}
+
+/// # A map with handles as keys, that preserves insertion order
+///
+/// Keys are compared by identity, as defined by [`Handle`]'s [`Ord`]
+/// implementation. Iteration happens in the order in which the keys were first
+/// inserted, which keeps any output derived from the map deterministic.
+pub struct HandleMap<T, V> {
+    indices: BTreeMap<Handle<T>, usize>,
+    entries: Vec<(Handle<T>, V)>,
+}
+
+impl<T, V> HandleMap<T, V> {
+    /// # Create an empty map
+    pub fn new() -> Self {
+        Self {
+            indices: BTreeMap::new(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// # Insert a value into the map
+    ///
+    /// If the map already contains the key, its value is replaced and the old
+    /// value is returned. The key's position in the iteration order does not
+    /// change in that case.
+    pub fn insert(&mut self, key: Handle<T>, value: V) -> Option<V> {
+        if let Some(&index) = self.indices.get(&key) {
+            let (_, old) = &mut self.entries[index];
+            return Some(std::mem::replace(old, value));
+        }
+
+        self.indices.insert(key.clone(), self.entries.len());
+        self.entries.push((key, value));
+
+        None
+    }
+
+    /// # Access the value for the provided key
+    pub fn get(&self, key: &Handle<T>) -> Option<&V> {
+        let index = *self.indices.get(key)?;
+        let (_, value) = &self.entries[index];
+        Some(value)
+    }
+
+    /// # Access the value for the provided key mutably
+    pub fn get_mut(&mut self, key: &Handle<T>) -> Option<&mut V> {
+        let index = *self.indices.get(key)?;
+        let (_, value) = &mut self.entries[index];
+        Some(value)
+    }
+
+    /// # Indicate whether the map contains the provided key
+    pub fn contains_key(&self, key: &Handle<T>) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// # Return the number of entries in the map
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// # Indicate whether the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// # Iterate over the entries, in insertion order
+    pub fn iter(&self) -> HandleMapIter<'_, T, V> {
+        HandleMapIter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// # Iterate over the keys, in insertion order
+    pub fn keys(&self) -> impl Iterator<Item = &Handle<T>> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// # Iterate over the values, in the insertion order of their keys
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<T, V> Clone for HandleMap<T, V>
+where
+    V: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            indices: self.indices.clone(),
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<T, V> fmt::Debug for HandleMap<T, V>
+where
+    T: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, V> Default for HandleMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, V> Extend<(Handle<T>, V)> for HandleMap<T, V> {
+    fn extend<I: IntoIterator<Item = (Handle<T>, V)>>(&mut self, entries: I) {
+        for (key, value) in entries {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<T, V> FromIterator<(Handle<T>, V)> for HandleMap<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Handle<T>, V)>>(entries: I) -> Self {
+        let mut map = Self::new();
+        map.extend(entries);
+        map
+    }
+}
+
+impl<'r, T, V> IntoIterator for &'r HandleMap<T, V> {
+    type Item = (&'r Handle<T>, &'r V);
+    type IntoIter = HandleMapIter<'r, T, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// # An iterator over the entries of a [`HandleMap`]
+///
+/// Returned by [`HandleMap::iter`].
+pub struct HandleMapIter<'r, T, V> {
+    inner: slice::Iter<'r, (Handle<T>, V)>,
+}
+
+impl<'r, T, V> Iterator for HandleMapIter<'r, T, V> {
+    type Item = (&'r Handle<T>, &'r V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.inner.next()?;
+        Some((key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core, operations::insert::Insert, storage::Handle, topology::Vertex,
+    };
+
+    use super::{HandleMap, HandleSet};
+
+    fn vertices(core: &mut Core) -> [Handle<Vertex>; 4] {
+        [(); 4].map(|()| Vertex::new().insert(core))
+    }
+
+    #[test]
+    fn insert_deduplicates_and_preserves_order() {
+        let mut core = Core::new();
+        let [a, b, c, _] = vertices(&mut core);
+
+        let mut set = HandleSet::new();
+        assert!(set.insert(c.clone()));
+        assert!(set.insert(a.clone()));
+        assert!(!set.insert(c.clone()));
+        assert!(set.insert(b.clone()));
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&a));
+        assert_eq!(set.iter().cloned().collect::<Vec<_>>(), [c, a, b]);
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let mut core = Core::new();
+        let [a, b, c, d] = vertices(&mut core);
+
+        let x = [d.clone(), b.clone(), a.clone()]
+            .into_iter()
+            .collect::<HandleSet<_>>();
+        let y = [a.clone(), c.clone(), d.clone()]
+            .into_iter()
+            .collect::<HandleSet<_>>();
+
+        let union = x.union(&y);
+        assert_eq!(
+            union.iter().cloned().collect::<Vec<_>>(),
+            [d.clone(), b, a.clone(), c],
+        );
+
+        let intersection = x.intersection(&y);
+        assert_eq!(intersection.iter().cloned().collect::<Vec<_>>(), [d, a]);
+        assert!(x.intersection(&HandleSet::new()).is_empty());
+    }
+
+    #[test]
+    fn map_replaces_values_in_place() {
+        let mut core = Core::new();
+        let [a, b, _, _] = vertices(&mut core);
+
+        let mut map = HandleMap::new();
+        assert_eq!(map.insert(b.clone(), 1), None);
+        assert_eq!(map.insert(a.clone(), 2), None);
+        assert_eq!(map.insert(b.clone(), 3), Some(1));
+
+        assert_eq!(map.get(&b), Some(&3));
+        assert_eq!(map.keys().cloned().collect::<Vec<_>>(), [b, a]);
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), [3, 2]);
+    }
+}
@@ -2,9 +2,11 @@
 
 mod blocks;
 mod handle;
+mod handle_set;
 mod store;
 
 pub use self::{
     handle::{Handle, ObjectId},
+    handle_set::{HandleMap, HandleMapIter, HandleSet},
     store::{Iter, Store},
 };
@@ -68,11 +68,12 @@ impl<T> Store<T> {
         let mut inner = self.inner.write();
 
         let (index, ptr) = inner.blocks.reserve();
+        let position = inner.blocks.position(index);
 
         Handle {
             store: self.inner.clone(),
-            index,
             ptr,
+            position,
         }
     }
 
@@ -85,7 +86,8 @@ impl<T> Store<T> {
     /// before.
     pub fn insert(&mut self, handle: Handle<T>, object: T) {
         let mut inner = self.inner.write();
-        inner.blocks.insert(handle.index, object);
+        let index = inner.blocks.index_at(handle.position);
+        inner.blocks.insert(index, object);
     }
 
     /// Iterate over all objects in this store
@@ -137,8 +139,8 @@ impl<'a, T: 'a> Iterator for Iter<'a, T> {
 
             return Some(Handle {
                 store: self.store.clone(),
-                index,
                 ptr,
+                position: inner.blocks.position(index),
             });
         }
     }
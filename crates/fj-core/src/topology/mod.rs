@@ -16,6 +16,7 @@ mod any_object;
 mod is_object;
 mod object_set;
 mod objects;
+mod serialize;
 mod stores;
 
 pub use self::{
@@ -25,7 +26,7 @@ pub use self::{
     objects::{
         curve::Curve,
         cycle::Cycle,
-        face::{Face, Handedness},
+        face::{Face, FaceBoundary, Handedness},
         half_edge::HalfEdge,
         region::Region,
         shell::Shell,
@@ -34,5 +35,6 @@ pub use self::{
         surface::Surface,
         vertex::Vertex,
     },
+    serialize::{LoadError, LoadedTopology, ObjectKind, SerializedTopology},
     stores::{Surfaces, Topology},
 };
@@ -147,6 +147,28 @@ impl<T> ObjectSet<T> {
         self.iter().circular_tuple_windows()
     }
 
+    /// Access an iterator over the neighboring triples of all contained objects
+    ///
+    /// Yields one `(previous, current, next)` triple for each object, in
+    /// order, wrapping around at both ends. The first triple is centered on the
+    /// first object, its previous one being the last object.
+    ///
+    /// Yields nothing, if there are fewer than three objects, as those can't
+    /// form a triple without repeating an object.
+    pub fn triples(
+        &self,
+    ) -> impl Iterator<Item = (&Handle<T>, &Handle<T>, &Handle<T>)> {
+        let len = if self.len() < 3 { 0 } else { self.len() };
+
+        (0..len).map(move |index| {
+            (
+                self.nth_circular(index + len - 1),
+                self.nth_circular(index),
+                self.nth_circular(index + 1),
+            )
+        })
+    }
+
     /// Create a new instance in which the provided object has been replaced
     ///
     /// Returns `None`, if the provided item is not present.
@@ -272,7 +294,11 @@ pub type ObjectSetIntoIter<T> = vec::IntoIter<Handle<T>>;
 mod tests {
     use std::collections::HashSet;
 
-    use crate::{Core, operations::insert::Insert, topology::Cycle};
+    use crate::{
+        Core,
+        operations::{build::BuildCycle, insert::Insert},
+        topology::Cycle,
+    };
 
     use super::ObjectSet;
 
@@ -289,6 +315,29 @@ mod tests {
         // shouldn't do that in this case.
     }
 
+    #[test]
+    fn triples() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let square = Cycle::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            surface.clone(),
+            &mut core,
+        );
+
+        let half_edges = square.half_edges();
+        let [a, b, c, d] = [0, 1, 2, 3].map(|i| half_edges.nth_circular(i));
+        assert_eq!(
+            half_edges.triples().collect::<Vec<_>>(),
+            [(d, a, b), (a, b, c), (b, c, d), (c, d, a)],
+        );
+
+        let circle = Cycle::new(half_edges.iter().take(2).cloned());
+        assert_eq!(circle.half_edges().triples().count(), 0);
+        assert_eq!(Cycle::new([]).half_edges().triples().count(), 0);
+    }
+
     #[test]
     fn object_set_from_handle_wrappers() {
         let mut core = Core::new();
@@ -1,9 +1,11 @@
-use fj_math::{Scalar, Winding};
+use fj_interop::Tolerance;
+use fj_math::{Point, Polygon, Scalar, Vector, Winding};
 
 use crate::{
-    geometry::{Geometry, Path},
+    algorithms::approx::ApproxCache,
+    geometry::{Geometry, Path, traits::GenPolyline},
     storage::Handle,
-    topology::{HalfEdge, ObjectSet},
+    topology::{HalfEdge, ObjectSet, Vertex},
 };
 
 use super::surface::Surface;
@@ -26,6 +28,286 @@ impl Cycle {
         &self.half_edges
     }
 
+    /// Compute the polylines of all half-edges, in surface coordinates
+    ///
+    /// Each polyline includes the start and end points of its half-edge.
+    /// Returns `None`, if the geometry of any half-edge is not defined on the
+    /// surface.
+    pub(crate) fn half_edge_polylines(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: Tolerance,
+        geometry: &Geometry,
+    ) -> Option<Vec<(Handle<HalfEdge>, Vec<Point<2>>)>> {
+        self.half_edge_polylines_with_cache(surface, tolerance, None, geometry)
+    }
+
+    /// Compute the polylines of all half-edges, using the provided cache
+    ///
+    /// Works like [`Cycle::half_edge_polylines`], but the polylines of the
+    /// half-edges' curves are taken from the cache, if one is provided.
+    pub(crate) fn half_edge_polylines_with_cache(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: Tolerance,
+        cache: Option<&ApproxCache>,
+        geometry: &Geometry,
+    ) -> Option<Vec<(Handle<HalfEdge>, Vec<Point<2>>)>> {
+        self.half_edges()
+            .pairs()
+            .map(|(half_edge, next)| {
+                let path = geometry
+                    .of_curve(half_edge.curve())?
+                    .local_on(surface)?
+                    .path;
+
+                let [start, end] =
+                    [half_edge.start_vertex(), next.start_vertex()].map(
+                        |vertex| {
+                            geometry
+                                .of_vertex(vertex)
+                                .and_then(|vertex| {
+                                    vertex.local_on(half_edge.curve())
+                                })
+                                .map(|vertex| vertex.position)
+                        },
+                    );
+                let boundary = [start?, end?];
+
+                // Depending on the type of curve, the generated polyline might
+                // or might not include the boundary. Add it, and remove any
+                // duplicates that this might produce.
+                let polyline = match cache {
+                    Some(cache) => cache.polyline.generate_polyline(
+                        half_edge.curve(),
+                        surface,
+                        &path,
+                        boundary.into(),
+                        tolerance,
+                    ),
+                    None => path.generate_polyline(boundary.into(), tolerance),
+                };
+                let mut points = [boundary[0]]
+                    .into_iter()
+                    .chain(polyline)
+                    .chain([boundary[1]])
+                    .map(|point_curve| path.point_from_path_coords(point_curve))
+                    .collect::<Vec<_>>();
+                points.dedup();
+
+                Some((half_edge.clone(), points))
+            })
+            .collect()
+    }
+
+    /// Compute a closed polyline of the cycle, in surface coordinates
+    ///
+    /// Concatenates the polylines that approximate all half-edges into a
+    /// single loop. Like the points of a [`CycleApprox`], the loop is closed by
+    /// repeating the first point at the end.
+    ///
+    /// This is intended for debugging, for example to dump the outline of a
+    /// cycle that a validation check has flagged. Returns `None`, if the
+    /// geometry of any half-edge is not defined on the surface.
+    ///
+    /// [`CycleApprox`]: crate::algorithms::approx::cycle::CycleApprox
+    pub fn to_polyline_2d(
+        &self,
+        surface: &Handle<Surface>,
+        geometry: &Geometry,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Vec<Point<2>>> {
+        let polylines =
+            self.half_edge_polylines(surface, tolerance.into(), geometry)?;
+
+        // The end point of each half-edge's polyline is the start point of the
+        // next one's, so we skip it.
+        let mut points = polylines
+            .into_iter()
+            .flat_map(|(_, mut points)| {
+                points.pop();
+                points
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(point) = points.first() {
+            points.push(*point);
+        }
+
+        Some(points)
+    }
+
+    /// Determine whether the cycle forms a convex polygon
+    ///
+    /// Treats the cycle as a polygon of its vertices: The polygon is convex, if
+    /// it turns in the same direction at every vertex, and turns around only
+    /// once in total. Vertices where the polygon doesn't turn at all, because
+    /// both adjacent half-edges are collinear, don't affect the result.
+    ///
+    /// Returns `None`, if the geometry of any half-edge or vertex is not
+    /// defined on the surface.
+    ///
+    /// # Implementation Note
+    ///
+    /// The half-edges are treated as straight lines between their vertices, so
+    /// the curvature of any arcs is not taken into account. A cycle needs at
+    /// least three half-edges, to be considered convex.
+    pub fn is_convex(
+        &self,
+        surface: &Handle<Surface>,
+        geometry: &Geometry,
+    ) -> Option<bool> {
+        let turn_angles = self.turn_angles(surface, geometry)?;
+
+        let has_left_turns = turn_angles.iter().any(|(_, a)| *a > Scalar::ZERO);
+        let has_right_turns =
+            turn_angles.iter().any(|(_, a)| *a < Scalar::ZERO);
+        let total_angle = turn_angles
+            .iter()
+            .fold(Scalar::ZERO, |total, (_, angle)| total + *angle);
+
+        let turns_around_once =
+            ((total_angle.abs() - Scalar::TAU).abs()) < Scalar::from(1e-9);
+
+        Some(turns_around_once && !(has_left_turns && has_right_turns))
+    }
+
+    /// Compute the angle by which the cycle turns at each vertex
+    ///
+    /// Treats the cycle as a polygon of its vertices, like
+    /// [`Cycle::is_convex`]. Returns each half-edge, together with the signed
+    /// angle between the previous half-edge and itself, at its start vertex.
+    /// Left turns are positive, right turns are negative. Vertices where the
+    /// polygon doesn't turn at all have an angle of exactly zero.
+    ///
+    /// Returns nothing, if the cycle has fewer than three half-edges.
+    pub(crate) fn turn_angles(
+        &self,
+        surface: &Handle<Surface>,
+        geometry: &Geometry,
+    ) -> Option<Vec<(&Handle<HalfEdge>, Scalar)>> {
+        let position = |half_edge: &Handle<HalfEdge>| {
+            let path = geometry
+                .of_curve(half_edge.curve())?
+                .local_on(surface)?
+                .path;
+            let position = geometry
+                .of_vertex(half_edge.start_vertex())?
+                .local_on(half_edge.curve())?
+                .position;
+
+            Some(path.point_from_path_coords(position))
+        };
+
+        self.half_edges()
+            .triples()
+            .map(|(prev, current, next)| {
+                let [a, b, c] = [prev, current, next].map(position);
+                let [a, b, c] = [a?, b?, c?];
+                let [ab, bc] = [b - a, c - b];
+
+                let cross = ab.cross2d(&bc);
+                let threshold = ab.magnitude() * bc.magnitude() * 1e-9;
+                let angle = if cross.abs() > threshold {
+                    cross.atan2(ab.dot(&bc))
+                } else {
+                    Scalar::ZERO
+                };
+
+                Some((current, angle))
+            })
+            .collect()
+    }
+
+    /// Compute the interior angle of the cycle at each vertex
+    ///
+    /// Returns the start vertex of each half-edge, together with the angle in
+    /// radians between the previous half-edge and that half-edge, on the
+    /// inside of the cycle. The inside is on the left of a cycle that winds
+    /// counter-clockwise, and on the right of one that winds clockwise. A
+    /// right angle is `π/2`, a reflex corner is larger than `π`.
+    ///
+    /// Unlike [`Cycle::is_convex`], this takes the curvature of the half-edges
+    /// into account: The angle is computed from the tangent at the end of the
+    /// incoming half-edge, and the one at the start of the outgoing half-edge.
+    /// Where both half-edges meet without a corner, for example where an arc
+    /// continues into a line along its tangent, the angle is exactly `π`. This
+    /// is the case, if the distance between the normalized tangents is within
+    /// the provided tolerance.
+    ///
+    /// The winding of the cycle is determined from its approximation at the
+    /// provided tolerance. Returns `None`, if the geometry of any half-edge or
+    /// vertex is not defined on the surface.
+    pub fn interior_angles(
+        &self,
+        surface: &Handle<Surface>,
+        geometry: &Geometry,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Vec<(Handle<Vertex>, Scalar)>> {
+        let tolerance = tolerance.into();
+
+        let points = self.to_polyline_2d(surface, geometry, tolerance)?;
+        let sign = if Polygon::from_points(points).signed_area() < Scalar::ZERO
+        {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+
+        let tangents = self
+            .half_edges()
+            .pairs()
+            .map(|(half_edge, next)| {
+                let path = geometry
+                    .of_curve(half_edge.curve())?
+                    .local_on(surface)?
+                    .path;
+                let [start, end] = [half_edge, next].map(|h| {
+                    geometry
+                        .of_vertex(h.start_vertex())?
+                        .local_on(half_edge.curve())
+                        .map(|vertex| vertex.position)
+                });
+                let [start, end] = [start?, end?];
+
+                let direction = if end.t < start.t {
+                    -Scalar::ONE
+                } else {
+                    Scalar::ONE
+                };
+
+                Some([start, end].map(|point| {
+                    tangent_of_path(&path, point).normalize() * direction
+                }))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let angles = self
+            .half_edges()
+            .iter()
+            .enumerate()
+            .map(|(i, half_edge)| {
+                let [_, incoming] =
+                    tangents[(i + tangents.len() - 1) % tangents.len()];
+                let [outgoing, _] = tangents[i];
+
+                let angle =
+                    if (outgoing - incoming).magnitude() <= tolerance.inner() {
+                        Scalar::PI
+                    } else {
+                        let turn = incoming
+                            .cross2d(&outgoing)
+                            .atan2(incoming.dot(&outgoing));
+                        Scalar::PI - turn * sign
+                    };
+
+                (half_edge.start_vertex().clone(), angle)
+            })
+            .collect();
+
+        Some(angles)
+    }
+
     /// Indicate the cycle's winding, assuming a right-handed coordinate system
     ///
     /// Please note that this is not *the* winding of the cycle, only one of the
@@ -94,39 +376,133 @@ impl Cycle {
         }
 
         // Now that we got the special case out of the way, we can treat the
-        // cycle as a polygon:
-        // https://stackoverflow.com/a/1165943
-
-        let mut sum = Scalar::ZERO;
-
-        for (a, b) in self.half_edges().pairs() {
-            let [a, b] = [a, b].map(|half_edge| {
-                geometry
-                    .of_curve(half_edge.curve())
-                    .unwrap()
-                    .local_on(surface)
-                    .unwrap()
-                    .path
-                    .point_from_path_coords(
-                        geometry
-                            .of_vertex(half_edge.start_vertex())
-                            .unwrap()
-                            .local_on(half_edge.curve())
-                            .unwrap()
-                            .position,
-                    )
-            });
-
-            sum += (b.u - a.u) * (b.v + a.v);
-        }
+        // cycle as a polygon.
+        let points = self.half_edges().iter().map(|half_edge| {
+            geometry
+                .of_curve(half_edge.curve())
+                .unwrap()
+                .local_on(surface)
+                .unwrap()
+                .path
+                .point_from_path_coords(
+                    geometry
+                        .of_vertex(half_edge.start_vertex())
+                        .unwrap()
+                        .local_on(half_edge.curve())
+                        .unwrap()
+                        .position,
+                )
+        });
 
-        if sum > Scalar::ZERO {
-            return Winding::Cw;
+        Polygon::from_points(points).winding().unwrap_or_else(|| {
+            unreachable!("Encountered invalid cycle: {self:#?}")
+        })
+    }
+}
+
+/// Compute the tangent of a path at the provided point, in path coordinates
+///
+/// The tangent points into the direction of increasing path coordinates.
+fn tangent_of_path(path: &Path<2>, point: Point<1>) -> Vector<2> {
+    match path {
+        Path::Circle(circle) => {
+            let (sin, cos) = point.t.sin_cos();
+            circle.b() * cos - circle.a() * sin
         }
-        if sum < Scalar::ZERO {
-            return Winding::Ccw;
+        Path::Line(line) => line.direction(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        operations::{build::BuildCycle, insert::Insert},
+        topology::Cycle,
+    };
+
+    #[test]
+    fn square_to_polyline_2d() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let cycle = Cycle::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            surface.clone(),
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let polyline = cycle
+            .to_polyline_2d(&surface, &core.layers.geometry, core.tolerance())
+            .unwrap();
+        assert_eq!(
+            polyline,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.], [0., 0.]].map(Point::from),
+        );
+    }
+
+    #[test]
+    fn interior_angles() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let angles = |cycle: Cycle, core: &mut Core| {
+            cycle
+                .interior_angles(&surface, &core.layers.geometry, 0.001)
+                .unwrap()
+                .into_iter()
+                .map(|(_, angle)| angle)
+                .collect::<Vec<_>>()
+        };
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        for points in [square, [square[3], square[2], square[1], square[0]]] {
+            let square = Cycle::polygon(points, surface.clone(), &mut core);
+            for angle in angles(square, &mut core) {
+                assert!((angle - Scalar::PI / 2.).abs() < Scalar::from(1e-12));
+            }
         }
 
-        unreachable!("Encountered invalid cycle: {self:#?}");
+        // A circle has no corners.
+        let circle = Cycle::circle([0., 0.], 1., surface.clone(), &mut core);
+        assert_eq!(angles(circle, &mut core), [Scalar::PI; 4]);
+    }
+
+    #[test]
+    fn is_convex() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let mut is_convex = |points: &[[f64; 2]]| {
+            Cycle::polygon(points.iter().copied(), surface.clone(), &mut core)
+                .is_convex(&surface, &core.layers.geometry)
+                .unwrap()
+        };
+
+        let square = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        assert!(is_convex(&square));
+        assert!(is_convex(&square.into_iter().rev().collect::<Vec<_>>()));
+
+        // A vertex in the middle of an edge doesn't count as a turn.
+        assert!(is_convex(&[
+            [0., 0.],
+            [1., 0.],
+            [2., 0.],
+            [2., 1.],
+            [0., 1.]
+        ]));
+
+        let l_shape =
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]];
+        assert!(!is_convex(&l_shape));
+
+        let pentagram = [0, 2, 4, 1, 3].map(|i| {
+            let (sin, cos) = (std::f64::consts::TAU * i as f64 / 5.).sin_cos();
+            [cos, sin]
+        });
+        assert!(!is_convex(&pentagram));
     }
 }
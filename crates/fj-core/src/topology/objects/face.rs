@@ -1,9 +1,12 @@
-use fj_math::Winding;
+use fj_interop::Tolerance;
+use fj_math::{Point, Polygon, Scalar, Vector, Winding};
 
 use crate::{
-    geometry::Geometry,
+    algorithms::approx::{ApproxCache, cycle::approx_cycle},
+    geometry::{Geometry, Path},
+    queries::BoundingVerticesOfHalfEdge,
     storage::Handle,
-    topology::{Region, Surface},
+    topology::{Region, Shell, Surface},
 };
 
 /// A face of a shape
@@ -68,6 +71,176 @@ impl Face {
             Winding::Cw => Handedness::LeftHanded,
         }
     }
+
+    /// Determine whether the face contains the provided point
+    ///
+    /// The point is provided in surface coordinates. It is contained in the
+    /// face, if it is within the exterior cycle, but not within any of the
+    /// interior cycles.
+    ///
+    /// The cycles are approximated using the provided tolerance, and the test
+    /// is done against that approximation. A point whose distance to the
+    /// approximation of any cycle is within the tolerance, is considered to be
+    /// on the boundary of the face. Whether it is contained in the face then,
+    /// is determined by `boundary`.
+    pub fn contains_point(
+        &self,
+        point_surface: impl Into<Point<2>>,
+        geometry: &Geometry,
+        tolerance: impl Into<Tolerance>,
+        boundary: FaceBoundary,
+    ) -> bool {
+        let point = point_surface.into();
+        let tolerance = tolerance.into();
+
+        let mut cache = ApproxCache::default();
+        let cycles = self
+            .region
+            .all_cycles()
+            .map(|cycle| {
+                let points = approx_cycle(
+                    cycle,
+                    &self.surface,
+                    tolerance,
+                    &mut cache,
+                    geometry,
+                )
+                .points()
+                .into_iter()
+                .map(|point| point.local_form);
+
+                Polygon::from_points(points)
+            })
+            .collect::<Vec<_>>();
+
+        if cycles
+            .iter()
+            .any(|cycle| cycle.is_on_boundary(point, tolerance.inner()))
+        {
+            return boundary == FaceBoundary::Inclusive;
+        }
+
+        // The exterior and interior cycles don't intersect, so a point within
+        // a hole is contained in an even number of cycles.
+        cycles
+            .iter()
+            .filter(|cycle| cycle.contains_point(point, Scalar::ZERO))
+            .count()
+            % 2
+            == 1
+    }
+
+    /// Intersect the face with a ray
+    ///
+    /// The ray starts at `origin` and goes into `direction`. Returns the point
+    /// where the ray hits the face, if it does. Points on the boundary of the
+    /// face count as a hit, as determined by [`Face::contains_point`] with the
+    /// provided tolerance.
+    ///
+    /// A ray that lies within the plane of the face, or that is parallel to
+    /// it, is not considered to hit the face.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only faces on planar surfaces are supported right now. For faces on any
+    /// other kind of surface, this method always returns `None`.
+    pub fn intersect_ray(
+        &self,
+        origin: impl Into<Point<3>>,
+        direction: impl Into<Vector<3>>,
+        geometry: &Geometry,
+        tolerance: impl Into<Tolerance>,
+    ) -> Option<Point<3>> {
+        let origin = origin.into();
+        let direction = direction.into();
+
+        let surface = geometry.of_surface_if_defined(&self.surface)?;
+        let Path::Line(line) = surface.u else {
+            return None;
+        };
+
+        // The ray hits the plane where `origin + direction * t` equals
+        // `line.origin() + a * u + b * v`. Solve that for `t`, `u`, and `v`,
+        // using Cramer's rule.
+        let [a, b] = [line.direction(), surface.v];
+        let to_origin = origin - line.origin();
+
+        let normal = a.cross(&b);
+        let determinant = -normal.dot(&direction);
+
+        if determinant.abs()
+            <= Scalar::from(1e-12) * normal.magnitude() * direction.magnitude()
+        {
+            // The ray is parallel to the plane.
+            return None;
+        }
+
+        let t = normal.dot(&to_origin) / determinant;
+        if t < Scalar::ZERO {
+            // The plane is behind the ray.
+            return None;
+        }
+
+        let u = to_origin.dot(&b.cross(&-direction)) / determinant;
+        let v = a.dot(&to_origin.cross(&-direction)) / determinant;
+
+        self.contains_point(
+            [u, v],
+            geometry,
+            tolerance,
+            FaceBoundary::Inclusive,
+        )
+        .then(|| origin + direction * t)
+    }
+
+    /// Access the faces of the shell that share an edge with this face
+    ///
+    /// Edges are matched by their curve and their bounding vertices,
+    /// regardless of the order of those vertices. Each neighbor is returned once, in
+    /// the order in which its shared edges are first encountered along the
+    /// cycles of this face.
+    ///
+    /// This face itself is never returned, even if it shares an edge with
+    /// itself, like the seam of a cylinder.
+    pub fn neighbors<'r>(&self, shell: &'r Shell) -> Vec<&'r Handle<Face>> {
+        let faces_by_edge = shell.faces_by_edge();
+        let mut neighbors = Vec::new();
+
+        for cycle in self.region.all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let vertices = cycle
+                    .bounding_vertices_of_half_edge(half_edge)
+                    .expect("`half_edge` came from `cycle`, must exist there");
+                let Some(faces) = faces_by_edge
+                    .get(&(half_edge.curve().clone(), vertices.normalize()))
+                else {
+                    continue;
+                };
+
+                for &face in faces {
+                    if face.region() != &self.region
+                        && !neighbors.contains(&face)
+                    {
+                        neighbors.push(face);
+                    }
+                }
+            }
+        }
+
+        neighbors
+    }
+}
+
+/// How points on the boundary of a face are treated
+///
+/// See [`Face::contains_point`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum FaceBoundary {
+    /// Points on the boundary are contained in the face
+    Inclusive,
+
+    /// Points on the boundary are not contained in the face
+    Exclusive,
 }
 
 /// The handedness of a face's coordinate system
@@ -81,3 +254,117 @@ pub enum Handedness {
     /// The face's coordinate system is right-handed
     RightHanded,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        Core,
+        operations::{build::FaceBuilder, insert::Insert},
+        storage::Handle,
+        topology::Face,
+    };
+
+    use super::FaceBoundary;
+
+    #[test]
+    fn contains_point() {
+        let mut core = Core::new();
+        let face = square_with_hole(&mut core);
+
+        let contains = |point: [f64; 2], boundary| {
+            face.contains_point(point, &core.layers.geometry, 0.001, boundary)
+        };
+
+        for boundary in [FaceBoundary::Inclusive, FaceBoundary::Exclusive] {
+            // Within the face.
+            assert!(contains([0.5, 0.5], boundary));
+            assert!(contains([3.5, 2.], boundary));
+
+            // Within the hole.
+            assert!(!contains([2., 2.], boundary));
+
+            // Outside of the face.
+            assert!(!contains([5., 2.], boundary));
+            assert!(!contains([-1., 2.], boundary));
+
+            // Ray goes through vertices of both cycles.
+            assert!(contains([0.5, 1.], boundary));
+            assert!(!contains([-0.5, 1.], boundary));
+        }
+    }
+
+    #[test]
+    fn contains_point_on_boundary() {
+        let mut core = Core::new();
+        let face = square_with_hole(&mut core);
+
+        let on_boundary = [
+            [0., 2.],
+            [4., 4.],
+            [2., 0.],
+            [1., 2.],
+            [3., 3.],
+            [2., 1.0005],
+        ];
+
+        for point in on_boundary {
+            let [inclusive, exclusive] =
+                [FaceBoundary::Inclusive, FaceBoundary::Exclusive].map(
+                    |boundary| {
+                        face.contains_point(
+                            point,
+                            &core.layers.geometry,
+                            0.001,
+                            boundary,
+                        )
+                    },
+                );
+
+            assert!(inclusive);
+            assert!(!exclusive);
+        }
+    }
+
+    #[test]
+    fn intersect_ray() {
+        let mut core = Core::new();
+        let face = square_with_hole(&mut core);
+
+        let intersect = |origin: [f64; 3], direction: [f64; 3]| {
+            face.intersect_ray(origin, direction, &core.layers.geometry, 0.001)
+        };
+
+        // Ray hits the face.
+        assert_eq!(
+            intersect([0.5, 0.5, 1.], [0., 0., -1.]),
+            Some(Point::from([0.5, 0.5, 0.]))
+        );
+        assert_eq!(
+            intersect([0., 2., -2.], [1., 0., 2.]),
+            Some(Point::from([1., 2., 0.]))
+        );
+
+        // Ray goes through the hole.
+        assert_eq!(intersect([2., 2., 1.], [0., 0., -1.]), None);
+
+        // Ray goes past the face.
+        assert_eq!(intersect([5., 2., 1.], [0., 0., -1.]), None);
+
+        // Face is behind the ray.
+        assert_eq!(intersect([0.5, 0.5, 1.], [0., 0., 1.]), None);
+
+        // Ray is parallel to the face.
+        assert_eq!(intersect([0.5, 0.5, 1.], [1., 0., 0.]), None);
+        assert_eq!(intersect([-1., 0.5, 0.], [1., 0., 0.]), None);
+    }
+
+    fn square_with_hole(core: &mut Core) -> Handle<Face> {
+        FaceBuilder::new()
+            .exterior([[0., 0.], [4., 0.], [4., 4.], [0., 4.]])
+            .hole([[1., 1.], [3., 1.], [3., 3.], [1., 3.]])
+            .build(core.layers.topology.surfaces.xy_plane(), core)
+            .insert(core)
+    }
+}
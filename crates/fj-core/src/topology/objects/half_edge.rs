@@ -1,4 +1,7 @@
+use fj_math::Point;
+
 use crate::{
+    geometry::{CurveBoundary, Geometry},
     storage::Handle,
     topology::{Curve, Vertex},
 };
@@ -54,4 +57,66 @@ impl HalfEdge {
     pub fn start_vertex(&self) -> &Handle<Vertex> {
         &self.start_vertex
     }
+
+    /// Compute the boundary of the edge on its curve
+    ///
+    /// The boundary consists of the positions of the start and end vertex, in
+    /// curve coordinates. Since the edge doesn't refer to its end vertex, it
+    /// must be provided. That's the start vertex of the next edge in the cycle.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the geometry of either vertex is not defined on the curve.
+    pub fn boundary(
+        &self,
+        end_vertex: &Handle<Vertex>,
+        geometry: &Geometry,
+    ) -> CurveBoundary<Point<1>> {
+        let inner = [self.start_vertex(), end_vertex].map(|vertex| {
+            geometry
+                .of_vertex(vertex)
+                .and_then(|vertex| vertex.local_on(self.curve()))
+                .expect("Expected vertex geometry to be defined on curve")
+                .position
+        });
+
+        CurveBoundary { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        geometry::LocalVertexGeom,
+        operations::{build::BuildHalfEdge, insert::Insert},
+        topology::{HalfEdge, Vertex},
+    };
+
+    #[test]
+    fn boundary_of_line_segment() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let (half_edge, boundary) =
+            HalfEdge::line_segment([[0., 0.], [2., 0.]], surface, &mut core);
+        let end_vertex = Vertex::new().insert(&mut core);
+
+        let [start, end] = boundary.inner;
+        for (vertex, position) in [
+            (half_edge.start_vertex().clone(), start),
+            (end_vertex.clone(), end),
+        ] {
+            core.layers.geometry.define_vertex(
+                vertex,
+                half_edge.curve().clone(),
+                LocalVertexGeom { position },
+            );
+        }
+
+        assert_eq!(
+            half_edge.boundary(&end_vertex, &core.layers.geometry),
+            boundary,
+        );
+    }
 }
@@ -1,8 +1,12 @@
 //! A single, continues 2d region
 
+use fj_interop::Tolerance;
+use fj_math::{Polygon, Scalar};
+
 use crate::{
+    geometry::Geometry,
     storage::Handle,
-    topology::{Cycle, ObjectSet},
+    topology::{Cycle, ObjectSet, Surface},
 };
 
 /// A single, continuous 2d region; may contain holes
@@ -49,4 +53,59 @@ impl Region {
         // for doing that here *and* in `interiors`.
         [self.exterior()].into_iter().chain(self.interiors())
     }
+
+    /// Determine whether the region is empty
+    ///
+    /// A region is empty, if its exterior cycle has no half-edges, or if the
+    /// area enclosed by the exterior cycle is too small to be distinguished
+    /// from zero, at the provided tolerance. Interior cycles are not taken into
+    /// account.
+    ///
+    /// The area is computed from the polygon that approximates the exterior
+    /// cycle, and compared to its perimeter: The region is considered empty, if
+    /// its area is not larger than half its perimeter times the tolerance. For
+    /// a thin sliver, half the perimeter is about its length, so it is only
+    /// considered empty, if it is thinner than the tolerance.
+    ///
+    /// Returns `false`, if the geometry of the exterior cycle is not defined on
+    /// the provided surface. In that case, there's no way to tell.
+    pub fn is_empty(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> bool {
+        let tolerance = tolerance.into();
+
+        if self.exterior().half_edges().is_empty() {
+            return true;
+        }
+
+        let Some(polylines) = self
+            .exterior()
+            .half_edge_polylines(surface, tolerance, geometry)
+        else {
+            return false;
+        };
+
+        let points = polylines
+            .into_iter()
+            .flat_map(|(_, mut polyline)| {
+                // The last point of each polyline is the first point of the
+                // next one.
+                polyline.pop();
+                polyline
+            })
+            .collect::<Vec<_>>();
+
+        let perimeter = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .fold(Scalar::ZERO, |perimeter, (a, b)| {
+                perimeter + a.distance_to(b)
+            });
+        let area = Polygon::from_points(points).signed_area().abs();
+
+        area * 2. <= perimeter * tolerance.inner()
+    }
 }
@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use crate::{
+    geometry::CurveBoundary,
+    queries::BoundingVerticesOfHalfEdge,
     storage::Handle,
-    topology::{Face, ObjectSet},
+    topology::{Curve, Cycle, Face, HalfEdge, ObjectSet, Vertex},
 };
 
 /// A 3-dimensional closed shell
@@ -21,4 +25,135 @@ impl Shell {
     pub fn faces(&self) -> &ObjectSet<Face> {
         &self.faces
     }
+
+    /// Access all cycles of the shell
+    ///
+    /// Iterates over the faces of the shell, returning the exterior cycle of
+    /// each face's region, followed by its interior cycles.
+    pub fn all_cycles(&self) -> impl Iterator<Item = &Handle<Cycle>> {
+        self.faces
+            .iter()
+            .flat_map(|face| face.region().all_cycles())
+    }
+
+    /// Access all half-edges of the shell
+    ///
+    /// Returns the half-edges of every cycle, in the order of
+    /// [`Shell::all_cycles`].
+    pub fn all_half_edges(&self) -> impl Iterator<Item = &Handle<HalfEdge>> {
+        self.all_cycles()
+            .flat_map(|cycle| cycle.half_edges().iter())
+    }
+
+    /// Access all vertices of the shell
+    ///
+    /// Returns the start vertex of every half-edge, in the order of
+    /// [`Shell::all_half_edges`]. Vertices are shared between half-edges, so
+    /// the same vertex is returned multiple times.
+    pub fn all_vertices(&self) -> impl Iterator<Item = &Handle<Vertex>> {
+        self.all_half_edges()
+            .map(|half_edge| half_edge.start_vertex())
+    }
+
+    /// Access the faces that have a half-edge on the provided curve
+    ///
+    /// Each face is returned once, in the order of [`Shell::faces`], even if
+    /// more than one of its half-edges is on the curve.
+    pub fn faces_on_edge(&self, curve: &Handle<Curve>) -> Vec<&Handle<Face>> {
+        self.faces
+            .iter()
+            .filter(|face| {
+                face.region()
+                    .all_cycles()
+                    .flat_map(|cycle| cycle.half_edges())
+                    .any(|half_edge| half_edge.curve() == curve)
+            })
+            .collect()
+    }
+
+    /// Index the faces of the shell by the edges they are bounded by
+    ///
+    /// An edge is identified by its curve and its bounding vertices,
+    /// regardless of their order.
+    pub(crate) fn faces_by_edge(&self) -> BTreeMap<Edge, Vec<&Handle<Face>>> {
+        let mut faces_by_edge = BTreeMap::new();
+
+        for face in &self.faces {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let vertices =
+                        cycle.bounding_vertices_of_half_edge(half_edge).expect(
+                            "`half_edge` came from `cycle`, must exist there",
+                        );
+
+                    let faces: &mut Vec<_> = faces_by_edge
+                        .entry((
+                            half_edge.curve().clone(),
+                            vertices.normalize(),
+                        ))
+                        .or_default();
+                    if !faces.contains(&face) {
+                        faces.push(face);
+                    }
+                }
+            }
+        }
+
+        faces_by_edge
+    }
+}
+
+/// An edge, identified by its curve and its normalized bounding vertices
+pub(crate) type Edge = (Handle<Curve>, CurveBoundary<Vertex>);
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        Core,
+        operations::{build::BuildSketch, sweep::SweepSketch},
+        topology::Sketch,
+    };
+
+    #[test]
+    fn all_half_edges_of_cube() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., 1.], &mut core);
+        let shell = solid.shells().only();
+
+        assert_eq!(shell.all_cycles().count(), 6);
+        assert_eq!(shell.all_half_edges().count(), 24);
+        assert_eq!(shell.all_vertices().collect::<BTreeSet<_>>().len(), 8);
+    }
+
+    #[test]
+    fn faces_on_edges_of_cube() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let solid = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., 1.], &mut core);
+        let shell = solid.shells().only();
+
+        for half_edge in shell.all_half_edges() {
+            assert_eq!(shell.faces_on_edge(half_edge.curve()).len(), 2);
+        }
+
+        for face in shell.faces() {
+            let neighbors = face.neighbors(shell);
+
+            assert_eq!(neighbors.len(), 4);
+            assert!(!neighbors.contains(&face));
+        }
+    }
 }
@@ -0,0 +1,877 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Circle, Line, Point, Vector};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Core,
+    geometry::{
+        Geometry, LocalCurveGeom, LocalVertexGeom, Path, surfaces::SweptCurve,
+    },
+    operations::{build::BuildSurface, insert::Insert},
+    storage::{Handle, ObjectId},
+    topology::{
+        Curve, Cycle, Face, HalfEdge, Region, Sketch, Surface, Topology, Vertex,
+    },
+};
+
+/// # A serializable representation of a topology graph
+///
+/// In memory, objects refer to each other through [`Handle`]s, whose identity
+/// is based on the location of the object in memory. That can't be serialized.
+/// In this representation, objects instead refer to each other through their
+/// index in the list of objects of the respective type.
+///
+/// Indices are assigned in the order in which objects are first encountered,
+/// when adding top-level objects. Serializing the same topology graph always
+/// results in the same representation.
+///
+/// The geometry that the topology requires to be valid is included: That of
+/// surfaces, the local definitions of curves on surfaces, and the local
+/// definitions of vertices on curves. Surfaces that are provided by default,
+/// like the basis planes, are serialized as such, and resolve to the respective
+/// surfaces of the topology that they are loaded into.
+///
+/// Use [`SerializedTopology::add_face`] and [`SerializedTopology::add_sketch`]
+/// to build it up, and [`SerializedTopology::load`] to insert its objects into
+/// the stores of a [`Core`] after it has been deserialized.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SerializedTopology {
+    surfaces: Vec<SerializedSurface>,
+    curves: Vec<SerializedCurve>,
+    vertices: Vec<SerializedVertex>,
+    half_edges: Vec<SerializedHalfEdge>,
+    cycles: Vec<SerializedCycle>,
+    regions: Vec<SerializedRegion>,
+    faces: Vec<SerializedFace>,
+    sketches: Vec<SerializedSketch>,
+
+    #[serde(skip)]
+    index: ObjectIndex,
+}
+
+impl SerializedTopology {
+    /// # Create an empty instance of `SerializedTopology`
+    ///
+    /// The topology is required to recognize the surfaces that it provides by
+    /// default.
+    pub fn new(topology: &Topology) -> Self {
+        let mut self_ = Self::default();
+
+        for (surface, serialized) in [
+            (topology.surfaces.space_2d(), SerializedSurface::Space2d),
+            (topology.surfaces.xy_plane(), SerializedSurface::XyPlane),
+            (topology.surfaces.xz_plane(), SerializedSurface::XzPlane),
+            (topology.surfaces.yz_plane(), SerializedSurface::YzPlane),
+        ] {
+            self_.index.basis.insert(surface.id(), serialized);
+        }
+
+        self_
+    }
+
+    /// # Add a face, and all objects it references
+    ///
+    /// Returns the index of the face. Adding a face that has already been
+    /// added returns the existing index.
+    pub fn add_face(
+        &mut self,
+        face: &Handle<Face>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.faces.get(&face.id()) {
+            return *index;
+        }
+
+        let serialized = SerializedFace {
+            surface: self.add_surface(face.surface(), geometry),
+            region: self.add_region(face.region(), geometry),
+        };
+
+        push(
+            &mut self.faces,
+            &mut self.index.faces,
+            face.id(),
+            serialized,
+        )
+    }
+
+    /// # Add a sketch, and all objects it references
+    ///
+    /// Returns the index of the sketch. Adding a sketch that has already been
+    /// added returns the existing index.
+    pub fn add_sketch(
+        &mut self,
+        sketch: &Handle<Sketch>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.sketches.get(&sketch.id()) {
+            return *index;
+        }
+
+        let serialized = SerializedSketch {
+            surface: self.add_surface(sketch.surface(), geometry),
+            regions: sketch
+                .regions()
+                .iter()
+                .map(|region| self.add_region(region, geometry))
+                .collect(),
+        };
+
+        push(
+            &mut self.sketches,
+            &mut self.index.sketches,
+            sketch.id(),
+            serialized,
+        )
+    }
+
+    /// # Insert the serialized objects into the stores
+    ///
+    /// Creates a new object for each serialized one, defines its geometry, and
+    /// re-links the references between them. Returns the handles of all faces
+    /// and sketches, in the order of their indices.
+    ///
+    /// All references are checked before any object is inserted. If any of
+    /// them is invalid, an error is returned, and the stores are left
+    /// untouched.
+    pub fn load(&self, core: &mut Core) -> Result<LoadedTopology, LoadError> {
+        self.check()?;
+
+        let surfaces = self
+            .surfaces
+            .iter()
+            .map(|surface| {
+                let surfaces = &core.layers.topology.surfaces;
+                match surface {
+                    SerializedSurface::Space2d => surfaces.space_2d(),
+                    SerializedSurface::XyPlane => surfaces.xy_plane(),
+                    SerializedSurface::XzPlane => surfaces.xz_plane(),
+                    SerializedSurface::YzPlane => surfaces.yz_plane(),
+                    SerializedSurface::Other { u, v } => {
+                        Surface::from_uv(u.to_path(), Vector::from(*v), core)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut curves = Vec::new();
+        for serialized in &self.curves {
+            let curve = Curve::new().insert(core);
+
+            for definition in &serialized.definitions {
+                core.layers.geometry.define_curve(
+                    curve.clone(),
+                    surfaces[definition.surface].clone(),
+                    LocalCurveGeom {
+                        path: definition.path.to_path(),
+                    },
+                );
+            }
+
+            curves.push(curve);
+        }
+
+        let mut vertices = Vec::new();
+        for serialized in &self.vertices {
+            let vertex = Vertex::new().insert(core);
+
+            for definition in &serialized.definitions {
+                core.layers.geometry.define_vertex(
+                    vertex.clone(),
+                    curves[definition.curve].clone(),
+                    LocalVertexGeom {
+                        position: Point::from([definition.position]),
+                    },
+                );
+            }
+
+            vertices.push(vertex);
+        }
+
+        let half_edges = self
+            .half_edges
+            .iter()
+            .map(|half_edge| {
+                HalfEdge::new(
+                    curves[half_edge.curve].clone(),
+                    vertices[half_edge.start_vertex].clone(),
+                )
+                .insert(core)
+            })
+            .collect::<Vec<_>>();
+        let cycles = self
+            .cycles
+            .iter()
+            .map(|cycle| {
+                Cycle::new(select(&half_edges, &cycle.half_edges)).insert(core)
+            })
+            .collect::<Vec<_>>();
+        let regions = self
+            .regions
+            .iter()
+            .map(|region| {
+                Region::new(
+                    cycles[region.exterior].clone(),
+                    select(&cycles, &region.interiors),
+                )
+                .insert(core)
+            })
+            .collect::<Vec<_>>();
+
+        let faces = self
+            .faces
+            .iter()
+            .map(|face| {
+                Face::new(
+                    surfaces[face.surface].clone(),
+                    regions[face.region].clone(),
+                )
+                .insert(core)
+            })
+            .collect();
+        let sketches = self
+            .sketches
+            .iter()
+            .map(|sketch| {
+                Sketch::new(
+                    surfaces[sketch.surface].clone(),
+                    select(&regions, &sketch.regions),
+                )
+                .insert(core)
+            })
+            .collect();
+
+        Ok(LoadedTopology { faces, sketches })
+    }
+
+    fn add_surface(
+        &mut self,
+        surface: &Handle<Surface>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.surfaces.get(&surface.id()) {
+            return *index;
+        }
+
+        let serialized = self
+            .index
+            .basis
+            .get(&surface.id())
+            .cloned()
+            .unwrap_or_else(|| {
+                let SweptCurve { u, v } = geometry.of_surface(surface);
+                SerializedSurface::Other {
+                    u: SerializedPath::from_path(u),
+                    v: v.components.map(|s| s.into_f64()),
+                }
+            });
+
+        push(
+            &mut self.surfaces,
+            &mut self.index.surfaces,
+            surface.id(),
+            serialized,
+        )
+    }
+
+    fn add_curve(
+        &mut self,
+        curve: &Handle<Curve>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.curves.get(&curve.id()) {
+            return *index;
+        }
+
+        let mut definitions = geometry
+            .of_curve(curve)
+            .map(|curve_geom| {
+                curve_geom
+                    .definitions
+                    .iter()
+                    .map(|(surface, local)| SerializedLocalCurve {
+                        surface: self.add_surface(surface, geometry),
+                        path: SerializedPath::from_path(&local.path),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // The definitions are stored in the order of their handles, which
+        // depends on memory layout. Sort them, to keep the result stable.
+        definitions.sort_by_key(|definition| definition.surface);
+
+        push(
+            &mut self.curves,
+            &mut self.index.curves,
+            curve.id(),
+            SerializedCurve { definitions },
+        )
+    }
+
+    fn add_vertex(
+        &mut self,
+        vertex: &Handle<Vertex>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.vertices.get(&vertex.id()) {
+            return *index;
+        }
+
+        let mut definitions = geometry
+            .of_vertex(vertex)
+            .map(|vertex_geom| {
+                vertex_geom
+                    .definitions
+                    .iter()
+                    .map(|(curve, local)| SerializedLocalVertex {
+                        curve: self.add_curve(curve, geometry),
+                        position: local.position.t.into_f64(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // Same as for curves, sort the definitions to keep the result stable.
+        definitions.sort_by_key(|definition| definition.curve);
+
+        push(
+            &mut self.vertices,
+            &mut self.index.vertices,
+            vertex.id(),
+            SerializedVertex { definitions },
+        )
+    }
+
+    fn add_half_edge(
+        &mut self,
+        half_edge: &Handle<HalfEdge>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.half_edges.get(&half_edge.id()) {
+            return *index;
+        }
+
+        let serialized = SerializedHalfEdge {
+            curve: self.add_curve(half_edge.curve(), geometry),
+            start_vertex: self.add_vertex(half_edge.start_vertex(), geometry),
+        };
+
+        push(
+            &mut self.half_edges,
+            &mut self.index.half_edges,
+            half_edge.id(),
+            serialized,
+        )
+    }
+
+    fn add_cycle(
+        &mut self,
+        cycle: &Handle<Cycle>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.cycles.get(&cycle.id()) {
+            return *index;
+        }
+
+        let serialized = SerializedCycle {
+            half_edges: cycle
+                .half_edges()
+                .iter()
+                .map(|half_edge| self.add_half_edge(half_edge, geometry))
+                .collect(),
+        };
+
+        push(
+            &mut self.cycles,
+            &mut self.index.cycles,
+            cycle.id(),
+            serialized,
+        )
+    }
+
+    fn add_region(
+        &mut self,
+        region: &Handle<Region>,
+        geometry: &Geometry,
+    ) -> usize {
+        if let Some(index) = self.index.regions.get(&region.id()) {
+            return *index;
+        }
+
+        let serialized = SerializedRegion {
+            exterior: self.add_cycle(region.exterior(), geometry),
+            interiors: region
+                .interiors()
+                .iter()
+                .map(|cycle| self.add_cycle(cycle, geometry))
+                .collect(),
+        };
+
+        push(
+            &mut self.regions,
+            &mut self.index.regions,
+            region.id(),
+            serialized,
+        )
+    }
+
+    fn check(&self) -> Result<(), LoadError> {
+        let [surfaces, curves, vertices, half_edges, cycles, regions] = [
+            self.surfaces.len(),
+            self.curves.len(),
+            self.vertices.len(),
+            self.half_edges.len(),
+            self.cycles.len(),
+            self.regions.len(),
+        ];
+
+        for surface in &self.surfaces {
+            if let SerializedSurface::Other { u, .. } = surface {
+                u.check::<3>()?;
+            }
+        }
+        for curve in &self.curves {
+            for definition in &curve.definitions {
+                check_index(ObjectKind::Surface, definition.surface, surfaces)?;
+                definition.path.check::<2>()?;
+            }
+        }
+        for vertex in &self.vertices {
+            for definition in &vertex.definitions {
+                check_index(ObjectKind::Curve, definition.curve, curves)?;
+            }
+        }
+        for half_edge in &self.half_edges {
+            check_index(ObjectKind::Curve, half_edge.curve, curves)?;
+            check_index(ObjectKind::Vertex, half_edge.start_vertex, vertices)?;
+        }
+        for cycle in &self.cycles {
+            check_set(ObjectKind::HalfEdge, &cycle.half_edges, half_edges)?;
+        }
+        for region in &self.regions {
+            check_index(ObjectKind::Cycle, region.exterior, cycles)?;
+            check_set(ObjectKind::Cycle, &region.interiors, cycles)?;
+        }
+        for face in &self.faces {
+            check_index(ObjectKind::Surface, face.surface, surfaces)?;
+            check_index(ObjectKind::Region, face.region, regions)?;
+        }
+        for sketch in &self.sketches {
+            check_index(ObjectKind::Surface, sketch.surface, surfaces)?;
+            check_set(ObjectKind::Region, &sketch.regions, regions)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// # The objects that were inserted by [`SerializedTopology::load`]
+#[derive(Clone, Debug)]
+pub struct LoadedTopology {
+    /// # The loaded faces, in the order of their indices
+    pub faces: Vec<Handle<Face>>,
+
+    /// # The loaded sketches, in the order of their indices
+    pub sketches: Vec<Handle<Sketch>>,
+}
+
+/// # Error loading a [`SerializedTopology`]
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum LoadError {
+    /// # An object refers to an object that doesn't exist
+    #[error("Reference to {kind:?} with index {index}; only {len} exist")]
+    IndexOutOfBounds {
+        /// # The kind of object that is referred to
+        kind: ObjectKind,
+
+        /// # The index of the referred object
+        index: usize,
+
+        /// # The number of objects of that kind
+        len: usize,
+    },
+
+    /// # An object refers to the same object multiple times in a set
+    #[error("Set of {kind:?} objects contains index {index} multiple times")]
+    DuplicateIndex {
+        /// # The kind of object that is referred to
+        kind: ObjectKind,
+
+        /// # The duplicate index
+        index: usize,
+    },
+
+    /// # A point or vector in the geometry has the wrong number of components
+    #[error("Expected {expected} components in geometry; found {found}")]
+    WrongDimension {
+        /// # The number of components that was expected
+        expected: usize,
+
+        /// # The number of components that was found
+        found: usize,
+    },
+}
+
+/// # The kind of object that an index refers to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ObjectKind {
+    /// # A [`Curve`]
+    Curve,
+
+    /// # A [`Cycle`]
+    Cycle,
+
+    /// # A [`HalfEdge`]
+    HalfEdge,
+
+    /// # A [`Region`]
+    Region,
+
+    /// # A [`Surface`]
+    Surface,
+
+    /// # A [`Vertex`]
+    Vertex,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum SerializedSurface {
+    Space2d,
+    XyPlane,
+    XzPlane,
+    YzPlane,
+    Other { u: SerializedPath, v: [f64; 3] },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedCurve {
+    definitions: Vec<SerializedLocalCurve>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedLocalCurve {
+    surface: usize,
+    path: SerializedPath,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedVertex {
+    definitions: Vec<SerializedLocalVertex>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedLocalVertex {
+    curve: usize,
+    position: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedHalfEdge {
+    curve: usize,
+    start_vertex: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedCycle {
+    half_edges: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedRegion {
+    exterior: usize,
+    interiors: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedFace {
+    surface: usize,
+    region: usize,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SerializedSketch {
+    surface: usize,
+    regions: Vec<usize>,
+}
+
+/// # A [`Path`] of any dimension
+///
+/// Serde can't derive implementations for arrays of generic length, so the
+/// components are stored in `Vec`s, and checked on load.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum SerializedPath {
+    Circle {
+        center: Vec<f64>,
+        a: Vec<f64>,
+        b: Vec<f64>,
+    },
+    Line {
+        origin: Vec<f64>,
+        direction: Vec<f64>,
+    },
+}
+
+impl SerializedPath {
+    fn from_path<const D: usize>(path: &Path<D>) -> Self {
+        let components = |vector: Vector<D>| {
+            vector.components.iter().map(|s| s.into_f64()).collect()
+        };
+
+        match path {
+            Path::Circle(circle) => Self::Circle {
+                center: components(circle.center().coords),
+                a: components(circle.a()),
+                b: components(circle.b()),
+            },
+            Path::Line(line) => Self::Line {
+                origin: components(line.origin().coords),
+                direction: components(line.direction()),
+            },
+        }
+    }
+
+    fn check<const D: usize>(&self) -> Result<(), LoadError> {
+        let vectors = match self {
+            Self::Circle { center, a, b } => vec![center, a, b],
+            Self::Line { origin, direction } => vec![origin, direction],
+        };
+
+        for vector in vectors {
+            if vector.len() != D {
+                return Err(LoadError::WrongDimension {
+                    expected: D,
+                    found: vector.len(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// # Convert into a path
+    ///
+    /// ## Panics
+    ///
+    /// Panics, if the number of components doesn't match `D`. Call
+    /// [`SerializedPath::check`] first.
+    fn to_path<const D: usize>(&self) -> Path<D> {
+        let vector = |components: &Vec<f64>| {
+            let components: [f64; D] = components
+                .as_slice()
+                .try_into()
+                .expect("Number of components has been checked");
+            Vector::from(components)
+        };
+
+        match self {
+            Self::Circle { center, a, b } => Path::Circle(Circle::new(
+                Point {
+                    coords: vector(center),
+                },
+                vector(a),
+                vector(b),
+            )),
+            Self::Line { origin, direction } => {
+                Path::Line(Line::from_origin_and_direction(
+                    Point {
+                        coords: vector(origin),
+                    },
+                    vector(direction),
+                ))
+            }
+        }
+    }
+}
+
+/// # Maps objects that have already been added to their index
+#[derive(Clone, Debug, Default)]
+struct ObjectIndex {
+    basis: BTreeMap<ObjectId, SerializedSurface>,
+
+    surfaces: BTreeMap<ObjectId, usize>,
+    curves: BTreeMap<ObjectId, usize>,
+    vertices: BTreeMap<ObjectId, usize>,
+    half_edges: BTreeMap<ObjectId, usize>,
+    cycles: BTreeMap<ObjectId, usize>,
+    regions: BTreeMap<ObjectId, usize>,
+    faces: BTreeMap<ObjectId, usize>,
+    sketches: BTreeMap<ObjectId, usize>,
+}
+
+fn push<T>(
+    objects: &mut Vec<T>,
+    index: &mut BTreeMap<ObjectId, usize>,
+    id: ObjectId,
+    object: T,
+) -> usize {
+    let i = objects.len();
+    objects.push(object);
+    index.insert(id, i);
+    i
+}
+
+fn select<T>(objects: &[Handle<T>], indices: &[usize]) -> Vec<Handle<T>> {
+    indices.iter().map(|&i| objects[i].clone()).collect()
+}
+
+fn check_index(
+    kind: ObjectKind,
+    index: usize,
+    len: usize,
+) -> Result<(), LoadError> {
+    if index >= len {
+        return Err(LoadError::IndexOutOfBounds { kind, index, len });
+    }
+
+    Ok(())
+}
+
+fn check_set(
+    kind: ObjectKind,
+    indices: &[usize],
+    len: usize,
+) -> Result<(), LoadError> {
+    let mut seen = BTreeSet::new();
+
+    for &index in indices {
+        check_index(kind, index, len)?;
+
+        if !seen.insert(index) {
+            return Err(LoadError::DuplicateIndex { kind, index });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        geometry::Path,
+        operations::{
+            build::{BuildFace, BuildSurface, FaceBuilder},
+            insert::Insert,
+        },
+        topology::{Face, Sketch, Surface},
+    };
+
+    use super::{LoadError, ObjectKind, SerializedTopology};
+
+    #[test]
+    fn round_trip_square_with_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let (u, _) = Path::line_from_points([[0., 0., 1.], [1., 0., 1.]]);
+        let surface = Surface::from_uv(u, [0., 1., 0.], &mut core);
+        let face = FaceBuilder::new()
+            .exterior([[0., 0.], [4., 0.], [4., 4.], [0., 4.]])
+            .hole([[1., 1.], [1., 3.], [3., 3.], [3., 1.]])
+            .build(surface, &mut core)
+            .insert(&mut core);
+
+        let mut serialized = SerializedTopology::new(&core.layers.topology);
+        serialized.add_face(&face, &core.layers.geometry);
+        let json = serde_json::to_string(&serialized)?;
+
+        let mut loaded_core = Core::new();
+        let deserialized: SerializedTopology = serde_json::from_str(&json)?;
+        let loaded = deserialized.load(&mut loaded_core)?;
+        loaded_core.layers.validation.take_errors()?;
+
+        let [loaded_face] = loaded.faces.as_slice() else {
+            panic!("Expected exactly one face");
+        };
+        assert_eq!(loaded_face.region().interiors().len(), 1);
+        for (cycle, original) in loaded_face
+            .region()
+            .all_cycles()
+            .zip(face.region().all_cycles())
+        {
+            assert_eq!(cycle.half_edges().len(), original.half_edges().len());
+        }
+
+        // Serializing the loaded topology results in the same representation,
+        // meaning the topology graph and its geometry are equal.
+        let mut reserialized =
+            SerializedTopology::new(&loaded_core.layers.topology);
+        reserialized.add_face(loaded_face, &loaded_core.layers.geometry);
+        assert_eq!(serde_json::to_string(&reserialized)?, json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn basis_planes_resolve_to_basis_planes() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face =
+            Face::polygon(surface, [[0., 0.], [1., 0.], [0., 1.]], &mut core)
+                .insert(&mut core);
+
+        let mut serialized = SerializedTopology::new(&core.layers.topology);
+        serialized.add_face(&face, &core.layers.geometry);
+
+        let mut loaded_core = Core::new();
+        let loaded = serialized.load(&mut loaded_core)?;
+        loaded_core.layers.validation.take_errors()?;
+
+        assert_eq!(
+            loaded.faces[0].surface(),
+            &loaded_core.layers.topology.surfaces.xy_plane(),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_objects_are_serialized_once() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        let sketch =
+            Sketch::new(surface, [face.region().clone()]).insert(&mut core);
+
+        let mut serialized = SerializedTopology::new(&core.layers.topology);
+        assert_eq!(serialized.add_sketch(&sketch, &core.layers.geometry), 0);
+        assert_eq!(serialized.add_face(&face, &core.layers.geometry), 0);
+
+        assert_eq!(serialized.regions.len(), 1);
+        assert_eq!(serialized.half_edges.len(), 3);
+    }
+
+    #[test]
+    fn reject_invalid_index() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face =
+            Face::polygon(surface, [[0., 0.], [1., 0.], [0., 1.]], &mut core)
+                .insert(&mut core);
+
+        let mut serialized = SerializedTopology::new(&core.layers.topology);
+        serialized.add_face(&face, &core.layers.geometry);
+        serialized.faces[0].region = 1;
+
+        let Err(err) = serialized.load(&mut Core::new()) else {
+            panic!("Expected invalid index to be rejected");
+        };
+        assert_eq!(
+            err,
+            LoadError::IndexOutOfBounds {
+                kind: ObjectKind::Region,
+                index: 1,
+                len: 1,
+            }
+        );
+    }
+}
@@ -4,8 +4,10 @@ use crate::{
     validation::{
         ValidationCheck, ValidationConfig, ValidationError,
         checks::{
-            AdjacentHalfEdgesNotConnected, FaceHasNoBoundary,
-            InteriorCycleHasInvalidWinding,
+            AdjacentHalfEdgesNotConnected, CycleSelfIntersection,
+            DegenerateHalfEdge, EmptyRegion, FaceHasNoBoundary,
+            InteriorCycleHasInvalidWinding, InteriorCycleOutsideExterior,
+            NonConvexCycle, VertexNotOnCurve,
         },
     },
 };
@@ -23,6 +25,15 @@ impl Validate for Face {
             AdjacentHalfEdgesNotConnected::check(self, geometry, config)
                 .map(Into::into),
         );
+        errors.extend(
+            CycleSelfIntersection::check(self, geometry, config)
+                .map(Into::into),
+        );
+        errors.extend(
+            DegenerateHalfEdge::check(self, geometry, config).map(Into::into),
+        );
+        errors
+            .extend(EmptyRegion::check(self, geometry, config).map(Into::into));
         errors.extend(
             FaceHasNoBoundary::check(self, geometry, config).map(Into::into),
         );
@@ -30,5 +41,15 @@ impl Validate for Face {
             InteriorCycleHasInvalidWinding::check(self, geometry, config)
                 .map(Into::into),
         );
+        errors.extend(
+            InteriorCycleOutsideExterior::check(self, geometry, config)
+                .map(Into::into),
+        );
+        errors.extend(
+            NonConvexCycle::check(self, geometry, config).map(Into::into),
+        );
+        errors.extend(
+            VertexNotOnCurve::check(self, geometry, config).map(Into::into),
+        );
     }
 }
@@ -77,7 +77,7 @@ use crate::{
     validation::{ValidationConfig, ValidationError},
 };
 
-pub use self::{sketch::SketchValidationError, solid::SolidValidationError};
+pub use self::solid::SolidValidationError;
 
 /// Assert that some object has a validation error which matches a specific
 /// pattern. This is preferred to matching on [`Validate::validate_and_return_first_error`], since usually we don't care about the order.
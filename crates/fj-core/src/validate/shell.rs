@@ -3,7 +3,10 @@ use crate::{
     topology::Shell,
     validation::{
         ValidationCheck,
-        checks::{CoincidentHalfEdgesAreNotSiblings, HalfEdgeHasNoSibling},
+        checks::{
+            CoincidentFaces, CoincidentHalfEdgesAreNotSiblings,
+            HalfEdgeHasNoSibling, NonManifoldEdge, ShellNotWatertight,
+        },
     },
 };
 
@@ -23,5 +26,14 @@ impl Validate for Shell {
             CoincidentHalfEdgesAreNotSiblings::check(self, geometry, config)
                 .map(Into::into),
         );
+        errors.extend(
+            CoincidentFaces::check(self, geometry, config).map(Into::into),
+        );
+        errors.extend(
+            NonManifoldEdge::check(self, geometry, config).map(Into::into),
+        );
+        errors.extend(
+            ShellNotWatertight::check(self, geometry, config).map(Into::into),
+        );
     }
 }
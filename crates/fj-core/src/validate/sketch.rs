@@ -1,12 +1,13 @@
-use fj_math::Winding;
-
 use crate::{
     geometry::Geometry,
-    storage::Handle,
     topology::{Cycle, HalfEdge, Region, Sketch},
     validation::{
         ValidationCheck,
-        checks::{AdjacentHalfEdgesNotConnected, MultipleReferencesToObject},
+        checks::{
+            AdjacentHalfEdgesNotConnected, CycleSelfIntersection,
+            CycleWindingOrder, EmptyRegion, InteriorCycleOutsideExterior,
+            MultipleReferencesToObject,
+        },
     },
 };
 
@@ -23,6 +24,19 @@ impl Validate for Sketch {
             AdjacentHalfEdgesNotConnected::check(self, geometry, config)
                 .map(Into::into),
         );
+        errors.extend(
+            CycleSelfIntersection::check(self, geometry, config)
+                .map(Into::into),
+        );
+        errors.extend(
+            CycleWindingOrder::check(self, geometry, config).map(Into::into),
+        );
+        errors
+            .extend(EmptyRegion::check(self, geometry, config).map(Into::into));
+        errors.extend(
+            InteriorCycleOutsideExterior::check(self, geometry, config)
+                .map(Into::into),
+        );
         errors.extend(
             MultipleReferencesToObject::<Cycle, Region>::check(
                 self, geometry, config,
@@ -35,86 +49,13 @@ impl Validate for Sketch {
             )
             .map(Into::into),
         );
-        SketchValidationError::check_exterior_cycles(
-            self, geometry, config, errors,
-        );
-        SketchValidationError::check_interior_cycles(
-            self, geometry, config, errors,
-        );
-    }
-}
-
-/// [`Sketch`] validation failed
-#[derive(Clone, Debug, thiserror::Error)]
-pub enum SketchValidationError {
-    /// Region within sketch has exterior cycle with clockwise winding
-    #[error(
-        "Exterior cycle within sketch region has clockwise winding\n
-        Cycle: {cycle:#?}"
-    )]
-    ClockwiseExteriorCycle {
-        /// Cycle with clockwise winding
-        cycle: Handle<Cycle>,
-    },
-
-    /// Region within sketch has interior cycle with counter-clockwise winding
-    #[error(
-        "Interior cycle within sketch region has counter-clockwise winding\n
-        Cycle: {cycle:#?}"
-    )]
-    CounterClockwiseInteriorCycle {
-        /// Cycle with counter-clockwise winding
-        cycle: Handle<Cycle>,
-    },
-}
-
-impl SketchValidationError {
-    fn check_exterior_cycles(
-        sketch: &Sketch,
-        geometry: &Geometry,
-        _config: &ValidationConfig,
-        errors: &mut Vec<ValidationError>,
-    ) {
-        sketch.regions().iter().for_each(|region| {
-            let cycle = region.exterior();
-            if cycle.winding(geometry, sketch.surface()) == Winding::Cw {
-                errors.push(ValidationError::Sketch(
-                    SketchValidationError::ClockwiseExteriorCycle {
-                        cycle: cycle.clone(),
-                    },
-                ))
-            }
-        });
-    }
-
-    fn check_interior_cycles(
-        sketch: &Sketch,
-        geometry: &Geometry,
-        _config: &ValidationConfig,
-        errors: &mut Vec<ValidationError>,
-    ) {
-        sketch.regions().iter().for_each(|region| {
-            region
-                .interiors()
-                .iter()
-                .filter(|interior| {
-                    interior.winding(geometry, sketch.surface()) == Winding::Ccw
-                })
-                .for_each(|cycle| {
-                    errors.push(ValidationError::Sketch(
-                        SketchValidationError::CounterClockwiseInteriorCycle {
-                            cycle: cycle.clone(),
-                        },
-                    ));
-                })
-        });
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        Core, assert_contains_err,
+        Core,
         operations::{
             build::{BuildCycle, BuildRegion, BuildSketch},
             insert::Insert,
@@ -122,7 +63,11 @@ mod tests {
             update::{UpdateRegion, UpdateSketch},
         },
         topology::{Cycle, Region, Sketch},
-        validate::{SketchValidationError, Validate, ValidationError},
+        validate::{Validate, ValidationError},
+        validation::{
+            ValidationConfig,
+            checks::{CycleRole, CycleWindingOrder},
+        },
     };
 
     #[test]
@@ -140,12 +85,9 @@ mod tests {
             },
             &mut core,
         );
-        assert_contains_err!(
-            core,
-            invalid_sketch,
-            ValidationError::Sketch(
-                SketchValidationError::ClockwiseExteriorCycle { cycle: _ }
-            )
+        assert_eq!(
+            winding_order_errors(&invalid_sketch, &core),
+            [CycleRole::Exterior],
         );
 
         Ok(())
@@ -178,15 +120,32 @@ mod tests {
             },
             &mut core,
         );
-        assert_contains_err!(
-            core,
-            invalid_sketch,
-            ValidationError::Sketch(
-                SketchValidationError::CounterClockwiseInteriorCycle {
-                    cycle: _
-                }
-            )
+        assert_eq!(
+            winding_order_errors(&invalid_sketch, &core),
+            [CycleRole::Interior],
         );
+
         Ok(())
     }
+
+    /// Return the roles of all cycles reported with wrong winding order
+    fn winding_order_errors(sketch: &Sketch, core: &Core) -> Vec<CycleRole> {
+        let mut errors = Vec::new();
+        sketch.validate(
+            &ValidationConfig::default(),
+            &mut errors,
+            &core.layers.geometry,
+        );
+
+        errors
+            .into_iter()
+            .filter_map(|err| match err {
+                ValidationError::CycleWindingOrder(CycleWindingOrder {
+                    role,
+                    ..
+                }) => Some(role),
+                _ => None,
+            })
+            .collect()
+    }
 }
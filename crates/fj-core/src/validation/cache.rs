@@ -0,0 +1,364 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::{
+    geometry::Geometry,
+    storage::{Handle, ObjectId},
+    topology::{AnyObject, Stored},
+    validate::Validate,
+};
+
+use super::{ValidationConfig, ValidationError, ValidationReport};
+
+/// # Run validation checks only on objects that changed since the last run
+///
+/// Works like [`validate_all`], but for multiple objects at once, and only
+/// runs the validation checks on objects that have not been validated using
+/// the provided cache before. For all other objects, the errors from the
+/// previous run are reused.
+///
+/// Objects are identified by their [`Handle`]. Since objects are immutable,
+/// updating an object results in a new handle that replaces the old one, and
+/// that new handle is validated on the next run. Objects that were validated
+/// on a previous run, but are not among the provided objects, are removed from
+/// the cache. This means a cache should be used for only one set of objects,
+/// like the faces of a shell that is being edited.
+///
+/// If the provided configuration differs from the one used for the previous
+/// run, the cache is cleared and all objects are validated.
+///
+/// An object that is a rigid copy of a cached object (see
+/// [`Geometry::original_of_rigid_copy`]) is not validated, if the original
+/// passed validation. Since a rigid motion doesn't change the outcome of any
+/// validation check, the copy would pass too. If the original failed
+/// validation, the copy is validated, as the errors of the original reference
+/// the original's objects, not the copy's.
+///
+/// # Implementation Note
+///
+/// Changes to the geometry of an object are not detected. If geometry is
+/// redefined for an object that was already validated, the cache must be
+/// cleared manually, using [`ValidationCache::clear`].
+///
+/// [`validate_all`]: super::validate_all
+pub fn validate_incremental<'r, T>(
+    objects: impl IntoIterator<Item = &'r Handle<T>>,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+    cache: &mut ValidationCache,
+) -> ValidationReport
+where
+    T: Validate + 'r,
+    Handle<T>: Into<AnyObject<Stored>>,
+{
+    if cache.config.as_ref() != Some(config) {
+        cache.clear();
+        cache.config = Some(config.clone());
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut errors = Vec::new();
+
+    for object in objects {
+        if !seen.insert(object.id()) {
+            continue;
+        }
+
+        if !cache.errors.contains_key(&object.id())
+            && cache.is_clean_rigid_copy(object.clone().into(), geometry)
+        {
+            cache.errors.insert(object.id(), Vec::new());
+        }
+
+        let object_errors =
+            cache.errors.entry(object.id()).or_insert_with(|| {
+                cache.num_validated += 1;
+
+                let mut errors = Vec::new();
+                object.validate(config, &mut errors, geometry);
+                errors
+            });
+
+        errors.extend(object_errors.iter().cloned());
+    }
+
+    cache.errors.retain(|id, _| seen.contains(id));
+
+    ValidationReport::from_errors(errors, config)
+}
+
+/// # Validation results, as cached by [`validate_incremental`]
+#[derive(Clone, Debug, Default)]
+pub struct ValidationCache {
+    errors: HashMap<ObjectId, Vec<ValidationError>>,
+    config: Option<ValidationConfig>,
+    num_validated: usize,
+}
+
+impl ValidationCache {
+    /// # Construct an empty instance of `ValidationCache`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// # Return the number of objects whose validation results are cached
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// # Indicate whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// # Return the number of times an object was validated using this cache
+    ///
+    /// Counts every object that was not found in the cache, over all runs of
+    /// [`validate_incremental`] so far.
+    pub fn num_validated(&self) -> usize {
+        self.num_validated
+    }
+
+    /// # Indicate whether the object is a rigid copy of an object that passed
+    fn is_clean_rigid_copy(
+        &self,
+        object: AnyObject<Stored>,
+        geometry: &Geometry,
+    ) -> bool {
+        let mut object = &object;
+
+        while let Some(original) = geometry.original_of_rigid_copy(object) {
+            if let Some(errors) = self.errors.get(&original.id()) {
+                return errors.is_empty();
+            }
+
+            object = original;
+        }
+
+        false
+    }
+
+    /// # Remove all cached validation results
+    ///
+    /// All objects are validated again on the next run.
+    pub fn clear(&mut self) {
+        self.errors.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildFace, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            sweep::SweepSketch,
+            transform::TransformObject,
+            update::UpdateShell,
+        },
+        topology::{Face, Sketch},
+        validation::{ValidationConfig, validate_all},
+    };
+
+    use super::{ValidationCache, validate_incremental};
+
+    #[test]
+    fn only_validate_changed_objects() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let cube = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., 1.], &mut core);
+        let shell = cube.shells().only();
+
+        let config = ValidationConfig::default();
+        let mut cache = ValidationCache::new();
+
+        let report = validate_incremental(
+            shell.faces(),
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert!(report.is_empty());
+        assert_eq!(cache.num_validated(), 6);
+
+        // Nothing changed, so nothing needs to be validated again.
+        let _ = validate_incremental(
+            shell.faces(),
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert_eq!(cache.num_validated(), 6);
+
+        let face = shell.faces().first();
+        let shell = shell.update_face(
+            face,
+            |face, core| [face.reverse(core)],
+            &mut core,
+        );
+
+        let report = validate_incremental(
+            shell.faces(),
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert!(report.is_empty());
+        assert_eq!(cache.num_validated(), 7);
+        assert_eq!(cache.len(), 6);
+
+        // Changing the configuration invalidates the cache.
+        let config = ValidationConfig::from_tolerance(0.01);
+        let _ = validate_incremental(
+            shell.faces(),
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert_eq!(cache.num_validated(), 13);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn reuse_cached_errors() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let valid = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+        let invalid = Face::polygon(
+            surface,
+            [[0., 0.], [1., 1.], [1., 0.], [0., 1.]],
+            &mut core,
+        );
+        let [valid, invalid] =
+            [valid, invalid].map(|face| face.insert(&mut core));
+
+        // The invalid face has been validated on insertion. We're not
+        // interested in that result here.
+        let _ = core.layers.validation.take_errors();
+
+        let config = ValidationConfig::default();
+        let mut cache = ValidationCache::new();
+
+        let expected = validate_all(&*invalid, &core.layers.geometry, &config);
+        assert!(!expected.is_empty());
+
+        for _ in 0..2 {
+            let report = validate_incremental(
+                [&valid, &invalid],
+                &core.layers.geometry,
+                &config,
+                &mut cache,
+            );
+            assert_eq!(report.len(), expected.len());
+        }
+        assert_eq!(cache.num_validated(), 2);
+    }
+
+    #[test]
+    fn reuse_results_of_rigidly_transformed_objects() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::polygon(
+            surface,
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+
+        let config = ValidationConfig::default();
+        let mut cache = ValidationCache::new();
+
+        let report = validate_incremental(
+            [&face],
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert!(report.is_empty());
+        assert_eq!(cache.num_validated(), 1);
+
+        // A rigid copy of a valid face doesn't need to be validated again.
+        let moved = face
+            .clone()
+            .rotate([0., 0., 1.], &mut core)?
+            .translate([1., 2., 3.], &mut core)?;
+        let report = validate_incremental(
+            [&moved],
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert!(report.is_empty());
+        assert_eq!(cache.num_validated(), 1);
+        assert_eq!(cache.len(), 1);
+
+        // Scaling changes the geometry, so the result must be validated.
+        let scaled = moved.scale([2., 2., 2.], &mut core)?;
+        let report = validate_incremental(
+            [&scaled],
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert!(report.is_empty());
+        assert_eq!(cache.num_validated(), 2);
+
+        core.layers.validation.take_errors()?;
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_rigid_copies_of_objects_of_other_types() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let valid = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        let invalid = Face::polygon(
+            surface,
+            [[0., 0.], [1., 1.], [1., 0.], [0., 1.]],
+            &mut core,
+        )
+        .insert(&mut core);
+        let _ = core.layers.validation.take_errors();
+
+        // Only the regions are rigid copies, not the faces.
+        core.layers.geometry.define_rigid_copy(
+            valid.region().clone().into(),
+            invalid.region().clone().into(),
+        );
+
+        let config = ValidationConfig::default();
+        let mut cache = ValidationCache::new();
+
+        let expected = validate_all(&*invalid, &core.layers.geometry, &config);
+        assert!(!expected.is_empty());
+
+        let report = validate_incremental(
+            [&valid, &invalid],
+            &core.layers.geometry,
+            &config,
+            &mut cache,
+        );
+        assert_eq!(report.len(), expected.len());
+        assert_eq!(cache.num_validated(), 2);
+    }
+}
@@ -0,0 +1,233 @@
+use fj_math::{Aabb, Point, Scalar, Vector};
+use geo::{Area, BooleanOps, Coord, LineString, Polygon};
+
+use crate::{
+    geometry::{Geometry, Path},
+    storage::Handle,
+    topology::{Face, Shell},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// A [`Shell`] contains two [`Face`]s that overlap
+///
+/// Faces of a shell may touch along their boundaries, but must not cover the
+/// same area. Boolean operations and importers can leave a duplicate copy of a
+/// face in place, which this check detects.
+///
+/// Faces are considered to be on coincident surfaces, if all points of one face
+/// are within [`ValidationConfig::identical_max_distance`] of the plane of the
+/// other. Faces are sorted along the x-axis by their bounding boxes, so only
+/// faces whose bounding boxes overlap in x are paired up, and pairs whose
+/// bounding boxes don't touch are skipped right away. For the remaining pairs, the area in which the polygons that
+/// approximate both faces overlap is computed. Faces whose overlap area is
+/// larger than the square of [`ValidationConfig::distinct_min_distance`] are
+/// reported.
+///
+/// ## Implementation Note
+///
+/// Only faces on planes are checked. Detecting coincident faces on curved
+/// surfaces would require a more general way to compare surfaces.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Shell` contains coincident faces\n\
+    - Faces: {faces:#?}\n\
+    - Overlap area: {overlap_area}"
+)]
+pub struct CoincidentFaces {
+    /// The faces that overlap
+    pub faces: [Handle<Face>; 2],
+
+    /// The area in which the faces overlap
+    pub overlap_area: Scalar,
+}
+
+impl ValidationCheck<Shell> for CoincidentFaces {
+    fn check<'r>(
+        object: &'r Shell,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let margin = config.identical_max_distance;
+
+        // Without geometry, there's nothing to compare.
+        let mut faces = object
+            .faces()
+            .iter()
+            .filter_map(|face| PlanarFace::new(face, geometry, config))
+            .collect::<Vec<_>>();
+        faces.sort_by_key(|face| face.aabb.min.x);
+
+        let mut errors = Vec::new();
+
+        for (i, a) in faces.iter().enumerate() {
+            // Faces are sorted by where their bounding boxes start along the
+            // x-axis. Once one starts beyond the end of `a`'s, all the
+            // following ones do too.
+            let candidates = faces
+                .iter()
+                .skip(i + 1)
+                .take_while(|b| b.aabb.min.x <= a.aabb.max.x + margin);
+
+            for b in candidates {
+                if !aabbs_touch(&a.aabb, &b.aabb, margin) {
+                    continue;
+                }
+
+                let is_coplanar = b.rings.iter().flatten().all(|point| {
+                    a.normal.dot(&(point - a.origin)).abs() <= margin
+                });
+                if !is_coplanar {
+                    continue;
+                }
+
+                let [polygon_a, polygon_b] =
+                    [a, b].map(|face| face.project_into(a));
+                let overlap_area = Scalar::from(
+                    polygon_a.intersection(&polygon_b).unsigned_area(),
+                );
+
+                if overlap_area
+                    > config.distinct_min_distance
+                        * config.distinct_min_distance
+                {
+                    errors.push(CoincidentFaces {
+                        faces: [a.face.clone(), b.face.clone()],
+                        overlap_area,
+                    });
+                }
+            }
+        }
+
+        errors.into_iter()
+    }
+}
+
+struct PlanarFace<'r> {
+    face: &'r Handle<Face>,
+    origin: Point<3>,
+    axes: [Vector<3>; 2],
+    normal: Vector<3>,
+
+    /// The approximated cycles of the face in global coordinates, exterior
+    /// first
+    rings: Vec<Vec<Point<3>>>,
+    aabb: Aabb<3>,
+}
+
+impl<'r> PlanarFace<'r> {
+    fn new(
+        face: &'r Handle<Face>,
+        geometry: &Geometry,
+        config: &ValidationConfig,
+    ) -> Option<Self> {
+        let surface = geometry.of_surface_if_defined(face.surface())?;
+        let Path::Line(line) = surface.u else {
+            return None;
+        };
+
+        let rings = face
+            .region()
+            .all_cycles()
+            .map(|cycle| {
+                let points = cycle.to_polyline_2d(
+                    face.surface(),
+                    geometry,
+                    config.tolerance,
+                )?;
+
+                Some(
+                    points
+                        .into_iter()
+                        .map(|point| {
+                            surface.u.point_from_path_coords([point.u])
+                                + surface.v * point.v
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let aabb = Aabb::<3>::from_points(rings.iter().flatten().copied());
+
+        let u = line.direction().normalize();
+        let normal = u.cross(&surface.v).normalize();
+        let v = normal.cross(&u);
+
+        Some(Self {
+            face,
+            origin: line.origin(),
+            axes: [u, v],
+            normal,
+            rings,
+            aabb,
+        })
+    }
+
+    /// Project the face into the plane of another one
+    fn project_into(&self, other: &PlanarFace) -> Polygon {
+        let [u, v] = other.axes;
+
+        let mut rings = self.rings.iter().map(|ring| {
+            LineString::from_iter(ring.iter().map(|point| {
+                let d = point - other.origin;
+                Coord {
+                    x: u.dot(&d).into_f64(),
+                    y: v.dot(&d).into_f64(),
+                }
+            }))
+        });
+
+        let exterior = rings
+            .next()
+            .expect("Every face has an exterior cycle, so there is a ring");
+        Polygon::new(exterior, rings.collect())
+    }
+}
+
+fn aabbs_touch(a: &Aabb<3>, b: &Aabb<3>, margin: Scalar) -> bool {
+    (0..3).all(|i| {
+        a.min.coords.components[i] <= b.max.coords.components[i] + margin
+            && b.min.coords.components[i] <= a.max.coords.components[i] + margin
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        Core,
+        operations::{build::BuildFace, insert::Insert},
+        topology::{Face, Shell},
+        validation::{ValidationCheck, checks::CoincidentFaces},
+    };
+
+    #[test]
+    fn coincident_faces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let [a, b, c] = [[0., 0.], [0., 0.], [2., 0.]].map(|[u, v]| {
+            Face::polygon(
+                surface.clone(),
+                [[u, v], [u + 1., v], [u + 1., v + 1.], [u, v + 1.]],
+                &mut core,
+            )
+            .insert(&mut core)
+        });
+
+        let valid = Shell::new([a.clone(), c]);
+        CoincidentFaces::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = Shell::new([a, b]);
+        let error = CoincidentFaces::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert!((error.overlap_area - 1.).abs() < Scalar::from(1e-9));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use fj_math::{Line, Point, Scalar};
+
+use crate::{
+    geometry::{Geometry, intersection::line_coords_of_intersection},
+    storage::Handle,
+    topology::{Cycle, Face, HalfEdge, Region, Sketch, Surface},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// Non-adjacent [`HalfEdge`]s of a [`Cycle`] intersect
+///
+/// A cycle is supposed to describe the boundary of an area on a surface. If two
+/// of its half-edges cross each other, the cycle no longer describes such an
+/// area, and operations that rely on that, like triangulation, will produce
+/// garbage.
+///
+/// Adjacent half-edges share a vertex, and are thus expected to touch. This
+/// check only considers half-edges that are not adjacent.
+///
+/// ## Implementation Note
+///
+/// The intersection is computed from the polylines that approximate the
+/// half-edges in surface coordinates, at the configured tolerance. Collinear
+/// overlap between segments of those polylines is not detected.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "Non-adjacent `HalfEdge`s in `Cycle` intersect\n\
+    - Intersection point (surface coordinates): {intersection:?}\n\
+    - The intersecting `HalfEdge`s: {half_edges:#?}"
+)]
+pub struct CycleSelfIntersection {
+    /// The intersecting half-edges
+    pub half_edges: [Handle<HalfEdge>; 2],
+
+    /// The approximate point of intersection, in surface coordinates
+    pub intersection: Point<2>,
+}
+
+impl ValidationCheck<Face> for CycleSelfIntersection {
+    fn check<'r>(
+        object: &'r Face,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        check_region(object.region(), object.surface(), geometry, config)
+    }
+}
+
+impl ValidationCheck<Sketch> for CycleSelfIntersection {
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object.regions().iter().flat_map(|region| {
+            check_region(region, object.surface(), geometry, config)
+        })
+    }
+}
+
+fn check_region<'r>(
+    region: &'r Region,
+    surface: &'r Handle<Surface>,
+    geometry: &'r Geometry,
+    config: &'r ValidationConfig,
+) -> impl Iterator<Item = CycleSelfIntersection> + 'r {
+    [region.exterior()]
+        .into_iter()
+        .chain(region.interiors())
+        .flat_map(|cycle| check_cycle(cycle, surface, geometry, config))
+}
+
+fn check_cycle(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+) -> Vec<CycleSelfIntersection> {
+    let Some(polylines) = cycle.half_edge_polylines_with_cache(
+        surface,
+        config.tolerance,
+        config.approx_cache.as_ref(),
+        geometry,
+    ) else {
+        // If the curve geometry is not defined for our local surface, there's
+        // nothing we can check.
+        return Vec::new();
+    };
+
+    let num_half_edges = polylines.len();
+    let mut errors = Vec::new();
+
+    for (i, (half_edge_a, polyline_a)) in polylines.iter().enumerate() {
+        for (j, (half_edge_b, polyline_b)) in
+            polylines.iter().enumerate().skip(i + 1)
+        {
+            let are_adjacent =
+                j == i + 1 || (i == 0 && j + 1 == num_half_edges);
+            if are_adjacent {
+                // Adjacent half-edges share a vertex. They always touch.
+                continue;
+            }
+
+            let intersection = polyline_a.windows(2).find_map(|segment_a| {
+                polyline_b.windows(2).find_map(|segment_b| {
+                    intersect_segments(
+                        [segment_a[0], segment_a[1]],
+                        [segment_b[0], segment_b[1]],
+                    )
+                })
+            });
+
+            if let Some(intersection) = intersection {
+                errors.push(CycleSelfIntersection {
+                    half_edges: [half_edge_a.clone(), half_edge_b.clone()],
+                    intersection,
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+fn intersect_segments(
+    [a, b]: [Point<2>; 2],
+    [c, d]: [Point<2>; 2],
+) -> Option<Point<2>> {
+    let ab = Line::from_origin_and_direction(a, b - a);
+    let cd = Line::from_origin_and_direction(c, d - c);
+
+    // If the segments are parallel, there's no intersection. As documented,
+    // collinear overlap is not detected.
+    let [t, u] = line_coords_of_intersection(&ab, &cd)?;
+
+    let range = Scalar::ZERO..=Scalar::ONE;
+    if range.contains(&t) && range.contains(&u) {
+        return Some(ab.point_from_line_coords([t]));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::build::BuildFace,
+        topology::Face,
+        validation::{ValidationCheck, checks::CycleSelfIntersection},
+    };
+
+    #[test]
+    fn cycle_self_intersection() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let valid = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+        CycleSelfIntersection::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = Face::polygon(
+            surface,
+            [[0., 0.], [1., 1.], [1., 0.], [0., 1.]],
+            &mut core,
+        );
+        let err = CycleSelfIntersection::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert_eq!(err.intersection, [0.5, 0.5].into());
+
+        Ok(())
+    }
+}
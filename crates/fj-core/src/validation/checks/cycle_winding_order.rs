@@ -0,0 +1,202 @@
+use fj_math::{Polygon, Scalar};
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Cycle, Sketch},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// [`Cycle`] of a [`Sketch`] region has the wrong winding order
+///
+/// Within a sketch, the exterior cycle of each region must wind
+/// counter-clockwise, while its interior cycles must wind clockwise. This check
+/// computes the signed area of each cycle in surface coordinates, using the
+/// shoelace formula, and reports exterior cycles with a negative area and
+/// interior cycles with a positive area.
+///
+/// This check is not applied to faces. The winding of a face's exterior cycle
+/// defines the orientation of that face, and thus can legitimately be either.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Cycle` has wrong winding order for its role\n\
+    - Role of the cycle: {role:?}\n\
+    - Signed area of the cycle: {signed_area}\n\
+    - The cycle with wrong winding order: {cycle:#?}"
+)]
+pub struct CycleWindingOrder {
+    /// The cycle with the wrong winding order
+    pub cycle: Handle<Cycle>,
+
+    /// The signed area of the cycle, in surface coordinates
+    ///
+    /// A positive area means counter-clockwise winding, a negative area means
+    /// clockwise winding.
+    pub signed_area: Scalar,
+
+    /// The role that the cycle plays within its region
+    pub role: CycleRole,
+}
+
+/// The role that a [`Cycle`] plays within a [`Region`]
+///
+/// [`Region`]: crate::topology::Region
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CycleRole {
+    /// The cycle is the exterior of the region
+    Exterior,
+
+    /// The cycle is one of the interiors of the region
+    Interior,
+}
+
+impl ValidationCheck<Sketch> for CycleWindingOrder {
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object
+            .regions()
+            .iter()
+            .flat_map(|region| {
+                [(region.exterior(), CycleRole::Exterior)]
+                    .into_iter()
+                    .chain(
+                        region
+                            .interiors()
+                            .iter()
+                            .map(|interior| (interior, CycleRole::Interior)),
+                    )
+            })
+            .filter_map(|(cycle, role)| {
+                let polylines = cycle.half_edge_polylines_with_cache(
+                    object.surface(),
+                    config.tolerance,
+                    config.approx_cache.as_ref(),
+                    geometry,
+                )?;
+
+                let points = polylines
+                    .into_iter()
+                    .flat_map(|(_, mut polyline)| {
+                        // The last point of each polyline is the first point
+                        // of the next one.
+                        polyline.pop();
+                        polyline
+                    })
+                    .collect::<Vec<_>>();
+
+                let signed_area = Polygon::from_points(points).signed_area();
+
+                let has_wrong_winding = match role {
+                    CycleRole::Exterior => signed_area < Scalar::ZERO,
+                    CycleRole::Interior => signed_area > Scalar::ZERO,
+                };
+
+                has_wrong_winding.then(|| CycleWindingOrder {
+                    cycle: cycle.clone(),
+                    signed_area,
+                    role,
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            reverse::Reverse,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, Region, Sketch},
+        validation::{
+            ValidationCheck,
+            checks::{CycleRole, CycleWindingOrder},
+        },
+    };
+
+    #[test]
+    fn exterior_cycle_winding_order() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let valid = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        CycleWindingOrder::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.update_region(
+            valid.regions().first(),
+            |region, core| {
+                [region
+                    .update_exterior(|cycle, core| cycle.reverse(core), core)]
+            },
+            &mut core,
+        );
+        let err = CycleWindingOrder::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert_eq!(err.role, CycleRole::Exterior);
+        assert_eq!(err.signed_area, Scalar::from(-1.));
+
+        Ok(())
+    }
+
+    #[test]
+    fn interior_cycle_winding_order() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let region = Region::polygon(
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            surface.clone(),
+            &mut core,
+        )
+        .add_interiors(
+            [Cycle::polygon(
+                [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+                surface.clone(),
+                &mut core,
+            )],
+            &mut core,
+        )
+        .insert(&mut core);
+        let valid = Sketch::new(surface, vec![region]);
+        CycleWindingOrder::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = valid.update_region(
+            valid.regions().first(),
+            |region, core| {
+                [region.update_interior(
+                    region.interiors().first(),
+                    |cycle, core| [cycle.reverse(core)],
+                    core,
+                )]
+            },
+            &mut core,
+        );
+        let err = CycleWindingOrder::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert_eq!(err.role, CycleRole::Interior);
+        assert_eq!(err.signed_area, Scalar::ONE);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use fj_math::Scalar;
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Face, HalfEdge},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// [`HalfEdge`] of a [`Face`] is degenerate
+///
+/// A half-edge whose start and end vertices collapse into the same point
+/// doesn't bound anything, and causes trouble in algorithms that expect each
+/// half-edge to contribute to the boundary of the face, like triangulation.
+///
+/// This check computes the length of the polyline that approximates each
+/// half-edge, and flags all half-edges whose length is below
+/// [`ValidationConfig::identical_max_distance`]. Since the length of the
+/// polyline is used, and not just the distance between the start and end
+/// vertices, half-edges that start and end close to each other, but are not
+/// short themselves (like an arc spanning most of a circle), are not flagged.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`HalfEdge` of `Face` is degenerate\n\
+    - Length of the half-edge: {length}\n\
+    - The degenerate half-edge: {half_edge:#?}"
+)]
+pub struct DegenerateHalfEdge {
+    /// The degenerate half-edge
+    pub half_edge: Handle<HalfEdge>,
+
+    /// The length of the half-edge, in surface coordinates
+    pub length: Scalar,
+}
+
+impl ValidationCheck<Face> for DegenerateHalfEdge {
+    fn check<'r>(
+        object: &'r Face,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        [object.region().exterior()]
+            .into_iter()
+            .chain(object.region().interiors())
+            .filter_map(|cycle| {
+                cycle.half_edge_polylines_with_cache(
+                    object.surface(),
+                    config.tolerance,
+                    config.approx_cache.as_ref(),
+                    geometry,
+                )
+            })
+            .flatten()
+            .filter_map(|(half_edge, polyline)| {
+                let length = polyline.windows(2).fold(
+                    Scalar::ZERO,
+                    |length, segment| {
+                        length + segment[0].distance_to(&segment[1])
+                    },
+                );
+
+                if length < config.identical_max_distance {
+                    return Some(DegenerateHalfEdge { half_edge, length });
+                }
+
+                None
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::build::BuildFace,
+        topology::Face,
+        validation::{ValidationCheck, checks::DegenerateHalfEdge},
+    };
+
+    #[test]
+    fn degenerate_half_edge() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let valid = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+        DegenerateHalfEdge::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = Face::polygon(
+            surface,
+            [[0., 0.], [1., 0.], [1., 0.001], [1., 1.]],
+            &mut core,
+        );
+        DegenerateHalfEdge::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn short_arcs_are_not_degenerate() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        // Each of the four arcs that make up this circle is very short, but
+        // still longer than the default `identical_max_distance`.
+        let valid = Face::circle(surface, [0., 0.], 0.01, &mut core);
+        DegenerateHalfEdge::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Face, Region, Sketch, Surface},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// [`Region`] is empty
+///
+/// A region must enclose an area. This check reports regions whose exterior
+/// cycle has no half-edges, or encloses an area that is too small to be
+/// distinguished from zero, at the configured tolerance. See
+/// [`Region::is_empty`] for details.
+///
+/// Empty regions can't be triangulated.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Region` is empty\n\
+    - The empty region: {region:#?}"
+)]
+pub struct EmptyRegion {
+    /// The empty region
+    pub region: Handle<Region>,
+}
+
+impl ValidationCheck<Face> for EmptyRegion {
+    fn check<'r>(
+        object: &'r Face,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        check_region(object.region(), object.surface(), geometry, config)
+            .into_iter()
+    }
+}
+
+impl ValidationCheck<Sketch> for EmptyRegion {
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object.regions().iter().filter_map(|region| {
+            check_region(region, object.surface(), geometry, config)
+        })
+    }
+}
+
+fn check_region(
+    region: &Handle<Region>,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+) -> Option<EmptyRegion> {
+    region
+        .is_empty(surface, config.tolerance, geometry)
+        .then(|| EmptyRegion {
+            region: region.clone(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildCycle, BuildFace, BuildRegion, BuildSketch},
+            insert::Insert,
+            update::{UpdateFace, UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, Face, Region, Sketch},
+        validation::{ValidationCheck, checks::EmptyRegion},
+    };
+
+    #[test]
+    fn square_is_not_empty() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let square = Face::polygon(
+            core.layers.topology.surfaces.space_2d(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        assert!(!square.region().is_empty(
+            square.surface(),
+            core.tolerance(),
+            &core.layers.geometry,
+        ));
+        EmptyRegion::check_and_return_first_error(
+            &square,
+            &core.layers.geometry,
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn region_without_half_edges_is_empty() {
+        let mut core = Core::new();
+
+        let square = Face::polygon(
+            core.layers.topology.surfaces.space_2d(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        let invalid = square.update_region(
+            |region, core| region.update_exterior(|_, _| Cycle::empty(), core),
+            &mut core,
+        );
+        assert!(invalid.region().is_empty(
+            invalid.surface(),
+            core.tolerance(),
+            &core.layers.geometry,
+        ));
+        EmptyRegion::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+    }
+
+    #[test]
+    fn sliver_is_only_empty_if_thinner_than_tolerance() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let tolerance = core.tolerance().inner().into_f64();
+
+        // Long and thin, but still clearly wider than the tolerance.
+        let sliver = Region::polygon(
+            [
+                [0., 0.],
+                [10., 0.],
+                [10., tolerance * 2.],
+                [0., tolerance * 2.],
+            ],
+            surface.clone(),
+            &mut core,
+        )
+        .insert(&mut core);
+        let valid = Sketch::empty(&core.layers.topology)
+            .add_regions([sliver], &mut core);
+        EmptyRegion::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let degenerate = Region::polygon(
+            [
+                [0., 0.],
+                [10., 0.],
+                [10., tolerance / 2.],
+                [0., tolerance / 2.],
+            ],
+            surface,
+            &mut core,
+        )
+        .insert(&mut core);
+        let invalid = Sketch::empty(&core.layers.topology)
+            .add_regions([degenerate], &mut core);
+        EmptyRegion::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        Ok(())
+    }
+}
@@ -139,7 +139,9 @@ fn check_cycle<'r>(
             - start_pos_of_second_half_edge)
             .magnitude();
 
-        if distance_between_positions > config.identical_max_distance {
+        if distance_between_positions
+            > config.identical_max_distance_for("AdjacentHalfEdgesNotConnected")
+        {
             return Some(AdjacentHalfEdgesNotConnected {
                 end_pos_of_first_half_edge,
                 start_pos_of_second_half_edge,
@@ -154,6 +156,7 @@ fn check_cycle<'r>(
 
 #[cfg(test)]
 mod tests {
+    use fj_math::Point;
 
     use crate::{
         Core,
@@ -163,7 +166,7 @@ mod tests {
             update::{UpdateCycle, UpdateFace, UpdateRegion},
         },
         topology::{Face, HalfEdge},
-        validation::ValidationCheck,
+        validation::{ValidationCheck, ValidationConfig},
     };
 
     use super::AdjacentHalfEdgesNotConnected;
@@ -257,4 +260,54 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn tolerance_override() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let face = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.]],
+            &mut core,
+        );
+
+        // Open a small gap between the first and second half-edge, larger than
+        // the default `identical_max_distance`.
+        let [first, second] = [0, 1].map(|i| {
+            face.region()
+                .exterior()
+                .half_edges()
+                .nth(i)
+                .unwrap()
+                .clone()
+        });
+        core.layers.geometry.define_vertex(
+            second.start_vertex().clone(),
+            first.curve().clone(),
+            LocalVertexGeom {
+                position: Point::from([1.05]),
+            },
+        );
+
+        AdjacentHalfEdgesNotConnected::check_and_expect_one_error(
+            &face,
+            &core.layers.geometry,
+        );
+
+        let config = ValidationConfig::default()
+            .with_identical_max_distance_for(
+                "AdjacentHalfEdgesNotConnected",
+                0.1,
+            );
+        assert_eq!(
+            AdjacentHalfEdgesNotConnected::check(
+                &face,
+                &core.layers.geometry,
+                &config
+            )
+            .count(),
+            0,
+        );
+    }
 }
@@ -0,0 +1,178 @@
+use fj_math::{Point, Polygon};
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Cycle, Face, Region, Sketch, Surface},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// Interior [`Cycle`] of a [`Region`] is not contained within its exterior
+///
+/// The interior cycles of a region define holes in that region. A hole that
+/// reaches outside of the exterior cycle doesn't make sense.
+///
+/// This check verifies that each vertex of every interior cycle lies within the
+/// polygon that approximates the exterior cycle. Vertices that lie on the
+/// boundary of that polygon, within the configured tolerance, are considered
+/// to be within the polygon.
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "Interior `Cycle` of `Region` is not contained within exterior\n\
+    - Position of vertex outside of exterior: {position:?}\n\
+    - Interior cycle outside of exterior: {interior_cycle:#?}"
+)]
+pub struct InteriorCycleOutsideExterior {
+    /// The interior cycle that is not contained within the exterior
+    pub interior_cycle: Handle<Cycle>,
+
+    /// The position of the first vertex found outside of the exterior
+    pub position: Point<2>,
+}
+
+impl ValidationCheck<Face> for InteriorCycleOutsideExterior {
+    fn check<'r>(
+        object: &'r Face,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        check_region(object.region(), object.surface(), geometry, config)
+            .into_iter()
+    }
+}
+
+impl ValidationCheck<Sketch> for InteriorCycleOutsideExterior {
+    fn check<'r>(
+        object: &'r Sketch,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object.regions().iter().flat_map(|region| {
+            check_region(region, object.surface(), geometry, config)
+        })
+    }
+}
+
+fn check_region(
+    region: &Region,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+) -> Vec<InteriorCycleOutsideExterior> {
+    let Some(exterior) = region.exterior().half_edge_polylines_with_cache(
+        surface,
+        config.tolerance,
+        config.approx_cache.as_ref(),
+        geometry,
+    ) else {
+        // If the curve geometry is not defined for our local surface, there's
+        // nothing we can check.
+        return Vec::new();
+    };
+    let exterior = exterior
+        .into_iter()
+        .flat_map(|(_, mut polyline)| {
+            // The last point of each polyline is the first point of the next
+            // one.
+            polyline.pop();
+            polyline
+        })
+        .collect::<Vec<_>>();
+
+    if exterior.len() < 3 {
+        // Can't determine containment, if the exterior doesn't span an area.
+        // Sounds like a job for a different validation check.
+        return Vec::new();
+    }
+    let exterior = Polygon::from_points(exterior);
+
+    region
+        .interiors()
+        .iter()
+        .filter_map(|interior| {
+            let polylines = interior.half_edge_polylines_with_cache(
+                surface,
+                config.tolerance,
+                config.approx_cache.as_ref(),
+                geometry,
+            )?;
+
+            let position = polylines
+                .into_iter()
+                .filter_map(|(_, polyline)| polyline.first().copied())
+                .find(|&vertex| {
+                    !exterior
+                        .contains_point(vertex, config.identical_max_distance)
+                })?;
+
+            Some(InteriorCycleOutsideExterior {
+                interior_cycle: interior.clone(),
+                position,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildCycle, BuildFace},
+            update::{UpdateFace, UpdateRegion},
+        },
+        topology::{Cycle, Face},
+        validation::{ValidationCheck, checks::InteriorCycleOutsideExterior},
+    };
+
+    #[test]
+    fn interior_cycle_outside_exterior() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let square = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            &mut core,
+        );
+
+        let valid = square.update_region(
+            |region, core| {
+                region.add_interiors(
+                    [Cycle::polygon(
+                        [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+                        surface.clone(),
+                        core,
+                    )],
+                    core,
+                )
+            },
+            &mut core,
+        );
+        InteriorCycleOutsideExterior::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = square.update_region(
+            |region, core| {
+                region.add_interiors(
+                    [Cycle::polygon(
+                        [[2., 1.], [2., 2.], [4., 2.], [4., 1.]],
+                        surface,
+                        core,
+                    )],
+                    core,
+                )
+            },
+            &mut core,
+        );
+        let err = InteriorCycleOutsideExterior::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert_eq!(err.position, [4., 2.].into());
+
+        Ok(())
+    }
+}
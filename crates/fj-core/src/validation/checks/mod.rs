@@ -2,18 +2,38 @@
 //!
 //! See documentation of [parent module](super) for more information.
 
+mod coincident_faces;
 mod coincident_half_edges_are_not_siblings;
+mod cycle_self_intersection;
+mod cycle_winding_order;
+mod degenerate_half_edge;
+mod empty_region;
 mod face_boundary;
 mod face_winding;
 mod half_edge_connection;
 mod half_edge_has_no_sibling;
+mod interior_cycle_outside_exterior;
 mod multiple_references;
+mod non_convex_cycle;
+mod non_manifold_edge;
+mod shell_not_watertight;
+mod vertex_on_curve;
 
 pub use self::{
+    coincident_faces::CoincidentFaces,
     coincident_half_edges_are_not_siblings::CoincidentHalfEdgesAreNotSiblings,
+    cycle_self_intersection::CycleSelfIntersection,
+    cycle_winding_order::{CycleRole, CycleWindingOrder},
+    degenerate_half_edge::DegenerateHalfEdge,
+    empty_region::EmptyRegion,
     face_boundary::FaceHasNoBoundary,
     face_winding::InteriorCycleHasInvalidWinding,
     half_edge_connection::AdjacentHalfEdgesNotConnected,
     half_edge_has_no_sibling::HalfEdgeHasNoSibling,
+    interior_cycle_outside_exterior::InteriorCycleOutsideExterior,
     multiple_references::MultipleReferencesToObject,
+    non_convex_cycle::NonConvexCycle,
+    non_manifold_edge::NonManifoldEdge,
+    shell_not_watertight::ShellNotWatertight,
+    vertex_on_curve::VertexNotOnCurve,
 };
@@ -0,0 +1,156 @@
+use fj_math::Scalar;
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Cycle, Face, Vertex},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// [`Cycle`] of a [`Face`] is not convex
+///
+/// Some algorithms only work with convex faces. This check reports the first
+/// reflex vertex of each cycle, meaning a vertex where the interior angle of
+/// the cycle is larger than half a turn.
+///
+/// The cycle is treated as a polygon of its vertices (see
+/// [`Cycle::is_convex`]). At each vertex, the cross product of the directions
+/// of the adjacent half-edges is computed, using consecutive triples of
+/// half-edges. A reflex vertex is one where the sign of that cross product is
+/// the opposite of the cycle's overall winding.
+///
+/// Cycles that aren't convex are perfectly valid, so this check only runs, if
+/// [`ValidationConfig::require_convex_cycles`] is set. Errors reported by it
+/// are of [`Severity::Warning`].
+///
+/// [`Severity::Warning`]: crate::validation::Severity::Warning
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Cycle` is not convex\n\
+    - Reflex vertex: {vertex:#?}\n\
+    - Interior angle at vertex: {interior_angle}\n\
+    - The non-convex cycle: {cycle:#?}"
+)]
+pub struct NonConvexCycle {
+    /// The cycle that is not convex
+    pub cycle: Handle<Cycle>,
+
+    /// The first vertex of the cycle, at which it is not convex
+    pub vertex: Handle<Vertex>,
+
+    /// The interior angle of the cycle at the vertex, in radians
+    ///
+    /// This is larger than half a turn.
+    pub interior_angle: Scalar,
+}
+
+impl ValidationCheck<Face> for NonConvexCycle {
+    fn check<'r>(
+        object: &'r Face,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object
+            .region()
+            .all_cycles()
+            .filter(|_| config.require_convex_cycles)
+            .filter_map(|cycle| {
+                // Without geometry, there's nothing to check.
+                let turn_angles =
+                    cycle.turn_angles(object.surface(), geometry)?;
+
+                // Left turns are convex, if the cycle winds counter-clockwise,
+                // which means its turn angles add up to a positive value.
+                let total_angle = turn_angles
+                    .iter()
+                    .fold(Scalar::ZERO, |total, (_, angle)| total + *angle);
+                let sign = if total_angle < Scalar::ZERO {
+                    -Scalar::ONE
+                } else {
+                    Scalar::ONE
+                };
+
+                let (half_edge, angle) = turn_angles
+                    .into_iter()
+                    .find(|(_, angle)| *angle * sign < Scalar::ZERO)?;
+
+                Some(NonConvexCycle {
+                    cycle: cycle.clone(),
+                    vertex: half_edge.start_vertex().clone(),
+                    interior_angle: Scalar::PI - angle * sign,
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        Core,
+        operations::{build::BuildFace, insert::Insert},
+        topology::Face,
+        validation::{
+            Severity, ValidationCheck, ValidationConfig, ValidationError,
+            checks::NonConvexCycle,
+        },
+    };
+
+    #[test]
+    fn non_convex_cycle() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let config = ValidationConfig {
+            require_convex_cycles: true,
+            ..ValidationConfig::default()
+        };
+
+        let valid = Face::polygon(
+            surface.clone(),
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        );
+        assert!(
+            NonConvexCycle::check(&valid, &core.layers.geometry, &config)
+                .next()
+                .is_none()
+        );
+
+        let invalid = Face::polygon(
+            surface,
+            [[0., 0.], [2., 0.], [2., 1.], [1., 1.], [1., 2.], [0., 2.]],
+            &mut core,
+        );
+        let errors =
+            NonConvexCycle::check(&invalid, &core.layers.geometry, &config)
+                .collect::<Vec<_>>();
+        assert_eq!(errors.len(), 1);
+
+        let error = &errors[0];
+        let reflex_vertex = invalid
+            .region()
+            .exterior()
+            .half_edges()
+            .nth_circular(3)
+            .start_vertex();
+        assert_eq!(&error.vertex, reflex_vertex);
+        assert!(
+            (error.interior_angle - Scalar::PI * 1.5).abs()
+                < Scalar::from(1e-9)
+        );
+
+        // The check is opt-in. Without the config, there are no errors.
+        let invalid = invalid.insert(&mut core);
+        NonConvexCycle::check_and_return_first_error(
+            &invalid,
+            &core.layers.geometry,
+        )?;
+
+        let error = ValidationError::from(errors[0].clone());
+        assert_eq!(error.severity(&config), Severity::Warning);
+
+        Ok(())
+    }
+}
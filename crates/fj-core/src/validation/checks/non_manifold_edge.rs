@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::{Geometry, repr::tri_mesh::convert_point_surface_to_global},
+    queries::BoundingVerticesOfHalfEdge,
+    storage::Handle,
+    topology::{Curve, Cycle, HalfEdge, Shell, Surface},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// A [`Shell`] contains an edge that is shared by more than two faces
+///
+/// In a manifold shell, each edge bounds exactly two faces. An edge that is
+/// shared by three or more faces is non-manifold, which breaks many algorithms
+/// that operate on shells.
+///
+/// Half-edges are grouped by the edge they lie on, by comparing their start,
+/// middle, and end points in 3D space. Points are considered to be the same,
+/// if they are within [`ValidationConfig::identical_max_distance`]. This means
+/// the check also detects non-manifold edges, if the half-edges involved don't
+/// share curves or vertices.
+///
+/// To avoid comparing every pair of half-edges, edges are indexed in a grid,
+/// whose cells are as large as `identical_max_distance`. Only edges whose
+/// start point lies in a cell next to the start or end point of a half-edge
+/// are compared to it.
+///
+/// Open edges, which are referenced by only one half-edge, are covered by
+/// [`ShellNotWatertight`].
+///
+/// [`ShellNotWatertight`]: super::ShellNotWatertight
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Shell` contains non-manifold edge\n\
+    - Curve: {curve:#?}\n\
+    - Half-edges on the edge: {half_edges:#?}"
+)]
+pub struct NonManifoldEdge {
+    /// The curve of the first half-edge on the non-manifold edge
+    ///
+    /// In a valid shell, all half-edges on an edge would share this curve.
+    pub curve: Handle<Curve>,
+
+    /// The half-edges that lie on the non-manifold edge
+    pub half_edges: Vec<Handle<HalfEdge>>,
+}
+
+impl ValidationCheck<Shell> for NonManifoldEdge {
+    fn check<'r>(
+        object: &'r Shell,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let cell_size = config.identical_max_distance;
+
+        let mut edges: Vec<([Point<3>; 3], Vec<Handle<HalfEdge>>)> = Vec::new();
+        let mut edges_by_cell: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+
+        for face in object.faces() {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let Some(points) = sample_half_edge(
+                        half_edge,
+                        cycle,
+                        face.surface(),
+                        geometry,
+                        config,
+                    ) else {
+                        // Without geometry, there's nothing to compare.
+                        continue;
+                    };
+                    let [start, middle, end] = points;
+
+                    // An edge that matches the half-edge starts close to
+                    // either its start or its end point, depending on their
+                    // relative direction. Its start point must be in one of
+                    // the cells around those points.
+                    let edge = [start, end]
+                        .into_iter()
+                        .flat_map(|point| neighboring_cells(point, cell_size))
+                        .filter_map(|cell| edges_by_cell.get(&cell))
+                        .flatten()
+                        .copied()
+                        .find(|&i| {
+                            let (edge_points, _) = &edges[i];
+
+                            [[start, middle, end], [end, middle, start]]
+                                .into_iter()
+                                .any(|points| {
+                                    points.into_iter().zip(*edge_points).all(
+                                        |(a, b)| {
+                                            a.distance_to(&b)
+                                                < config.identical_max_distance
+                                        },
+                                    )
+                                })
+                        });
+
+                    match edge {
+                        Some(i) => {
+                            let (_, half_edges) = &mut edges[i];
+                            half_edges.push(half_edge.clone());
+                        }
+                        None => {
+                            edges_by_cell
+                                .entry(cell_of(start, cell_size))
+                                .or_default()
+                                .push(edges.len());
+                            edges.push((points, vec![half_edge.clone()]));
+                        }
+                    }
+                }
+            }
+        }
+
+        edges.into_iter().filter_map(|(_, half_edges)| {
+            if half_edges.len() <= 2 {
+                return None;
+            }
+
+            Some(NonManifoldEdge {
+                curve: half_edges[0].curve().clone(),
+                half_edges,
+            })
+        })
+    }
+}
+
+/// Compute the grid cell that contains the point
+fn cell_of(point: Point<3>, cell_size: Scalar) -> [i64; 3] {
+    point
+        .coords
+        .components
+        .map(|c| (c / cell_size).floor().into_f64() as i64)
+}
+
+/// Compute the grid cells that may contain points close to the given one
+///
+/// Any point that is closer to `point` than `cell_size` is in one of them.
+fn neighboring_cells(
+    point: Point<3>,
+    cell_size: Scalar,
+) -> impl Iterator<Item = [i64; 3]> {
+    let [x, y, z] = cell_of(point, cell_size);
+
+    (-1..=1).flat_map(move |dx| {
+        (-1..=1).flat_map(move |dy| {
+            (-1..=1).map(move |dz| [x + dx, y + dy, z + dz])
+        })
+    })
+}
+
+/// Compute the start, middle, and end points of the half-edge in 3D space
+fn sample_half_edge(
+    half_edge: &Handle<HalfEdge>,
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+) -> Option<[Point<3>; 3]> {
+    let vertices = cycle
+        .bounding_vertices_of_half_edge(half_edge)
+        .expect("`half_edge` came from `cycle`, must exist there");
+    let [start, end] = vertices.inner.each_ref().map(|vertex| {
+        geometry
+            .of_vertex(vertex)?
+            .local_on(half_edge.curve())
+            .map(|vertex| vertex.position)
+    });
+    let [start, end] = [start?, end?];
+    let middle = start + (end - start) * 0.5;
+
+    let path = geometry
+        .of_curve(half_edge.curve())?
+        .local_on(surface)?
+        .path;
+    let surface_geom = geometry.of_surface_2(surface)?;
+
+    Some([start, middle, end].map(|point_curve| {
+        convert_point_surface_to_global(
+            &surface_geom.generator,
+            path.point_from_path_coords(point_curve),
+            config.tolerance,
+            geometry,
+        )
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        Core,
+        operations::{
+            build::{BuildFace, BuildShell, BuildSurface},
+            insert::Insert,
+        },
+        topology::{Face, Shell, Surface},
+        validation::{
+            ValidationCheck, ValidationConfig, checks::NonManifoldEdge,
+        },
+    };
+
+    #[test]
+    fn non_manifold_edge() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let valid = Shell::tetrahedron(
+            [[0., 0., 0.], [0., 1., 0.], [1., 0., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        NonManifoldEdge::check_and_return_first_error(
+            &valid.shell,
+            &core.layers.geometry,
+        )?;
+
+        // Three triangles that share the edge from `[0, 0, 0]` to `[1, 0, 0]`,
+        // without sharing any objects.
+        let faces = [[0.5, 1., 0.], [0.5, 0., 1.], [0.5, -1., -1.]].map(|c| {
+            let (surface, points) = Surface::plane_from_points(
+                [[0., 0., 0.], [1., 0., 0.], c],
+                &mut core,
+            );
+            Face::polygon(surface, points, &mut core).insert(&mut core)
+        });
+        let invalid = Shell::new(faces);
+
+        let error = NonManifoldEdge::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert_eq!(error.half_edges.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_manifold_edge_across_grid_cells() {
+        let mut core = Core::new();
+
+        // Points on the edge are offset by less than `identical_max_distance`,
+        // so their coordinates end up on either side of cell boundaries.
+        let d = ValidationConfig::default().identical_max_distance * 0.25;
+
+        let faces = [
+            ([0.5, 1., 0.], d),
+            ([0.5, 0., 1.], -d),
+            ([0.5, -1., -1.], Scalar::ZERO),
+        ]
+        .map(|(c, offset)| {
+            let [a, b] = [[0., 0., 0.], [1., 0., 0.]]
+                .map(|point| Point::from(point) + Vector::from([offset; 3]));
+
+            let (surface, points) =
+                Surface::plane_from_points([a, b, c.into()], &mut core);
+            Face::polygon(surface, points, &mut core).insert(&mut core)
+        });
+        let invalid = Shell::new(faces);
+
+        let error = NonManifoldEdge::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert_eq!(error.half_edges.len(), 3);
+    }
+}
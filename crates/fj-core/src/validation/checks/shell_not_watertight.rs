@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+
+use itertools::Itertools;
+
+use crate::{
+    geometry::Geometry,
+    queries::BoundingVerticesOfHalfEdge,
+    storage::Handle,
+    topology::{HalfEdge, Shell},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// A [`Shell`] is not watertight
+///
+/// A shell is watertight, if each of its edges is referenced by two of its
+/// half-edges. An edge is identified by its curve and its bounding vertices,
+/// regardless of their order.
+///
+/// This check counts the half-edges that reference each edge. Any edge that is
+/// only referenced by a single half-edge is an open boundary of the shell.
+/// Those half-edges are reported together, as a single error.
+///
+/// Note that an edge that is referenced twice by the half-edges of the same
+/// face (like the seam of a cylinder) counts as closed.
+///
+/// This overlaps with [`HalfEdgeHasNoSibling`], which reports each open
+/// half-edge as a separate error. This check provides an overview of all of
+/// the shell's open boundaries instead.
+///
+/// [`HalfEdgeHasNoSibling`]: super::HalfEdgeHasNoSibling
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "`Shell` is not watertight\n\
+    - Number of open half-edges: {}\n\
+    - Open half-edges: {open_half_edges:#?}",
+    open_half_edges.len()
+)]
+pub struct ShellNotWatertight {
+    /// The half-edges that reference an edge no other half-edge references
+    pub open_half_edges: Vec<Handle<HalfEdge>>,
+}
+
+impl ValidationCheck<Shell> for ShellNotWatertight {
+    fn check<'r>(
+        object: &'r Shell,
+        _: &'r Geometry,
+        _: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        let mut half_edges_by_edge = BTreeMap::new();
+
+        for cycle in object.all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let vertices = cycle
+                    .bounding_vertices_of_half_edge(half_edge)
+                    .expect("`half_edge` came from `cycle`, must exist there");
+                let edge = (half_edge.curve().clone(), vertices.normalize());
+
+                half_edges_by_edge
+                    .entry(edge)
+                    .or_insert_with(Vec::new)
+                    .push(half_edge.clone());
+            }
+        }
+
+        let open_half_edges = half_edges_by_edge
+            .into_values()
+            .filter_map(|half_edges| half_edges.into_iter().exactly_one().ok())
+            .collect::<Vec<_>>();
+
+        (!open_half_edges.is_empty())
+            .then_some(ShellNotWatertight { open_half_edges })
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{
+            build::BuildSketch, sweep::SweepSketch, update::UpdateShell,
+        },
+        topology::Sketch,
+        validation::{ValidationCheck, checks::ShellNotWatertight},
+    };
+
+    #[test]
+    fn shell_not_watertight() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let cube = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., 1.], &mut core);
+
+        let valid = cube.shells().only();
+        ShellNotWatertight::check_and_return_first_error(
+            valid,
+            &core.layers.geometry,
+        )?;
+
+        let face = valid.faces().first();
+        let invalid = valid.remove_face(face);
+        let error = ShellNotWatertight::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+        assert_eq!(error.open_half_edges.len(), 4);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,214 @@
+use fj_math::{Point, Scalar};
+
+use crate::{
+    geometry::Geometry,
+    storage::Handle,
+    topology::{Curve, Face, Surface, Vertex},
+    validation::{ValidationCheck, ValidationConfig},
+};
+
+/// # Vertex of a [`Face`]'s half-edge does not lie on the half-edge's curve
+///
+/// A vertex has a position on each curve it is located on, and each of those
+/// definitions is independent of the others. This check makes sure that they
+/// agree. For each half-edge of a face, the half-edge's curve is evaluated at
+/// the positions of its start and end vertices. The result is compared against
+/// the positions of the same vertex on all other curves it is defined on, on
+/// all surfaces that those curves are defined on. Positions on other surfaces
+/// can only be compared, if both surfaces are defined in 3D space.
+///
+/// Within a single cycle, this overlaps with [`AdjacentHalfEdgesNotConnected`].
+/// But it also covers definitions that don't belong to the same face, like the
+/// position of a vertex on the curve of a sibling half-edge.
+///
+/// The definitions of the vertex disagree, but there's no way to tell which of
+/// them is wrong. If only one definition is off, this check reports an error
+/// for every half-edge whose curve is involved.
+///
+/// [`AdjacentHalfEdgesNotConnected`]: super::AdjacentHalfEdgesNotConnected
+#[derive(Clone, Debug, thiserror::Error)]
+#[error(
+    "Vertex of `HalfEdge` does not lie on the `HalfEdge`'s curve\n\
+    - Distance to position on other curve: {distance}\n\
+    - The vertex: {vertex:#?}\n\
+    - The curve of the half-edge: {curve:#?}\n\
+    - The other curve: {other_curve:#?}"
+)]
+pub struct VertexNotOnCurve {
+    /// The vertex whose definitions disagree
+    pub vertex: Handle<Vertex>,
+
+    /// The curve of the half-edge that the vertex bounds
+    pub curve: Handle<Curve>,
+
+    /// The curve with the most distant definition of the vertex
+    pub other_curve: Handle<Curve>,
+
+    /// The distance between the positions
+    ///
+    /// If both positions are defined on the surface of the face, this is the
+    /// distance in surface coordinates. Otherwise, it's the distance in 3D
+    /// space.
+    pub distance: Scalar,
+}
+
+impl ValidationCheck<Face> for VertexNotOnCurve {
+    fn check<'r>(
+        object: &'r Face,
+        geometry: &'r Geometry,
+        config: &'r ValidationConfig,
+    ) -> impl Iterator<Item = Self> + 'r {
+        object
+            .region()
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges().pairs())
+            .flat_map(|(half_edge, next)| {
+                [half_edge.start_vertex(), next.start_vertex()]
+                    .map(|vertex| (vertex.clone(), half_edge.curve().clone()))
+            })
+            .filter_map(move |(vertex, curve)| {
+                let vertex = &vertex;
+                let surface = object.surface();
+                let position =
+                    position_on_surface(vertex, &curve, surface, geometry)?;
+
+                let (other_curve, distance) = geometry
+                    .of_vertex(vertex)?
+                    .definitions
+                    .keys()
+                    .filter(|other_curve| **other_curve != curve)
+                    .flat_map(|other_curve| {
+                        geometry
+                            .of_curve(other_curve)
+                            .into_iter()
+                            .flat_map(|curve_geom| {
+                                curve_geom.definitions.keys()
+                            })
+                            .filter_map(move |other_surface| {
+                                let other_position = position_on_surface(
+                                    vertex,
+                                    other_curve,
+                                    other_surface,
+                                    geometry,
+                                )?;
+
+                                let distance = if other_surface == surface {
+                                    position.distance_to(&other_position)
+                                } else {
+                                    let [a, b] = [
+                                        (surface, position),
+                                        (other_surface, other_position),
+                                    ]
+                                    .map(|(surface, position)| {
+                                        position_global(
+                                            surface, position, geometry,
+                                        )
+                                    });
+                                    a?.distance_to(&b?)
+                                };
+
+                                Some((other_curve.clone(), distance))
+                            })
+                    })
+                    .max_by_key(|(_, distance)| *distance)?;
+
+                if distance > config.identical_max_distance {
+                    return Some(VertexNotOnCurve {
+                        vertex: vertex.clone(),
+                        curve,
+                        other_curve,
+                        distance,
+                    });
+                }
+
+                None
+            })
+    }
+}
+
+fn position_on_surface(
+    vertex: &Handle<Vertex>,
+    curve: &Handle<Curve>,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+) -> Option<Point<2>> {
+    let position_curve = geometry.of_vertex(vertex)?.local_on(curve)?.position;
+    let path = geometry.of_curve(curve)?.local_on(surface)?.path;
+    Some(path.point_from_path_coords(position_curve))
+}
+
+fn position_global(
+    surface: &Handle<Surface>,
+    position: Point<2>,
+    geometry: &Geometry,
+) -> Option<Point<3>> {
+    // Not all surfaces have a definition in 3D space. Definitions on those can
+    // only be compared to other definitions on the same surface.
+    let surface = geometry.of_surface_if_defined(surface)?;
+    Some(
+        surface.u.point_from_path_coords([position.u]) + surface.v * position.v,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        Core,
+        geometry::LocalVertexGeom,
+        operations::build::BuildFace,
+        topology::Face,
+        validation::{
+            ValidationCheck, ValidationConfig, checks::VertexNotOnCurve,
+        },
+    };
+
+    #[test]
+    fn vertex_not_on_curve() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let face =
+            Face::polygon(surface, [[0., 0.], [1., 0.], [1., 1.]], &mut core);
+        VertexNotOnCurve::check_and_return_first_error(
+            &face,
+            &core.layers.geometry,
+        )?;
+
+        // Corrupt the position of the vertex between the first and the second
+        // half-edge, on the curve of the first one.
+        let [first, second] = [0, 1].map(|i| {
+            face.region()
+                .exterior()
+                .half_edges()
+                .nth(i)
+                .unwrap()
+                .clone()
+        });
+        core.layers.geometry.define_vertex(
+            second.start_vertex().clone(),
+            first.curve().clone(),
+            LocalVertexGeom {
+                position: Point::from([1.5]),
+            },
+        );
+
+        let config = ValidationConfig::default();
+        let errors =
+            VertexNotOnCurve::check(&face, &core.layers.geometry, &config)
+                .collect::<Vec<_>>();
+
+        // Both definitions of the vertex disagree with each other, so the
+        // check reports both curves.
+        assert_eq!(errors.len(), 2);
+        for err in &errors {
+            assert_eq!(err.vertex, *second.start_vertex());
+            assert!((err.distance - 0.5).abs() < Scalar::from(1e-12));
+        }
+        assert!(errors.iter().any(|err| err.curve == *first.curve()));
+        assert!(errors.iter().any(|err| err.curve == *second.curve()));
+
+        Ok(())
+    }
+}
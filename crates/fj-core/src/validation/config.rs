@@ -1,8 +1,12 @@
-use fj_interop::Tolerance;
-use fj_math::Scalar;
+use std::collections::BTreeMap;
+
+use fj_interop::{InvalidTolerance, Tolerance};
+use fj_math::{Aabb, Scalar};
+
+use crate::algorithms::approx::ApproxCache;
 
 /// Configuration required for the validation process
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ValidationConfig {
     /// Panic on first validation error, instead of storing it
     ///
@@ -36,6 +40,50 @@ pub struct ValidationConfig {
     /// Objects whose distance is less than the value defined in this field, are
     /// considered identical.
     pub distinct_min_distance: Scalar,
+
+    /// Overrides of `identical_max_distance` for specific validation checks
+    ///
+    /// Different validation checks can have different requirements regarding
+    /// their tolerance. The keys of this map identify validation checks, using
+    /// the same names that [`ValidationError::check_name`] returns. Checks that
+    /// have no entry here, use the global `identical_max_distance`.
+    ///
+    /// Not all checks support overrides. See [`identical_max_distance_for`]
+    /// for details.
+    ///
+    /// [`ValidationError::check_name`]: super::ValidationError::check_name
+    /// [`identical_max_distance_for`]: Self::identical_max_distance_for
+    pub identical_max_distance_overrides: BTreeMap<&'static str, Scalar>,
+
+    /// Treat all validation errors with [`Severity::Warning`] as errors
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Severity::Warning`]: super::Severity::Warning
+    pub warnings_as_errors: bool,
+
+    /// Require all cycles of faces to be convex
+    ///
+    /// Enables the [`NonConvexCycle`] check, for models that are used with
+    /// algorithms that only support convex faces.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`NonConvexCycle`]: super::checks::NonConvexCycle
+    pub require_convex_cycles: bool,
+
+    /// Cache for the approximations that validation checks generate
+    ///
+    /// If this is set, validation checks take the polylines they need from
+    /// the cache, and add any they generate. Clones of the configuration share
+    /// the cache, which makes it possible to reuse approximations across checks
+    /// and validation runs at the same tolerance, as well as with
+    /// triangulation (see [`triangulate_with_cache`]).
+    ///
+    /// This is `None` by default.
+    ///
+    /// [`triangulate_with_cache`]: crate::algorithms::triangulate::triangulate_with_cache
+    pub approx_cache: Option<ApproxCache>,
 }
 
 impl ValidationConfig {
@@ -59,8 +107,59 @@ impl ValidationConfig {
             tolerance,
             identical_max_distance,
             distinct_min_distance,
+            identical_max_distance_overrides: BTreeMap::new(),
+            warnings_as_errors: false,
+            require_convex_cycles: false,
+            approx_cache: None,
         }
     }
+
+    /// Compute validation config from a tolerance relative to a model's size
+    ///
+    /// A single absolute tolerance doesn't fit models of any size. Depending on
+    /// the scale of a model, it can be too large, making small features
+    /// degenerate, or too small to absorb numerical noise.
+    ///
+    /// This resolves the relative tolerance against the diagonal of the
+    /// provided bounding box (see [`Tolerance::relative_to`]), then works like
+    /// [`ValidationConfig::from_tolerance`]. All values of the configuration,
+    /// which the validation checks use, are absolute.
+    ///
+    /// Returns an error, if the bounding box is empty.
+    pub fn from_relative_tolerance(
+        tolerance: impl Into<Tolerance>,
+        aabb: &Aabb<3>,
+    ) -> Result<Self, InvalidTolerance> {
+        let extent = aabb.size().magnitude();
+        let tolerance = tolerance.into().relative_to(extent)?;
+
+        Ok(Self::from_tolerance(tolerance))
+    }
+
+    /// Override `identical_max_distance` for a specific validation check
+    pub fn with_identical_max_distance_for(
+        mut self,
+        check_name: &'static str,
+        identical_max_distance: impl Into<Scalar>,
+    ) -> Self {
+        self.identical_max_distance_overrides
+            .insert(check_name, identical_max_distance.into());
+        self
+    }
+
+    /// Access `identical_max_distance` for a specific validation check
+    ///
+    /// Returns the override for the check, if one is defined. Falls back to the
+    /// global value otherwise.
+    ///
+    /// As of this writing, only `AdjacentHalfEdgesNotConnected` reads its
+    /// tolerance through this method.
+    pub fn identical_max_distance_for(&self, check_name: &str) -> Scalar {
+        self.identical_max_distance_overrides
+            .get(check_name)
+            .copied()
+            .unwrap_or(self.identical_max_distance)
+    }
 }
 
 impl Default for ValidationConfig {
@@ -68,3 +167,49 @@ impl Default for ValidationConfig {
         Self::from_tolerance(0.001)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point};
+
+    use crate::{
+        Core,
+        operations::{build::BuildSketch, sweep::SweepSketch},
+        topology::Sketch,
+    };
+
+    use super::ValidationConfig;
+
+    #[test]
+    fn relative_tolerance_fits_any_scale() -> anyhow::Result<()> {
+        for size in [0.001, 1000.] {
+            let aabb = Aabb {
+                min: Point::origin(),
+                max: Point::from([size, size, size]),
+            };
+            let config =
+                ValidationConfig::from_relative_tolerance(1e-6, &aabb)?;
+
+            let mut core = Core::with_validation_config(config);
+            cube(size, &mut core);
+
+            core.layers.validation.take_errors()?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn absolute_tolerance_does_not_fit_small_scale() {
+        let mut core = Core::new();
+        cube(0.001, &mut core);
+
+        assert!(core.layers.validation.take_errors().is_err());
+    }
+
+    fn cube(size: f64, core: &mut Core) {
+        let surface = core.layers.topology.surfaces.xy_plane();
+        Sketch::polygon([[0., 0.], [size, 0.], [size, size], [0., size]], core)
+            .sweep_sketch(surface, [0., 0., size], core);
+    }
+}
@@ -2,13 +2,19 @@ use std::{convert::Infallible, fmt};
 
 use crate::{
     topology::{Cycle, Face, HalfEdge, Region, Shell},
-    validate::{SketchValidationError, SolidValidationError},
+    validate::SolidValidationError,
 };
 
-use super::checks::{
-    AdjacentHalfEdgesNotConnected, CoincidentHalfEdgesAreNotSiblings,
-    FaceHasNoBoundary, HalfEdgeHasNoSibling, InteriorCycleHasInvalidWinding,
-    MultipleReferencesToObject,
+use super::{
+    Severity, ValidationConfig,
+    checks::{
+        AdjacentHalfEdgesNotConnected, CoincidentFaces,
+        CoincidentHalfEdgesAreNotSiblings, CycleSelfIntersection,
+        CycleWindingOrder, DegenerateHalfEdge, EmptyRegion, FaceHasNoBoundary,
+        HalfEdgeHasNoSibling, InteriorCycleHasInvalidWinding,
+        InteriorCycleOutsideExterior, MultipleReferencesToObject,
+        NonConvexCycle, NonManifoldEdge, ShellNotWatertight, VertexNotOnCurve,
+    },
 };
 
 /// An error that can occur during a validation
@@ -18,12 +24,32 @@ pub enum ValidationError {
     #[error(transparent)]
     AdjacentHalfEdgesNotConnected(#[from] AdjacentHalfEdgesNotConnected),
 
+    /// Faces of shell overlap
+    #[error(transparent)]
+    CoincidentFaces(#[from] CoincidentFaces),
+
     /// Coincident half-edges are not siblings
     #[error(transparent)]
     CoincidentHalfEdgesAreNotSiblings(
         #[from] CoincidentHalfEdgesAreNotSiblings,
     ),
 
+    /// Non-adjacent half-edges in cycle intersect
+    #[error(transparent)]
+    CycleSelfIntersection(#[from] CycleSelfIntersection),
+
+    /// Cycle has wrong winding order for its role
+    #[error(transparent)]
+    CycleWindingOrder(#[from] CycleWindingOrder),
+
+    /// Half-edge is degenerate
+    #[error(transparent)]
+    DegenerateHalfEdge(#[from] DegenerateHalfEdge),
+
+    /// Region is empty
+    #[error(transparent)]
+    EmptyRegion(#[from] EmptyRegion),
+
     /// Face has no boundary
     #[error(transparent)]
     FaceHasNoBoundary(#[from] FaceHasNoBoundary),
@@ -36,6 +62,10 @@ pub enum ValidationError {
     #[error(transparent)]
     InteriorCycleHasInvalidWinding(#[from] InteriorCycleHasInvalidWinding),
 
+    /// Interior cycle is not contained within exterior
+    #[error(transparent)]
+    InteriorCycleOutsideExterior(#[from] InteriorCycleOutsideExterior),
+
     /// Multiple references to [`Cycle`]
     #[error(transparent)]
     MultipleReferencesToCycle(
@@ -58,13 +88,101 @@ pub enum ValidationError {
         #[from] MultipleReferencesToObject<Region, Face>,
     ),
 
+    /// Cycle of face is not convex
+    #[error(transparent)]
+    NonConvexCycle(#[from] NonConvexCycle),
+
+    /// Edge is shared by more than two faces
+    #[error(transparent)]
+    NonManifoldEdge(#[from] NonManifoldEdge),
+
+    /// Shell is not watertight
+    #[error(transparent)]
+    ShellNotWatertight(#[from] ShellNotWatertight),
+
+    /// Vertex does not lie on the curve of its half-edge
+    #[error(transparent)]
+    VertexNotOnCurve(#[from] VertexNotOnCurve),
+
     /// `Solid` validation error
     #[error("`Solid` validation error")]
     Solid(#[from] SolidValidationError),
+}
 
-    /// `Sketch` validation error
-    #[error("`Sketch` validation error")]
-    Sketch(#[from] SketchValidationError),
+impl ValidationError {
+    /// # Return the severity of this error
+    ///
+    /// Most validation errors are always of [`Severity::Error`]. Some can be of
+    /// [`Severity::Warning`], if the defect they represent is small enough to
+    /// likely be the result of numerical inaccuracy:
+    ///
+    /// - [`AdjacentHalfEdgesNotConnected`], if the gap between the half-edges
+    ///   is smaller than [`ValidationConfig::distinct_min_distance`].
+    /// - [`VertexNotOnCurve`], if the distance between the positions of the
+    ///   vertex is smaller than [`ValidationConfig::distinct_min_distance`].
+    ///
+    /// [`NonConvexCycle`] is always of [`Severity::Warning`], as it doesn't
+    /// represent a defect, just a limitation of some algorithms.
+    ///
+    /// If [`ValidationConfig::warnings_as_errors`] is set, this always returns
+    /// [`Severity::Error`].
+    pub fn severity(&self, config: &ValidationConfig) -> Severity {
+        if config.warnings_as_errors {
+            return Severity::Error;
+        }
+
+        match self {
+            Self::AdjacentHalfEdgesNotConnected(err)
+                if err.distance_between_positions
+                    < config.distinct_min_distance =>
+            {
+                Severity::Warning
+            }
+            Self::VertexNotOnCurve(err)
+                if err.distance < config.distinct_min_distance =>
+            {
+                Severity::Warning
+            }
+            Self::NonConvexCycle(_) => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// # Return the name of the validation check that produced this error
+    pub fn check_name(&self) -> &'static str {
+        match self {
+            Self::AdjacentHalfEdgesNotConnected(_) => {
+                "AdjacentHalfEdgesNotConnected"
+            }
+            Self::CoincidentFaces(_) => "CoincidentFaces",
+            Self::CoincidentHalfEdgesAreNotSiblings(_) => {
+                "CoincidentHalfEdgesAreNotSiblings"
+            }
+            Self::CycleSelfIntersection(_) => "CycleSelfIntersection",
+            Self::CycleWindingOrder(_) => "CycleWindingOrder",
+            Self::DegenerateHalfEdge(_) => "DegenerateHalfEdge",
+            Self::EmptyRegion(_) => "EmptyRegion",
+            Self::FaceHasNoBoundary(_) => "FaceHasNoBoundary",
+            Self::HalfEdgeHasNoSibling(_) => "HalfEdgeHasNoSibling",
+            Self::InteriorCycleHasInvalidWinding(_) => {
+                "InteriorCycleHasInvalidWinding"
+            }
+            Self::InteriorCycleOutsideExterior(_) => {
+                "InteriorCycleOutsideExterior"
+            }
+            Self::MultipleReferencesToCycle(_) => "MultipleReferencesToCycle",
+            Self::MultipleReferencesToFace(_) => "MultipleReferencesToFace",
+            Self::MultipleReferencesToHalfEdge(_) => {
+                "MultipleReferencesToHalfEdge"
+            }
+            Self::MultipleReferencesToRegion(_) => "MultipleReferencesToRegion",
+            Self::NonConvexCycle(_) => "NonConvexCycle",
+            Self::NonManifoldEdge(_) => "NonManifoldEdge",
+            Self::ShellNotWatertight(_) => "ShellNotWatertight",
+            Self::VertexNotOnCurve(_) => "VertexNotOnCurve",
+            Self::Solid(_) => "Solid",
+        }
+    }
 }
 
 impl From<Infallible> for ValidationError {
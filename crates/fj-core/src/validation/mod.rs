@@ -20,16 +20,27 @@
 //! - <https://github.com/hannobraun/fornjot/issues/1713>
 //! - <https://github.com/hannobraun/fornjot/issues/2157>
 
+mod cache;
 mod config;
 mod error;
+#[cfg(feature = "parallel")]
+mod parallel;
+mod report;
+mod severity;
 mod validation;
 mod validation_check;
 
 pub mod checks;
 
 pub use self::{
+    cache::{ValidationCache, validate_incremental},
     config::ValidationConfig,
     error::{ValidationError, ValidationErrors},
+    report::{ValidationReport, validate_all},
+    severity::Severity,
     validation::Validation,
     validation_check::ValidationCheck,
 };
+
+#[cfg(feature = "parallel")]
+pub use self::parallel::validate_all_parallel;
@@ -0,0 +1,108 @@
+use rayon::prelude::*;
+
+use crate::{geometry::Geometry, storage::Handle, validate::Validate};
+
+use super::{ValidationConfig, ValidationReport};
+
+/// # Run all validation checks on multiple objects, using multiple threads
+///
+/// Produces the same report as validating each object using [`validate_all`],
+/// and combining the results, but distributes the objects over a thread pool.
+/// This is most useful for validating the faces of large shapes.
+///
+/// The order of the errors in the returned report does not depend on the order
+/// in which the threads finish. Errors are ordered by validation check, then
+/// by the position of the object that produced them in `objects`.
+///
+/// [`validate_all`]: super::validate_all
+pub fn validate_all_parallel<'r, T>(
+    objects: impl IntoIterator<Item = &'r Handle<T>>,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+) -> ValidationReport
+where
+    T: Validate + Sync + 'r,
+{
+    let objects = objects.into_iter().collect::<Vec<_>>();
+
+    let mut errors = objects
+        .par_iter()
+        .enumerate()
+        .flat_map_iter(|(index, object)| {
+            let mut errors = Vec::new();
+            object.validate(config, &mut errors, geometry);
+
+            errors.into_iter().map(move |err| (index, err))
+        })
+        .collect::<Vec<_>>();
+
+    // The sort is stable, so errors from the same object remain in the order
+    // that its validation checks produced them in.
+    errors.sort_by_key(|(index, err)| (err.check_name(), *index));
+
+    ValidationReport::from_errors(
+        errors.into_iter().map(|(_, err)| err),
+        config,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Core,
+        operations::{build::BuildFace, insert::Insert},
+        topology::Face,
+        validation::{ValidationConfig, ValidationReport, validate_all},
+    };
+
+    use super::validate_all_parallel;
+
+    #[test]
+    fn parallel_report_matches_serial_report() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let faces = (0..32)
+            .map(|i| {
+                let x = f64::from(i) * 2.;
+
+                // Make every third face self-intersecting, to get errors from
+                // multiple faces into the report.
+                let points = if i % 3 == 0 {
+                    [[x, 0.], [x + 1., 1.], [x + 1., 0.], [x, 1.]]
+                } else {
+                    [[x, 0.], [x + 1., 0.], [x + 1., 1.], [x, 1.]]
+                };
+
+                Face::polygon(surface.clone(), points, &mut core)
+                    .insert(&mut core)
+            })
+            .collect::<Vec<_>>();
+
+        // The invalid faces have been validated on insertion. We're not
+        // interested in that result here.
+        let _ = core.layers.validation.take_errors();
+
+        let config = ValidationConfig::default();
+
+        let serial = faces
+            .iter()
+            .flat_map(|face| {
+                let report =
+                    validate_all(&**face, &core.layers.geometry, &config);
+                report
+                    .iter()
+                    .flat_map(|(_, errors)| errors.to_vec())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let serial = ValidationReport::from_errors(serial, &config);
+
+        let parallel =
+            validate_all_parallel(&faces, &core.layers.geometry, &config);
+
+        assert!(!parallel.is_empty());
+        assert_eq!(parallel.len(), serial.len());
+        assert_eq!(parallel.to_string(), serial.to_string());
+    }
+}
@@ -0,0 +1,250 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::{geometry::Geometry, validate::Validate};
+
+use super::{Severity, ValidationConfig, ValidationError};
+
+/// # Run all validation checks on an object and collect their errors
+///
+/// Unlike [`Validate::validate_and_return_first_error`], this doesn't stop at
+/// the first error. All validation checks that apply to the object are run, and
+/// all errors they produce are returned, grouped by validation check.
+///
+/// The returned report also provides access to the [`Severity`] of each error,
+/// as determined by the provided configuration.
+pub fn validate_all(
+    object: &impl Validate,
+    geometry: &Geometry,
+    config: &ValidationConfig,
+) -> ValidationReport {
+    let mut errors = Vec::new();
+    object.validate(config, &mut errors, geometry);
+
+    ValidationReport::from_errors(errors, config)
+}
+
+/// # The result of running all validation checks on an object
+///
+/// Returned by [`validate_all`].
+#[derive(Clone, Debug)]
+pub struct ValidationReport {
+    errors: BTreeMap<&'static str, Vec<ValidationError>>,
+    config: ValidationConfig,
+}
+
+impl ValidationReport {
+    pub(super) fn from_errors(
+        errors: impl IntoIterator<Item = ValidationError>,
+        config: &ValidationConfig,
+    ) -> Self {
+        let mut report = Self {
+            errors: BTreeMap::new(),
+            config: config.clone(),
+        };
+        for err in errors {
+            report.errors.entry(err.check_name()).or_default().push(err);
+        }
+
+        report
+    }
+
+    /// # Indicate whether the report contains no errors
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// # Indicate whether the report contains errors of [`Severity::Error`]
+    ///
+    /// Callers that can tolerate warnings can use this instead of
+    /// [`ValidationReport::is_empty`], to decide whether to proceed.
+    pub fn has_errors(&self) -> bool {
+        self.errors
+            .values()
+            .flatten()
+            .any(|err| self.severity_of(err) == Severity::Error)
+    }
+
+    /// # Return the severity of an error, as configured for this report
+    pub fn severity_of(&self, err: &ValidationError) -> Severity {
+        err.severity(&self.config)
+    }
+
+    /// # Return a report that only contains errors of the provided severity
+    pub fn filter_by_severity(&self, severity: Severity) -> Self {
+        let errors = self
+            .errors
+            .iter()
+            .filter_map(|(check_name, errors)| {
+                let errors = errors
+                    .iter()
+                    .filter(|err| self.severity_of(err) == severity)
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                (!errors.is_empty()).then_some((*check_name, errors))
+            })
+            .collect();
+
+        Self {
+            errors,
+            config: self.config.clone(),
+        }
+    }
+
+    /// # Return the total number of errors in the report
+    pub fn len(&self) -> usize {
+        self.errors.values().map(Vec::len).sum()
+    }
+
+    /// # Access the errors produced by a specific validation check
+    ///
+    /// Validation checks are identified by the name of their variant in
+    /// [`ValidationError`], as returned by [`ValidationError::check_name`].
+    pub fn errors_of(&self, check_name: &str) -> &[ValidationError] {
+        self.errors
+            .get(check_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// # Iterate over the errors in the report, grouped by validation check
+    pub fn iter(
+        &self,
+    ) -> impl Iterator<Item = (&'static str, &[ValidationError])> {
+        self.errors
+            .iter()
+            .map(|(check_name, errors)| (*check_name, errors.as_slice()))
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No validation errors");
+        }
+
+        let num_errors = self.len();
+        let num_checks = self.errors.len();
+        writeln!(
+            f,
+            "{num_errors} validation errors from {num_checks} checks:"
+        )?;
+
+        for (check_name, errors) in self.iter() {
+            writeln!(f, "\n{check_name} ({}):", errors.len())?;
+
+            for err in errors {
+                let severity = match self.severity_of(err) {
+                    Severity::Warning => "warning",
+                    Severity::Error => "error",
+                };
+
+                writeln!(f, "- [{severity}] {err}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::{
+        Core,
+        geometry::LocalVertexGeom,
+        operations::{
+            build::{BuildFace, BuildSketch},
+            sweep::SweepSketch,
+            update::UpdateShell,
+        },
+        topology::{Face, Sketch},
+        validation::{Severity, ValidationConfig, validate_all},
+    };
+
+    #[test]
+    fn validate_all_collects_errors_from_all_checks() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let cube = Sketch::polygon(
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+            &mut core,
+        )
+        .sweep_sketch(surface, [0., 0., 1.], &mut core);
+
+        let config = ValidationConfig::default();
+
+        let valid = cube.shells().only();
+        let report = validate_all(&**valid, &core.layers.geometry, &config);
+        assert!(report.is_empty());
+
+        // Removing a face results in two distinct defects: The half-edges
+        // bounding the hole have no siblings, and the shell is not watertight.
+        let face = valid.faces().first();
+        let invalid = valid.remove_face(face);
+        let report = validate_all(&invalid, &core.layers.geometry, &config);
+
+        assert!(!report.is_empty());
+        assert_eq!(report.errors_of("HalfEdgeHasNoSibling").len(), 4);
+        assert_eq!(report.errors_of("ShellNotWatertight").len(), 1);
+        assert_eq!(report.iter().count(), 2);
+
+        let summary = report.to_string();
+        assert!(summary.contains("HalfEdgeHasNoSibling (4)"));
+        assert!(summary.contains("ShellNotWatertight (1)"));
+    }
+
+    #[test]
+    fn warnings_only_fail_in_strict_mode() {
+        test_gap(0.015, Severity::Warning);
+        test_gap(0.5, Severity::Error);
+
+        fn test_gap(gap: f64, expected: Severity) {
+            let mut core = Core::new();
+
+            let surface = core.layers.topology.surfaces.space_2d();
+            let face = Face::polygon(
+                surface,
+                [[0., 0.], [1., 0.], [1., 1.]],
+                &mut core,
+            );
+
+            // Move the end of the first half-edge away from the start of the
+            // second one, opening a gap between them.
+            let [first, second] = [0, 1].map(|i| {
+                face.region()
+                    .exterior()
+                    .half_edges()
+                    .nth(i)
+                    .unwrap()
+                    .clone()
+            });
+            core.layers.geometry.define_vertex(
+                second.start_vertex().clone(),
+                first.curve().clone(),
+                LocalVertexGeom {
+                    position: Point::from([1. + gap]),
+                },
+            );
+
+            let lenient = ValidationConfig::default();
+            let strict = ValidationConfig {
+                warnings_as_errors: true,
+                ..lenient.clone()
+            };
+
+            let report = validate_all(&face, &core.layers.geometry, &lenient);
+            let errors = report.errors_of("AdjacentHalfEdgesNotConnected");
+            assert_eq!(errors.len(), 1);
+            assert_eq!(report.severity_of(&errors[0]), expected);
+            assert_eq!(report.has_errors(), expected == Severity::Error);
+            assert_eq!(report.filter_by_severity(expected).len(), report.len(),);
+
+            let report = validate_all(&face, &core.layers.geometry, &strict);
+            assert!(report.has_errors());
+            assert!(report.filter_by_severity(Severity::Warning).is_empty());
+        }
+    }
+}
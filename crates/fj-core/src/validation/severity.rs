@@ -0,0 +1,16 @@
+/// # The severity of a validation error
+///
+/// See [`ValidationError::severity`].
+///
+/// [`ValidationError::severity`]: super::ValidationError::severity
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Severity {
+    /// # The object is questionable, but can still be worked with
+    ///
+    /// This is used for defects that are within a loose tolerance, and are
+    /// likely the result of numerical inaccuracy, rather than a modeling error.
+    Warning,
+
+    /// # The object is invalid
+    Error,
+}
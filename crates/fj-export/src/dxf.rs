@@ -0,0 +1,186 @@
+use std::io::Write;
+
+use fj_core::{
+    geometry::{Geometry, Path},
+    topology::Sketch,
+};
+use fj_interop::Tolerance;
+use fj_math::Scalar;
+
+use crate::{
+    Error,
+    sketch::{approx_edge, cycle_edges},
+};
+
+/// # Export the provided sketch to the provided writer in the DXF format
+///
+/// Writes a minimal DXF file that only contains an `ENTITIES` section. Each
+/// half-edge whose curve is a circle is written as an `ARC` entity. All other
+/// half-edges are approximated within the provided tolerance, and written as
+/// `LINE` entities.
+///
+/// Each cycle is placed on its own layer, named `CYCLE_<n>`, where `<n>` is
+/// the index of the cycle within the sketch. Cycles are numbered region by
+/// region, starting with the exterior cycle of each region.
+pub fn export_dxf(
+    sketch: &Sketch,
+    geometry: &Geometry,
+    tolerance: impl Into<Tolerance>,
+    mut write: impl Write,
+) -> Result<(), Error> {
+    let tolerance = tolerance.into();
+
+    writeln!(write, "0\nSECTION\n2\nENTITIES")?;
+
+    let cycles = sketch
+        .regions()
+        .iter()
+        .flat_map(|region| region.all_cycles());
+    for (i, cycle) in cycles.enumerate() {
+        let layer = format!("CYCLE_{i}");
+
+        for (path, boundary) in cycle_edges(cycle, sketch.surface(), geometry) {
+            if let Path::Circle(circle) = path {
+                let [start, end] = boundary.inner;
+
+                // DXF arcs always go counter-clockwise from their start angle
+                // to their end angle. Whether that is the direction of the
+                // half-edge depends on the orientation of the circle, and on
+                // the direction in which the half-edge runs along it.
+                let circle_is_ccw =
+                    circle.a().cross2d(&circle.b()) > Scalar::ZERO;
+                let [start, end] = if circle_is_ccw == (start < end) {
+                    [start, end]
+                } else {
+                    [end, start]
+                };
+
+                let [start_angle, end_angle] = [start, end].map(|point| {
+                    let point = path.point_from_path_coords(point);
+                    let direction = point - circle.center();
+                    direction
+                        .v
+                        .atan2(direction.u)
+                        .into_f64()
+                        .to_degrees()
+                        .rem_euclid(360.)
+                });
+                let end_angle = if (end.t - start.t).abs() >= Scalar::TAU {
+                    start_angle + 360.
+                } else {
+                    end_angle
+                };
+
+                let center = circle.center();
+                writeln!(
+                    write,
+                    "0\nARC\n8\n{layer}\n10\n{}\n20\n{}\n30\n0\n40\n{}\n\
+                    50\n{start_angle}\n51\n{end_angle}",
+                    center.u,
+                    center.v,
+                    circle.radius(),
+                )?;
+
+                continue;
+            }
+
+            let [_, end] = boundary.inner;
+            let mut points = approx_edge(&path, boundary, tolerance);
+            points.push(path.point_from_path_coords(end));
+
+            for line in points.windows(2) {
+                let [a, b] = [line[0], line[1]];
+                writeln!(
+                    write,
+                    "0\nLINE\n8\n{layer}\n10\n{}\n20\n{}\n30\n0\n11\n{}\n\
+                    21\n{}\n31\n0",
+                    a.u, a.v, b.u, b.v,
+                )?;
+            }
+        }
+    }
+
+    writeln!(write, "0\nENDSEC\n0\nEOF")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::{Core, operations::build::BuildSketch, topology::Sketch};
+
+    use super::export_dxf;
+
+    #[test]
+    fn export_dxf_triangle() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let sketch = Sketch::polygon([[0., 0.], [1., 0.], [0., 1.]], &mut core);
+
+        let mut buffer = Vec::new();
+        export_dxf(&sketch, &core.layers.geometry, 0.001, &mut buffer)?;
+        let dxf = String::from_utf8(buffer)?;
+
+        let lines = dxf.lines().collect::<Vec<_>>();
+        let count = |entity| {
+            lines
+                .windows(2)
+                .filter(|pair| pair[0] == "0" && pair[1] == entity)
+                .count()
+        };
+        assert_eq!(count("LINE"), 3);
+        assert_eq!(count("ARC"), 0);
+        assert_eq!(lines.first(), Some(&"0"));
+        assert_eq!(lines.last(), Some(&"EOF"));
+        assert_eq!(dxf.matches("CYCLE_0").count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_dxf_circle_as_arcs() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let sketch = Sketch::circle([1., 2.], 3., &mut core);
+
+        let mut buffer = Vec::new();
+        export_dxf(&sketch, &core.layers.geometry, 0.001, &mut buffer)?;
+        let dxf = String::from_utf8(buffer)?;
+
+        let lines = dxf.lines().collect::<Vec<_>>();
+        assert!(!lines.contains(&"LINE"));
+
+        // The circle consists of four half-edges, each a quarter of it.
+        let arcs = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| **line == "ARC")
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        assert_eq!(arcs.len(), 4);
+
+        let mut start_angles = Vec::new();
+        for arc in arcs {
+            let value = |code| {
+                let i =
+                    lines[arc..].iter().position(|line| *line == code).unwrap();
+                lines[arc + i + 1].parse::<f64>().unwrap()
+            };
+
+            for (actual, expected) in [value("10"), value("20"), value("40")]
+                .into_iter()
+                .zip([1., 2., 3.])
+            {
+                assert!((actual - expected).abs() < 1e-12);
+            }
+
+            let span = (value("51") - value("50")).rem_euclid(360.);
+            assert!((span - 90.).abs() < 1e-9);
+
+            start_angles.push(value("50").round());
+        }
+        assert_eq!(start_angles, [0., 90., 180., 270.]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+use std::io::Write;
+
+use base64::Engine as _;
+
+use fj_interop::Mesh;
+use fj_math::Aabb;
+
+use crate::Error;
+
+/// # Export the provided mesh to the provided writer in the glTF 2.0 format
+///
+/// Writes a single mesh, with one primitive that has a position accessor, a
+/// normal accessor, and indices. If the mesh has no normals, they are computed
+/// using [`Mesh::compute_normals`].
+///
+/// Depending on the provided [`GltfFormat`], the binary data is either
+/// embedded into the JSON document as a base64-encoded data URI, or written
+/// into the binary chunk of a GLB container.
+pub fn export_gltf(
+    mesh: &Mesh,
+    mut write: impl Write,
+    format: GltfFormat,
+) -> Result<(), Error> {
+    let normals = match mesh.normals() {
+        Some(normals) => normals.to_vec(),
+        None => {
+            let mut mesh = mesh.clone();
+            mesh.compute_normals();
+            mesh.normals().unwrap_or_default().to_vec()
+        }
+    };
+
+    let positions = mesh
+        .vertices()
+        .iter()
+        .map(|point| point.coords)
+        .collect::<Vec<_>>();
+
+    // glTF doesn't support 64-bit floats for vertex attributes.
+    let mut buffer = Vec::new();
+    for vector in positions.iter().chain(&normals) {
+        for component in vector.components {
+            buffer.extend((component.into_f64() as f32).to_le_bytes());
+        }
+    }
+    for index in mesh.indices().iter().flatten() {
+        buffer.extend(index.to_le_bytes());
+    }
+
+    let num_vertices = positions.len();
+    let num_indices = mesh.indices().len() * 3;
+    let vectors_length = num_vertices * 3 * size_of::<f32>();
+    let indices_length = num_indices * size_of::<u32>();
+
+    let (min, max) = if positions.is_empty() {
+        ([0.; 3], [0.; 3])
+    } else {
+        let aabb = Aabb::<3>::from_points(mesh.vertices().iter().copied());
+        (
+            aabb.min.coords.components.map(|s| s.into_f64() as f32),
+            aabb.max.coords.components.map(|s| s.into_f64() as f32),
+        )
+    };
+
+    let mut buffer_description = serde_json::json!({
+        "byteLength": buffer.len(),
+    });
+    if format == GltfFormat::Embedded {
+        let data = base64::engine::general_purpose::STANDARD.encode(&buffer);
+        buffer_description["uri"] =
+            format!("data:application/octet-stream;base64,{data}").into();
+    }
+
+    // Constants as defined by the glTF specification.
+    const ARRAY_BUFFER: u32 = 34962;
+    const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+    const FLOAT: u32 = 5126;
+    const UNSIGNED_INT: u32 = 5125;
+    const TRIANGLES: u32 = 4;
+
+    let document = serde_json::json!({
+        "asset": {
+            "version": "2.0",
+            "generator": "Fornjot",
+        },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "mesh": 0 }],
+        "meshes": [{
+            "primitives": [{
+                "attributes": {
+                    "POSITION": 0,
+                    "NORMAL": 1,
+                },
+                "indices": 2,
+                "mode": TRIANGLES,
+            }],
+        }],
+        "buffers": [buffer_description],
+        "bufferViews": [
+            {
+                "buffer": 0,
+                "byteOffset": 0,
+                "byteLength": vectors_length,
+                "target": ARRAY_BUFFER,
+            },
+            {
+                "buffer": 0,
+                "byteOffset": vectors_length,
+                "byteLength": vectors_length,
+                "target": ARRAY_BUFFER,
+            },
+            {
+                "buffer": 0,
+                "byteOffset": vectors_length * 2,
+                "byteLength": indices_length,
+                "target": ELEMENT_ARRAY_BUFFER,
+            },
+        ],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": FLOAT,
+                "count": num_vertices,
+                "type": "VEC3",
+                "min": min,
+                "max": max,
+            },
+            {
+                "bufferView": 1,
+                "componentType": FLOAT,
+                "count": num_vertices,
+                "type": "VEC3",
+            },
+            {
+                "bufferView": 2,
+                "componentType": UNSIGNED_INT,
+                "count": num_indices,
+                "type": "SCALAR",
+            },
+        ],
+    });
+    let json = document.to_string();
+
+    match format {
+        GltfFormat::Embedded => {
+            write.write_all(json.as_bytes())?;
+        }
+        GltfFormat::Binary => {
+            // Both chunks must be aligned to 4 bytes. The JSON chunk is padded
+            // with spaces, the binary chunk with zeros.
+            let mut json = json.into_bytes();
+            json.resize(json.len().next_multiple_of(4), b' ');
+            buffer.resize(buffer.len().next_multiple_of(4), 0);
+
+            let header_length = 12;
+            let chunk_header_length = 8;
+            let total_length = header_length
+                + chunk_header_length
+                + json.len()
+                + chunk_header_length
+                + buffer.len();
+
+            write.write_all(b"glTF")?;
+            write.write_all(&2u32.to_le_bytes())?;
+            write.write_all(&(total_length as u32).to_le_bytes())?;
+
+            for (chunk_type, data) in [(b"JSON", &json), (b"BIN\0", &buffer)] {
+                write.write_all(&(data.len() as u32).to_le_bytes())?;
+                write.write_all(chunk_type)?;
+                write.write_all(data)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// # The format of a glTF file
+///
+/// See [`export_gltf`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GltfFormat {
+    /// # A JSON document, with the binary data embedded as base64
+    ///
+    /// This is the format of `.gltf` files.
+    #[default]
+    Embedded,
+
+    /// # A binary GLB container
+    ///
+    /// This is the format of `.glb` files. It is more compact, as the binary
+    /// data doesn't need to be encoded.
+    Binary,
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine as _;
+
+    use crate::{mesh_from_triangles, tests::cube};
+
+    use super::{GltfFormat, export_gltf};
+
+    #[test]
+    fn export_embedded_gltf() -> anyhow::Result<()> {
+        let mesh = mesh_from_triangles(cube());
+
+        let mut buffer = Vec::new();
+        export_gltf(&mesh, &mut buffer, GltfFormat::Embedded)?;
+        let gltf: serde_json::Value = serde_json::from_slice(&buffer)?;
+
+        let accessors = gltf["accessors"].as_array().unwrap();
+        assert_eq!(accessors[0]["count"], 8);
+        assert_eq!(accessors[1]["count"], 8);
+        assert_eq!(accessors[2]["count"], 12 * 3);
+        assert_eq!(accessors[0]["min"], serde_json::json!([0., 0., 0.]));
+        assert_eq!(accessors[0]["max"], serde_json::json!([1., 1., 1.]));
+
+        let views = gltf["bufferViews"].as_array().unwrap();
+        let vectors_length = 8 * 3 * 4;
+        let indices_length = 12 * 3 * 4;
+        assert_eq!(views[0]["byteLength"], vectors_length);
+        assert_eq!(views[1]["byteLength"], vectors_length);
+        assert_eq!(views[2]["byteLength"], indices_length);
+
+        let byte_length = vectors_length * 2 + indices_length;
+        assert_eq!(gltf["buffers"][0]["byteLength"], byte_length);
+
+        let uri = gltf["buffers"][0]["uri"].as_str().unwrap();
+        let data = uri
+            .strip_prefix("data:application/octet-stream;base64,")
+            .unwrap();
+        let data = base64::engine::general_purpose::STANDARD.decode(data)?;
+        assert_eq!(data.len(), byte_length);
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_binary_gltf() -> anyhow::Result<()> {
+        let mesh = mesh_from_triangles(cube());
+
+        let mut buffer = Vec::new();
+        export_gltf(&mesh, &mut buffer, GltfFormat::Binary)?;
+
+        let u32_at = |offset: usize| {
+            u32::from_le_bytes(buffer[offset..offset + 4].try_into().unwrap())
+                as usize
+        };
+
+        assert_eq!(&buffer[0..4], b"glTF");
+        assert_eq!(u32_at(4), 2);
+        assert_eq!(u32_at(8), buffer.len());
+
+        let json_length = u32_at(12);
+        assert_eq!(&buffer[16..20], b"JSON");
+        let gltf: serde_json::Value =
+            serde_json::from_slice(&buffer[20..20 + json_length])?;
+        assert!(gltf["buffers"][0].get("uri").is_none());
+
+        let bin = 20 + json_length;
+        assert_eq!(&buffer[bin + 4..bin + 8], b"BIN\0");
+        assert_eq!(u32_at(bin), 8 * 3 * 4 * 2 + 12 * 3 * 4);
+
+        Ok(())
+    }
+}
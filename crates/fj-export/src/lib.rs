@@ -4,19 +4,37 @@
 //! split into multiple libraries that can be used semi-independently, and this
 //! is one of those.
 //!
-//! This library exports Fornjot models to external file formats.
+//! This library exports Fornjot models to external file formats. Meshes can be
+//! exported to 3MF, STL, OBJ, and glTF. Sketches can be exported to SVG and
+//! DXF. Each format is implemented in its own module.
+//!
+//! Importing meshes from STL is not part of this library. See
+//! [`fj_interop::import_stl`].
 //!
 //! [Fornjot]: https://www.fornjot.app/
 
-use std::{
-    fs::File,
-    io::{Seek, Write},
-    path::Path,
+mod dxf;
+mod gltf;
+mod obj;
+mod sketch;
+mod stl;
+mod svg;
+mod threemf;
+
+pub use self::{
+    dxf::export_dxf,
+    gltf::{GltfFormat, export_gltf},
+    obj::{ObjOptions, export_obj},
+    stl::{StlFormat, export_stl},
+    svg::{SvgOptions, export_svg},
+    threemf::export_3mf,
 };
 
+use std::{fs::File, path::Path};
+
 use thiserror::Error;
 
-use fj_interop::vertices_to_indexed_vertices;
+use fj_interop::Mesh;
 use fj_math::Triangle;
 
 /// # Export the provided mesh to the file at the given path
@@ -24,24 +42,35 @@ use fj_math::Triangle;
 /// This function will create a file if it does not exist, and will truncate it
 /// if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file
-/// extension of the provided path is used to switch between supported types.
+/// Currently 3MF, STL, OBJ, and glTF (`.gltf` and `.glb`) file types are
+/// supported. The case insensitive file extension of the provided path is used
+/// to switch between supported types.
 pub fn export(
     triangles: impl IntoIterator<Item = Triangle<3>>,
     path: impl AsRef<Path>,
 ) -> Result<(), Error> {
+    let mesh = mesh_from_triangles(triangles);
+
     match path.as_ref().extension() {
         Some(extension) if extension.eq_ignore_ascii_case("3MF") => {
             let mut file = File::create(path)?;
-            export_3mf(triangles, &mut file)
+            export_3mf(&mesh, None, &mut file)
         }
         Some(extension) if extension.eq_ignore_ascii_case("STL") => {
             let mut file = File::create(path)?;
-            export_stl(triangles, &mut file)
+            export_stl(&mesh, &mut file, StlFormat::Binary)
         }
         Some(extension) if extension.eq_ignore_ascii_case("OBJ") => {
             let mut file = File::create(path)?;
-            export_obj(triangles, &mut file)
+            export_obj(&mesh, &mut file, ObjOptions::default())
+        }
+        Some(extension) if extension.eq_ignore_ascii_case("GLTF") => {
+            let mut file = File::create(path)?;
+            export_gltf(&mesh, &mut file, GltfFormat::Embedded)
+        }
+        Some(extension) if extension.eq_ignore_ascii_case("GLB") => {
+            let mut file = File::create(path)?;
+            export_gltf(&mesh, &mut file, GltfFormat::Binary)
         }
         Some(extension) => Err(Error::InvalidExtension(
             extension.to_string_lossy().into_owned(),
@@ -50,137 +79,20 @@ pub fn export(
     }
 }
 
-/// # Export the provided mesh to the provided writer in the 3MF format
-pub fn export_3mf(
-    triangles: impl IntoIterator<Item = Triangle<3>>,
-    write: impl Write + Seek,
-) -> Result<(), Error> {
-    let (vertices, indices) = vertices_to_indexed_vertices(
-        triangles.into_iter().flat_map(|triangle| triangle.points),
-        |point| threemf::model::Vertex {
-            x: point.x.into_f64(),
-            y: point.y.into_f64(),
-            z: point.z.into_f64(),
-        },
-    );
-
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| threemf::model::Triangle {
-            v1: triangle[0] as usize,
-            v2: triangle[1] as usize,
-            v3: triangle[2] as usize,
-        })
-        .collect();
-
-    let mesh = threemf::Mesh {
-        vertices: threemf::model::Vertices { vertex: vertices },
-        triangles: threemf::model::Triangles {
-            triangle: triangles,
-        },
-    };
-
-    threemf::write(write, mesh)?;
-
-    Ok(())
-}
-
-/// # Export the provided mesh to the provided writer in the STL format
-pub fn export_stl(
-    triangles: impl IntoIterator<Item = Triangle<3>>,
-    mut write: impl Write,
-) -> Result<(), Error> {
-    let points = triangles
-        .into_iter()
-        .map(|triangle| triangle.points)
-        .collect::<Vec<_>>();
-
-    let vertices = points.iter().map(|points| {
-        points.map(|point| point.coords.components.map(|s| s.into_f32()))
-    });
-
-    let normals = points
-        .iter()
-        .map(|&points| points.into())
-        .map(|triangle: Triangle<3>| triangle.normal())
-        .map(|vector| vector.components.map(|s| s.into_f32()));
-
-    let triangles = vertices
-        .zip(normals)
-        .map(|([v1, v2, v3], normal)| stl::Triangle {
-            normal,
-            v1,
-            v2,
-            v3,
-            attr_byte_count: 0,
-        })
-        .collect::<Vec<_>>();
-
-    let binary_stl_file = stl::BinaryStlFile {
-        header: stl::BinaryStlHeader {
-            header: [0u8; 80],
-            num_triangles: triangles
-                .len()
-                .try_into()
-                .map_err(|_| Error::InvalidTriangleCount)?,
-        },
-        triangles,
-    };
-
-    stl::write_stl(&mut write, &binary_stl_file)?;
-
-    Ok(())
-}
-
-/// # Export the provided mesh to the provided writer in the OBJ format
-pub fn export_obj(
+/// # Collect triangles into a [`Mesh`], merging their shared vertices
+///
+/// Vertices are only merged, if they are practically identical. Triangles that
+/// degenerate, because some of their vertices are merged, are skipped.
+fn mesh_from_triangles(
     triangles: impl IntoIterator<Item = Triangle<3>>,
-    mut write: impl Write,
-) -> Result<(), Error> {
-    for (cnt, t) in triangles.into_iter().enumerate() {
-        // write each point of the triangle
-        for v in t.points {
-            wavefront_rs::obj::writer::Writer { auto_newline: true }
-                .write(
-                    &mut write,
-                    &wavefront_rs::obj::entity::Entity::Vertex {
-                        x: v.x.into_f64(),
-                        y: v.y.into_f64(),
-                        z: v.z.into_f64(),
-                        w: None,
-                    },
-                )
-                .or(Err(Error::OBJ))?;
-        }
+) -> Mesh {
+    let mut mesh = Mesh::new(1e-9);
 
-        // write the triangle
-        wavefront_rs::obj::writer::Writer { auto_newline: true }
-            .write(
-                &mut write,
-                &wavefront_rs::obj::entity::Entity::Face {
-                    vertices: vec![
-                        wavefront_rs::obj::entity::FaceVertex {
-                            vertex: (cnt * 3 + 1) as i64,
-                            texture: None,
-                            normal: None,
-                        },
-                        wavefront_rs::obj::entity::FaceVertex {
-                            vertex: (cnt * 3 + 2) as i64,
-                            texture: None,
-                            normal: None,
-                        },
-                        wavefront_rs::obj::entity::FaceVertex {
-                            vertex: (cnt * 3 + 3) as i64,
-                            texture: None,
-                            normal: None,
-                        },
-                    ],
-                },
-            )
-            .or(Err(Error::OBJ))?;
+    for triangle in triangles {
+        mesh.push_triangle(triangle);
     }
 
-    Ok(())
+    mesh
 }
 
 /// An error that can occur while exporting
@@ -202,11 +114,90 @@ pub enum Error {
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// ZIP error whilst exporting to 3MF file
+    #[error("ZIP error whilst exporting to 3MF file")]
+    Zip(#[from] zip::result::ZipError),
 
     /// OBJ exporter error whilst exporting to OBJ file
     #[error("obj error whilst exporting to OBJ file")]
     OBJ,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Triangle};
+
+    /// # Approximate a unit sphere with triangles
+    ///
+    /// Each triangle computes its own vertices. Those that are supposed to be
+    /// shared end up in slightly different positions, due to floating point
+    /// inaccuracy, like at the seam, where the longitude is both `0` and `2π`.
+    pub fn sphere() -> Vec<Triangle<3>> {
+        let num_latitudes = 8;
+        let num_longitudes = 16;
+
+        let point = |i: usize, j: usize| {
+            let latitude = Scalar::PI * i as f64 / num_latitudes as f64;
+            let longitude = Scalar::TAU * j as f64 / num_longitudes as f64;
+
+            Point::from([
+                latitude.sin() * longitude.cos(),
+                latitude.sin() * longitude.sin(),
+                latitude.cos(),
+            ])
+        };
+
+        let mut triangles = Vec::new();
+        for i in 0..num_latitudes {
+            for j in 0..num_longitudes {
+                let [a, b, c, d] = [
+                    point(i, j),
+                    point(i + 1, j),
+                    point(i + 1, j + 1),
+                    point(i, j + 1),
+                ];
+
+                if i != 0 {
+                    triangles.push(Triangle::from([a, b, d]));
+                }
+                if i != num_latitudes - 1 {
+                    triangles.push(Triangle::from([b, c, d]));
+                }
+            }
+        }
+
+        triangles
+    }
+
+    pub fn cube() -> Vec<Triangle<3>> {
+        let vertices = [
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [1., 1., 0.],
+            [0., 1., 0.],
+            [0., 0., 1.],
+            [1., 0., 1.],
+            [1., 1., 1.],
+            [0., 1., 1.],
+        ]
+        .map(Point::from);
+
+        [
+            [0, 2, 1],
+            [0, 3, 2],
+            [4, 5, 6],
+            [4, 6, 7],
+            [0, 1, 5],
+            [0, 5, 4],
+            [1, 2, 6],
+            [1, 6, 5],
+            [2, 3, 7],
+            [2, 7, 6],
+            [3, 0, 4],
+            [3, 4, 7],
+        ]
+        .into_iter()
+        .map(|indices| Triangle::from(indices.map(|i| vertices[i])))
+        .collect()
+    }
+}
@@ -0,0 +1,143 @@
+use std::io::Write;
+
+use fj_interop::{Mesh, vertices_to_indexed_vertices};
+use fj_math::Triangle;
+
+use crate::Error;
+
+/// # Export the provided mesh to the provided writer in the OBJ format
+///
+/// The vertices of the mesh are written as-is, and shared by all triangles
+/// that refer to them. How close vertices need to be, to be merged, is decided
+/// by the tolerance of the [`Mesh`].
+pub fn export_obj(
+    mesh: &Mesh,
+    mut write: impl Write,
+    options: ObjOptions,
+) -> Result<(), Error> {
+    let faces = mesh
+        .indices()
+        .iter()
+        .zip(mesh.triangles())
+        .map(|(&indices, points)| (indices, Triangle::from(points).normal()))
+        .collect::<Vec<_>>();
+
+    let (normals, normal_indices) = vertices_to_indexed_vertices(
+        faces.iter().map(|&(_, normal)| normal),
+        |normal| normal,
+    );
+
+    let mut entities = Vec::new();
+
+    for point in mesh.vertices() {
+        entities.push(wavefront_rs::obj::entity::Entity::Vertex {
+            x: point.x.into_f64(),
+            y: point.y.into_f64(),
+            z: point.z.into_f64(),
+            w: None,
+        });
+    }
+
+    if options.normals {
+        for normal in normals {
+            entities.push(wavefront_rs::obj::entity::Entity::VertexNormal {
+                x: normal.x.into_f64(),
+                y: normal.y.into_f64(),
+                z: normal.z.into_f64(),
+            });
+        }
+    }
+
+    for ((indices, _), normal_index) in faces.into_iter().zip(normal_indices) {
+        // Indices in OBJ files start at `1`.
+        let normal = options.normals.then_some(i64::from(normal_index) + 1);
+
+        entities.push(wavefront_rs::obj::entity::Entity::Face {
+            vertices: indices
+                .map(|index| wavefront_rs::obj::entity::FaceVertex {
+                    vertex: index as i64 + 1,
+                    texture: None,
+                    normal,
+                })
+                .into(),
+        });
+    }
+
+    for entity in entities {
+        wavefront_rs::obj::writer::Writer { auto_newline: true }
+            .write(&mut write, &entity)
+            .or(Err(Error::OBJ))?;
+    }
+
+    Ok(())
+}
+
+/// # Options for exporting to the OBJ format
+///
+/// See [`export_obj`].
+#[derive(Clone, Copy, Debug)]
+pub struct ObjOptions {
+    /// # Whether to write normals
+    ///
+    /// If enabled, the normal of each triangle is computed from its winding,
+    /// and written as the vertex normal of all of its vertices. Disable this,
+    /// to get smaller files.
+    pub normals: bool,
+}
+
+impl Default for ObjOptions {
+    fn default() -> Self {
+        Self { normals: true }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mesh_from_triangles, tests::sphere};
+
+    use super::{ObjOptions, export_obj};
+
+    #[test]
+    fn export_obj_with_normals() -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        export_obj(
+            &mesh_from_triangles(sphere()),
+            &mut buffer,
+            ObjOptions::default(),
+        )?;
+
+        let obj = String::from_utf8(buffer)?;
+        let count = |prefix| {
+            obj.lines().filter(|line| line.starts_with(prefix)).count()
+        };
+
+        // Two poles, plus 7 rings of 16 vertices.
+        assert_eq!(count("v "), 2 + 7 * 16);
+        assert_eq!(count("f "), 224);
+        assert!(count("vn ") > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_obj_without_normals() -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        export_obj(
+            &mesh_from_triangles(sphere()),
+            &mut buffer,
+            ObjOptions { normals: false },
+        )?;
+
+        let obj = String::from_utf8(buffer)?;
+        let count = |prefix| {
+            obj.lines().filter(|line| line.starts_with(prefix)).count()
+        };
+
+        assert_eq!(count("v "), 2 + 7 * 16);
+        assert_eq!(count("f "), 224);
+        assert_eq!(count("vn "), 0);
+        assert!(!obj.contains("//"));
+
+        Ok(())
+    }
+}
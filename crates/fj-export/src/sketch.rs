@@ -0,0 +1,77 @@
+use fj_core::{
+    geometry::{CurveBoundary, Geometry, Path, traits::GenPolyline},
+    storage::Handle,
+    topology::{Cycle, Surface},
+};
+use fj_interop::Tolerance;
+use fj_math::Point;
+
+/// # Approximate a cycle in the surface coordinates of the sketch
+///
+/// Returns the start point of each half-edge, followed by the points that
+/// approximate the half-edge. The start point of the cycle is not repeated at
+/// the end.
+pub fn approx_cycle(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> Vec<Point<2>> {
+    cycle_edges(cycle, surface, geometry)
+        .into_iter()
+        .flat_map(|(path, boundary)| approx_edge(&path, boundary, tolerance))
+        .collect()
+}
+
+/// # Approximate an edge, excluding its end point
+pub fn approx_edge(
+    path: &Path<2>,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: Tolerance,
+) -> Vec<Point<2>> {
+    // Depending on the type of curve, the polyline may or may not include
+    // the boundary points. Make sure each point is only added once.
+    let [start, end] = boundary.inner;
+    let interior = path
+        .generate_polyline(boundary, tolerance)
+        .into_iter()
+        .filter(|point| *point != start && *point != end);
+
+    [start]
+        .into_iter()
+        .chain(interior)
+        .map(|point| path.point_from_path_coords(point))
+        .collect()
+}
+
+/// # Access the path and boundary of each half-edge in a cycle
+pub fn cycle_edges(
+    cycle: &Cycle,
+    surface: &Handle<Surface>,
+    geometry: &Geometry,
+) -> Vec<(Path<2>, CurveBoundary<Point<1>>)> {
+    cycle
+        .half_edges()
+        .pairs()
+        .map(|(half_edge, next_half_edge)| {
+            let path = geometry
+                .of_curve(half_edge.curve())
+                .unwrap()
+                .local_on(surface)
+                .unwrap()
+                .path;
+            let boundary = CurveBoundary {
+                inner: [half_edge, next_half_edge].map(|start| {
+                    geometry
+                        .of_vertex(start.start_vertex())
+                        .unwrap()
+                        .local_on(half_edge.curve())
+                        .unwrap()
+                        .position
+                }),
+            };
+
+            (path, boundary)
+        })
+        .collect()
+}
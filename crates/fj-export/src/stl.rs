@@ -0,0 +1,194 @@
+use std::io::Write;
+
+use fj_interop::Mesh;
+use fj_math::Triangle;
+
+use crate::Error;
+
+/// # Export the provided mesh to the provided writer in the STL format
+///
+/// STL doesn't share vertices between facets, so each triangle of the mesh is
+/// written with its own copy of its vertices. The normal of each facet is
+/// computed from the winding of its triangle.
+pub fn export_stl(
+    mesh: &Mesh,
+    mut write: impl Write,
+    format: StlFormat,
+) -> Result<(), Error> {
+    let points = mesh.triangles().collect::<Vec<_>>();
+
+    let vertices = points.iter().map(|points| {
+        points.map(|point| point.coords.components.map(|s| s.into_f32()))
+    });
+
+    let normals = points
+        .iter()
+        .map(|&points| points.into())
+        .map(|triangle: Triangle<3>| triangle.normal())
+        .map(|vector| vector.components.map(|s| s.into_f32()));
+
+    let triangles = vertices
+        .zip(normals)
+        .map(|([v1, v2, v3], normal)| ::stl::Triangle {
+            normal,
+            v1,
+            v2,
+            v3,
+            attr_byte_count: 0,
+        })
+        .collect::<Vec<_>>();
+
+    if format == StlFormat::Ascii {
+        return write_ascii_stl(&triangles, write);
+    }
+
+    let binary_stl_file = ::stl::BinaryStlFile {
+        header: ::stl::BinaryStlHeader {
+            header: [0u8; 80],
+            num_triangles: triangles
+                .len()
+                .try_into()
+                .map_err(|_| Error::InvalidTriangleCount)?,
+        },
+        triangles,
+    };
+
+    ::stl::write_stl(&mut write, &binary_stl_file)?;
+
+    Ok(())
+}
+
+fn write_ascii_stl(
+    triangles: &[::stl::Triangle],
+    mut write: impl Write,
+) -> Result<(), Error> {
+    writeln!(write, "solid")?;
+
+    for triangle in triangles {
+        let [x, y, z] = triangle.normal;
+        writeln!(write, "  facet normal {x:e} {y:e} {z:e}")?;
+        writeln!(write, "    outer loop")?;
+
+        for [x, y, z] in [triangle.v1, triangle.v2, triangle.v3] {
+            writeln!(write, "      vertex {x:e} {y:e} {z:e}")?;
+        }
+
+        writeln!(write, "    endloop")?;
+        writeln!(write, "  endfacet")?;
+    }
+
+    writeln!(write, "endsolid")?;
+
+    Ok(())
+}
+
+/// # The format of an STL file
+///
+/// See [`export_stl`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StlFormat {
+    /// # Binary STL
+    ///
+    /// The more compact format, and the one that [`export`](crate::export) uses.
+    #[default]
+    Binary,
+
+    /// # ASCII STL
+    ///
+    /// A human-readable format, which results in much larger files.
+    Ascii,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use fj_interop::import_stl;
+
+    use crate::{mesh_from_triangles, tests::cube};
+
+    use super::{StlFormat, export_stl};
+
+    #[test]
+    fn export_binary_stl() -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        export_stl(
+            &mesh_from_triangles(cube()),
+            &mut buffer,
+            StlFormat::Binary,
+        )?;
+
+        let stl = ::stl::read_stl(&mut Cursor::new(buffer))?;
+        assert_eq!(stl.header.header, [0u8; 80]);
+        assert_eq!(stl.header.num_triangles, 12);
+        assert_eq!(stl.triangles.len(), 12);
+
+        // The normal of the first triangle points out of the cube.
+        assert_eq!(stl.triangles[0].normal, [0., 0., -1.]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_ascii_stl() -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        export_stl(
+            &mesh_from_triangles(cube()),
+            &mut buffer,
+            StlFormat::Ascii,
+        )?;
+
+        let stl = String::from_utf8(buffer)?;
+        let mut lines = stl.lines();
+
+        assert_eq!(lines.next(), Some("solid"));
+        assert_eq!(lines.next_back(), Some("endsolid"));
+        assert_eq!(
+            lines
+                .clone()
+                .filter(|line| line.trim().starts_with("facet normal"))
+                .count(),
+            12,
+        );
+        assert_eq!(
+            lines
+                .filter(|line| line.trim().starts_with("vertex"))
+                .count(),
+            12 * 3,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn import_binary_stl() -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        export_stl(
+            &mesh_from_triangles(cube()),
+            &mut buffer,
+            StlFormat::Binary,
+        )?;
+
+        let mesh = import_stl(buffer.as_slice(), 1e-6)?;
+        assert_eq!(mesh.vertices().len(), 8);
+        assert_eq!(mesh.indices().len(), 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn import_ascii_stl() -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        export_stl(
+            &mesh_from_triangles(cube()),
+            &mut buffer,
+            StlFormat::Ascii,
+        )?;
+
+        let mesh = import_stl(buffer.as_slice(), 1e-6)?;
+        assert_eq!(mesh.vertices().len(), 8);
+        assert_eq!(mesh.indices().len(), 12);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+use std::io::Write;
+
+use fj_core::{geometry::Geometry, topology::Sketch};
+use fj_interop::Tolerance;
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::{Error, sketch::approx_cycle};
+
+/// # Export the provided sketch to the provided writer in the SVG format
+///
+/// Each cycle of the sketch is approximated within the provided tolerance. Each
+/// region is written as a single `<path>` element, with one subpath per cycle.
+/// The path uses `fill-rule="evenodd"`, which makes the interior cycles of the
+/// region show up as holes, if the path is filled.
+///
+/// The y-axis of SVG points down, so the sketch is mirrored along the x-axis,
+/// to make it appear the same way it would in a y-up coordinate system. The
+/// `viewBox` of the SVG encloses all paths, including their stroke.
+pub fn export_svg(
+    sketch: &Sketch,
+    geometry: &Geometry,
+    tolerance: impl Into<Tolerance>,
+    mut write: impl Write,
+    options: SvgOptions,
+) -> Result<(), Error> {
+    let tolerance = tolerance.into();
+
+    let regions = sketch
+        .regions()
+        .iter()
+        .map(|region| {
+            region
+                .all_cycles()
+                .map(|cycle| {
+                    approx_cycle(cycle, sketch.surface(), tolerance, geometry)
+                        .into_iter()
+                        .map(|point| {
+                            Point::from([point.u, Scalar::ZERO - point.v])
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let points = regions.iter().flatten().flatten().copied();
+    let [x, y, width, height] = if points.clone().next().is_some() {
+        let aabb = Aabb::<2>::from_points(points);
+        let margin = options.stroke_width / 2.;
+
+        [
+            aabb.min.u - margin,
+            aabb.min.v - margin,
+            aabb.max.u - aabb.min.u + margin * 2.,
+            aabb.max.v - aabb.min.v + margin * 2.,
+        ]
+    } else {
+        [Scalar::ZERO; 4]
+    };
+
+    writeln!(
+        write,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{x} {y} {width} \
+        {height}\">"
+    )?;
+
+    for cycles in regions {
+        let mut data = Vec::new();
+
+        for points in cycles {
+            for (i, point) in points.iter().enumerate() {
+                let command = if i == 0 { "M" } else { "L" };
+                data.push(format!("{command} {} {}", point.u, point.v));
+            }
+            data.push("Z".to_string());
+        }
+
+        writeln!(
+            write,
+            "  <path d=\"{}\" fill=\"none\" fill-rule=\"evenodd\" \
+            stroke=\"black\" stroke-width=\"{}\"/>",
+            data.join(" "),
+            options.stroke_width,
+        )?;
+    }
+
+    writeln!(write, "</svg>")?;
+
+    Ok(())
+}
+
+/// # Options for exporting to the SVG format
+///
+/// See [`export_svg`].
+#[derive(Clone, Copy, Debug)]
+pub struct SvgOptions {
+    /// # The width of the outlines
+    ///
+    /// This uses the same units as the sketch.
+    pub stroke_width: Scalar,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            stroke_width: Scalar::from(0.1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_core::{
+        Core,
+        operations::{
+            build::{BuildCycle, BuildRegion, BuildSketch},
+            insert::Insert,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, Region, Sketch},
+    };
+
+    use super::{SvgOptions, export_svg};
+
+    #[test]
+    fn export_svg_square_with_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let space_2d = core.layers.topology.surfaces.space_2d();
+        let region = Region::polygon(
+            [[0., 0.], [3., 0.], [3., 3.], [0., 3.]],
+            space_2d.clone(),
+            &mut core,
+        )
+        .add_interiors(
+            [Cycle::polygon(
+                [[1., 1.], [1., 2.], [2., 2.], [2., 1.]],
+                space_2d,
+                &mut core,
+            )],
+            &mut core,
+        );
+        let sketch = Sketch::empty(&core.layers.topology)
+            .add_regions([region.insert(&mut core)], &mut core);
+
+        let mut buffer = Vec::new();
+        export_svg(
+            &sketch,
+            &core.layers.geometry,
+            0.001,
+            &mut buffer,
+            SvgOptions::default(),
+        )?;
+        let svg = String::from_utf8(buffer)?;
+
+        // One path for the region, with one subpath for each cycle.
+        assert_eq!(svg.matches("<path").count(), 1);
+        assert_eq!(svg.matches('M').count(), 2);
+        assert!(svg.contains("fill-rule=\"evenodd\""));
+
+        let attribute = |name: &str| {
+            let start =
+                svg.find(&format!("{name}=\"")).unwrap() + name.len() + 2;
+            let end = start + svg[start..].find('"').unwrap();
+            svg[start..end].to_string()
+        };
+        let view_box = attribute("viewBox")
+            .split_whitespace()
+            .map(str::parse::<f64>)
+            .collect::<Result<Vec<_>, _>>()?;
+        let [x, y, width, height] = view_box[..] else {
+            panic!("Expected `viewBox` to have four values");
+        };
+
+        let coords = attribute("d")
+            .split_whitespace()
+            .filter_map(|token| token.parse::<f64>().ok())
+            .collect::<Vec<_>>();
+        assert_eq!(coords.len(), 8 * 2);
+        for point in coords.chunks(2) {
+            let [u, v] = [point[0], point[1]];
+            assert!(x <= u && u <= x + width);
+            assert!(y <= v && v <= y + height);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_svg_circle() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let sketch = Sketch::circle([0., 0.], 1., &mut core);
+
+        let mut buffer = Vec::new();
+        export_svg(
+            &sketch,
+            &core.layers.geometry,
+            0.01,
+            &mut buffer,
+            SvgOptions::default(),
+        )?;
+        let svg = String::from_utf8(buffer)?;
+
+        assert_eq!(svg.matches("<path").count(), 1);
+        assert!(svg.matches('L').count() > 8);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use std::io::{Seek, Write};
+
+use fj_interop::{Color, Mesh};
+use fj_math::Scalar;
+
+use crate::Error;
+
+/// # Export the provided mesh to the provided writer in the 3MF format
+///
+/// Writes a 3MF package, a ZIP archive that contains the model and the
+/// metadata that the Open Packaging Conventions require. The model consists of
+/// a single mesh object and a build item that references it.
+///
+/// If a color is provided, it is written as the display color of a base
+/// material, which the object references. Slicers typically show the object in
+/// that color.
+pub fn export_3mf(
+    mesh: &Mesh,
+    color: Option<Color>,
+    write: impl Write + Seek,
+) -> Result<(), Error> {
+    let mut model = String::new();
+    write_3mf_model(mesh, color, &mut model)
+        .expect("Writing to `String` can't fail");
+
+    let mut archive = zip::ZipWriter::new(write);
+    let options = zip::write::SimpleFileOptions::default();
+
+    for (name, content) in [
+        ("[Content_Types].xml", THREEMF_CONTENT_TYPES),
+        ("_rels/.rels", THREEMF_RELATIONSHIPS),
+        ("3D/3dmodel.model", model.as_str()),
+    ] {
+        archive.start_file(name, options)?;
+        archive.write_all(content.as_bytes())?;
+    }
+
+    archive.finish()?;
+
+    Ok(())
+}
+
+const THREEMF_CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#;
+
+const THREEMF_RELATIONSHIPS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#;
+
+fn write_3mf_model(
+    mesh: &Mesh,
+    color: Option<Color>,
+    write: &mut impl std::fmt::Write,
+) -> std::fmt::Result {
+    // The IDs of all resources share the same namespace.
+    const MATERIAL_ID: u32 = 1;
+    const OBJECT_ID: u32 = 2;
+
+    writeln!(write, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        write,
+        "<model unit=\"millimeter\" xml:lang=\"en-US\" \
+        xmlns=\"http://schemas.microsoft.com/3dmanufacturing/core/2015/02\">"
+    )?;
+    writeln!(write, "  <resources>")?;
+
+    let material = if let Some(Color([r, g, b, a])) = color {
+        writeln!(write, r#"    <basematerials id="{MATERIAL_ID}">"#)?;
+        writeln!(
+            write,
+            r##"      <base name="color" displaycolor="#{r:02X}{g:02X}{b:02X}{a:02X}"/>"##
+        )?;
+        writeln!(write, "    </basematerials>")?;
+
+        format!(r#" pid="{MATERIAL_ID}" pindex="0""#)
+    } else {
+        String::new()
+    };
+
+    writeln!(
+        write,
+        r#"    <object id="{OBJECT_ID}" type="model"{material}>"#
+    )?;
+    writeln!(write, "      <mesh>")?;
+
+    writeln!(write, "        <vertices>")?;
+    for point in mesh.vertices() {
+        let [x, y, z] = point.coords.components.map(Scalar::into_f64);
+        writeln!(write, r#"          <vertex x="{x}" y="{y}" z="{z}"/>"#)?;
+    }
+    writeln!(write, "        </vertices>")?;
+
+    writeln!(write, "        <triangles>")?;
+    for [v1, v2, v3] in mesh.indices() {
+        writeln!(
+            write,
+            r#"          <triangle v1="{v1}" v2="{v2}" v3="{v3}"/>"#
+        )?;
+    }
+    writeln!(write, "        </triangles>")?;
+
+    writeln!(write, "      </mesh>")?;
+    writeln!(write, "    </object>")?;
+    writeln!(write, "  </resources>")?;
+    writeln!(write, "  <build>")?;
+    writeln!(write, r#"    <item objectid="{OBJECT_ID}"/>"#)?;
+    writeln!(write, "  </build>")?;
+    writeln!(write, "</model>")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use fj_interop::Color;
+
+    use crate::{mesh_from_triangles, tests::cube};
+
+    use super::export_3mf;
+
+    #[test]
+    fn export_3mf_cube() -> anyhow::Result<()> {
+        let mut buffer = Vec::new();
+        export_3mf(
+            &mesh_from_triangles(cube()),
+            Some(Color([255, 0, 0, 255])),
+            Cursor::new(&mut buffer),
+        )?;
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(buffer))?;
+        assert!(archive.by_name("[Content_Types].xml").is_ok());
+        assert!(archive.by_name("_rels/.rels").is_ok());
+
+        let mut model = String::new();
+        archive
+            .by_name("3D/3dmodel.model")?
+            .read_to_string(&mut model)?;
+        assert_eq!(model.matches("<vertex ").count(), 8);
+        assert_eq!(model.matches("<triangle ").count(), 12);
+        assert!(model.contains(r##"displaycolor="#FF0000FF""##));
+        assert!(model.contains(r#"<item objectid="2"/>"#));
+
+        Ok(())
+    }
+}
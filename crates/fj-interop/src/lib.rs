@@ -5,12 +5,15 @@
 //! is one of those.
 //!
 //! This library defines types that allow other components of Fornjot to
-//! interoperate, without having to depend on each other.
+//! interoperate, without having to depend on each other. It also provides an
+//! importer for STL files, which produces a [`Mesh`].
 //!
 //! [Fornjot]: https://www.fornjot.app/
 
 mod approx;
 mod color;
+mod mesh;
+mod stl;
 mod tolerance;
 mod tri_mesh;
 
@@ -19,6 +22,8 @@ pub mod ext;
 pub use self::{
     approx::CircleApproxParams,
     color::Color,
+    mesh::Mesh,
+    stl::{StlImportError, import_stl},
     tolerance::{InvalidTolerance, Tolerance},
     tri_mesh::{Index, MeshTriangle, TriMesh, vertices_to_indexed_vertices},
 };
@@ -0,0 +1,943 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, BinaryHeap},
+};
+
+use fj_math::{Aabb, Point, Scalar, Triangle, Vector};
+
+use crate::{Index, Tolerance};
+
+/// # A triangle mesh with shared vertices
+///
+/// Unlike [`TriMesh`], which stores each triangle with its own points, this
+/// stores each vertex once, and refers to vertices by index. This is the
+/// representation that most file formats and renderers expect.
+///
+/// Vertices are de-duplicated as they are added: A vertex whose distance to an
+/// existing vertex is within the tolerance of the mesh is merged with it.
+///
+/// [`TriMesh`]: crate::TriMesh
+#[derive(Clone, Debug)]
+pub struct Mesh {
+    vertices: Vec<Point<3>>,
+    triangles: Vec<[Index; 3]>,
+    normals: Option<Vec<Vector<3>>>,
+
+    tolerance: Tolerance,
+
+    /// # The vertices, sorted into a grid of cubic cells
+    ///
+    /// The size of each cell is the tolerance. A vertex can only be merged with
+    /// vertices in its own or the neighboring cells.
+    cells: BTreeMap<[i64; 3], Vec<Index>>,
+}
+
+impl Mesh {
+    /// # Construct an empty mesh
+    ///
+    /// The tolerance defines the distance up to which vertices are merged.
+    pub fn new(tolerance: impl Into<Tolerance>) -> Self {
+        Self {
+            vertices: Vec::new(),
+            triangles: Vec::new(),
+            normals: None,
+            tolerance: tolerance.into(),
+            cells: BTreeMap::new(),
+        }
+    }
+
+    /// # Add a vertex to the mesh, returning its index
+    ///
+    /// If the mesh already contains a vertex within the tolerance of the
+    /// provided one, the index of that existing vertex is returned instead.
+    pub fn push_vertex(&mut self, point: impl Into<Point<3>>) -> Index {
+        let point = point.into();
+        let tolerance = self.tolerance.inner();
+
+        let cell = self.cell(point);
+
+        for x in -1..=1 {
+            for y in -1..=1 {
+                for z in -1..=1 {
+                    let neighbor = [cell[0] + x, cell[1] + y, cell[2] + z];
+                    let Some(indices) = self.cells.get(&neighbor) else {
+                        continue;
+                    };
+
+                    for &index in indices {
+                        let vertex = self.vertices[index as usize];
+                        if vertex.distance_to(&point) <= tolerance {
+                            return index;
+                        }
+                    }
+                }
+            }
+        }
+
+        let index = self.vertices.len() as Index;
+        self.vertices.push(point);
+        self.cells.entry(cell).or_default().push(index);
+
+        // Normals that have been computed before don't account for this
+        // vertex, or for the triangles that will refer to it.
+        self.normals = None;
+
+        index
+    }
+
+    /// # Add a triangle to the mesh, returning the indices of its vertices
+    ///
+    /// The vertices of the triangle are added using [`Mesh::push_vertex`]. If
+    /// this merges any of them with each other, the triangle is degenerate. It
+    /// is not added to the mesh then, and `None` is returned.
+    pub fn push_triangle(
+        &mut self,
+        triangle: impl Into<Triangle<3>>,
+    ) -> Option<[Index; 3]> {
+        let indices =
+            triangle.into().points.map(|point| self.push_vertex(point));
+
+        let [a, b, c] = indices;
+        if a == b || b == c || c == a {
+            return None;
+        }
+
+        self.triangles.push(indices);
+        self.normals = None;
+
+        Some(indices)
+    }
+
+    /// # Access the vertex buffer
+    pub fn vertices(&self) -> &[Point<3>] {
+        &self.vertices
+    }
+
+    /// # Access the index buffer
+    ///
+    /// Each entry is a triangle, defined by the indices of its vertices.
+    pub fn indices(&self) -> &[[Index; 3]] {
+        &self.triangles
+    }
+
+    /// # Access the per-vertex normals, if they have been computed
+    ///
+    /// See [`Mesh::compute_normals`]. Adding vertices or triangles to the mesh
+    /// invalidates the normals.
+    pub fn normals(&self) -> Option<&[Vector<3>]> {
+        self.normals.as_deref()
+    }
+
+    /// # Compute per-vertex normals
+    ///
+    /// The normal of each vertex is the normalized sum of the normals of all
+    /// triangles that refer to it, weighted by their area. Vertices that are
+    /// not referred to by any triangle get a zero normal.
+    pub fn compute_normals(&mut self) {
+        let mut normals = vec![Vector::from([0., 0., 0.]); self.vertices.len()];
+
+        for (indices, [a, b, c]) in self.triangles.iter().zip(self.triangles())
+        {
+            // The length of the cross product is twice the area of the
+            // triangle, which provides the weighting.
+            let normal = (b - a).cross(&(c - a));
+            for &index in indices {
+                normals[index as usize] = normals[index as usize] + normal;
+            }
+        }
+
+        for normal in &mut normals {
+            if normal.magnitude() > Scalar::ZERO {
+                *normal = normal.normalize();
+            }
+        }
+
+        self.normals = Some(normals);
+    }
+
+    /// # Simplify the mesh, by collapsing edges
+    ///
+    /// Uses edge-collapse decimation, guided by quadric error metrics: Each
+    /// vertex keeps track of the planes of the original triangles that were
+    /// merged into it. Edges are collapsed in order of increasing error, which
+    /// is the sum of the squared distances between the vertex that results
+    /// from the collapse, and those planes.
+    ///
+    /// Collapsing stops, once the error of the next collapse would exceed the
+    /// square of `target_error`. Since the error is a sum of squared distances,
+    /// this makes sure that every vertex of the simplified mesh is within
+    /// `target_error` of the planes of all original triangles that were merged
+    /// into it.
+    ///
+    /// The following edges are never collapsed:
+    ///
+    /// - Edges that touch a boundary of the mesh (an edge that only a single
+    ///   triangle refers to), or a non-manifold edge (one that more than two
+    ///   triangles refer to). This preserves the boundaries of the mesh.
+    /// - Edges whose collapse would make the mesh non-manifold, or flip the
+    ///   orientation of any triangle.
+    ///
+    /// Vertices that are no longer referred to by any triangle are removed. This
+    /// invalidates any indices into the vertex buffer that were obtained before,
+    /// as well as any normals that have been computed.
+    pub fn simplify(&mut self, target_error: impl Into<Scalar>) {
+        let max_error = target_error.into().into_f64().powi(2);
+
+        let mut positions = self.vertices.clone();
+        let mut triangles =
+            self.triangles.iter().copied().map(Some).collect::<Vec<_>>();
+
+        let mut triangles_at_vertex = vec![BTreeSet::new(); positions.len()];
+        let mut quadrics = vec![Quadric::default(); positions.len()];
+        let mut num_triangles_at_edge = BTreeMap::new();
+
+        for (i, &[a, b, c]) in self.triangles.iter().enumerate() {
+            let [pa, pb, pc] = [a, b, c].map(|index| positions[index as usize]);
+            let quadric = Quadric::from_triangle([pa, pb, pc]);
+
+            for index in [a, b, c] {
+                triangles_at_vertex[index as usize].insert(i);
+                quadrics[index as usize] = quadrics[index as usize] + quadric;
+            }
+            for edge in [[a, b], [b, c], [c, a]] {
+                *num_triangles_at_edge.entry(sorted(edge)).or_insert(0) += 1;
+            }
+        }
+
+        // Vertices on boundary or non-manifold edges are never moved or
+        // removed.
+        let mut is_locked = vec![false; positions.len()];
+        for (&[a, b], &num_triangles) in &num_triangles_at_edge {
+            if num_triangles != 2 {
+                is_locked[a as usize] = true;
+                is_locked[b as usize] = true;
+            }
+        }
+
+        // Every time a vertex changes, its version is incremented. Collapses
+        // that were queued for previous versions are skipped.
+        let mut versions = vec![0u32; positions.len()];
+        let mut is_removed = vec![false; positions.len()];
+        let mut queue = BinaryHeap::new();
+
+        let candidate = |[a, b]: [Index; 2],
+                         positions: &[Point<3>],
+                         quadrics: &[Quadric],
+                         versions: &[u32]| {
+            let quadric = quadrics[a as usize] + quadrics[b as usize];
+            let (position, error) = quadric
+                .minimize([positions[a as usize], positions[b as usize]]);
+
+            Reverse(Collapse {
+                error: Scalar::from(error),
+                edge: [a, b],
+                versions: [versions[a as usize], versions[b as usize]],
+                position,
+            })
+        };
+
+        for &[a, b] in num_triangles_at_edge.keys() {
+            if is_locked[a as usize] || is_locked[b as usize] {
+                continue;
+            }
+            queue.push(candidate([a, b], &positions, &quadrics, &versions));
+        }
+
+        while let Some(Reverse(collapse)) = queue.pop() {
+            if collapse.error.into_f64() > max_error {
+                break;
+            }
+
+            let [a, b] = collapse.edge;
+            let is_stale = [a, b].into_iter().zip(collapse.versions).any(
+                |(index, version)| {
+                    is_removed[index as usize]
+                        || versions[index as usize] != version
+                },
+            );
+            if is_stale {
+                continue;
+            }
+
+            if !can_collapse(
+                [a, b],
+                collapse.position,
+                &positions,
+                &triangles,
+                &triangles_at_vertex,
+            ) {
+                continue;
+            }
+
+            // Move `a` to the new position, remove `b`, and update all
+            // triangles that referred to `b` to refer to `a` instead.
+            // Triangles that referred to both collapse and are removed.
+            positions[a as usize] = collapse.position;
+            quadrics[a as usize] = quadrics[a as usize] + quadrics[b as usize];
+            versions[a as usize] += 1;
+            is_removed[b as usize] = true;
+
+            for t in std::mem::take(&mut triangles_at_vertex[b as usize]) {
+                let Some(triangle) = &mut triangles[t] else {
+                    continue;
+                };
+
+                if triangle.contains(&a) {
+                    for index in *triangle {
+                        triangles_at_vertex[index as usize].remove(&t);
+                    }
+                    triangles[t] = None;
+                } else {
+                    for index in triangle.iter_mut() {
+                        if *index == b {
+                            *index = a;
+                        }
+                    }
+                    triangles_at_vertex[a as usize].insert(t);
+                }
+            }
+
+            for n in neighbors(a, &triangles, &triangles_at_vertex) {
+                if is_locked[n as usize] {
+                    continue;
+                }
+                queue.push(candidate([a, n], &positions, &quadrics, &versions));
+            }
+        }
+
+        // Rebuild the mesh from the remaining triangles, dropping vertices
+        // that are no longer referred to.
+        let mut new_indices = BTreeMap::new();
+        self.vertices.clear();
+        self.cells.clear();
+        self.triangles.clear();
+        self.normals = None;
+
+        for triangle in triangles.into_iter().flatten() {
+            let triangle = triangle.map(|index| {
+                *new_indices.entry(index).or_insert_with(|| {
+                    let point = positions[index as usize];
+                    let new_index = self.vertices.len() as Index;
+
+                    self.vertices.push(point);
+                    self.cells
+                        .entry(self.cell(point))
+                        .or_default()
+                        .push(new_index);
+
+                    new_index
+                })
+            });
+
+            self.triangles.push(triangle);
+        }
+    }
+
+    /// # Iterate over the triangles, as triples of vertex positions
+    pub fn triangles(&self) -> impl Iterator<Item = [Point<3>; 3]> + '_ {
+        self.triangles
+            .iter()
+            .map(|indices| indices.map(|index| self.vertices[index as usize]))
+    }
+
+    /// # Compute the Hausdorff distance between this mesh and another one
+    ///
+    /// This is the larger of the two directed distances: The distance from
+    /// the point on this mesh that is farthest from the other mesh, to the
+    /// other mesh; and vice versa. This makes it suitable for comparing a
+    /// tessellation against a reference.
+    ///
+    /// Points are sampled at the vertices and the centers of the triangles of
+    /// each mesh. For each sample, the distance to the closest point on any
+    /// triangle of the other mesh is computed, using a grid of triangles to
+    /// avoid checking all of them.
+    ///
+    /// If neither mesh has any triangles, the distance is zero. If only one of
+    /// them has, it is [`Scalar::MAX`].
+    pub fn hausdorff_distance(&self, other: &Mesh) -> Scalar {
+        self.directed_hausdorff_distance(other)
+            .max(other.directed_hausdorff_distance(self))
+    }
+
+    fn directed_hausdorff_distance(&self, other: &Mesh) -> Scalar {
+        if self.triangles.is_empty() {
+            return Scalar::ZERO;
+        }
+        let Some(grid) = TriangleGrid::new(other) else {
+            return Scalar::MAX;
+        };
+
+        let centers = self
+            .triangles()
+            .map(|points| Triangle::from_points(points).center());
+
+        self.vertices
+            .iter()
+            .copied()
+            .chain(centers)
+            .map(|point| grid.distance_to(point))
+            .max()
+            .unwrap_or(Scalar::ZERO)
+    }
+
+    fn cell(&self, point: Point<3>) -> [i64; 3] {
+        let tolerance = self.tolerance.inner();
+
+        point
+            .coords
+            .components
+            .map(|s| (s / tolerance).floor().into_f64() as i64)
+    }
+}
+
+/// # The triangles of a mesh, sorted into a grid of cubic cells
+///
+/// Used by [`Mesh::hausdorff_distance`]. Each triangle is added to every cell
+/// that its bounding box overlaps.
+struct TriangleGrid {
+    triangles: Vec<Triangle<3>>,
+    cells: BTreeMap<[i64; 3], Vec<usize>>,
+    origin: Point<3>,
+    cell_size: Scalar,
+    num_cells: [i64; 3],
+}
+
+impl TriangleGrid {
+    fn new(mesh: &Mesh) -> Option<Self> {
+        let triangles = mesh
+            .triangles()
+            .map(Triangle::from_points)
+            .collect::<Vec<_>>();
+        if triangles.is_empty() {
+            return None;
+        }
+
+        let aabb = Aabb::<3>::from_points(
+            triangles.iter().flat_map(|triangle| triangle.points),
+        );
+        let size = aabb.size().components.into_iter().max()?;
+
+        // Aim for about one triangle per cell, along the largest dimension of
+        // the mesh.
+        let cell_size = if size > Scalar::ZERO {
+            size / (triangles.len() as f64).cbrt()
+        } else {
+            Scalar::ONE
+        };
+
+        let mut grid = Self {
+            triangles: Vec::new(),
+            cells: BTreeMap::new(),
+            origin: aabb.min,
+            cell_size,
+            num_cells: [0; 3],
+        };
+        grid.num_cells = grid.cell(aabb.max).map(|coord| coord + 1);
+
+        for (index, triangle) in triangles.iter().enumerate() {
+            let aabb = Aabb::<3>::from_points(triangle.points);
+            let [min, max] = [aabb.min, aabb.max].map(|point| grid.cell(point));
+
+            for x in min[0]..=max[0] {
+                for y in min[1]..=max[1] {
+                    for z in min[2]..=max[2] {
+                        grid.cells.entry([x, y, z]).or_default().push(index);
+                    }
+                }
+            }
+        }
+
+        grid.triangles = triangles;
+        Some(grid)
+    }
+
+    /// # Compute the distance from a point to the closest triangle
+    ///
+    /// Searches the cells in rings of increasing distance around the cell of
+    /// the point. Any triangle that hasn't been found after searching ring
+    /// `r`, is at least `r` cells away from the point. The search stops, once
+    /// that is farther than the closest triangle found so far.
+    fn distance_to(&self, point: Point<3>) -> Scalar {
+        let center = self.cell(point);
+        let mut distance = Scalar::MAX;
+
+        for r in 0.. {
+            if self.cell_size * Scalar::from(r as f64) >= distance {
+                break;
+            }
+
+            let is_outside_grid = (0..3).all(|i| {
+                center[i] - r < 0 && center[i] + r >= self.num_cells[i]
+            });
+            if r > 0 && is_outside_grid {
+                // All cells have been searched.
+                break;
+            }
+
+            for x in -r..=r {
+                for y in -r..=r {
+                    for z in -r..=r {
+                        if x.abs().max(y.abs()).max(z.abs()) != r {
+                            continue;
+                        }
+
+                        let cell =
+                            [center[0] + x, center[1] + y, center[2] + z];
+                        let Some(indices) = self.cells.get(&cell) else {
+                            continue;
+                        };
+
+                        for &index in indices {
+                            let closest =
+                                self.triangles[index].closest_point(point);
+                            distance =
+                                distance.min(point.distance_to(&closest));
+                        }
+                    }
+                }
+            }
+        }
+
+        distance
+    }
+
+    fn cell(&self, point: Point<3>) -> [i64; 3] {
+        (point - self.origin)
+            .components
+            .map(|s| (s / self.cell_size).floor().into_f64() as i64)
+    }
+}
+
+/// # An edge collapse, as queued by [`Mesh::simplify`]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Collapse {
+    error: Scalar,
+    edge: [Index; 2],
+    versions: [u32; 2],
+    position: Point<3>,
+}
+
+/// # A quadric error metric
+///
+/// Represents the sum of the squared distances of a point to a set of planes,
+/// as a symmetric 4x4 matrix. Only the upper triangle of the matrix is stored.
+#[derive(Clone, Copy, Debug, Default)]
+struct Quadric {
+    inner: [f64; 10],
+}
+
+impl Quadric {
+    fn from_triangle([a, b, c]: [Point<3>; 3]) -> Self {
+        let normal = (b - a).cross(&(c - a));
+        if normal.magnitude() == Scalar::ZERO {
+            return Self::default();
+        }
+
+        let [x, y, z] = normal.normalize().components.map(Scalar::into_f64);
+        let d = -(x * a.x.into_f64() + y * a.y.into_f64() + z * a.z.into_f64());
+
+        Self {
+            inner: [
+                x * x,
+                x * y,
+                x * z,
+                x * d,
+                y * y,
+                y * z,
+                y * d,
+                z * z,
+                z * d,
+                d * d,
+            ],
+        }
+    }
+
+    fn error(&self, point: Point<3>) -> f64 {
+        let [xx, xy, xz, xd, yy, yz, yd, zz, zd, dd] = self.inner;
+        let [x, y, z] = point.coords.components.map(Scalar::into_f64);
+
+        let error = xx * x * x
+            + 2. * xy * x * y
+            + 2. * xz * x * z
+            + 2. * xd * x
+            + yy * y * y
+            + 2. * yz * y * z
+            + 2. * yd * y
+            + zz * z * z
+            + 2. * zd * z
+            + dd;
+
+        // Rounding errors can make the result slightly negative.
+        error.max(0.)
+    }
+
+    /// # Find the position with the least error for collapsing an edge
+    ///
+    /// Tries the position where the error is minimal, if that's well-defined,
+    /// as well as the endpoints and the midpoint of the edge. Returns the best
+    /// of those positions, and its error.
+    fn minimize(&self, [a, b]: [Point<3>; 2]) -> (Point<3>, f64) {
+        let mut candidates = vec![a, b, a + (b - a) / 2.];
+        candidates.extend(self.optimum());
+
+        candidates
+            .into_iter()
+            .map(|point| (point, self.error(point)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .expect("List of candidates is not empty")
+    }
+
+    fn optimum(&self) -> Option<Point<3>> {
+        let [xx, xy, xz, xd, yy, yz, yd, zz, zd, _] = self.inner;
+
+        // The gradient of the error vanishes at the optimum. Solve the
+        // resulting linear system using Cramer's rule.
+        let det3 = |[a, b, c]: [[f64; 3]; 3]| {
+            a[0] * (b[1] * c[2] - b[2] * c[1])
+                - a[1] * (b[0] * c[2] - b[2] * c[0])
+                + a[2] * (b[0] * c[1] - b[1] * c[0])
+        };
+
+        let columns = [[xx, xy, xz], [xy, yy, yz], [xz, yz, zz]];
+        let rhs = [-xd, -yd, -zd];
+
+        let det = det3(columns);
+        if det.abs() < 1e-12 {
+            // The planes don't determine a single point, for example because
+            // they are all parallel.
+            return None;
+        }
+
+        let coords = [0, 1, 2].map(|i| {
+            let mut columns = columns;
+            columns[i] = rhs;
+            det3(columns) / det
+        });
+        if coords.iter().any(|coord| !coord.is_finite()) {
+            return None;
+        }
+
+        Some(Point::from(coords))
+    }
+}
+
+impl std::ops::Add for Quadric {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let mut inner = self.inner;
+        for (a, b) in inner.iter_mut().zip(other.inner) {
+            *a += b;
+        }
+        Self { inner }
+    }
+}
+
+fn sorted([a, b]: [Index; 2]) -> [Index; 2] {
+    if a < b { [a, b] } else { [b, a] }
+}
+
+fn neighbors(
+    vertex: Index,
+    triangles: &[Option<[Index; 3]>],
+    triangles_at_vertex: &[BTreeSet<usize>],
+) -> BTreeSet<Index> {
+    triangles_at_vertex[vertex as usize]
+        .iter()
+        .filter_map(|&t| triangles[t])
+        .flatten()
+        .filter(|&index| index != vertex)
+        .collect()
+}
+
+/// # Determine whether collapsing an edge keeps the mesh valid
+///
+/// The collapse must not make the mesh non-manifold. This is the case, if the
+/// only vertices that are connected to both vertices of the edge, are the
+/// opposite vertices of the two triangles that share it (the link condition).
+/// In addition, the collapse must not result in two triangles that share all of
+/// their vertices.
+///
+/// The collapse must also not flip or degenerate any of the triangles that
+/// remain around the collapsed edge.
+fn can_collapse(
+    [a, b]: [Index; 2],
+    position: Point<3>,
+    positions: &[Point<3>],
+    triangles: &[Option<[Index; 3]>],
+    triangles_at_vertex: &[BTreeSet<usize>],
+) -> bool {
+    let shared = triangles_at_vertex[a as usize]
+        .intersection(&triangles_at_vertex[b as usize])
+        .filter_map(|&t| triangles[t])
+        .collect::<Vec<_>>();
+    if shared.len() != 2 {
+        return false;
+    }
+
+    let opposite = shared
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|&index| index != a && index != b)
+        .collect::<BTreeSet<_>>();
+    let common = neighbors(a, triangles, triangles_at_vertex)
+        .intersection(&neighbors(b, triangles, triangles_at_vertex))
+        .copied()
+        .collect::<BTreeSet<_>>();
+    if common != opposite {
+        return false;
+    }
+
+    let mut remaining = BTreeSet::new();
+
+    for vertex in [a, b] {
+        for &t in &triangles_at_vertex[vertex as usize] {
+            let Some(triangle) = triangles[t] else {
+                continue;
+            };
+            if triangle.contains(&a) && triangle.contains(&b) {
+                continue;
+            }
+
+            let before = triangle.map(|index| positions[index as usize]);
+            let after = triangle.map(|index| {
+                if index == a || index == b {
+                    position
+                } else {
+                    positions[index as usize]
+                }
+            });
+
+            let [normal_before, normal_after] =
+                [before, after].map(|[p0, p1, p2]| (p1 - p0).cross(&(p2 - p0)));
+            if normal_after.magnitude() == Scalar::ZERO
+                || normal_before.dot(&normal_after) <= Scalar::ZERO
+            {
+                return false;
+            }
+
+            let mut indices =
+                triangle.map(|index| if index == b { a } else { index });
+            indices.sort();
+            if !remaining.insert(indices) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use super::Mesh;
+
+    fn sphere(tolerance: f64, segments: u32) -> Mesh {
+        let mut mesh = Mesh::new(tolerance);
+        let rings = segments / 2;
+
+        let point = |i: u32, j: u32| {
+            let (sin_azimuth, cos_azimuth) =
+                (Scalar::TAU * i as f64 / segments as f64).sin_cos();
+            let (sin_polar, cos_polar) =
+                (Scalar::PI * j as f64 / rings as f64).sin_cos();
+
+            Point::from([
+                sin_polar * cos_azimuth,
+                sin_polar * sin_azimuth,
+                cos_polar,
+            ])
+        };
+
+        for i in 0..segments {
+            for j in 0..rings {
+                let [a, b, c, d] =
+                    [(i, j), (i, j + 1), (i + 1, j + 1), (i + 1, j)]
+                        .map(|(i, j)| point(i, j));
+
+                mesh.push_triangle([a, b, c]);
+                mesh.push_triangle([a, c, d]);
+            }
+        }
+
+        mesh
+    }
+
+    fn max_deviation_from_unit_sphere(mesh: &Mesh) -> Scalar {
+        mesh.triangles()
+            .flat_map(|[a, b, c]| {
+                let center = Point {
+                    coords: (a.coords + b.coords + c.coords) / 3.,
+                };
+                [a, b, c, center]
+            })
+            .map(|point| (point.coords.magnitude() - Scalar::ONE).abs())
+            .max()
+            .unwrap_or(Scalar::ZERO)
+    }
+
+    #[test]
+    fn push_vertex_merges_coincident_vertices() {
+        let mut mesh = Mesh::new(0.001);
+
+        let a = mesh.push_vertex([0., 0., 0.]);
+        let b = mesh.push_vertex([1., 0., 0.]);
+        assert_ne!(a, b);
+
+        // Within tolerance, even across the boundary of a grid cell.
+        assert_eq!(mesh.push_vertex([0.0005, 0., 0.]), a);
+        assert_eq!(mesh.push_vertex([-0.0005, 0., 0.]), a);
+        assert_eq!(mesh.push_vertex([1., 0.0009, 0.]), b);
+
+        // Outside of tolerance.
+        assert_ne!(mesh.push_vertex([0.002, 0., 0.]), a);
+
+        assert_eq!(mesh.vertices().len(), 3);
+    }
+
+    #[test]
+    fn adjacent_triangles_share_vertices() {
+        let mut mesh = Mesh::new(1e-9);
+
+        mesh.push_triangle([[0., 0., 0.], [1., 0., 0.], [1., 1., 0.]]);
+        mesh.push_triangle([[0., 0., 0.], [1., 1., 0.], [0., 1., 0.]]);
+
+        assert_eq!(mesh.vertices().len(), 4);
+        assert_eq!(mesh.indices(), [[0, 1, 2], [0, 2, 3]]);
+        assert_eq!(
+            mesh.triangles().nth(1),
+            Some([[0., 0., 0.], [1., 1., 0.], [0., 1., 0.]].map(Point::from)),
+        );
+    }
+
+    #[test]
+    fn push_triangle_skips_degenerate_triangles() {
+        let mut mesh = Mesh::new(0.01);
+
+        let triangle = mesh.push_triangle([
+            [0., 0., 0.],
+            [1., 0., 0.],
+            [0.005, 0., 0.001],
+        ]);
+
+        assert_eq!(triangle, None);
+        assert!(mesh.indices().is_empty());
+    }
+
+    #[test]
+    fn compute_normals() {
+        let mut mesh = Mesh::new(1e-9);
+
+        mesh.push_triangle([[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]);
+        mesh.push_triangle([[0., 0., 0.], [0., 0., 1.], [1., 0., 0.]]);
+        assert_eq!(mesh.normals(), None);
+
+        mesh.compute_normals();
+        let normals = mesh.normals().unwrap();
+
+        assert_eq!(normals[2], Vector::from([0., 0., 1.]));
+        assert_eq!(normals[3], Vector::from([0., 1., 0.]));
+
+        // The shared vertices get the average of both normals.
+        let expected = Vector::from([0., 1., 1.]).normalize();
+        assert!((normals[0] - expected).magnitude() < Scalar::from(1e-12));
+    }
+
+    #[test]
+    fn hausdorff_distance_shrinks_with_refinement() {
+        let reference = sphere(1e-9, 64);
+
+        let distances = [8, 16, 32].map(|segments| {
+            sphere(1e-9, segments).hausdorff_distance(&reference)
+        });
+
+        for pair in distances.windows(2) {
+            let [coarse, fine] = [pair[0], pair[1]];
+            assert!(fine < coarse, "{fine} is not less than {coarse}");
+        }
+        assert!(distances[2] < Scalar::from(0.01));
+
+        assert!(reference.hausdorff_distance(&reference) < Scalar::from(1e-12));
+    }
+
+    #[test]
+    fn simplify_sphere() {
+        let mut mesh = sphere(1e-9, 128);
+        let num_triangles = mesh.indices().len();
+        let tessellation_error = max_deviation_from_unit_sphere(&mesh);
+
+        let target_error = 0.01;
+        mesh.simplify(target_error);
+
+        assert!(mesh.indices().len() * 5 < num_triangles);
+        assert!(
+            max_deviation_from_unit_sphere(&mesh)
+                <= tessellation_error + target_error
+        );
+
+        // The sphere is closed, so every edge must still be shared by exactly
+        // two triangles, in opposite directions.
+        let mut edges = std::collections::BTreeSet::new();
+        for &[a, b, c] in mesh.indices() {
+            for edge in [[a, b], [b, c], [c, a]] {
+                assert!(edges.insert(edge), "Duplicate edge {edge:?}");
+            }
+        }
+        for &[a, b] in &edges {
+            assert!(edges.contains(&[b, a]), "Boundary edge {:?}", [a, b]);
+        }
+
+        let num_vertices = mesh.vertices().len();
+        assert!(
+            mesh.indices()
+                .iter()
+                .flatten()
+                .all(|&i| (i as usize) < num_vertices)
+        );
+    }
+
+    #[test]
+    fn simplify_preserves_boundary() {
+        // A flat grid of triangles. Interior vertices can be removed without
+        // any error, but the boundary must stay.
+        let mut mesh = Mesh::new(1e-9);
+        let n = 8;
+        for i in 0..n {
+            for j in 0..n {
+                let [a, b, c, d] =
+                    [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)]
+                        .map(|(x, y)| [x as f64, y as f64, 0.]);
+
+                mesh.push_triangle([a, b, c]);
+                mesh.push_triangle([a, c, d]);
+            }
+        }
+
+        mesh.simplify(0.001);
+
+        // All boundary vertices are still there, in their original positions.
+        // Most interior vertices are gone.
+        let num_on_boundary = mesh
+            .vertices()
+            .iter()
+            .filter(|point| {
+                [point.x, point.y].into_iter().any(|coord| {
+                    coord == Scalar::ZERO || coord == Scalar::from(n as f64)
+                })
+            })
+            .count();
+        assert_eq!(num_on_boundary, 4 * n);
+        assert!(mesh.vertices().len() < 4 * n + (n - 1) * (n - 1) / 4);
+
+        let area = mesh
+            .triangles()
+            .map(|[a, b, c]| (b - a).cross(&(c - a)).magnitude() / 2.)
+            .fold(Scalar::ZERO, |sum, area| sum + area);
+        assert!(
+            (area - Scalar::from((n * n) as f64)).abs() < Scalar::from(1e-9)
+        );
+    }
+}
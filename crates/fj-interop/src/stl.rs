@@ -0,0 +1,333 @@
+use std::io::Read;
+
+use fj_math::{Point, Scalar, Vector};
+use thiserror::Error;
+
+use crate::{Mesh, Tolerance};
+
+/// # Import a mesh from the provided reader in the STL format
+///
+/// Both binary and ASCII STL are supported. Since binary files are allowed to
+/// start with `solid`, just like ASCII files, the format is detected like
+/// this: Input whose size matches the number of triangles in the binary header
+/// is binary. Other input is ASCII, if it starts with `solid`, or binary
+/// otherwise (which results in an error, as its size doesn't match).
+///
+/// Vertices are de-duplicated using [`Mesh::push_vertex`], meaning vertices
+/// whose distance is within `tolerance` are merged. Triangles that degenerate
+/// as a result are skipped.
+///
+/// The winding of each triangle is preserved, unless it disagrees with the
+/// normal of its facet. Such triangles are flipped. STL allows facets to have
+/// a zero normal, and those always keep their winding.
+pub fn import_stl(
+    mut read: impl Read,
+    tolerance: impl Into<Tolerance>,
+) -> Result<Mesh, StlImportError> {
+    let mut data = Vec::new();
+    read.read_to_end(&mut data)?;
+
+    let is_binary = binary_stl_size(&data) == Some(data.len());
+    let facets = if !is_binary && data.trim_ascii_start().starts_with(b"solid")
+    {
+        read_ascii_stl(&data)?
+    } else {
+        read_binary_stl(&data)?
+    };
+
+    let mut mesh = Mesh::new(tolerance);
+
+    for (i, StlFacet { normal, vertices }) in facets.into_iter().enumerate() {
+        let to_scalars = |coords: [f64; 3]| {
+            if coords.iter().all(|coord| coord.is_finite()) {
+                Ok(coords.map(Scalar::from))
+            } else {
+                Err(StlImportError::NonFiniteNumber { facet: i })
+            }
+        };
+
+        let normal = Vector::from(to_scalars(normal)?);
+        let [a, b, c] = [
+            to_scalars(vertices[0])?,
+            to_scalars(vertices[1])?,
+            to_scalars(vertices[2])?,
+        ]
+        .map(Point::from);
+
+        let winding = (b - a).cross(&(c - a));
+        let points = if winding.dot(&normal) < Scalar::ZERO {
+            [a, c, b]
+        } else {
+            [a, b, c]
+        };
+
+        mesh.push_triangle(points);
+    }
+
+    Ok(mesh)
+}
+
+/// # A facet, as read from an STL file
+struct StlFacet {
+    normal: [f64; 3],
+    vertices: [[f64; 3]; 3],
+}
+
+const BINARY_STL_HEADER_SIZE: usize = 84;
+const BINARY_STL_FACET_SIZE: usize = 50;
+
+/// # Compute the size of a binary STL file, according to its header
+fn binary_stl_size(data: &[u8]) -> Option<usize> {
+    let num_triangles = data.get(80..BINARY_STL_HEADER_SIZE)?;
+    let num_triangles = u32::from_le_bytes(num_triangles.try_into().ok()?);
+
+    (num_triangles as usize)
+        .checked_mul(BINARY_STL_FACET_SIZE)?
+        .checked_add(BINARY_STL_HEADER_SIZE)
+}
+
+fn read_binary_stl(data: &[u8]) -> Result<Vec<StlFacet>, StlImportError> {
+    let expected = binary_stl_size(data).unwrap_or(BINARY_STL_HEADER_SIZE);
+    if data.len() < expected {
+        return Err(StlImportError::UnexpectedEof {
+            expected,
+            found: data.len(),
+        });
+    }
+
+    let facets = data[BINARY_STL_HEADER_SIZE..expected]
+        .chunks_exact(BINARY_STL_FACET_SIZE)
+        .map(|facet| {
+            let vector = |i: usize| {
+                [0, 1, 2].map(|j| {
+                    let offset = (i * 3 + j) * 4;
+                    let bytes = [0, 1, 2, 3].map(|k| facet[offset + k]);
+                    f64::from(f32::from_le_bytes(bytes))
+                })
+            };
+
+            // The last two bytes of each facet are the "attribute byte
+            // count", which is not used.
+            StlFacet {
+                normal: vector(0),
+                vertices: [vector(1), vector(2), vector(3)],
+            }
+        })
+        .collect();
+
+    Ok(facets)
+}
+
+fn read_ascii_stl(data: &[u8]) -> Result<Vec<StlFacet>, StlImportError> {
+    let text = std::str::from_utf8(data).map_err(|err| {
+        let valid = &data[..err.valid_up_to()];
+        StlImportError::Syntax {
+            line: valid.iter().filter(|&&byte| byte == b'\n').count() + 1,
+            message: "file is neither valid binary STL nor valid UTF-8"
+                .to_string(),
+        }
+    })?;
+
+    let mut tokens = AsciiStlTokens::new(text);
+    let mut facets = Vec::new();
+
+    loop {
+        let (line, token) = tokens.next("`facet` or `endsolid`")?;
+        match token {
+            "facet" => {}
+            // Anything after `endsolid`, like the name of the solid, is
+            // ignored.
+            "endsolid" => break,
+            token => {
+                return Err(StlImportError::Syntax {
+                    line,
+                    message: format!(
+                        "expected `facet` or `endsolid`, found `{token}`"
+                    ),
+                });
+            }
+        }
+
+        tokens.keyword("normal")?;
+        let normal = tokens.vector()?;
+        tokens.keyword("outer")?;
+        tokens.keyword("loop")?;
+
+        let mut vertices = [[0.; 3]; 3];
+        for vertex in &mut vertices {
+            tokens.keyword("vertex")?;
+            *vertex = tokens.vector()?;
+        }
+
+        tokens.keyword("endloop")?;
+        tokens.keyword("endfacet")?;
+
+        facets.push(StlFacet { normal, vertices });
+    }
+
+    Ok(facets)
+}
+
+/// # The whitespace-separated tokens of an ASCII STL file
+///
+/// Each token is returned with the number of the line it was found in, for use
+/// in error messages.
+struct AsciiStlTokens<'r> {
+    tokens: Box<dyn Iterator<Item = (usize, &'r str)> + 'r>,
+    num_lines: usize,
+}
+
+impl<'r> AsciiStlTokens<'r> {
+    fn new(text: &'r str) -> Self {
+        // The first line contains the keyword `solid`, optionally followed by
+        // a name, which we don't need.
+        let tokens = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line))
+            .skip_while(|(_, line)| line.trim().is_empty())
+            .skip(1)
+            .flat_map(|(line, text)| {
+                text.split_whitespace().map(move |token| (line, token))
+            });
+
+        Self {
+            tokens: Box::new(tokens),
+            num_lines: text.lines().count(),
+        }
+    }
+
+    fn next(
+        &mut self,
+        expected: &str,
+    ) -> Result<(usize, &'r str), StlImportError> {
+        self.tokens.next().ok_or_else(|| StlImportError::Syntax {
+            line: self.num_lines,
+            message: format!("unexpected end of file, expected {expected}"),
+        })
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Result<(), StlImportError> {
+        let (line, token) = self.next(&format!("`{keyword}`"))?;
+
+        if token != keyword {
+            return Err(StlImportError::Syntax {
+                line,
+                message: format!("expected `{keyword}`, found `{token}`"),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn vector(&mut self) -> Result<[f64; 3], StlImportError> {
+        let mut coords = [0.; 3];
+
+        for coord in &mut coords {
+            let (line, token) = self.next("a number")?;
+            *coord = token.parse().map_err(|_| StlImportError::Syntax {
+                line,
+                message: format!("expected a number, found `{token}`"),
+            })?;
+        }
+
+        Ok(coords)
+    }
+}
+
+/// An error that can occur while importing from STL
+#[derive(Debug, Error)]
+pub enum StlImportError {
+    /// I/O error whilst importing from file
+    #[error("I/O error whilst importing from file")]
+    Io(#[from] std::io::Error),
+
+    /// Binary file ended before all triangles were read
+    #[error("unexpected end of file: expected {expected} bytes, found {found}")]
+    UnexpectedEof {
+        /// The size of the file, according to its header
+        expected: usize,
+
+        /// The actual size of the file
+        found: usize,
+    },
+
+    /// ASCII file is malformed
+    #[error("syntax error in line {line}: {message}")]
+    Syntax {
+        /// The line in which the error was found, starting at `1`
+        line: usize,
+
+        /// A description of the error
+        message: String,
+    },
+
+    /// A coordinate is infinite or NaN
+    #[error("facet {facet} contains a number that is infinite or NaN")]
+    NonFiniteNumber {
+        /// The index of the facet that contains the number
+        facet: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use super::{StlImportError, import_stl};
+
+    #[test]
+    fn import_stl_flips_triangles_against_their_normal()
+    -> Result<(), StlImportError> {
+        let stl = "\
+            solid triangle
+              facet normal 0 0 -1
+                outer loop
+                  vertex 0 0 0
+                  vertex 1 0 0
+                  vertex 0 1 0
+                endloop
+              endfacet
+            endsolid triangle
+        ";
+
+        let mesh = import_stl(stl.as_bytes(), 1e-6)?;
+        let [a, b, c] = mesh.triangles().next().unwrap();
+        assert_eq!((b - a).cross(&(c - a)).z, Scalar::from(-1.));
+
+        Ok(())
+    }
+
+    #[test]
+    fn import_malformed_stl() {
+        // A binary STL file with 12 triangles, but 10 bytes short.
+        let num_triangles = 12u32;
+        let mut buffer = vec![0; 84 + 50 * num_triangles as usize];
+        buffer[80..84].copy_from_slice(&num_triangles.to_le_bytes());
+        buffer.truncate(buffer.len() - 10);
+
+        let result = import_stl(buffer.as_slice(), 1e-6);
+        assert!(matches!(
+            result,
+            Err(StlImportError::UnexpectedEof {
+                expected: 684,
+                found: 674,
+            })
+        ));
+
+        let stl =
+            "solid\n  facet normal 0 0 1\n    outer loop\n      vertex 0 0\n";
+        let result = import_stl(stl.as_bytes(), 1e-6);
+        assert!(matches!(
+            result,
+            Err(StlImportError::Syntax { line: 4, .. })
+        ));
+
+        let stl = "solid\n  facet normal 0 0 1\n";
+        let result = import_stl(stl.as_bytes(), 1e-6);
+        assert!(matches!(
+            result,
+            Err(StlImportError::Syntax { line: 2, .. })
+        ));
+    }
+}
@@ -40,6 +40,21 @@ impl Tolerance {
         Ok(Self(scalar))
     }
 
+    /// Resolve a relative tolerance into an absolute one
+    ///
+    /// Interprets `self` as a fraction of the provided extent, and returns the
+    /// resulting absolute tolerance. The extent is typically the length of the
+    /// diagonal of a model's bounding box, which makes the tolerance scale with
+    /// the model.
+    ///
+    /// Returns an error, if the extent is not larger than zero.
+    pub fn relative_to(
+        self,
+        extent: impl Into<Scalar>,
+    ) -> Result<Self, InvalidTolerance> {
+        Self::from_scalar(self.0 * extent.into())
+    }
+
     /// Return the [`Scalar`] that defines the tolerance
     pub fn inner(&self) -> Scalar {
         self.0
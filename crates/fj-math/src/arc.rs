@@ -67,13 +67,16 @@ impl Arc {
         };
         let end_angle = {
             let from_center = p1 - center;
-            let offset = if more_than_half_turn {
-                Scalar::TAU
-            } else {
-                Scalar::ZERO
-            };
+            let end_angle = from_center.v.atan2(from_center.u);
+
+            // `atan2` returns an angle in the range `(-PI, PI]`, which doesn't
+            // necessarily match the direction and size of the arc. Add however
+            // many full turns are required, for the end angle to be `angle_rad`
+            // away from the start angle.
+            let full_turns =
+                ((start_angle + angle_rad - end_angle) / Scalar::TAU).round();
 
-            from_center.v.atan2(from_center.u) + offset
+            end_angle + Scalar::TAU * full_turns
         };
         Self {
             center,
@@ -124,6 +127,18 @@ mod tests {
             0_f64.to_radians(),
             270_f64.to_radians(),
         );
+        check_arc_calculation(
+            [1., 1.],
+            0.5,
+            180_f64.to_radians(),
+            270_f64.to_radians(),
+        );
+        check_arc_calculation(
+            [1., 1.],
+            0.5,
+            -90_f64.to_radians(),
+            -180_f64.to_radians(),
+        );
     }
 
     fn check_arc_calculation(
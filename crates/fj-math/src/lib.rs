@@ -40,6 +40,7 @@ mod line;
 mod line_segment;
 mod point;
 mod poly_chain;
+mod polygon;
 mod scalar;
 mod transform;
 mod triangle;
@@ -55,6 +56,7 @@ pub use self::{
     line_segment::LineSegment,
     point::Point,
     poly_chain::PolyChain,
+    polygon::Polygon,
     scalar::{Scalar, Sign},
     transform::Transform,
     triangle::{Triangle, Winding},
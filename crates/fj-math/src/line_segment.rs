@@ -25,6 +25,28 @@ impl<const D: usize> LineSegment<D> {
         a + (b - a) / Scalar::TWO
     }
 
+    /// # Compute the distance between the segment and the provided point
+    ///
+    /// This is the distance to the nearest point on the segment, which might
+    /// be one of its end points. If both end points are the same, this is the
+    /// distance to that point.
+    pub fn distance_to_point(&self, point: impl Into<Point<D>>) -> Scalar {
+        let point = point.into();
+
+        let [a, b] = self.points;
+        let ab = b - a;
+        let length_squared = ab.dot(&ab);
+
+        if length_squared == Scalar::ZERO {
+            return point.distance_to(&a);
+        }
+
+        let t = ((point - a).dot(&ab) / length_squared)
+            .clamp(Scalar::ZERO, Scalar::ONE);
+
+        point.distance_to(&(a + ab * t))
+    }
+
     /// Create a segment with the same points in the opposite order
     pub fn reverse(mut self) -> Self {
         self.points.reverse();
@@ -73,3 +95,27 @@ impl<const D: usize> fmt::Debug for LineSegment<D> {
         write!(f, "[{:?} -> {:?}]", self.points[0], self.points[1])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar};
+
+    use super::LineSegment;
+
+    #[test]
+    fn distance_to_point() {
+        let segment = LineSegment::from([[0., 0.], [2., 0.]]);
+
+        // The nearest point is on the segment, or one of its end points.
+        assert_eq!(segment.distance_to_point([1., 1.]), Scalar::ONE);
+        assert_eq!(segment.distance_to_point([1., 0.]), Scalar::ZERO);
+        assert_eq!(segment.distance_to_point([5., 4.]), Scalar::from(5.));
+        assert_eq!(segment.distance_to_point([-3., -4.]), Scalar::from(5.));
+
+        let degenerate = LineSegment::from([[1., 1., 1.]; 2]);
+        assert_eq!(
+            degenerate.distance_to_point(Point::from([1., 1., 3.])),
+            Scalar::TWO,
+        );
+    }
+}
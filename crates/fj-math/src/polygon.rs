@@ -0,0 +1,208 @@
+use crate::{LineSegment, Point, Scalar, Winding};
+
+/// # A polygon in 2D
+///
+/// The polygon is defined by its vertices. It is implicitly closed, meaning its
+/// last vertex is connected to its first one. Repeating the first vertex at the
+/// end is allowed, and doesn't change the results of any of the methods.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Polygon {
+    points: Vec<Point<2>>,
+}
+
+impl Polygon {
+    /// # Construct a polygon from its vertices
+    pub fn from_points(
+        points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> Self {
+        Self {
+            points: points.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// # Access the vertices of the polygon
+    pub fn points(&self) -> &[Point<2>] {
+        &self.points
+    }
+
+    /// # Compute the signed area of the polygon
+    ///
+    /// Uses the shoelace formula. The area is positive, if the polygon is wound
+    /// counter-clockwise, and negative, if it is wound clockwise. If the
+    /// polygon intersects itself, the areas of its parts with opposite windings
+    /// cancel each other out.
+    pub fn signed_area(&self) -> Scalar {
+        let double_area = self
+            .points
+            .iter()
+            .zip(self.points.iter().cycle().skip(1))
+            .fold(Scalar::ZERO, |area, (a, b)| {
+                area + a.coords.cross2d(&b.coords)
+            });
+
+        double_area / 2.
+    }
+
+    /// # Determine the winding of the polygon
+    ///
+    /// The winding is derived from the sign of [`Polygon::signed_area`].
+    /// Returns `None`, if that is zero.
+    pub fn winding(&self) -> Option<Winding> {
+        let signed_area = self.signed_area();
+
+        if signed_area > Scalar::ZERO {
+            return Some(Winding::Ccw);
+        }
+        if signed_area < Scalar::ZERO {
+            return Some(Winding::Cw);
+        }
+
+        None
+    }
+
+    /// # Determine whether a point is on the boundary of the polygon
+    ///
+    /// Points that are exactly on the boundary, as determined using exact
+    /// predicates, and points whose distance to the boundary is at most
+    /// `tolerance`, are considered to be on it.
+    pub fn is_on_boundary(
+        &self,
+        point: impl Into<Point<2>>,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        let point = point.into();
+        let tolerance = tolerance.into();
+
+        self.edges().any(|edge| {
+            let [a, b] = edge.points;
+
+            let is_exactly_on_edge = orient2d([a, b], point) == 0.
+                && (0..2).all(|i| {
+                    let [a, b, p] =
+                        [a, b, point].map(|point| point.coords.components[i]);
+                    a.min(b) <= p && p <= a.max(b)
+                });
+
+            is_exactly_on_edge || edge.distance_to_point(point) <= tolerance
+        })
+    }
+
+    /// # Determine whether the polygon contains a point
+    ///
+    /// Uses the even-odd rule: A ray is cast from the point in positive u
+    /// direction, and the point is contained, if the ray crosses the boundary
+    /// an odd number of times. The crossings are determined using exact
+    /// predicates, so vertices and edges that the ray passes through are
+    /// handled correctly.
+    ///
+    /// Points on the boundary are considered to be contained. See
+    /// [`Polygon::is_on_boundary`] for the meaning of `tolerance`.
+    pub fn contains_point(
+        &self,
+        point: impl Into<Point<2>>,
+        tolerance: impl Into<Scalar>,
+    ) -> bool {
+        let point = point.into();
+
+        if self.is_on_boundary(point, tolerance) {
+            return true;
+        }
+
+        let mut is_inside = false;
+
+        for edge in self.edges() {
+            let [a, b] = edge.points;
+            let [lower, upper] = if a.v <= b.v { [a, b] } else { [b, a] };
+
+            // Each edge includes its lower vertex, but not its upper one. That
+            // way, a ray that passes through a vertex is counted once, if it
+            // crosses the boundary there, and not at all or twice, if it just
+            // touches it. Horizontal edges are never crossed.
+            let straddles_ray = lower.v <= point.v && point.v < upper.v;
+
+            // The ray crosses the edge, if the point is left of the edge, when
+            // looking from its lower to its upper vertex.
+            if straddles_ray && orient2d([lower, upper], point) > 0. {
+                is_inside = !is_inside;
+            }
+        }
+
+        is_inside
+    }
+
+    fn edges(&self) -> impl Iterator<Item = LineSegment<2>> + '_ {
+        self.points
+            .iter()
+            .zip(self.points.iter().cycle().skip(1))
+            .map(|(&a, &b)| LineSegment::from([a, b]))
+    }
+}
+
+/// # Compute the orientation of a point relative to a line, exactly
+///
+/// The result is positive, if the point is left of the line that goes through
+/// `a` and `b`, negative if it's right of it, and zero if it's on it.
+fn orient2d([a, b]: [Point<2>; 2], point: Point<2>) -> f64 {
+    let [a, b, point] = [a, b, point].map(|point| robust::Coord {
+        x: point.u.into_f64(),
+        y: point.v.into_f64(),
+    });
+
+    robust::orient2d(a, b, point)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Scalar, Winding};
+
+    use super::Polygon;
+
+    #[test]
+    fn signed_area_and_winding() {
+        let square = [[0., 0.], [2., 0.], [2., 2.], [0., 2.]];
+
+        let ccw = Polygon::from_points(square);
+        assert_eq!(ccw.signed_area(), Scalar::from(4.));
+        assert_eq!(ccw.winding(), Some(Winding::Ccw));
+
+        let mut points = square;
+        points.reverse();
+        let cw = Polygon::from_points(points);
+        assert_eq!(cw.signed_area(), Scalar::from(-4.));
+        assert_eq!(cw.winding(), Some(Winding::Cw));
+
+        let closed = Polygon::from_points(square.into_iter().chain([[0., 0.]]));
+        assert_eq!(closed.signed_area(), Scalar::from(4.));
+
+        let degenerate = Polygon::from_points([[0., 0.], [1., 1.], [2., 2.]]);
+        assert_eq!(degenerate.winding(), None);
+    }
+
+    #[test]
+    fn contains_point() {
+        let polygon = Polygon::from_points([
+            [0., 0.],
+            [2., 0.],
+            [2., 2.],
+            [1., 1.],
+            [0., 2.],
+        ]);
+
+        assert!(polygon.contains_point([0.5, 0.5], 0.));
+        assert!(!polygon.contains_point([1., 1.5], 0.));
+        assert!(!polygon.contains_point([3., 1.], 0.));
+
+        // The ray passes through the vertex at `[2, 2]`, without crossing the
+        // boundary there.
+        assert!(!polygon.contains_point([-1., 2.], 0.));
+
+        // Points on the boundary are contained, and with a tolerance, so are
+        // points close to it.
+        assert!(polygon.contains_point([1., 0.], 0.));
+        assert!(polygon.contains_point([1.5, 1.5], 0.));
+        assert!(!polygon.contains_point([2.1, 1.], 0.));
+        assert!(polygon.contains_point([2.1, 1.], 0.2));
+        assert!(polygon.is_on_boundary([1.5, 1.5], 0.));
+        assert!(!polygon.is_on_boundary([0.5, 0.5], 0.));
+    }
+}
@@ -54,6 +54,32 @@ impl Transform {
         }
     }
 
+    /// Construct a reflection across a plane
+    ///
+    /// The plane is defined by a point on it and its normal. The normal doesn't
+    /// need to be normalized.
+    pub fn reflection(
+        point: impl Into<Point<3>>,
+        normal: impl Into<Vector<3>>,
+    ) -> Self {
+        let point = point.into();
+        let normal = normal.into().normalize();
+
+        let n = normal.to_na();
+        let linear = nalgebra::Matrix3::identity() - n * n.transpose() * 2.;
+
+        // The reflection of the origin. Translating by this, after applying
+        // the linear part, leaves the points on the plane in place.
+        let offset = normal * point.coords.dot(&normal) * 2.;
+
+        Self::translation(offset)
+            * Self {
+                inner: nalgebra::Transform::from_matrix_unchecked(
+                    linear.to_homogeneous(),
+                ),
+            }
+    }
+
     /// # Extract the "right" vector from the rotational component
     pub fn right(&self) -> Vector<3> {
         let d = self.data();
@@ -129,6 +155,36 @@ impl Transform {
         self.inner.matrix().data.as_slice()
     }
 
+    /// Indicate whether this transform is a rigid motion
+    ///
+    /// A rigid motion is a combination of a rotation and a translation. It
+    /// preserves distances, angles, and orientation. Reflections preserve
+    /// distances and angles too, but reverse orientation, so they don't count.
+    ///
+    /// Since the components of a transform are subject to floating point
+    /// inaccuracies, they are compared using a small, fixed epsilon value.
+    pub fn is_rigid(&self) -> bool {
+        const EPSILON: f64 = 1e-12;
+
+        let matrix = self.inner.matrix();
+
+        let linear = matrix.fixed_view::<3, 3>(0, 0);
+        let is_orthonormal = (linear.transpose() * linear).relative_eq(
+            &nalgebra::Matrix3::identity(),
+            EPSILON,
+            EPSILON,
+        );
+        let preserves_orientation = linear.determinant() > 0.;
+
+        let is_affine = matrix.fixed_view::<1, 4>(3, 0).relative_eq(
+            &nalgebra::RowVector4::new(0., 0., 0., 1.),
+            EPSILON,
+            EPSILON,
+        );
+
+        is_orthonormal && preserves_orientation && is_affine
+    }
+
     /// Extract the rotation component of this transform
     pub fn extract_rotation(&self) -> Self {
         Self {
@@ -161,7 +217,7 @@ impl ops::Mul<Self> for Transform {
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Scalar, Vector};
+    use crate::{Point, Scalar, Vector};
 
     use super::Transform;
 
@@ -195,4 +251,36 @@ mod tests {
             epsilon = 1e-8,
         );
     }
+
+    #[test]
+    fn is_rigid() {
+        let rotation =
+            Transform::rotation(Vector::unit_z() * (Scalar::PI / 3.));
+        let translation = Transform::translation([1., 2., 3.]);
+
+        assert!(Transform::identity().is_rigid());
+        assert!(rotation.is_rigid());
+        assert!(translation.is_rigid());
+        assert!((translation * rotation).is_rigid());
+
+        assert!(!Transform::scale([2., 2., 2.]).is_rigid());
+        assert!(!Transform::scale([1., 1., 1.00001]).is_rigid());
+        assert!(!Transform::reflection([0., 0., 0.], [1., 0., 0.]).is_rigid());
+    }
+
+    #[test]
+    fn reflection() {
+        let reflection = Transform::reflection([1., 0., 0.], [2., 0., 0.]);
+
+        assert_abs_diff_eq!(
+            reflection.transform_point(&Point::from([3., 2., 1.])),
+            Point::from([-1., 2., 1.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            reflection.transform_vector(&Vector::from([1., 1., 0.])),
+            Vector::from([-1., 1., 0.]),
+            epsilon = Scalar::from(1e-8),
+        );
+    }
 }
@@ -1,13 +1,28 @@
-use std::{any::type_name_of_val, cmp::Ordering, fmt, ops::Deref, rc::Rc};
+use std::{any::type_name_of_val, cmp::Ordering, fmt, ops::Deref};
+
+// With the `sync` feature enabled, handles are backed by `Arc` instead of `Rc`.
+// That makes them `Send` and `Sync` (if the objects they refer to are), at the
+// cost of atomic reference counting.
+#[cfg(not(feature = "sync"))]
+use std::rc::{Rc as Pointer, Weak};
+#[cfg(feature = "sync")]
+use std::sync::{Arc as Pointer, Weak};
 
 pub struct Handle<T> {
-    inner: Rc<T>,
+    inner: Pointer<T>,
 }
 
 impl<T> Handle<T> {
     pub fn new(inner: T) -> Self {
         Self {
-            inner: Rc::new(inner),
+            inner: Pointer::new(inner),
+        }
+    }
+
+    #[allow(unused)] // not used by any topology yet
+    pub fn downgrade(&self) -> WeakHandle<T> {
+        WeakHandle {
+            inner: Pointer::downgrade(&self.inner),
         }
     }
 }
@@ -33,7 +48,7 @@ impl<T> Eq for Handle<T> {}
 impl<T> Ord for Handle<T> {
     fn cmp(&self, other: &Self) -> Ordering {
         let [self_ptr, other_ptr] =
-            [self, other].map(|handle| Rc::as_ptr(&handle.inner));
+            [self, other].map(|handle| Pointer::as_ptr(&handle.inner));
 
         self_ptr.cmp(&other_ptr)
     }
@@ -41,7 +56,7 @@ impl<T> Ord for Handle<T> {
 
 impl<T> PartialEq for Handle<T> {
     fn eq(&self, other: &Self) -> bool {
-        Rc::ptr_eq(&self.inner, &other.inner)
+        Pointer::ptr_eq(&self.inner, &other.inner)
     }
 }
 
@@ -57,7 +72,7 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let type_name = short_type_name_of_val(self);
-        let address = Rc::as_ptr(&self.inner);
+        let address = Pointer::as_ptr(&self.inner);
         let object = &self.inner;
 
         write!(f, "{type_name}: {address:?} => ")?;
@@ -72,6 +87,67 @@ where
     }
 }
 
+/// # A weak reference to an object
+///
+/// Doesn't keep the object alive, which makes it suitable for back-references
+/// (like from a half-edge to the cycle it belongs to), which would otherwise
+/// result in a reference cycle.
+///
+/// Compares by identity, just like [`Handle`]. Two `WeakHandle`s are equal, if
+/// they refer to the same object, and their ordering is consistent with that of
+/// the [`Handle`]s they were created from.
+#[allow(unused)] // not used by any topology yet
+pub struct WeakHandle<T> {
+    inner: Weak<T>,
+}
+
+impl<T> WeakHandle<T> {
+    #[allow(unused)] // not used by any topology yet
+    pub fn upgrade(&self) -> Option<Handle<T>> {
+        self.inner.upgrade().map(|inner| Handle { inner })
+    }
+}
+
+impl<T> Clone for WeakHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Eq for WeakHandle<T> {}
+
+impl<T> Ord for WeakHandle<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let [self_ptr, other_ptr] =
+            [self, other].map(|handle| Weak::as_ptr(&handle.inner));
+
+        self_ptr.cmp(&other_ptr)
+    }
+}
+
+impl<T> PartialEq for WeakHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Weak::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T> PartialOrd for WeakHandle<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> fmt::Debug for WeakHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let type_name = short_type_name_of_val(self);
+        let address = Weak::as_ptr(&self.inner);
+
+        write!(f, "{type_name}: {address:?}")
+    }
+}
+
 fn short_type_name_of_val<T>(val: &T) -> String {
     let full_name = type_name_of_val(val);
 
@@ -79,8 +155,8 @@ fn short_type_name_of_val<T>(val: &T) -> String {
         [full_name.find("<"), full_name.rfind(">")].map(|maybe_pos| {
             let Some(pos) = maybe_pos else {
                 unreachable!(
-                    "Only using this function for `Handle`, which has a type \
-                    parameter."
+                    "Only using this function for `Handle` and `WeakHandle`, \
+                    which have a type parameter."
                 );
             };
 
@@ -100,3 +176,84 @@ fn shorten_type_name(name: &str) -> &str {
         .map(|(_, short)| short)
         .unwrap_or(name)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::{Handle, WeakHandle};
+
+    #[test]
+    fn weak_handle_compares_like_handle() {
+        let [a, b] = [0, 0].map(Handle::new);
+        let [weak_a, weak_b] = [&a, &b].map(|handle| handle.downgrade());
+
+        assert_eq!(weak_a, a.downgrade());
+        assert_ne!(weak_a, weak_b);
+        assert_eq!(weak_a.cmp(&weak_b), a.cmp(&b));
+    }
+
+    #[test]
+    fn weak_handle_breaks_reference_cycle() {
+        struct Parent {
+            children: RefCell<Vec<Handle<Child>>>,
+        }
+
+        struct Child {
+            parent: WeakHandle<Parent>,
+            num_dropped: Rc<RefCell<usize>>,
+        }
+
+        impl Drop for Child {
+            fn drop(&mut self) {
+                *self.num_dropped.borrow_mut() += 1;
+            }
+        }
+
+        let num_dropped = Rc::new(RefCell::new(0));
+
+        let parent = Handle::new(Parent {
+            children: RefCell::new(Vec::new()),
+        });
+        for _ in 0..2 {
+            let child = Handle::new(Child {
+                parent: parent.downgrade(),
+                num_dropped: num_dropped.clone(),
+            });
+            parent.children.borrow_mut().push(child);
+        }
+
+        let child = parent.children.borrow()[0].clone();
+        assert!(child.parent.upgrade() == Some(parent.clone()));
+
+        let weak_parent = parent.downgrade();
+        drop(parent);
+
+        // The parent is gone, even though its children refer back to it. The
+        // child we kept around is still alive, but the other one was dropped
+        // with the parent.
+        assert!(weak_parent.upgrade().is_none());
+        assert!(child.parent.upgrade().is_none());
+        assert_eq!(*num_dropped.borrow(), 1);
+
+        drop(child);
+        assert_eq!(*num_dropped.borrow(), 2);
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn handle_can_be_shared_between_threads() {
+        let handle = Handle::new(vec![1, 2, 3]);
+
+        let threads = (0..4)
+            .map(|_| {
+                let handle = handle.clone();
+                std::thread::spawn(move || handle.iter().sum::<i32>())
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), 6);
+        }
+    }
+}
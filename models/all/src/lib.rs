@@ -34,7 +34,8 @@ pub fn model(core: &mut fj::core::Core) -> Solid {
 
         let model = model
             .translate(offset * f, core)
-            .rotate(axis * angle_rad * f, core);
+            .and_then(|model| model.rotate(axis * angle_rad * f, core))
+            .expect("Models have geometry");
 
         all = all.merge(&model, core);
     }